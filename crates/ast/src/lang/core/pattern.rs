@@ -274,6 +274,8 @@ pub fn to_pattern2<'a>(
 
         OpaqueRef(..) => internal_error!("opaques not implemented"),
 
+        Range(..) => internal_error!("range patterns not implemented"),
+
         Apply(tag, patterns) => {
             let can_patterns = PoolVec::with_capacity(patterns.len() as u32, env.pool);
             for (loc_pattern, node_id) in (*patterns).iter().zip(can_patterns.iter_node_ids()) {
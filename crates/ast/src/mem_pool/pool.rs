@@ -86,9 +86,21 @@ pub struct Pool {
     pub(super) nodes: *mut [MaybeUninit<u8>; NODE_BYTES],
     num_nodes: u32,
     capacity: u32,
+    journal: Option<PoolChanges>,
     // free_1node_slots: Vec<NodeId<T>>,
 }
 
+/// Nodes of a [Pool] that were overwritten, together with their contents from before, e.g.
+/// the changes of an edit in the editor. Swapping them with the pool's nodes undoes the
+/// changes, and swapping them again redoes them.
+#[derive(Debug, Default)]
+pub struct PoolChanges {
+    // Nodes from this index on were added after the changes started. They are not logged,
+    // because nothing from before the changes can point to them.
+    first_added: u32,
+    nodes: Vec<(u32, [MaybeUninit<u8>; NODE_BYTES])>,
+}
+
 impl Pool {
     pub fn with_capacity(nodes: u32) -> Self {
         // round up number of nodes requested to nearest page size in bytes
@@ -143,6 +155,7 @@ impl Pool {
             nodes,
             num_nodes: 0,
             capacity,
+            journal: None,
         }
     }
 
@@ -192,6 +205,8 @@ impl Pool {
     }
 
     pub fn get_mut<T>(&mut self, node_id: NodeId<T>) -> &mut T {
+        self.log_overwrite(node_id.index, 1);
+
         unsafe {
             let node_ptr = self.get_ptr(node_id) as *mut T;
 
@@ -200,6 +215,8 @@ impl Pool {
     }
 
     pub fn set<T>(&mut self, node_id: NodeId<T>, element: T) {
+        self.log_overwrite(node_id.index, 1);
+
         unsafe {
             let node_ptr = self.get_ptr(node_id);
 
@@ -216,6 +233,43 @@ impl Pool {
         node_offset as *mut MaybeUninit<T>
     }
 
+    /// From now on, keep the contents of every node from before it gets overwritten,
+    /// until [Pool::end_journal] returns them.
+    pub fn start_journal(&mut self) {
+        debug_assert!(self.journal.is_none(), "the pool is already journaling");
+
+        self.journal = Some(PoolChanges {
+            first_added: self.num_nodes,
+            nodes: Vec::new(),
+        });
+    }
+
+    pub fn end_journal(&mut self) -> PoolChanges {
+        self.journal.take().unwrap_or_default()
+    }
+
+    /// Puts the logged contents of the changed nodes back into the pool, and keeps the
+    /// contents they had instead, so that swapping again reverses this.
+    pub fn swap_changes(&mut self, changes: &mut PoolChanges) {
+        for (index, contents) in changes.nodes.iter_mut() {
+            unsafe { std::ptr::swap(self.nodes.offset(*index as isize), contents) };
+        }
+    }
+
+    /// Must be called before the `len` nodes starting at `index` are written to,
+    /// so the journal can keep their contents.
+    pub(super) fn log_overwrite(&mut self, index: u32, len: u32) {
+        if let Some(journal) = &mut self.journal {
+            for index in index..(index + len).min(journal.first_added) {
+                if !journal.nodes.iter().any(|(logged, _)| *logged == index) {
+                    let contents = unsafe { *self.nodes.offset(index as isize) };
+
+                    journal.nodes.push((index, contents));
+                }
+            }
+        }
+    }
+
     // A node is available iff its bytes are all zeroes
     #[allow(dead_code)]
     fn is_available<T>(&self, node_id: NodeId<T>) -> bool {
@@ -267,3 +321,28 @@ impl Drop for Pool {
         }
     }
 }
+
+#[test]
+fn pool_changes_undo_and_redo() {
+    let mut pool = Pool::with_capacity(1024);
+    let first = pool.add(1u64);
+    let second = pool.add(2u64);
+
+    pool.start_journal();
+
+    // Nodes added after the journal started are not logged, only overwritten ones are.
+    let added = pool.add(3u64);
+    pool.set(first, 10);
+    *pool.get_mut(first) += 1;
+    pool.set(added, 30);
+
+    let mut changes = pool.end_journal();
+
+    assert_eq!(changes.nodes.len(), 1);
+
+    pool.swap_changes(&mut changes);
+    assert_eq!((pool[first], pool[second]), (1, 2));
+
+    pool.swap_changes(&mut changes);
+    assert_eq!((pool[first], pool[second], pool[added]), (11, 2, 30));
+}
@@ -84,6 +84,8 @@ impl<'a, T: 'a + Sized> PoolVec<T> {
     }
 
     pub fn iter_mut(&self, pool: &'a mut Pool) -> impl ExactSizeIterator<Item = &'a mut T> {
+        pool.log_overwrite(self.first_node_id.index, self.len);
+
         self.pool_list_iter_mut(pool)
     }
 
@@ -122,6 +124,8 @@ impl<'a, T: 'a + Sized> PoolVec<T> {
     }
 
     pub fn free<S>(self, pool: &'a mut Pool) {
+        pool.log_overwrite(self.first_node_id.index, self.len);
+
         // zero out the memory
         unsafe {
             let index = self.first_node_id.index as isize;
@@ -1,5 +1,5 @@
 use bumpalo::Bump;
-use roc_load::{ExecutionMode, LoadConfig, LoadedModule, Threading};
+use roc_load::{ExecutionMode, LoadConfig, LoadedModule, OptLevel, Threading};
 use roc_target::TargetInfo;
 use std::path::Path;
 
@@ -11,6 +11,8 @@ pub fn load_module(src_file: &Path, threading: Threading) -> LoadedModule {
         render: roc_reporting::report::RenderTarget::ColorTerminal,
         threading,
         exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Development,
+        warning_config: Default::default(),
     };
 
     let arena = Bump::new();
@@ -1,3 +1,5 @@
+use crate::profile::Profile;
+use crate::watch::WatchedFiles;
 use bumpalo::Bump;
 use roc_build::{
     link::{link, preprocess_host_wasm32, rebuild_host, LinkType, LinkingStrategy},
@@ -12,9 +14,13 @@ use roc_load::{
 use roc_module::symbol::{Interns, ModuleId};
 use roc_mono::ir::OptLevel;
 use roc_reporting::report::RenderTarget;
+use roc_reporting::warnings::WarningConfig;
 use roc_target::TargetInfo;
 use std::time::{Duration, Instant};
-use std::{path::PathBuf, thread::JoinHandle};
+use std::{
+    path::{Path, PathBuf},
+    thread::JoinHandle,
+};
 use target_lexicon::Triple;
 use tempfile::Builder;
 
@@ -63,12 +69,15 @@ pub fn build_file<'a>(
     opt_level: OptLevel,
     emit_debug_info: bool,
     emit_timings: bool,
+    profile_path: Option<&Path>,
     link_type: LinkType,
     linking_strategy: LinkingStrategy,
     prebuilt: bool,
     threading: Threading,
     wasm_dev_stack_bytes: Option<u32>,
     order: BuildOrdering,
+    warning_config: &WarningConfig,
+    mut watched: Option<&mut WatchedFiles>,
 ) -> Result<BuiltFile, BuildFileError<'a>> {
     let compilation_start = Instant::now();
     let target_info = TargetInfo::from(target);
@@ -87,6 +96,8 @@ pub fn build_file<'a>(
         render: RenderTarget::ColorTerminal,
         threading,
         exec_mode,
+        opt_level,
+        warning_config: warning_config.clone(),
     };
     let load_result = roc_load::load_and_monomorphize(
         arena,
@@ -100,13 +111,35 @@ pub fn build_file<'a>(
             return Err(BuildFileError::LoadingProblem(problem))
         }
         Err(LoadMonomorphizedError::ErrorModule(module)) => {
+            if let Some(watched) = watched {
+                watched.watch_modules(module.sources.values().map(|(path, _)| path));
+            }
+
+            if let Some(profile_path) = profile_path {
+                let mut profile = Profile::default();
+                profile.add_module_timings(&module.interns, &module.timings);
+
+                write_profile(&profile, profile_path);
+            }
+
             return Err(BuildFileError::ErrorModule {
                 module,
                 total_time: compilation_start.elapsed(),
-            })
+            });
         }
     };
 
+    if let Some(watched) = watched.as_deref_mut() {
+        watched.watch_modules(loaded.sources.values().map(|(path, _)| path));
+    }
+
+    let mut profile = profile_path.map(|_| {
+        let mut profile = Profile::default();
+        profile.add_module_timings(&loaded.interns, &loaded.timings);
+
+        profile
+    });
+
     use target_lexicon::Architecture;
     let emit_wasm = matches!(target.architecture, Architecture::Wasm32);
 
@@ -179,6 +212,7 @@ pub fn build_file<'a>(
         host_input_path.with_file_name("preprocessedhost")
     };
 
+    let rebuild_start = Instant::now();
     let rebuild_thread = spawn_rebuild_thread(
         opt_level,
         linking_strategy,
@@ -246,7 +280,7 @@ pub fn build_file<'a>(
     // This only needs to be mutable for report_problems. This can't be done
     // inside a nested scope without causing a borrow error!
     let mut loaded = loaded;
-    let problems = program::report_problems_monomorphized(&mut loaded);
+    let problems = program::report_problems_monomorphized(&mut loaded, warning_config);
     let expectations = std::mem::take(&mut loaded.expectations);
     let loaded = loaded;
 
@@ -270,6 +304,7 @@ pub fn build_file<'a>(
         HostRebuildTiming::ConcurrentWithApp(rebuild_thread)
     };
 
+    let code_gen_start = Instant::now();
     let code_gen_timing = program::gen_from_mono_module(
         arena,
         loaded,
@@ -294,6 +329,17 @@ pub fn build_file<'a>(
     );
     report_timing(buf, "Emit .o file", code_gen_timing.emit_o_file);
 
+    if let Some(profile) = profile.as_mut() {
+        let code_gen_end = code_gen_start + code_gen_timing.code_gen;
+
+        profile.add_phase("generate code", code_gen_start, code_gen_end);
+        profile.add_phase(
+            "emit .o file",
+            code_gen_end,
+            code_gen_end + code_gen_timing.emit_o_file,
+        );
+    }
+
     let compilation_end = compilation_start.elapsed();
 
     let size = std::fs::metadata(&app_o_file)
@@ -318,16 +364,33 @@ pub fn build_file<'a>(
         );
     }
 
-    if let HostRebuildTiming::ConcurrentWithApp(thread) = rebuild_timing {
-        let rebuild_duration = thread.join().unwrap();
-        if emit_timings && !prebuilt {
-            println!(
-                "Finished rebuilding the platform in {} ms\n",
-                rebuild_duration
-            );
+    let rebuild_duration = match rebuild_timing {
+        HostRebuildTiming::BeforeApp(rebuild_duration) => rebuild_duration,
+        HostRebuildTiming::ConcurrentWithApp(thread) => {
+            let rebuild_duration = thread.join().unwrap();
+            if emit_timings && !prebuilt {
+                println!(
+                    "Finished rebuilding the platform in {} ms\n",
+                    rebuild_duration
+                );
+            }
+
+            rebuild_duration
+        }
+    };
+
+    if let Some(profile) = profile.as_mut() {
+        if !prebuilt {
+            let rebuild_end = rebuild_start + Duration::from_millis(rebuild_duration as u64);
+
+            profile.add_host_rebuild(rebuild_start, rebuild_end);
         }
     }
 
+    if let Some(watched) = watched {
+        watched.watch_host(host_input_path.parent().unwrap());
+    }
+
     // Step 2: link the prebuilt platform and compiled app
     let link_start = Instant::now();
     let problems = match (linking_strategy, link_type) {
@@ -376,6 +439,12 @@ pub fn build_file<'a>(
 
     let linking_time = link_start.elapsed();
 
+    if let (Some(mut profile), Some(profile_path)) = (profile, profile_path) {
+        profile.add_phase("link", link_start, link_start + linking_time);
+
+        write_profile(&profile, profile_path);
+    }
+
     if emit_timings {
         println!("Finished linking in {} ms\n", linking_time.as_millis());
     }
@@ -391,6 +460,12 @@ pub fn build_file<'a>(
     })
 }
 
+fn write_profile(profile: &Profile, path: &Path) {
+    if let Err(err) = profile.write(path) {
+        eprintln!("Could not write the profile to {}: {}", path.display(), err);
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn spawn_rebuild_thread(
     opt_level: OptLevel,
@@ -461,7 +536,10 @@ pub fn check_file(
     arena: &Bump,
     roc_file_path: PathBuf,
     emit_timings: bool,
+    profile_path: Option<&Path>,
     threading: Threading,
+    warning_config: &WarningConfig,
+    watched: Option<&mut WatchedFiles>,
 ) -> Result<(program::Problems, Duration), LoadingProblem> {
     let compilation_start = Instant::now();
 
@@ -478,10 +556,23 @@ pub fn check_file(
         render: RenderTarget::ColorTerminal,
         threading,
         exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Development,
+        warning_config: warning_config.clone(),
     };
     let mut loaded =
         roc_load::load_and_typecheck(arena, roc_file_path, subs_by_module, load_config)?;
 
+    if let Some(watched) = watched {
+        watched.watch_modules(loaded.sources.values().map(|(path, _)| path));
+    }
+
+    if let Some(profile_path) = profile_path {
+        let mut profile = Profile::default();
+        profile.add_module_timings(&loaded.interns, &loaded.timings);
+
+        write_profile(&profile, profile_path);
+    }
+
     let buf = &mut String::with_capacity(1024);
 
     let mut it = loaded.timings.iter().peekable();
@@ -526,7 +617,7 @@ pub fn check_file(
     }
 
     Ok((
-        program::report_problems_typechecked(&mut loaded),
+        program::report_problems_typechecked(&mut loaded, warning_config),
         compilation_end,
     ))
 }
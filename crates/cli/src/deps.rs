@@ -0,0 +1,386 @@
+//! `roc deps`: prints which modules of a program import which other modules.
+//!
+//! The import graph is found by loading only the headers of the modules, so this stays fast for
+//! large programs. Whether an import is used can only be known after canonicalization, so finding
+//! unused imports checks the whole program.
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+use bumpalo::Bump;
+use roc_collections::{MutMap, MutSet};
+use roc_load::{ExecutionMode, LoadConfig, LoadingProblem, ModuleGraph, Threading};
+use roc_module::ident::ModuleName;
+use roc_module::symbol::ModuleId;
+use roc_mono::ir::OptLevel;
+use roc_problem::can::Problem;
+use roc_reporting::report::RenderTarget;
+use roc_target::TargetInfo;
+use serde_json::json;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepsFormat {
+    Text,
+    Dot,
+    Json,
+}
+
+/// What `roc deps` should print
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepsQuery<'a> {
+    /// The whole import graph, optionally marking the imports that are not used
+    Graph {
+        format: DepsFormat,
+        find_unused: bool,
+    },
+    /// A shortest chain of imports that makes the first module depend on the second
+    Why(&'a str, &'a str),
+}
+
+/// A module of the program, as it is shown to the user
+#[derive(Debug)]
+struct Node {
+    label: String,
+    path: PathBuf,
+    exposes: Vec<String>,
+    /// Indices of the imported nodes, in the same order as the nodes
+    imports: Vec<usize>,
+}
+
+/// The modules of a program, without the builtin modules that every module imports implicitly
+#[derive(Debug)]
+struct Graph {
+    /// The root module comes first, the others are sorted by label
+    nodes: Vec<Node>,
+}
+
+impl Graph {
+    fn new(module_graph: ModuleGraph) -> Self {
+        let ModuleGraph { root_id, modules } = module_graph;
+
+        let mut modules: Vec<_> = modules
+            .into_iter()
+            .filter(|(module_id, _)| !module_id.is_builtin())
+            .map(|(module_id, module)| (module_id, label(&module.name), module))
+            .collect();
+
+        modules.sort_by(|(id1, label1, _), (id2, label2, _)| {
+            (*id1 != root_id, label1).cmp(&(*id2 != root_id, label2))
+        });
+
+        let index_of: MutMap<ModuleId, usize> = modules
+            .iter()
+            .enumerate()
+            .map(|(index, (module_id, _, _))| (*module_id, index))
+            .collect();
+
+        let nodes = modules
+            .into_iter()
+            .map(|(_, label, module)| {
+                let mut imports: Vec<usize> = module
+                    .imports
+                    .keys()
+                    .filter_map(|module_id| index_of.get(module_id).copied())
+                    .collect();
+                imports.sort_unstable();
+
+                Node {
+                    label,
+                    path: module.path,
+                    exposes: module.exposes,
+                    imports,
+                }
+            })
+            .collect();
+
+        Graph { nodes }
+    }
+
+    /// Finds a module by its label, e.g. `app` or `pf.Task`, or by the path of its file
+    fn find(&self, name: &str) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| node.label == name || node.path == Path::new(name))
+    }
+
+    /// A shortest chain of imports from one module to another, including both of them
+    fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        // the node every node was first reached from
+        let mut reached_from: MutMap<usize, usize> = MutMap::default();
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(index) = queue.pop_front() {
+            if index == to {
+                let mut path = vec![to];
+
+                while let Some(previous) = reached_from.get(path.last().unwrap()) {
+                    path.push(*previous);
+                }
+
+                path.reverse();
+
+                return Some(path);
+            }
+
+            for import in self.nodes[index].imports.iter() {
+                if *import != from && !reached_from.contains_key(import) {
+                    reached_from.insert(*import, index);
+                    queue.push_back(*import);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// The module name to show for a module. App and platform modules don't have a name of their
+/// own, so they are shown as `app` and `platform`, which can't clash with the name of a module.
+fn label(name: &str) -> String {
+    match name {
+        ModuleName::APP => "app".to_string(),
+        "" => "platform".to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// Prints the import graph of the program that the given module belongs to, or why one of its
+/// modules depends on another, and returns the exit code.
+pub fn deps<'a>(
+    arena: &'a Bump,
+    roc_file_path: PathBuf,
+    threading: Threading,
+    query: DepsQuery,
+) -> Result<i32, LoadingProblem<'a>> {
+    // only used for generating errors. We don't do code generation, so hardcoding should be fine
+    let target_info = TargetInfo::default_x86_64();
+
+    let load_config = LoadConfig {
+        target_info,
+        render: RenderTarget::ColorTerminal,
+        threading,
+        exec_mode: ExecutionMode::Headers,
+        opt_level: OptLevel::Development,
+        warning_config: Default::default(),
+    };
+    let graph = Graph::new(roc_load::load_module_graph(
+        arena,
+        roc_file_path.clone(),
+        load_config,
+    )?);
+
+    match query {
+        DepsQuery::Graph {
+            format,
+            find_unused,
+        } => {
+            let unused = if find_unused {
+                Some(find_unused_imports(
+                    arena,
+                    &graph,
+                    roc_file_path,
+                    threading,
+                )?)
+            } else {
+                None
+            };
+
+            match format {
+                DepsFormat::Text => print_text(&graph, unused.as_ref()),
+                DepsFormat::Dot => print_dot(&graph, unused.as_ref()),
+                DepsFormat::Json => print_json(&graph, unused.as_ref()),
+            }
+
+            // like `roc format --check`, finding problems fails the command
+            match unused {
+                Some(unused) if !unused.is_empty() => Ok(1),
+                _ => Ok(0),
+            }
+        }
+        DepsQuery::Why(from, to) => {
+            let find = |name| {
+                let found = graph.find(name);
+
+                if found.is_none() {
+                    eprintln!("There is no module named {} in this program.", name);
+                }
+
+                found
+            };
+
+            let (from, to) = match (find(from), find(to)) {
+                (Some(from), Some(to)) => (from, to),
+                _ => return Ok(1),
+            };
+
+            match graph.path(from, to) {
+                Some(path) => {
+                    let labels: Vec<&str> = path
+                        .iter()
+                        .map(|index| graph.nodes[*index].label.as_str())
+                        .collect();
+
+                    println!("{}", labels.join(" -> "));
+
+                    Ok(0)
+                }
+                None => {
+                    println!(
+                        "{} does not depend on {}.",
+                        graph.nodes[from].label, graph.nodes[to].label
+                    );
+
+                    Ok(1)
+                }
+            }
+        }
+    }
+}
+
+/// The imports that nothing is used from, as (importing node, imported node) pairs
+fn find_unused_imports<'a>(
+    arena: &'a Bump,
+    graph: &Graph,
+    roc_file_path: PathBuf,
+    threading: Threading,
+) -> Result<MutSet<(usize, usize)>, LoadingProblem<'a>> {
+    let load_config = LoadConfig {
+        target_info: TargetInfo::default_x86_64(),
+        render: RenderTarget::ColorTerminal,
+        threading,
+        exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Development,
+        warning_config: Default::default(),
+    };
+    let loaded =
+        roc_load::load_and_typecheck(arena, roc_file_path, Default::default(), load_config)?;
+
+    // module ids are not stable between two loads, but the paths of the modules are
+    let index_of_path: MutMap<&Path, usize> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.path.as_path(), index))
+        .collect();
+    let index_of = |module_id: &ModuleId| {
+        loaded
+            .sources
+            .get(module_id)
+            .and_then(|(path, _)| index_of_path.get(path.as_path()).copied())
+    };
+
+    let mut unused = MutSet::default();
+
+    for (module_id, problems) in loaded.can_problems.iter() {
+        let importing = match index_of(module_id) {
+            Some(importing) => importing,
+            None => continue,
+        };
+
+        for problem in problems {
+            if let Problem::UnusedImport(imported, _) = problem {
+                if let Some(imported) = index_of(imported) {
+                    unused.insert((importing, imported));
+                }
+            }
+        }
+    }
+
+    Ok(unused)
+}
+
+fn print_text(graph: &Graph, unused: Option<&MutSet<(usize, usize)>>) {
+    for (index, node) in graph.nodes.iter().enumerate() {
+        println!("{} ({})", node.label, node.path.display());
+
+        for import in node.imports.iter() {
+            let is_unused = unused.map_or(false, |unused| unused.contains(&(index, *import)));
+
+            println!(
+                "    {}{}",
+                graph.nodes[*import].label,
+                if is_unused { " (unused)" } else { "" }
+            );
+        }
+    }
+
+    if let Some(unused) = unused {
+        println!(
+            "\n{} unused {}.",
+            unused.len(),
+            if unused.len() == 1 {
+                "import"
+            } else {
+                "imports"
+            }
+        );
+    }
+}
+
+fn print_dot(graph: &Graph, unused: Option<&MutSet<(usize, usize)>>) {
+    println!("digraph deps {{");
+
+    for node in graph.nodes.iter() {
+        println!(
+            "    \"{}\" [tooltip=\"{}\"];",
+            node.label,
+            node.path.display().to_string().replace('"', "\\\"")
+        );
+    }
+
+    for (index, node) in graph.nodes.iter().enumerate() {
+        for import in node.imports.iter() {
+            let is_unused = unused.map_or(false, |unused| unused.contains(&(index, *import)));
+
+            println!(
+                "    \"{}\" -> \"{}\"{};",
+                node.label,
+                graph.nodes[*import].label,
+                if is_unused {
+                    " [style=dashed, color=red]"
+                } else {
+                    ""
+                }
+            );
+        }
+    }
+
+    println!("}}");
+}
+
+fn print_json(graph: &Graph, unused: Option<&MutSet<(usize, usize)>>) {
+    let modules: Vec<_> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| {
+            let label_of = |import: &usize| graph.nodes[*import].label.as_str();
+
+            let mut module = json!({
+                "name": node.label,
+                "path": node.path.display().to_string(),
+                "exposes": node.exposes,
+                "imports": node.imports.iter().map(label_of).collect::<Vec<_>>(),
+            });
+
+            if let Some(unused) = unused {
+                let unused_imports: Vec<_> = node
+                    .imports
+                    .iter()
+                    .filter(|import| unused.contains(&(index, **import)))
+                    .map(label_of)
+                    .collect();
+
+                module["unused_imports"] = json!(unused_imports);
+            }
+
+            module
+        })
+        .collect();
+
+    let output = json!({
+        "root": graph.nodes.first().map(|node| node.label.as_str()),
+        "modules": modules,
+    });
+
+    println!("{}", serde_json::to_string_pretty(&output).unwrap());
+}
@@ -0,0 +1,337 @@
+use std::io;
+use std::path::PathBuf;
+
+use crate::format::{fmt_all, parse_all};
+use crate::FixMode;
+use bumpalo::Bump;
+use roc_collections::{MutMap, MutSet};
+use roc_fmt::Buf;
+use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+use roc_module::ident::Ident;
+use roc_module::symbol::ModuleId;
+use roc_mono::ir::OptLevel;
+use roc_parse::ast::{Collection, Defs, ExtractSpaces, Module, Pattern, Spaced, ValueDef};
+use roc_parse::header::{ExposedName, ImportsEntry};
+use roc_problem::can::{Problem, RuntimeError};
+use roc_region::all::{Loc, Region};
+use roc_reporting::report::RenderTarget;
+use roc_target::TargetInfo;
+
+/// The number of problems that were fixed, and the number of files that changed because of that
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FixSummary {
+    pub fixes: usize,
+    pub files: usize,
+}
+
+/// Replace the text between `start` and `end` with `replacement`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct TextEdit {
+    start: usize,
+    end: usize,
+    replacement: &'static str,
+}
+
+/// The fixes for a single module
+#[derive(Debug, Default)]
+struct ModuleFixes {
+    /// edits to the body of the module
+    edits: MutSet<TextEdit>,
+    /// regions of `imports` entries to remove from the header
+    unused_imports: Vec<Region>,
+    /// values to add to the `exposes` list in the header
+    missing_exposes: Vec<Ident>,
+}
+
+impl ModuleFixes {
+    fn is_empty(&self) -> bool {
+        self.edits.is_empty() && self.unused_imports.is_empty() && self.missing_exposes.is_empty()
+    }
+}
+
+/// Applies automatic fixes for a curated set of problems that `roc check` reports in the given
+/// module and the modules it imports:
+///
+/// - unused imports are removed from the `imports` list
+/// - unused arguments are prefixed with an underscore
+/// - values that are used by another module, but not exposed, are added to the `exposes` list
+///
+/// The fixed files are then formatted. In `FixMode::DryRun`, the changes are printed as a diff
+/// instead of written to disk.
+///
+/// The outer `io::Result` is an error writing one of the fixed files, the inner `Result` holds
+/// the problems that prevented the module from loading.
+pub fn fix_file(
+    arena: &Bump,
+    roc_file_path: PathBuf,
+    threading: Threading,
+    mode: FixMode,
+) -> io::Result<Result<FixSummary, LoadingProblem>> {
+    // only used for generating errors. We don't do code generation, so hardcoding should be fine
+    let target_info = TargetInfo::default_x86_64();
+
+    let load_config = LoadConfig {
+        target_info,
+        render: RenderTarget::ColorTerminal,
+        threading,
+        exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Development,
+        warning_config: Default::default(),
+    };
+    let loaded =
+        match roc_load::load_and_typecheck(arena, roc_file_path, Default::default(), load_config) {
+            Ok(loaded) => loaded,
+            Err(problem) => return Ok(Err(problem)),
+        };
+
+    let mut summary = FixSummary::default();
+
+    let mut fixes_by_module: Vec<_> = collect_fixes(&loaded).into_iter().collect();
+    fixes_by_module.sort_by_key(|(module_id, _)| loaded.sources[module_id].0.clone());
+
+    for (module_id, fixes) in fixes_by_module {
+        let (path, src) = &loaded.sources[&module_id];
+
+        let (fixed, applied) = match apply_fixes(arena, src, &fixes) {
+            Some(fixed) => fixed,
+            None => {
+                eprintln!(
+                    "I could not fix {} because it does not parse after applying the fixes.",
+                    path.display()
+                );
+
+                continue;
+            }
+        };
+
+        if applied == 0 || fixed.as_str() == &**src {
+            continue;
+        }
+
+        summary.fixes += applied;
+        summary.files += 1;
+
+        match mode {
+            FixMode::Apply => {
+                std::fs::write(path, fixed).map_err(|error| {
+                    io::Error::new(
+                        error.kind(),
+                        format!(
+                            "I could not write the fixes to {}: {}",
+                            path.display(),
+                            error
+                        ),
+                    )
+                })?;
+            }
+            FixMode::DryRun => {
+                let path = path.display().to_string();
+
+                print!(
+                    "{}",
+                    similar::TextDiff::from_lines(&**src, fixed.as_str())
+                        .unified_diff()
+                        .header(&path, &path)
+                );
+            }
+        }
+    }
+
+    Ok(Ok(summary))
+}
+
+fn collect_fixes(loaded: &LoadedModule) -> MutMap<ModuleId, ModuleFixes> {
+    let mut fixes_by_module: MutMap<ModuleId, ModuleFixes> = MutMap::default();
+
+    for (module_id, problems) in loaded.can_problems.iter() {
+        if module_id.is_builtin() || !loaded.sources.contains_key(module_id) {
+            continue;
+        }
+
+        let src = &loaded.sources[module_id].1;
+
+        // when a value is not exposed by an imported module, that import is also reported as
+        // unused; it becomes used once the value is exposed, so it must not be removed.
+        let mut not_exposed_from = MutSet::default();
+
+        for problem in problems {
+            if let Problem::RuntimeError(RuntimeError::ValueNotExposed {
+                module_name, ident, ..
+            }) = problem
+            {
+                let exposing_module = match loaded.interns.module_ids.get_id(module_name) {
+                    Some(exposing_module) => exposing_module,
+                    None => continue,
+                };
+
+                not_exposed_from.insert(exposing_module);
+
+                // whether the value is actually defined is checked when the fixes are applied
+                let fixes = fixes_by_module.entry(exposing_module).or_default();
+
+                if !fixes.missing_exposes.contains(ident) {
+                    fixes.missing_exposes.push(ident.clone());
+                }
+            }
+        }
+
+        let fixes = fixes_by_module.entry(*module_id).or_default();
+
+        for problem in problems {
+            match problem {
+                Problem::UnusedImport(imported_module, region) => {
+                    if !not_exposed_from.contains(imported_module) {
+                        fixes.unused_imports.push(*region);
+                    }
+                }
+                Problem::UnusedArgument(_, _, _, region) => {
+                    let start = region.start().offset as usize;
+                    let end = region.end().offset as usize;
+
+                    // only plain identifiers can be prefixed; destructures are left alone
+                    if is_lowercase_ident(&src[start..end]) {
+                        fixes.edits.insert(TextEdit {
+                            start,
+                            end: start,
+                            replacement: "_",
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fixes_by_module.retain(|module_id, fixes| {
+        !fixes.is_empty() && !module_id.is_builtin() && loaded.sources.contains_key(module_id)
+    });
+
+    fixes_by_module
+}
+
+fn is_lowercase_ident(text: &str) -> bool {
+    let mut chars = text.chars();
+
+    matches!(chars.next(), Some(c) if c.is_lowercase()) && chars.all(|c| c.is_alphanumeric())
+}
+
+/// Applies the fixes to the source of a module, and formats the result. Also returns how many
+/// fixes were applied.
+fn apply_fixes(arena: &Bump, src: &str, fixes: &ModuleFixes) -> Option<(String, usize)> {
+    let mut edits: Vec<&TextEdit> = fixes.edits.iter().collect();
+
+    // apply the edits back to front, so the positions of the remaining edits stay valid
+    edits.sort_by_key(|edit| std::cmp::Reverse((edit.start, edit.end)));
+
+    let mut edited = src.to_string();
+    let mut previous_start = usize::MAX;
+    let mut applied = 0;
+
+    for edit in edits {
+        // skip edits that overlap with an edit that was already applied
+        if edit.end > previous_start {
+            continue;
+        }
+
+        edited.replace_range(edit.start..edit.end, edit.replacement);
+        previous_start = edit.start;
+        applied += 1;
+    }
+
+    // edits only touch the body of the module, so the regions in the header are unchanged
+    let edited = arena.alloc_str(&edited);
+    let mut ast = parse_all(arena, edited).ok()?;
+
+    // only values that are defined at the top level of the module can be exposed
+    let missing_exposes: Vec<&Ident> = fixes
+        .missing_exposes
+        .iter()
+        .filter(|ident| defines_value(&ast.defs, ident.as_str()))
+        .collect();
+
+    applied += fix_header(
+        arena,
+        &mut ast.module,
+        &fixes.unused_imports,
+        &missing_exposes,
+    );
+
+    let ast = arena.alloc(ast);
+    let mut buf = Buf::new_in(arena);
+    fmt_all(&mut buf, ast);
+
+    Some((buf.as_str().to_string(), applied))
+}
+
+fn defines_value(defs: &Defs, name: &str) -> bool {
+    defs.value_defs.iter().any(|def| {
+        let pattern = match def {
+            ValueDef::Body(pattern, _) => pattern,
+            ValueDef::AnnotatedBody { body_pattern, .. } => body_pattern,
+            _ => return false,
+        };
+
+        matches!(pattern.extract_spaces().item, Pattern::Identifier(ident) if ident == name)
+    })
+}
+
+/// Removes the unused imports from the header and adds the missing exposes. Returns how many
+/// fixes were applied.
+fn fix_header<'a>(
+    arena: &'a Bump,
+    module: &mut Module<'a>,
+    unused_imports: &[Region],
+    missing_exposes: &[&Ident],
+) -> usize {
+    let (imports, exposes) = match module {
+        Module::Interface { header } => (&mut header.imports, Some(&mut header.exposes)),
+        Module::Hosted { header } => (&mut header.imports, Some(&mut header.exposes)),
+        Module::App { header } => (&mut header.imports, None),
+        Module::Platform { header } => (&mut header.imports, None),
+    };
+
+    let before = imports.len();
+    *imports = remove_imports(arena, imports, unused_imports);
+
+    let mut applied = before - imports.len();
+
+    if let Some(exposes) = exposes {
+        *exposes = add_exposes(arena, exposes, missing_exposes);
+        applied += missing_exposes.len();
+    }
+
+    applied
+}
+
+fn remove_imports<'a>(
+    arena: &'a Bump,
+    imports: &Collection<'a, Loc<Spaced<'a, ImportsEntry<'a>>>>,
+    unused: &[Region],
+) -> Collection<'a, Loc<Spaced<'a, ImportsEntry<'a>>>> {
+    let items = bumpalo::collections::Vec::from_iter_in(
+        imports
+            .iter()
+            .filter(|entry| !unused.contains(&entry.region))
+            .copied(),
+        arena,
+    );
+
+    imports.replace_items(items.into_bump_slice())
+}
+
+fn add_exposes<'a>(
+    arena: &'a Bump,
+    exposes: &Collection<'a, Loc<Spaced<'a, ExposedName<'a>>>>,
+    missing: &[&Ident],
+) -> Collection<'a, Loc<Spaced<'a, ExposedName<'a>>>> {
+    let added = missing.iter().map(|ident| {
+        let name = ExposedName::new(arena.alloc_str(ident.as_str()));
+
+        Loc::at_zero(Spaced::Item(name))
+    });
+
+    let items =
+        bumpalo::collections::Vec::from_iter_in(exposes.iter().copied().chain(added), arena);
+
+    exposes.replace_items(items.into_bump_slice())
+}
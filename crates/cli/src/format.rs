@@ -1,14 +1,18 @@
 use std::ffi::OsStr;
+use std::io::Read;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
 use crate::FormatMode;
 use bumpalo::Bump;
 use roc_error_macros::{internal_error, user_error};
+use roc_fmt::annotation::Formattable;
 use roc_fmt::def::fmt_defs;
 use roc_fmt::module::fmt_module;
 use roc_fmt::spaces::RemoveSpaces;
 use roc_fmt::{Ast, Buf};
 use roc_parse::{
+    ast::Defs,
     module::{self, module_defs},
     parser::{Parser, SyntaxError},
     state::State,
@@ -58,7 +62,11 @@ fn is_roc_file(path: &Path) -> bool {
     matches!(path.extension().and_then(OsStr::to_str), Some("roc"))
 }
 
-pub fn format(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(), String> {
+pub fn format(
+    files: std::vec::Vec<PathBuf>,
+    mode: FormatMode,
+    range: Option<Range<usize>>,
+) -> Result<(), String> {
     let files = flatten_directories(files);
 
     for file in files {
@@ -66,16 +74,239 @@ pub fn format(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(), Str
 
         let src = std::fs::read_to_string(&file).unwrap();
 
-        let ast = arena.alloc(parse_all(&arena, &src).unwrap_or_else(|e| {
+        let ast: &Ast = arena.alloc(parse_all(&arena, &src).unwrap_or_else(|e| {
             user_error!("Unexpected parse failure when parsing this formatting:\n\n{:?}\n\nParse error was:\n\n{:?}\n\n", src, e)
         }));
-        let mut buf = Buf::new_in(&arena);
-        fmt_all(&mut buf, ast);
 
-        let reparsed_ast = arena.alloc(parse_all(&arena, buf.as_str()).unwrap_or_else(|e| {
-            let mut fail_file = file.clone();
+        let selection = match &range {
+            Some(range) => Selection::Defs(defs_overlapping(&ast.defs, range)),
+            None => Selection::All,
+        };
+        let formatted = fmt_selection(&arena, &src, ast, &selection);
+
+        // We always verify before overwriting a file, so a formatter bug can't lose any code.
+        if let Err(bug) = verify(&arena, ast, &formatted, &selection) {
+            report_bug_in_file(&file, &formatted, bug);
+        }
+
+        match mode {
+            FormatMode::CheckOnly => {
+                // If we notice that this file needs to be formatted, return early
+                if formatted != src {
+                    return Err("One or more files need to be reformatted.".to_string());
+                }
+            }
+
+            FormatMode::Format => {
+                // If all the checks above passed, actually write out the new file.
+                std::fs::write(&file, &formatted).unwrap();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Formats the code read from stdin, and writes it to stdout instead of to a file, so editors can
+/// format buffers that haven't been saved.
+pub fn format_stdin(mode: FormatMode, range: Option<Range<usize>>) -> Result<(), String> {
+    let mut src = String::new();
+
+    std::io::stdin()
+        .read_to_string(&mut src)
+        .map_err(|error| format!("I could not read the code to format from stdin: {}", error))?;
+
+    let arena = Bump::new();
+    let ast = parse_all(&arena, &src)
+        .map_err(|error| format!("I could not parse the code to format:\n\n{:?}", error))?;
+    let ast: &Ast = arena.alloc(ast);
+
+    let selection = match &range {
+        Some(range) => Selection::Defs(defs_overlapping(&ast.defs, range)),
+        None => Selection::All,
+    };
+    let formatted = fmt_selection(&arena, &src, ast, &selection);
+
+    // Like when formatting files, never write out code that doesn't mean the same thing.
+    verify(&arena, ast, &formatted, &selection).map_err(|bug| bug.describe())?;
+
+    match mode {
+        FormatMode::CheckOnly => {
+            if formatted != src {
+                return Err("The code needs to be reformatted.".to_string());
+            }
+        }
+
+        FormatMode::Format => {
+            print!("{}", formatted);
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses the `start:end` argument of `--range`, a range of byte offsets.
+pub fn parse_byte_range(range: &str) -> Result<Range<usize>, String> {
+    let invalid = || format!("`{}` is not a range of bytes like `120:480`", range);
+
+    let (start, end) = range.split_once(':').ok_or_else(invalid)?;
+    let start: usize = start.trim().parse().map_err(|_| invalid())?;
+    let end: usize = end.trim().parse().map_err(|_| invalid())?;
+
+    if start > end {
+        return Err(format!(
+            "The range `{}` ends before it starts; did you mean `{}:{}`?",
+            range, end, start
+        ));
+    }
+
+    Ok(start..end)
+}
+
+/// Which parts of a module to format
+enum Selection {
+    All,
+    /// Only these top-level defs, by index; the header and everything else is left as it is.
+    Defs(std::vec::Vec<usize>),
+}
+
+/// The indices of the top-level defs that overlap `range`. An empty range, like the position of a
+/// cursor, selects the def it's in.
+fn defs_overlapping(defs: &Defs, range: &Range<usize>) -> std::vec::Vec<usize> {
+    let mut selected = vec![];
+
+    for (index, region) in defs.regions.iter().enumerate() {
+        let start = region.start().offset as usize;
+        let end = region.end().offset as usize;
+
+        let overlaps = if range.is_empty() {
+            start <= range.start && range.start <= end
+        } else {
+            start < range.end && range.start < end
+        };
+
+        if overlaps {
+            selected.push(index);
+        }
+    }
+
+    selected
+}
+
+fn fmt_selection<'a>(
+    arena: &'a Bump,
+    src: &str,
+    ast: &'a Ast<'a>,
+    selection: &Selection,
+) -> String {
+    match selection {
+        Selection::All => {
+            let mut buf = Buf::new_in(arena);
+            fmt_all(&mut buf, ast);
+
+            buf.as_str().to_string()
+        }
+        Selection::Defs(indices) => {
+            let mut output = String::with_capacity(src.len());
+            let mut copied_up_to = 0;
+
+            for &index in indices {
+                let region = ast.defs.regions[index];
+                let mut buf = Buf::new_in(arena);
+
+                match ast.defs.defs().nth(index) {
+                    Some(Ok(type_def)) => type_def.format(&mut buf, 0),
+                    Some(Err(value_def)) => value_def.format(&mut buf, 0),
+                    None => internal_error!("There is no top-level def at index {}", index),
+                }
+
+                output.push_str(&src[copied_up_to..region.start().offset as usize]);
+                output.push_str(buf.as_str());
+                copied_up_to = region.end().offset as usize;
+            }
+
+            output.push_str(&src[copied_up_to..]);
+
+            output
+        }
+    }
+}
+
+/// The ways formatting can go wrong, which are all bugs in the formatter
+enum FormattingBug<'a> {
+    /// The formatted code doesn't parse
+    Invalid(SyntaxError<'a>),
+    /// The formatted code parses to a different tree than the original
+    ChangedTree { before: String, after: String },
+    /// Formatting the formatted code changes it again
+    Unstable { reformatted: String },
+}
+
+impl<'a> FormattingBug<'a> {
+    fn describe(&self) -> String {
+        match self {
+            FormattingBug::Invalid(error) => format!(
+                "Formatting bug; formatted code isn't valid\n\nParse error was: {:?}\n\n",
+                error
+            ),
+            FormattingBug::ChangedTree { before, after } => format!(
+                "Formatting bug; formatting didn't reparse as the same tree\n\n\
+                The tree before formatting was:\n{}\n\nThe tree after formatting was:\n{}\n\n",
+                before, after
+            ),
+            FormattingBug::Unstable { reformatted } => format!(
+                "Formatting bug; formatting is not stable. Reformatting the formatted code changed it again.\n\n\
+                The result of double-formatting was:\n{}\n\n",
+                reformatted
+            ),
+        }
+    }
+}
+
+/// Checks that the formatted code parses to the same tree as the original, and that formatting
+/// it again doesn't change it.
+fn verify<'a>(
+    arena: &'a Bump,
+    ast: &'a Ast<'a>,
+    formatted: &str,
+    selection: &Selection,
+) -> Result<(), FormattingBug<'a>> {
+    let formatted: &'a str = arena.alloc_str(formatted);
+    let reparsed_ast: &Ast =
+        arena.alloc(parse_all(arena, formatted).map_err(FormattingBug::Invalid)?);
+
+    let ast_normalized = ast.remove_spaces(arena);
+    let reparsed_ast_normalized = reparsed_ast.remove_spaces(arena);
+
+    // HACK!
+    // We compare the debug format strings of the ASTs, because I'm finding in practice that _somewhere_ deep inside the ast,
+    // the PartialEq implementation is returning `false` even when the Debug-formatted impl is exactly the same.
+    // I don't have the patience to debug this right now, so let's leave it for another day...
+    // TODO: fix PartialEq impl on ast types
+    if format!("{:?}", ast_normalized) != format!("{:?}", reparsed_ast_normalized) {
+        return Err(FormattingBug::ChangedTree {
+            before: format!("{:#?}\n", ast_normalized),
+            after: format!("{:#?}\n", reparsed_ast_normalized),
+        });
+    }
+
+    // Now verify that the resultant formatting is _stable_ - i.e. that it doesn't change again if re-formatted.
+    // The tree didn't change, so the selected defs have the same indices in it.
+    let reformatted = fmt_selection(arena, formatted, reparsed_ast, selection);
+
+    if formatted != reformatted {
+        return Err(FormattingBug::Unstable { reformatted });
+    }
+
+    Ok(())
+}
+
+fn report_bug_in_file(file: &Path, formatted: &str, bug: FormattingBug) -> ! {
+    match bug {
+        FormattingBug::Invalid(e) => {
+            let mut fail_file = file.to_path_buf();
             fail_file.set_extension("roc-format-failed");
-            std::fs::write(&fail_file, buf.as_str()).unwrap();
+            std::fs::write(&fail_file, formatted).unwrap();
             internal_error!(
                 "Formatting bug; formatted code isn't valid\n\n\
                 I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
@@ -83,28 +314,19 @@ pub fn format(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(), Str
                 fail_file.display(),
                 e
             );
-        }));
-
-        let ast_normalized = ast.remove_spaces(&arena);
-        let reparsed_ast_normalized = reparsed_ast.remove_spaces(&arena);
-
-        // HACK!
-        // We compare the debug format strings of the ASTs, because I'm finding in practice that _somewhere_ deep inside the ast,
-        // the PartialEq implementation is returning `false` even when the Debug-formatted impl is exactly the same.
-        // I don't have the patience to debug this right now, so let's leave it for another day...
-        // TODO: fix PartialEq impl on ast types
-        if format!("{:?}", ast_normalized) != format!("{:?}", reparsed_ast_normalized) {
-            let mut fail_file = file.clone();
+        }
+        FormattingBug::ChangedTree { before, after } => {
+            let mut fail_file = file.to_path_buf();
             fail_file.set_extension("roc-format-failed");
-            std::fs::write(&fail_file, buf.as_str()).unwrap();
+            std::fs::write(&fail_file, formatted).unwrap();
 
-            let mut before_file = file.clone();
+            let mut before_file = file.to_path_buf();
             before_file.set_extension("roc-format-failed-ast-before");
-            std::fs::write(&before_file, &format!("{:#?}\n", ast_normalized)).unwrap();
+            std::fs::write(&before_file, before).unwrap();
 
-            let mut after_file = file.clone();
+            let mut after_file = file.to_path_buf();
             after_file.set_extension("roc-format-failed-ast-after");
-            std::fs::write(&after_file, &format!("{:#?}\n", reparsed_ast_normalized)).unwrap();
+            std::fs::write(&after_file, after).unwrap();
 
             internal_error!(
                 "Formatting bug; formatting didn't reparse as the same tree\n\n\
@@ -114,18 +336,14 @@ pub fn format(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(), Str
                 before_file.display(),
                 after_file.display());
         }
-
-        // Now verify that the resultant formatting is _stable_ - i.e. that it doesn't change again if re-formatted
-        let mut reformatted_buf = Buf::new_in(&arena);
-        fmt_all(&mut reformatted_buf, reparsed_ast);
-        if buf.as_str() != reformatted_buf.as_str() {
-            let mut unstable_1_file = file.clone();
+        FormattingBug::Unstable { reformatted } => {
+            let mut unstable_1_file = file.to_path_buf();
             unstable_1_file.set_extension("roc-format-unstable-1");
-            std::fs::write(&unstable_1_file, buf.as_str()).unwrap();
+            std::fs::write(&unstable_1_file, formatted).unwrap();
 
-            let mut unstable_2_file = file.clone();
+            let mut unstable_2_file = file.to_path_buf();
             unstable_2_file.set_extension("roc-format-unstable-2");
-            std::fs::write(&unstable_2_file, reformatted_buf.as_str()).unwrap();
+            std::fs::write(&unstable_2_file, reformatted).unwrap();
 
             internal_error!(
                 "Formatting bug; formatting is not stable. Reformatting the formatted file changed it again.\n\n\
@@ -134,26 +352,10 @@ pub fn format(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(), Str
                 unstable_1_file.display(),
                 unstable_2_file.display());
         }
-
-        match mode {
-            FormatMode::CheckOnly => {
-                // If we notice that this file needs to be formatted, return early
-                if buf.as_str() != src {
-                    return Err("One or more files need to be reformatted.".to_string());
-                }
-            }
-
-            FormatMode::Format => {
-                // If all the checks above passed, actually write out the new file.
-                std::fs::write(&file, buf.as_str()).unwrap();
-            }
-        }
     }
-
-    Ok(())
 }
 
-fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
+pub(crate) fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
     let (module, state) = module::parse_header(arena, State::new(src.as_bytes()))
         .map_err(|e| SyntaxError::Header(e.problem))?;
 
@@ -162,7 +364,7 @@ fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'
     Ok(Ast { module, defs })
 }
 
-fn fmt_all<'a>(buf: &mut Buf<'a>, ast: &'a Ast) {
+pub(crate) fn fmt_all<'a>(buf: &mut Buf<'a>, ast: &'a Ast) {
     fmt_module(buf, &ast.module);
 
     fmt_defs(buf, &ast.defs, 0);
@@ -10,6 +10,7 @@ use roc_error_macros::{internal_error, user_error};
 use roc_load::{Expectations, LoadingProblem, Threading};
 use roc_module::symbol::{Interns, ModuleId};
 use roc_mono::ir::OptLevel;
+use roc_reporting::warnings::{WarningConfig, WarningKind};
 use std::env;
 use std::ffi::{CString, OsStr};
 use std::io;
@@ -25,12 +26,22 @@ use target_lexicon::{
 use tempfile::TempDir;
 
 pub mod build;
+mod deps;
+mod fix;
 mod format;
-pub use format::format;
+mod lint;
+pub use deps::{deps, DepsFormat, DepsQuery};
+pub use fix::{fix_file, FixSummary};
+pub use format::{format, format_stdin, parse_byte_range};
+pub use lint::lint_file;
+mod profile;
+pub mod watch;
 
 use crate::build::{BuildFileError, BuildOrdering};
+use crate::watch::WatchedFiles;
 
 const DEFAULT_ROC_FILENAME: &str = "main.roc";
+const DEFAULT_DOCS_DIR: &str = "generated-docs";
 
 pub const CMD_BUILD: &str = "build";
 pub const CMD_RUN: &str = "run";
@@ -43,6 +54,9 @@ pub const CMD_VERSION: &str = "version";
 pub const CMD_FORMAT: &str = "format";
 pub const CMD_TEST: &str = "test";
 pub const CMD_GLUE: &str = "glue";
+pub const CMD_FIX: &str = "fix";
+pub const CMD_LINT: &str = "lint";
+pub const CMD_DEPS: &str = "deps";
 
 pub const FLAG_DEBUG: &str = "debug";
 pub const FLAG_DEV: &str = "dev";
@@ -56,6 +70,25 @@ pub const FLAG_TIME: &str = "time";
 pub const FLAG_LINKER: &str = "linker";
 pub const FLAG_PREBUILT: &str = "prebuilt-platform";
 pub const FLAG_CHECK: &str = "check";
+pub const FLAG_STDIN: &str = "stdin";
+pub const FLAG_RANGE: &str = "range";
+pub const FLAG_VERIFY: &str = "verify";
+pub const FLAG_FIX: &str = "fix";
+pub const FLAG_DRY_RUN: &str = "dry-run";
+pub const FLAG_ALLOW: &str = "allow";
+pub const FLAG_WARN: &str = "warn";
+pub const FLAG_DENY: &str = "deny";
+pub const FLAG_DENY_WARNINGS: &str = "deny-warnings";
+pub const FLAG_WATCH: &str = "watch";
+pub const FLAG_PROFILE: &str = "profile";
+pub const FLAG_FORMAT: &str = "format";
+pub const FLAG_WHY: &str = "why";
+pub const FLAG_UNUSED: &str = "unused";
+pub const FLAG_OUTPUT: &str = "output";
+pub const FLAG_BASE_URL: &str = "base-url";
+pub const FLAG_BUILTINS_URL: &str = "builtins-url";
+pub const FLAG_PACKAGE_URL: &str = "package-url";
+pub const FLAG_DOC: &str = "doc";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const ROC_DIR: &str = "ROC_DIR";
@@ -65,6 +98,17 @@ pub const ARGS_FOR_APP: &str = "ARGS_FOR_APP";
 
 const VERSION: &str = include_str!("../../../version.txt");
 
+fn lint_level_flag<'a>(name: &'a str, help: &'a str) -> Arg<'a> {
+    Arg::new(name)
+        .long(name)
+        .help(help)
+        .value_name("LINT")
+        .takes_value(true)
+        .multiple_occurrences(true)
+        .possible_values(roc_lint::all_rules().iter().map(|rule| rule.name()))
+        .required(false)
+}
+
 pub fn build_app<'a>() -> Command<'a> {
     let flag_optimize = Arg::new(FLAG_OPTIMIZE)
         .long(FLAG_OPTIMIZE)
@@ -117,6 +161,33 @@ pub fn build_app<'a>() -> Command<'a> {
         .validator(|s| s.parse::<u32>())
         .required(false);
 
+    let flag_deny_warnings = Arg::new(FLAG_DENY_WARNINGS)
+        .long(FLAG_DENY_WARNINGS)
+        .help("Report warnings as errors, so they fail the build")
+        .required(false);
+
+    let flag_allow_warning = Arg::new(FLAG_ALLOW)
+        .long(FLAG_ALLOW)
+        .help("Don't report warnings of this kind, e.g. `--allow unused-definition`\n(Warnings can also be allowed in the source with a `# roc-allow: <kind>` comment on the line before them.)")
+        .value_name("KIND")
+        .takes_value(true)
+        .multiple_occurrences(true)
+        .possible_values(WarningKind::ALL.iter().map(|kind| kind.name()))
+        .required(false);
+
+    let flag_watch = Arg::new(FLAG_WATCH)
+        .long(FLAG_WATCH)
+        .help("Run the command again whenever one of the program's source files changes\n(Every module is loaded again on each run; the platform host is only rebuilt when its own sources change.)")
+        .required(false);
+
+    let flag_profile = Arg::new(FLAG_PROFILE)
+        .long(FLAG_PROFILE)
+        .help("Write when each compiler phase ran for each module to this file, in the Chrome Trace Event format\n(Open it in chrome://tracing or https://ui.perfetto.dev to see which modules take the longest to compile.)")
+        .value_name("FILE")
+        .takes_value(true)
+        .allow_invalid_utf8(true)
+        .required(false);
+
     let roc_file_to_run = Arg::new(ROC_FILE)
         .help("The .roc file of an app to run")
         .allow_invalid_utf8(true)
@@ -142,8 +213,12 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_dev.clone())
             .arg(flag_debug.clone())
             .arg(flag_time.clone())
+            .arg(flag_profile.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_deny_warnings.clone())
+            .arg(flag_allow_warning.clone())
+            .arg(flag_watch.clone())
             .arg(flag_wasm_stack_size_kb.clone())
             .arg(
                 Arg::new(FLAG_TARGET)
@@ -183,6 +258,15 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_deny_warnings.clone())
+            .arg(flag_allow_warning.clone())
+            .arg(flag_watch.clone())
+            .arg(
+                Arg::new(FLAG_DOC)
+                    .long(FLAG_DOC)
+                    .help("Also run the `roc` code blocks with `expect`s in them in doc comments")
+                    .required(false),
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file for the main module")
@@ -203,8 +287,12 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_dev.clone())
             .arg(flag_debug.clone())
             .arg(flag_time.clone())
+            .arg(flag_profile.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_deny_warnings.clone())
+            .arg(flag_allow_warning.clone())
+            .arg(flag_watch.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone())
         )
@@ -216,8 +304,12 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_dev.clone())
             .arg(flag_debug.clone())
             .arg(flag_time.clone())
+            .arg(flag_profile.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_deny_warnings.clone())
+            .arg(flag_allow_warning.clone())
+            .arg(flag_watch.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone())
         )
@@ -235,13 +327,46 @@ pub fn build_app<'a>() -> Command<'a> {
                     .help("Checks that specified files are formatted\n(If formatting is needed, return a non-zero exit code.)")
                     .required(false),
             )
+            .arg(
+                Arg::new(FLAG_STDIN)
+                    .long(FLAG_STDIN)
+                    .help("Format code from stdin and write the result to stdout, instead of formatting files")
+                    .conflicts_with(DIRECTORY_OR_FILES)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_RANGE)
+                    .long(FLAG_RANGE)
+                    .help("Only format the top-level defs that overlap this range of byte offsets\n(Needs a single file, or --stdin.)")
+                    .value_name("START:END")
+                    .takes_value(true)
+                    .validator(parse_byte_range)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_VERIFY)
+                    .long(FLAG_VERIFY)
+                    .help("Check that the formatted code parses to the same tree and that formatting it again changes nothing, before writing it to stdout\n(This is always done, so this flag has no effect.)")
+                    .requires(FLAG_STDIN)
+                    .required(false),
+            )
         )
         .subcommand(Command::new(CMD_VERSION)
             .about(concatcp!("Print the Roc compiler’s version, which is currently ", VERSION)))
         .subcommand(Command::new(CMD_CHECK)
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_time.clone())
+            .arg(flag_profile.clone())
             .arg(flag_max_threads.clone())
+            .arg(flag_deny_warnings.clone())
+            .arg(flag_allow_warning.clone())
+            .arg(flag_watch.clone())
+            .arg(
+                Arg::new(FLAG_FIX)
+                    .long(FLAG_FIX)
+                    .help("Fix the problems that can be fixed automatically before checking\n(See `roc fix` for which problems those are.)")
+                    .required(false),
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file of an app to check")
@@ -250,15 +375,131 @@ pub fn build_app<'a>() -> Command<'a> {
                     .default_value(DEFAULT_ROC_FILENAME),
             )
             )
+        .subcommand(Command::new(CMD_FIX)
+            .about("Fix some of the problems that `roc check` reports, then format the changed files\n(This removes unused imports, prefixes unused arguments with an underscore, and exposes values that other modules use.)")
+            .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(FLAG_DRY_RUN)
+                    .long(FLAG_DRY_RUN)
+                    .help("Print the fixes as a diff, without changing any files")
+                    .required(false),
+            )
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file of an app to fix")
+                    .allow_invalid_utf8(true)
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+        )
+        .subcommand(Command::new(CMD_LINT)
+            .about("Check a Roc program for code that compiles, but could be written more clearly\n(Lints are reported as warnings by default.)")
+            .arg(flag_max_threads.clone())
+            .arg(flag_deny_warnings.clone())
+            .arg(
+                flag_allow_warning
+                    .clone()
+                    .value_name("LINT_OR_KIND")
+                    .help("Don't report this lint, or warnings of this kind")
+                    .possible_values(roc_lint::all_rules().iter().map(|rule| rule.name())),
+            )
+            .arg(lint_level_flag(FLAG_WARN, "Report this lint as a warning"))
+            .arg(lint_level_flag(FLAG_DENY, "Report this lint as an error\n(When a lint is passed to several of these flags, --deny wins over --warn, which wins over --allow.)"))
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file of an app to lint")
+                    .allow_invalid_utf8(true)
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+        )
+        .subcommand(Command::new(CMD_DEPS)
+            .about("Print which modules of a program import which other modules")
+            .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(FLAG_FORMAT)
+                    .long(FLAG_FORMAT)
+                    .help("How to print the imports\n(Use `dot` to draw them with Graphviz, e.g. `roc deps --format dot | dot -Tsvg > deps.svg`.)")
+                    .default_value("text")
+                    .possible_values(["text", "dot", "json"])
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_UNUSED)
+                    .long(FLAG_UNUSED)
+                    .help("Also find the imports that nothing is used from, and return a non-zero exit code if there are any\n(This checks the whole program, so it takes as long as `roc check`.)")
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_WHY)
+                    .long(FLAG_WHY)
+                    .help("Print a shortest chain of imports through which the first module depends on the second, instead of all imports\n(Modules are given by name, e.g. `pf.Task`, or by path. The app and platform modules are called `app` and `platform`.)")
+                    .value_names(&["FROM", "TO"])
+                    .number_of_values(2)
+                    .conflicts_with(FLAG_UNUSED)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file of the main module of the program")
+                    .allow_invalid_utf8(true)
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+        )
         .subcommand(
             Command::new(CMD_DOCS)
                 .about("Generate documentation for Roc modules (Work In Progress)")
                 .arg(Arg::new(DIRECTORY_OR_FILES)
                     .multiple_values(true)
                     .required(false)
-                    .help("The directory or files to build documentation for")
+                    .help("The directory or files to build documentation for\n(If they include a platform module, only the modules it exposes get documented.)")
                     .allow_invalid_utf8(true)
                 )
+                .arg(
+                    Arg::new(FLAG_OUTPUT)
+                        .long(FLAG_OUTPUT)
+                        .help("The directory to write the generated docs to")
+                        .value_name("DIR")
+                        .takes_value(true)
+                        .allow_invalid_utf8(true)
+                        .default_value(DEFAULT_DOCS_DIR)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(FLAG_BASE_URL)
+                        .long(FLAG_BASE_URL)
+                        .help("The URL path the generated docs will be served from, e.g. /packages/cli")
+                        .takes_value(true)
+                        .default_value("/")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(FLAG_BUILTINS_URL)
+                        .long(FLAG_BUILTINS_URL)
+                        .help("The URL of the builtins' docs, which types like Str and List link to")
+                        .takes_value(true)
+                        .default_value(roc_docs::BUILTINS_DOCS_URL)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(FLAG_PACKAGE_URL)
+                        .long(FLAG_PACKAGE_URL)
+                        .help("The URL of the docs of another package, which types from its modules link to\n(For example: --package-url roc/cli=https://example.com/cli/ for the package whose platform module is called \"roc/cli\")")
+                        .value_name("NAME=URL")
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .validator(roc_docs::parse_package_url)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(FLAG_FORMAT)
+                        .long(FLAG_FORMAT)
+                        .help("What to generate\n(`json` writes a single docs.json, for search indexes and other tools.)")
+                        .default_value("html")
+                        .possible_values(["html", "json"])
+                        .required(false),
+                )
         )
         .subcommand(Command::new(CMD_GLUE)
             .about("Generate glue code between a platform's Roc API and its host language")
@@ -282,8 +523,12 @@ pub fn build_app<'a>() -> Command<'a> {
         .arg(flag_dev)
         .arg(flag_debug)
         .arg(flag_time)
+        .arg(flag_profile)
         .arg(flag_linker)
         .arg(flag_prebuilt)
+        .arg(flag_deny_warnings)
+        .arg(flag_allow_warning)
+        .arg(flag_watch)
         .arg(roc_file_to_run.required(false))
         .arg(args_for_app);
 
@@ -303,7 +548,7 @@ pub fn build_app<'a>() -> Command<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BuildConfig {
     BuildOnly,
     BuildAndRun,
@@ -315,6 +560,27 @@ pub enum FormatMode {
     CheckOnly,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixMode {
+    Apply,
+    DryRun,
+}
+
+/// The warnings to report, according to the `--deny-warnings` and `--allow` flags. Values of
+/// `--allow` that are not kinds of warnings (i.e. the names of lints) are skipped.
+pub fn warning_config(matches: &ArgMatches) -> WarningConfig {
+    WarningConfig {
+        deny_warnings: matches.is_present(FLAG_DENY_WARNINGS),
+        // clap has already checked that these are kinds of warnings, or lints
+        allowed: matches
+            .values_of(FLAG_ALLOW)
+            .into_iter()
+            .flatten()
+            .filter_map(WarningKind::from_name)
+            .collect(),
+    }
+}
+
 #[cfg(windows)]
 pub fn test(_matches: &ArgMatches, _triple: Triple) -> io::Result<i32> {
     todo!("running tests does not work on windows right now")
@@ -322,8 +588,25 @@ pub fn test(_matches: &ArgMatches, _triple: Triple) -> io::Result<i32> {
 
 #[cfg(not(windows))]
 pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
+    if matches.is_present(FLAG_WATCH) {
+        let root_module = Path::new(matches.value_of_os(ROC_FILE).unwrap());
+
+        watch::watch(root_module, |watched| {
+            test_help(matches, triple.clone(), Some(watched))
+        })
+    } else {
+        test_help(matches, triple, None)
+    }
+}
+
+#[cfg(not(windows))]
+fn test_help(
+    matches: &ArgMatches,
+    triple: Triple,
+    watched: Option<&mut WatchedFiles>,
+) -> io::Result<i32> {
     use roc_gen_llvm::llvm::build::LlvmBackendMode;
-    use roc_load::{ExecutionMode, LoadConfig};
+    use roc_load::{ExecutionMode, LoadConfig, LoadMonomorphizedError};
     use roc_target::TargetInfo;
     use std::time::Instant;
 
@@ -385,13 +668,38 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
         // TODO: expose this from CLI?
         render: roc_reporting::report::RenderTarget::ColorTerminal,
         threading,
-        exec_mode: ExecutionMode::Test,
+        exec_mode: if matches.is_present(FLAG_DOC) {
+            ExecutionMode::DocTest
+        } else {
+            ExecutionMode::Test
+        },
+        opt_level,
+        warning_config: Default::default(),
+    };
+    let loaded = match roc_load::load_and_monomorphize(
+        arena,
+        path.to_path_buf(),
+        subs_by_module,
+        load_config,
+    ) {
+        Ok(loaded) => loaded,
+        Err(LoadMonomorphizedError::LoadingProblem(LoadingProblem::FormattedReport(report))) => {
+            print!("{}", report);
+
+            return Ok(1);
+        }
+        Err(other) => {
+            panic!("load_and_monomorphize failed with error:\n{:?}", other);
+        }
     };
-    let loaded =
-        roc_load::load_and_monomorphize(arena, path.to_path_buf(), subs_by_module, load_config)
-            .unwrap();
+
+    if let Some(watched) = watched {
+        watched.watch_modules(loaded.sources.values().map(|(path, _)| path));
+    }
 
     let mut loaded = loaded;
+    let problems =
+        roc_build::program::report_problems_monomorphized(&mut loaded, &warning_config(matches));
     let mut expectations = std::mem::take(&mut loaded.expectations);
     let loaded = loaded;
 
@@ -447,7 +755,8 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
             total_time.as_millis(),
         );
 
-        Ok((failed > 0) as i32)
+        // Problems in the code fail the tests too, even if all the expectations passed
+        Ok((failed > 0 || problems.errors > 0) as i32)
     }
 }
 
@@ -456,6 +765,24 @@ pub fn build(
     config: BuildConfig,
     triple: Triple,
     link_type: LinkType,
+) -> io::Result<i32> {
+    if matches.is_present(FLAG_WATCH) {
+        let root_module = Path::new(matches.value_of_os(ROC_FILE).unwrap());
+
+        watch::watch(root_module, |watched| {
+            build_help(matches, config, triple.clone(), link_type, Some(watched))
+        })
+    } else {
+        build_help(matches, config, triple, link_type, None)
+    }
+}
+
+fn build_help(
+    matches: &ArgMatches,
+    config: BuildConfig,
+    triple: Triple,
+    link_type: LinkType,
+    mut watched: Option<&mut WatchedFiles>,
 ) -> io::Result<i32> {
     use build::build_file;
     use BuildConfig::*;
@@ -475,6 +802,7 @@ pub fn build(
     };
     let emit_debug_info = matches.is_present(FLAG_DEBUG);
     let emit_timings = matches.is_present(FLAG_TIME);
+    let profile_path = matches.value_of_os(FLAG_PROFILE).map(Path::new);
 
     let threading = match matches
         .value_of(FLAG_MAX_THREADS)
@@ -507,6 +835,9 @@ pub fn build(
         // We make an exception for Wasm, because cross-compiling is the norm in that case.
         triple != Triple::host() && !matches!(triple.architecture, Architecture::Wasm32)
     };
+
+    // When watching, the host built by an earlier run can be used until its sources change
+    let prebuilt = prebuilt || matches!(&watched, Some(watched) if !watched.host_changed());
     let path = Path::new(filename);
 
     // Spawn the root task
@@ -534,6 +865,7 @@ pub fn build(
         .and_then(|s| s.parse::<u32>().ok())
         .map(|x| x * 1024);
 
+    let warning_config = warning_config(matches);
     let build_ordering = match config {
         BuildAndRunIfNoErrors => BuildOrdering::BuildIfChecks,
         _ => BuildOrdering::AlwaysBuild,
@@ -545,12 +877,15 @@ pub fn build(
         opt_level,
         emit_debug_info,
         emit_timings,
+        profile_path,
         link_type,
         linking_strategy,
         prebuilt,
         threading,
         wasm_dev_stack_bytes,
         build_ordering,
+        &warning_config,
+        watched.as_deref_mut(),
     );
 
     match res_binary_path {
@@ -636,6 +971,10 @@ pub fn build(
 
                     let args = matches.values_of_os(ARGS_FOR_APP).unwrap_or_default();
 
+                    if let Some(watched) = watched {
+                        return run_until_changed(&binary_path, args, watched);
+                    }
+
                     let bytes = std::fs::read(&binary_path).unwrap();
 
                     let x = roc_run(
@@ -671,6 +1010,10 @@ pub fn build(
 
                     let args = matches.values_of_os(ARGS_FOR_APP).unwrap_or_default();
 
+                    if let Some(watched) = watched {
+                        return run_until_changed(&binary_path, args, watched);
+                    }
+
                     // ManuallyDrop will leak the bytes because we don't drop manually
                     let bytes = &ManuallyDrop::new(std::fs::read(&binary_path).unwrap());
 
@@ -684,7 +1027,8 @@ pub fn build(
         }) => {
             debug_assert!(module.total_problems() > 0);
 
-            let problems = roc_build::program::report_problems_typechecked(&mut module);
+            let problems =
+                roc_build::program::report_problems_typechecked(&mut module, &warning_config);
 
             let mut output = format!(
                 "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms.\n\nYou can run the program anyway with \x1B[32mroc run",
@@ -734,6 +1078,20 @@ pub fn build(
     }
 }
 
+/// Runs the program in a child process rather than replacing this process with it, so that
+/// `--watch` can stop it and run the new version after a change.
+fn run_until_changed<'a, I: IntoIterator<Item = &'a OsStr>>(
+    binary_path: &Path,
+    args: I,
+    watched: &mut WatchedFiles,
+) -> io::Result<i32> {
+    let child = process::Command::new(binary_path).args(args).spawn()?;
+
+    watched.run_until_changed(child);
+
+    Ok(0)
+}
+
 fn roc_run<'a, I: IntoIterator<Item = &'a OsStr>>(
     arena: Bump, // This should be passed an owned value, not a reference, so we can usefully mem::forget it!
     opt_level: OptLevel,
@@ -0,0 +1,87 @@
+use std::path::PathBuf;
+
+use bumpalo::Bump;
+use roc_build::program::{report_problems_typechecked, Problems};
+use roc_lint::{lint_loaded_module, lint_report, Level, LintConfig};
+use roc_load::{ExecutionMode, LoadConfig, LoadingProblem, Threading};
+use roc_mono::ir::OptLevel;
+use roc_region::all::LineInfo;
+use roc_reporting::report::{RenderTarget, Report, RocDocAllocator, DEFAULT_PALETTE};
+use roc_reporting::warnings::WarningConfig;
+use roc_target::TargetInfo;
+
+/// Reports the problems that `roc check` would report, and if there are no errors, the lints
+/// of the given module. Lints at `Level::Deny` count as errors, and lints at `Level::Warn` count
+/// as warnings, unless warnings are denied. Like warnings, lints can be allowed with a
+/// `# roc-allow:` comment.
+pub fn lint_file<'a>(
+    arena: &'a Bump,
+    roc_file_path: PathBuf,
+    threading: Threading,
+    config: &LintConfig,
+    warning_config: &WarningConfig,
+) -> Result<Problems, LoadingProblem<'a>> {
+    // only used for generating errors. We don't do code generation, so hardcoding should be fine
+    let target_info = TargetInfo::default_x86_64();
+
+    let load_config = LoadConfig {
+        target_info,
+        render: RenderTarget::ColorTerminal,
+        threading,
+        exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Development,
+        warning_config: Default::default(),
+    };
+    let mut loaded =
+        roc_load::load_and_typecheck(arena, roc_file_path, Default::default(), load_config)?;
+
+    let mut problems = report_problems_typechecked(&mut loaded, warning_config);
+
+    // the types of a program with errors are not reliable enough to lint it
+    if problems.errors > 0 {
+        return Ok(problems);
+    }
+
+    let home = loaded.module_id;
+    let (module_path, src) = &loaded.sources[&home];
+    let lines = LineInfo::new(src);
+    let suppressions = loaded.suppressions.get(&home);
+
+    let lints: Vec<_> = lint_loaded_module(&loaded, config)
+        .into_iter()
+        .filter(|lint| {
+            let line = lines.convert_pos(lint.finding.region.start()).line;
+
+            !suppressions.map_or(false, |suppressions| {
+                suppressions.allows(lint.rule, Some(line))
+            })
+        })
+        .collect();
+
+    if lints.is_empty() {
+        return Ok(problems);
+    }
+
+    let palette = DEFAULT_PALETTE;
+    let src_lines: Vec<&str> = src.split('\n').collect();
+    let alloc = RocDocAllocator::new(&src_lines, home, &loaded.interns);
+
+    for lint in lints {
+        match lint.level {
+            Level::Warn if !warning_config.deny_warnings => problems.warnings += 1,
+            Level::Warn | Level::Deny => problems.errors += 1,
+            Level::Allow => continue,
+        }
+
+        let report = lint_report(&alloc, &lines, module_path.clone(), lint);
+        let mut buf = String::new();
+
+        report.render_color_terminal(&mut buf, &alloc, &palette);
+
+        println!("\n{}\n", buf);
+    }
+
+    println!("{}\u{001B}[0m\n", Report::horizontal_rule(&palette));
+
+    Ok(problems)
+}
@@ -1,14 +1,21 @@
+use clap::ArgMatches;
 use roc_build::link::LinkType;
 use roc_cli::build::check_file;
+use roc_cli::watch::{watch, WatchedFiles};
 use roc_cli::{
-    build_app, format, test, BuildConfig, FormatMode, Target, CMD_BUILD, CMD_CHECK, CMD_DEV,
-    CMD_DOCS, CMD_EDIT, CMD_FORMAT, CMD_GLUE, CMD_REPL, CMD_RUN, CMD_TEST, CMD_VERSION,
-    DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_LIB, FLAG_NO_LINK, FLAG_TARGET, FLAG_TIME, GLUE_FILE,
-    ROC_FILE,
+    build_app, deps, fix_file, format, format_stdin, lint_file, parse_byte_range, test,
+    warning_config, BuildConfig, DepsFormat, DepsQuery, FixMode, FixSummary, FormatMode, Target,
+    CMD_BUILD, CMD_CHECK, CMD_DEPS, CMD_DEV, CMD_DOCS, CMD_EDIT, CMD_FIX, CMD_FORMAT, CMD_GLUE,
+    CMD_LINT, CMD_REPL, CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, FLAG_ALLOW,
+    FLAG_BASE_URL, FLAG_BUILTINS_URL, FLAG_CHECK, FLAG_DENY, FLAG_DRY_RUN, FLAG_FIX, FLAG_FORMAT,
+    FLAG_LIB, FLAG_NO_LINK, FLAG_OUTPUT, FLAG_PACKAGE_URL, FLAG_PROFILE, FLAG_RANGE, FLAG_STDIN,
+    FLAG_TARGET, FLAG_TIME, FLAG_UNUSED, FLAG_WARN, FLAG_WATCH, FLAG_WHY, GLUE_FILE, ROC_FILE,
 };
-use roc_docs::generate_docs_html;
+use roc_docs::{generate_docs_html, generate_docs_json};
 use roc_error_macros::user_error;
+use roc_lint::{Level, LintConfig};
 use roc_load::{LoadingProblem, Threading};
+use roc_reporting::warnings::WarningKind;
 use std::fs::{self, FileType};
 use std::io;
 use std::path::{Path, PathBuf};
@@ -114,25 +121,94 @@ fn main() -> io::Result<()> {
             )?)
         }
         Some((CMD_CHECK, matches)) => {
+            if matches.is_present(FLAG_WATCH) {
+                let root_module = Path::new(matches.value_of_os(ROC_FILE).unwrap());
+
+                watch(root_module, |watched| check(matches, Some(watched)))
+            } else {
+                check(matches, None)
+            }
+        }
+        Some((CMD_FIX, matches)) => {
             let arena = bumpalo::Bump::new();
 
-            let emit_timings = matches.is_present(FLAG_TIME);
             let filename = matches.value_of_os(ROC_FILE).unwrap();
             let roc_file_path = PathBuf::from(filename);
-            let threading = match matches
-                .value_of(roc_cli::FLAG_MAX_THREADS)
-                .and_then(|s| s.parse::<usize>().ok())
-            {
-                None => Threading::AllAvailable,
-                Some(0) => user_error!("cannot build with at most 0 threads"),
-                Some(1) => Threading::Single,
-                Some(n) => Threading::AtMost(n),
+            let mode = match matches.is_present(FLAG_DRY_RUN) {
+                true => FixMode::DryRun,
+                false => FixMode::Apply,
             };
 
-            match check_file(&arena, roc_file_path, emit_timings, threading) {
-                Ok((problems, total_time)) => {
+            match fix_file(&arena, roc_file_path, threading(matches), mode)? {
+                Ok(FixSummary { fixes: 0, .. }) => {
+                    println!("There was nothing to fix.");
+
+                    Ok(0)
+                }
+                Ok(FixSummary { fixes, files }) => {
+                    let fixes = format!(
+                        "{} {} in {} {}",
+                        fixes,
+                        if fixes == 1 { "problem" } else { "problems" },
+                        files,
+                        if files == 1 { "file" } else { "files" },
+                    );
+
+                    match mode {
+                        FixMode::Apply => println!("Fixed {}.", fixes),
+                        FixMode::DryRun => println!(
+                            "\nI can fix {}. Run `roc fix` without `--{}` to apply the fixes.",
+                            fixes, FLAG_DRY_RUN
+                        ),
+                    }
+
+                    Ok(0)
+                }
+                Err(LoadingProblem::FormattedReport(report)) => {
+                    print!("{}", report);
+
+                    Ok(1)
+                }
+                Err(other) => {
+                    panic!("fix_file failed with error:\n{:?}", other);
+                }
+            }
+        }
+        Some((CMD_LINT, matches)) => {
+            let arena = bumpalo::Bump::new();
+
+            let filename = matches.value_of_os(ROC_FILE).unwrap();
+            let roc_file_path = PathBuf::from(filename);
+            let mut config = LintConfig::default();
+            // `--allow` takes both lints and kinds of compiler warnings
+            let warning_config = warning_config(matches);
+
+            // apply the least severe level first, so the most severe level wins
+            for (flag, level) in [
+                (FLAG_ALLOW, Level::Allow),
+                (FLAG_WARN, Level::Warn),
+                (FLAG_DENY, Level::Deny),
+            ] {
+                for rule in matches.values_of(flag).into_iter().flatten() {
+                    if level == Level::Allow && WarningKind::from_name(rule).is_some() {
+                        continue;
+                    }
+
+                    // clap has already checked that this is the name of a rule
+                    config.set_level(rule, level).unwrap();
+                }
+            }
+
+            match lint_file(
+                &arena,
+                roc_file_path,
+                threading(matches),
+                &config,
+                &warning_config,
+            ) {
+                Ok(problems) => {
                     println!(
-                        "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms.",
+                        "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found.",
                         if problems.errors == 0 {
                             32 // green
                         } else {
@@ -155,19 +231,50 @@ fn main() -> io::Result<()> {
                         } else {
                             "warnings"
                         },
-                        total_time.as_millis(),
                     );
 
                     Ok(problems.exit_code())
                 }
+                Err(LoadingProblem::FormattedReport(report)) => {
+                    print!("{}", report);
+
+                    Ok(1)
+                }
+                Err(other) => {
+                    panic!("lint_file failed with error:\n{:?}", other);
+                }
+            }
+        }
+        Some((CMD_DEPS, matches)) => {
+            let arena = bumpalo::Bump::new();
 
+            let filename = matches.value_of_os(ROC_FILE).unwrap();
+            let roc_file_path = PathBuf::from(filename);
+
+            let query = match matches.values_of(FLAG_WHY) {
+                Some(mut modules) => {
+                    // clap has already checked that there are two of them
+                    DepsQuery::Why(modules.next().unwrap(), modules.next().unwrap())
+                }
+                None => DepsQuery::Graph {
+                    format: match matches.value_of(FLAG_FORMAT) {
+                        Some("dot") => DepsFormat::Dot,
+                        Some("json") => DepsFormat::Json,
+                        _ => DepsFormat::Text,
+                    },
+                    find_unused: matches.is_present(FLAG_UNUSED),
+                },
+            };
+
+            match deps(&arena, roc_file_path, threading(matches), query) {
+                Ok(exit_code) => Ok(exit_code),
                 Err(LoadingProblem::FormattedReport(report)) => {
                     print!("{}", report);
 
                     Ok(1)
                 }
                 Err(other) => {
-                    panic!("build_file failed with error:\n{:?}", other);
+                    panic!("deps failed with error:\n{:?}", other);
                 }
             }
         }
@@ -226,47 +333,93 @@ fn main() -> io::Result<()> {
                 roc_files_recursive(os_str.as_os_str(), metadata.file_type(), &mut roc_files)?;
             }
 
-            generate_docs_html(roc_files);
+            let output_dir = Path::new(matches.value_of_os(FLAG_OUTPUT).unwrap());
+
+            // clap has already validated these
+            let package_urls: Vec<(String, String)> = matches
+                .values_of(FLAG_PACKAGE_URL)
+                .into_iter()
+                .flatten()
+                .filter_map(|arg| roc_docs::parse_package_url(arg).ok())
+                .collect();
+
+            let docs_result = match matches.value_of(FLAG_FORMAT) {
+                Some("json") => generate_docs_json(roc_files, output_dir),
+                _ => generate_docs_html(
+                    roc_files,
+                    output_dir,
+                    matches.value_of(FLAG_BASE_URL).unwrap(),
+                    matches.value_of(FLAG_BUILTINS_URL).unwrap(),
+                    &package_urls,
+                ),
+            };
 
-            Ok(0)
+            let docs_exit_code = match docs_result {
+                Ok(()) => 0,
+                Err(message) => {
+                    eprintln!("{}", message);
+                    1
+                }
+            };
+
+            Ok(docs_exit_code)
         }
         Some((CMD_FORMAT, matches)) => {
-            let maybe_values = matches.values_of_os(DIRECTORY_OR_FILES);
+            let format_mode = match matches.is_present(FLAG_CHECK) {
+                true => FormatMode::CheckOnly,
+                false => FormatMode::Format,
+            };
 
-            let mut values: Vec<OsString> = Vec::new();
+            // clap has already validated the range
+            let range = matches
+                .value_of(FLAG_RANGE)
+                .map(|range| parse_byte_range(range).unwrap());
 
-            match maybe_values {
-                None => {
-                    let mut os_string_values: Vec<OsString> = Vec::new();
-                    read_all_roc_files(
-                        &std::env::current_dir()?.as_os_str().to_os_string(),
-                        &mut os_string_values,
-                    )?;
-                    for os_string in os_string_values {
-                        values.push(os_string);
+            let format_result = if matches.is_present(FLAG_STDIN) {
+                format_stdin(format_mode, range)
+            } else {
+                let maybe_values = matches.values_of_os(DIRECTORY_OR_FILES);
+
+                let mut values: Vec<OsString> = Vec::new();
+
+                match maybe_values {
+                    None => {
+                        let mut os_string_values: Vec<OsString> = Vec::new();
+                        read_all_roc_files(
+                            &std::env::current_dir()?.as_os_str().to_os_string(),
+                            &mut os_string_values,
+                        )?;
+                        for os_string in os_string_values {
+                            values.push(os_string);
+                        }
                     }
-                }
-                Some(os_values) => {
-                    for os_str in os_values {
-                        values.push(os_str.to_os_string());
+                    Some(os_values) => {
+                        for os_str in os_values {
+                            values.push(os_str.to_os_string());
+                        }
                     }
                 }
-            }
 
-            let mut roc_files = Vec::new();
+                let mut roc_files = Vec::new();
 
-            // Populate roc_files
-            for os_str in values {
-                let metadata = fs::metadata(os_str.clone())?;
-                roc_files_recursive(os_str.as_os_str(), metadata.file_type(), &mut roc_files)?;
-            }
+                // Populate roc_files
+                for os_str in values {
+                    let metadata = fs::metadata(os_str.clone())?;
+                    roc_files_recursive(os_str.as_os_str(), metadata.file_type(), &mut roc_files)?;
+                }
 
-            let format_mode = match matches.is_present(FLAG_CHECK) {
-                true => FormatMode::CheckOnly,
-                false => FormatMode::Format,
+                if range.is_some() && roc_files.len() != 1 {
+                    Err(format!(
+                        "--{} needs exactly one file to format, but I found {}.",
+                        FLAG_RANGE,
+                        roc_files.len()
+                    ))
+                } else {
+                    format(roc_files, format_mode, range)
+                }
             };
 
-            let format_exit_code = match format(roc_files, format_mode) {
+            let format_exit_code = match format_result {
                 Ok(_) => 0,
                 Err(message) => {
                     eprintln!("{}", message);
@@ -290,6 +443,100 @@ fn main() -> io::Result<()> {
     std::process::exit(exit_code);
 }
 
+fn check(matches: &ArgMatches, watched: Option<&mut WatchedFiles>) -> io::Result<i32> {
+    let arena = bumpalo::Bump::new();
+
+    let emit_timings = matches.is_present(FLAG_TIME);
+    let filename = matches.value_of_os(ROC_FILE).unwrap();
+    let roc_file_path = PathBuf::from(filename);
+    let threading = threading(matches);
+
+    if matches.is_present(FLAG_FIX) {
+        match fix_file(&arena, roc_file_path.clone(), threading, FixMode::Apply)? {
+            Ok(FixSummary { fixes, files }) if fixes > 0 => {
+                println!(
+                    "Fixed {} {} in {} {}.\n",
+                    fixes,
+                    if fixes == 1 { "problem" } else { "problems" },
+                    files,
+                    if files == 1 { "file" } else { "files" },
+                );
+            }
+            Ok(_) => {}
+            Err(LoadingProblem::FormattedReport(report)) => {
+                print!("{}", report);
+
+                return Ok(1);
+            }
+            Err(other) => {
+                panic!("fix_file failed with error:\n{:?}", other);
+            }
+        }
+    }
+
+    match check_file(
+        &arena,
+        roc_file_path,
+        emit_timings,
+        matches.value_of_os(FLAG_PROFILE).map(Path::new),
+        threading,
+        &warning_config(matches),
+        watched,
+    ) {
+        Ok((problems, total_time)) => {
+            println!(
+                "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms.",
+                if problems.errors == 0 {
+                    32 // green
+                } else {
+                    33 // yellow
+                },
+                problems.errors,
+                if problems.errors == 1 {
+                    "error"
+                } else {
+                    "errors"
+                },
+                if problems.warnings == 0 {
+                    32 // green
+                } else {
+                    33 // yellow
+                },
+                problems.warnings,
+                if problems.warnings == 1 {
+                    "warning"
+                } else {
+                    "warnings"
+                },
+                total_time.as_millis(),
+            );
+
+            Ok(problems.exit_code())
+        }
+
+        Err(LoadingProblem::FormattedReport(report)) => {
+            print!("{}", report);
+
+            Ok(1)
+        }
+        Err(other) => {
+            panic!("build_file failed with error:\n{:?}", other);
+        }
+    }
+}
+
+fn threading(matches: &ArgMatches) -> Threading {
+    match matches
+        .value_of(roc_cli::FLAG_MAX_THREADS)
+        .and_then(|s| s.parse::<usize>().ok())
+    {
+        None => Threading::AllAvailable,
+        Some(0) => user_error!("cannot build with at most 0 threads"),
+        Some(1) => Threading::Single,
+        Some(n) => Threading::AtMost(n),
+    }
+}
+
 fn read_all_roc_files(
     dir: &OsString,
     roc_file_paths: &mut Vec<OsString>,
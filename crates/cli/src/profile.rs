@@ -0,0 +1,273 @@
+//! `--profile` support: records when every phase of the compiler ran, and writes that to a file
+//! in the Chrome Trace Event format, which can be opened in `chrome://tracing` or
+//! <https://ui.perfetto.dev>.
+use roc_collections::MutMap;
+use roc_load::ModuleTiming;
+use roc_module::ident::ModuleName;
+use roc_module::symbol::{Interns, ModuleId};
+use serde_json::{json, Value};
+use std::io;
+use std::path::Path;
+use std::time::Instant;
+
+/// Where a span is drawn in the trace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Track {
+    /// The thread that generates code and links, after the modules are loaded
+    Main,
+    /// The platform host is rebuilt concurrently with the app
+    Host,
+    /// A thread of the loader, numbered by `roc_load`
+    Load(usize),
+}
+
+#[derive(Debug)]
+struct Span {
+    name: String,
+    category: &'static str,
+    start: Instant,
+    end: Instant,
+    track: Track,
+}
+
+#[derive(Debug, Default)]
+pub struct Profile {
+    spans: Vec<Span>,
+}
+
+impl Profile {
+    /// Adds the spans of every phase that ran for every module
+    pub fn add_module_timings(
+        &mut self,
+        interns: &Interns,
+        timings: &MutMap<ModuleId, ModuleTiming>,
+    ) {
+        for (module_id, timing) in timings {
+            let module_name = match interns.module_name(*module_id).as_str() {
+                ModuleName::APP => "Application Module",
+                // the platform module is loaded under an empty name
+                "" => "Platform Module",
+                module_name => module_name,
+            };
+
+            for span in timing.spans.iter() {
+                self.spans.push(Span {
+                    name: format!("{}: {}", module_name, span.phase),
+                    category: span.phase,
+                    start: span.start,
+                    end: span.end,
+                    track: Track::Load(span.thread),
+                });
+            }
+        }
+    }
+
+    /// Adds a phase that runs after loading, like code generation or linking
+    pub fn add_phase(&mut self, phase: &'static str, start: Instant, end: Instant) {
+        self.spans.push(Span {
+            name: phase.to_string(),
+            category: phase,
+            start,
+            end,
+            track: Track::Main,
+        });
+    }
+
+    pub fn add_host_rebuild(&mut self, start: Instant, end: Instant) {
+        self.spans.push(Span {
+            name: "rebuild platform host".to_string(),
+            category: "rebuild platform host",
+            start,
+            end,
+            track: Track::Host,
+        });
+    }
+
+    fn to_json(&self) -> Value {
+        let origin = match self.spans.iter().map(|span| span.start).min() {
+            Some(origin) => origin,
+            None => return json!({ "traceEvents": [] }),
+        };
+
+        let micros = |instant: Instant| instant.duration_since(origin).as_secs_f64() * 1e6;
+
+        let load_threads = self.spans.iter().filter_map(|span| match span.track {
+            Track::Load(thread) => Some(thread),
+            Track::Main | Track::Host => None,
+        });
+        let host_thread = load_threads.clone().max().unwrap_or(0) + 1;
+
+        let thread_id = |track| match track {
+            Track::Main => 0,
+            Track::Host => host_thread,
+            Track::Load(thread) => thread,
+        };
+
+        let mut thread_names: Vec<_> = load_threads
+            .map(|thread| (thread, format!("load thread {}", thread)))
+            .collect();
+        thread_names.sort_unstable();
+        thread_names.dedup();
+
+        for (track, name) in [(Track::Main, "main"), (Track::Host, "platform host")] {
+            if self.spans.iter().any(|span| span.track == track) {
+                thread_names.push((thread_id(track), name.to_string()));
+            }
+        }
+
+        // metadata events, which give the threads a name in the trace viewer
+        let mut events: Vec<Value> = thread_names
+            .into_iter()
+            .map(|(thread, name)| {
+                json!({
+                    "name": "thread_name",
+                    "ph": "M",
+                    "pid": 0,
+                    "tid": thread,
+                    "args": { "name": name },
+                })
+            })
+            .collect();
+
+        // complete events, which have both a start time and a duration
+        events.extend(self.spans.iter().map(|span| {
+            json!({
+                "name": span.name,
+                "cat": span.category,
+                "ph": "X",
+                "ts": micros(span.start),
+                "dur": micros(span.end) - micros(span.start),
+                "pid": 0,
+                "tid": thread_id(span.track),
+            })
+        }));
+
+        json!({ "traceEvents": events, "displayTimeUnit": "ms" })
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string(&self.to_json())?;
+
+        std::fs::write(path, json)
+    }
+}
+
+#[cfg(test)]
+mod test_profile {
+    use super::*;
+    use roc_load::PhaseSpan;
+    use roc_module::symbol::ModuleId;
+    use std::time::Duration;
+
+    fn millis(start: Instant, millis: u64) -> Instant {
+        start + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn empty_profile() {
+        assert_eq!(Profile::default().to_json(), json!({ "traceEvents": [] }));
+    }
+
+    #[test]
+    fn chrome_trace_events() {
+        let start = Instant::now();
+        let mut interns = Interns::default();
+        let app_id = interns.module_id(&ModuleName::APP.into());
+
+        let mut app_timing = ModuleTiming::new(start);
+        app_timing.spans = vec![
+            PhaseSpan {
+                phase: "parse",
+                start,
+                end: millis(start, 2),
+                thread: 1,
+            },
+            PhaseSpan {
+                phase: "solve",
+                start: millis(start, 5),
+                end: millis(start, 9),
+                thread: 2,
+            },
+        ];
+
+        let mut str_timing = ModuleTiming::new(start);
+        str_timing.spans = vec![PhaseSpan {
+            phase: "canonicalize",
+            start: millis(start, 1),
+            end: millis(start, 4),
+            thread: 2,
+        }];
+
+        let mut timings = MutMap::default();
+        timings.insert(app_id, app_timing);
+        timings.insert(ModuleId::STR, str_timing);
+
+        let mut profile = Profile::default();
+        profile.add_module_timings(&interns, &timings);
+        profile.add_phase("generate code", millis(start, 10), millis(start, 20));
+        profile.add_host_rebuild(millis(start, 3), millis(start, 30));
+
+        let json = profile.to_json();
+
+        assert_eq!(json["displayTimeUnit"], "ms");
+
+        let events = json["traceEvents"].as_array().unwrap();
+
+        let thread_names: Vec<_> = events
+            .iter()
+            .filter(|event| event["ph"] == "M")
+            .map(|event| {
+                assert_eq!(event["name"], "thread_name");
+
+                (
+                    event["tid"].as_u64().unwrap(),
+                    event["args"]["name"].as_str().unwrap(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            thread_names,
+            [
+                (1, "load thread 1"),
+                (2, "load thread 2"),
+                (0, "main"),
+                (3, "platform host"),
+            ]
+        );
+
+        let mut spans: Vec<_> = events
+            .iter()
+            .filter(|event| event["ph"] == "X")
+            .map(|event| {
+                assert_eq!(event["pid"], 0);
+
+                (
+                    event["name"].as_str().unwrap(),
+                    event["cat"].as_str().unwrap(),
+                    event["tid"].as_u64().unwrap(),
+                    event["ts"].as_f64().unwrap().round() as u64,
+                    event["dur"].as_f64().unwrap().round() as u64,
+                )
+            })
+            .collect();
+        spans.sort_unstable();
+
+        assert_eq!(
+            spans,
+            [
+                ("Application Module: parse", "parse", 1, 0, 2_000),
+                ("Application Module: solve", "solve", 2, 5_000, 4_000),
+                ("Str: canonicalize", "canonicalize", 2, 1_000, 3_000),
+                ("generate code", "generate code", 0, 10_000, 10_000),
+                (
+                    "rebuild platform host",
+                    "rebuild platform host",
+                    3,
+                    3_000,
+                    27_000
+                ),
+            ]
+        );
+    }
+}
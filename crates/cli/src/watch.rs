@@ -0,0 +1,274 @@
+//! Support for `--watch`, which reruns a command whenever one of the files it depends on changes.
+//!
+//! Files are watched by polling their modification times, which works the same way on every OS
+//! and with every editor. The loader does not have an incremental mode yet, so every rerun loads
+//! all modules again. What is reused between runs is the platform host: it is only rebuilt when
+//! one of its sources changed.
+use roc_collections::all::MutMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Child;
+use std::time::{Duration, SystemTime};
+
+/// How often the watched files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long the files have to stay the same before the command is rerun. Editors often write a
+/// file in several steps, and saving all open files changes several files in a row; waiting a
+/// little makes those changes cause a single rerun.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// The extensions of the files a platform host can be built from
+const HOST_SOURCE_EXTENSIONS: &[&str] = &["c", "h", "zig", "rs", "swift", "toml"];
+
+/// Directories that only contain build outputs, which are never watched
+const IGNORED_DIRS: &[&str] = &["target", "zig-cache", "zig-out"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileState {
+    modified: Option<SystemTime>,
+    /// The hash of the contents, so that saving a file without changing it doesn't cause a rerun
+    hash: Option<u64>,
+}
+
+impl FileState {
+    fn read(path: &Path) -> Self {
+        let modified = std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        let hash = std::fs::read(path).ok().map(|bytes| {
+            let mut hasher = DefaultHasher::new();
+            bytes.hash(&mut hasher);
+
+            hasher.finish()
+        });
+
+        FileState { modified, hash }
+    }
+}
+
+#[derive(Debug)]
+struct WatchedFile {
+    state: FileState,
+    is_host_source: bool,
+}
+
+/// The files a command depended on the last time it ran
+#[derive(Debug)]
+pub struct WatchedFiles {
+    files: MutMap<PathBuf, WatchedFile>,
+    /// Whether the loader found the modules of the program, rather than failing before that
+    found_modules: bool,
+    /// Whether the host has to be rebuilt, because it wasn't built yet or its sources changed
+    host_changed: bool,
+    /// The program started by the last run, which is stopped before the next run
+    running: Option<Child>,
+}
+
+impl WatchedFiles {
+    fn new() -> Self {
+        WatchedFiles {
+            files: MutMap::default(),
+            found_modules: false,
+            host_changed: true,
+            running: None,
+        }
+    }
+
+    fn watch_file(&mut self, path: PathBuf, is_host_source: bool) {
+        self.files
+            .entry(path)
+            .or_insert_with_key(|path| WatchedFile {
+                state: FileState::read(path),
+                is_host_source,
+            });
+    }
+
+    /// Watches the source files of the modules that were loaded
+    pub fn watch_modules<'a>(&mut self, paths: impl IntoIterator<Item = &'a PathBuf>) {
+        self.found_modules = true;
+
+        for path in paths {
+            // builtin modules have a path, but are not read from disk
+            if path.is_file() {
+                self.watch_file(path.clone(), false);
+            }
+        }
+    }
+
+    /// Watches the sources of the host in the given platform directory. This is called after the
+    /// host was built, so from now on it only has to be rebuilt when one of them changes.
+    pub fn watch_host(&mut self, platform_dir: &Path) {
+        let mut host_sources = Vec::new();
+
+        find_files(platform_dir, HOST_SOURCE_EXTENSIONS, &mut host_sources);
+
+        for path in host_sources {
+            self.watch_file(path, true);
+        }
+
+        self.host_changed = false;
+    }
+
+    /// Whether the host has to be rebuilt before the program can run
+    pub fn host_changed(&self) -> bool {
+        self.host_changed
+    }
+
+    /// Lets the program run until one of the watched files changes
+    pub fn run_until_changed(&mut self, child: Child) {
+        self.running = Some(child);
+    }
+
+    /// The watched files that changed since they were last looked at
+    fn changed_files(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+
+        for (path, file) in self.files.iter_mut() {
+            let modified = std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+
+            if modified == file.state.modified {
+                continue;
+            }
+
+            let state = FileState::read(path);
+
+            if state.hash != file.state.hash {
+                self.host_changed |= file.is_host_source;
+                changed.push(path.clone());
+            }
+
+            file.state = state;
+        }
+
+        changed
+    }
+
+    /// Blocks until some of the watched files changed, and returns them
+    fn wait_for_change(&mut self) -> io::Result<Vec<PathBuf>> {
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+
+            let mut changed = self.changed_files();
+
+            if changed.is_empty() {
+                continue;
+            }
+
+            loop {
+                std::thread::sleep(DEBOUNCE);
+
+                let more = self.changed_files();
+
+                if more.is_empty() {
+                    break;
+                }
+
+                changed.extend(more);
+            }
+
+            if let Some(mut child) = self.running.take() {
+                // the program may well have exited already, in which case there is nothing to kill
+                let _ = child.kill();
+                child.wait()?;
+            }
+
+            changed.sort();
+            changed.dedup();
+
+            return Ok(changed);
+        }
+    }
+}
+
+/// Runs the command, and reruns it whenever one of the files it depends on changes, until the
+/// process is interrupted. The command tells which files it depends on through [WatchedFiles];
+/// if it fails before that, the `.roc` files next to the root module are watched instead.
+pub fn watch(
+    root_module: &Path,
+    mut run: impl FnMut(&mut WatchedFiles) -> io::Result<i32>,
+) -> io::Result<i32> {
+    let mut watched = WatchedFiles::new();
+
+    loop {
+        watched.found_modules = false;
+        watched.watch_file(root_module.to_path_buf(), false);
+
+        run(&mut watched)?;
+
+        if !watched.found_modules {
+            let dir = match root_module.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir,
+                _ => Path::new("."),
+            };
+            let mut roc_files = Vec::new();
+
+            find_files(dir, &["roc"], &mut roc_files);
+
+            for path in roc_files {
+                watched.watch_file(path, false);
+            }
+        }
+
+        println!(
+            "\n\x1B[36mWatching {} {} for changes. Press Ctrl-C to stop.\x1B[39m",
+            watched.files.len(),
+            if watched.files.len() == 1 {
+                "file"
+            } else {
+                "files"
+            },
+        );
+
+        let changed = watched.wait_for_change()?;
+
+        // clear the screen, and move the cursor to the top left corner
+        print!("\x1B[2J\x1B[H");
+
+        for path in changed {
+            println!("\x1B[36mChanged: {}\x1B[39m", path.display());
+        }
+
+        println!();
+    }
+}
+
+/// Finds the files with one of the given extensions in a directory and its subdirectories
+fn find_files(dir: &Path, extensions: &[&str], files: &mut Vec<PathBuf>) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+
+        if name.starts_with('.') {
+            continue;
+        }
+
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() && !IGNORED_DIRS.contains(&name.as_ref()) => {
+                find_files(&path, extensions, files);
+            }
+            Ok(file_type) if file_type.is_file() => {
+                let has_extension = path
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .map_or(false, |extension| extensions.contains(&extension));
+
+                if has_extension {
+                    files.push(path);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -18,7 +18,7 @@ mod cli_run {
     use indoc::indoc;
     use once_cell::sync::Lazy;
     use parking_lot::{Mutex, RwLock};
-    use roc_cli::{CMD_BUILD, CMD_CHECK, CMD_FORMAT, CMD_RUN};
+    use roc_cli::{CMD_BUILD, CMD_CHECK, CMD_DEPS, CMD_FIX, CMD_FORMAT, CMD_LINT, CMD_RUN};
     use roc_test_utils::assert_multiline_str_eq;
     use serial_test::serial;
     use std::iter;
@@ -30,6 +30,15 @@ mod cli_run {
     const OPTIMIZE_FLAG: &str = concatcp!("--", roc_cli::FLAG_OPTIMIZE);
     const LINKER_FLAG: &str = concatcp!("--", roc_cli::FLAG_LINKER);
     const CHECK_FLAG: &str = concatcp!("--", roc_cli::FLAG_CHECK);
+    const DRY_RUN_FLAG: &str = concatcp!("--", roc_cli::FLAG_DRY_RUN);
+    const ALLOW_FLAG: &str = concatcp!("--", roc_cli::FLAG_ALLOW);
+    const DENY_FLAG: &str = concatcp!("--", roc_cli::FLAG_DENY);
+    const DENY_WARNINGS_FLAG: &str = concatcp!("--", roc_cli::FLAG_DENY_WARNINGS);
+    const WHY_FLAG: &str = concatcp!("--", roc_cli::FLAG_WHY);
+    const UNUSED_FLAG: &str = concatcp!("--", roc_cli::FLAG_UNUSED);
+    const STDIN_FLAG: &str = concatcp!("--", roc_cli::FLAG_STDIN);
+    const RANGE_FLAG: &str = concatcp!("--", roc_cli::FLAG_RANGE);
+    const VERIFY_FLAG: &str = concatcp!("--", roc_cli::FLAG_VERIFY);
     const PREBUILT_PLATFORM: &str = concatcp!("--", roc_cli::FLAG_PREBUILT, "=true");
     #[allow(dead_code)]
     const TARGET_FLAG: &str = concatcp!("--", roc_cli::FLAG_TARGET);
@@ -1145,6 +1154,165 @@ mod cli_run {
         // This doesn't fail, since only "Formatted.roc" and non-roc files are present in this folder
         check_format_check_as_expected(&fixtures_dir("format/formatted_directory"), true);
     }
+
+    #[test]
+    fn format_stdin() {
+        let src = "interface Main exposes [a, b] imports []\n\na =    1\n\nb = [1,2]\n";
+        let out = run_roc([CMD_FORMAT, STDIN_FLAG, VERIFY_FLAG], &[src]);
+
+        assert!(out.status.success(), "bad status {:?}", out);
+        assert_eq!(
+            out.stdout,
+            "interface Main exposes [a, b] imports []\n\na = 1\n\nb = [1, 2]\n"
+        );
+    }
+
+    #[test]
+    fn format_stdin_range() {
+        let src = "interface Main exposes [a, b] imports []\n\na =    1\n\nb = [1,2]\n";
+        let out = run_roc([CMD_FORMAT, STDIN_FLAG, RANGE_FLAG, "42:43"], &[src]);
+
+        // Only `a`, which overlaps the range, is formatted
+        assert!(out.status.success(), "bad status {:?}", out);
+        assert_eq!(
+            out.stdout,
+            "interface Main exposes [a, b] imports []\n\na = 1\n\nb = [1,2]\n"
+        );
+    }
+
+    #[test]
+    fn fix_dry_run() {
+        let file = fixture_file("fix", "Fixable.roc");
+        let out = run_roc([CMD_FIX, file.to_str().unwrap(), DRY_RUN_FLAG], &[]);
+
+        assert!(out.status.success(), "bad status {:?}", out);
+
+        // unused imports are removed, and unused arguments prefixed with an underscore
+        assert!(out
+            .stdout
+            .contains("-    imports [Helpers, Unused.{ unused }]\n+    imports [Helpers]\n"));
+        assert!(out
+            .stdout
+            .contains("+greet = \\name, _punctuation -> Helpers.hello name\n"));
+
+        // `Helpers.hello` is used, so it is added to the exposes of `Helpers`
+        assert!(out
+            .stdout
+            .contains("-    exposes []\n+    exposes [hello]\n"));
+
+        assert!(out.stdout.contains("I can fix 3 problems in 2 files."));
+    }
+
+    #[test]
+    fn lint_reports_warnings() {
+        let file = fixture_file("lint", "Lintable.roc");
+        let out = run_roc([CMD_LINT, file.to_str().unwrap()], &[]);
+
+        assert!(!out.status.success(), "bad status {:?}", out);
+        assert!(out.stdout.contains("WHEN ON BOOL"));
+        assert!(out.stdout.contains("LIST LENGTH COMPARED TO ZERO"));
+        assert!(out
+            .stdout
+            .contains("0\x1B[39m errors and \x1B[33m2\x1B[39m warnings found."));
+    }
+
+    #[test]
+    fn lint_levels() {
+        let file = fixture_file("lint", "Lintable.roc");
+        let out = run_roc(
+            [
+                CMD_LINT,
+                DENY_FLAG,
+                "when-on-bool",
+                ALLOW_FLAG,
+                "list-len-zero",
+                file.to_str().unwrap(),
+            ],
+            &[],
+        );
+
+        assert!(out.stdout.contains("WHEN ON BOOL"));
+        assert!(!out.stdout.contains("LIST LENGTH COMPARED TO ZERO"));
+        assert!(out
+            .stdout
+            .contains("1\x1B[39m error and \x1B[32m0\x1B[39m warnings found."));
+
+        let out = run_roc(
+            [
+                CMD_LINT,
+                ALLOW_FLAG,
+                "when-on-bool",
+                ALLOW_FLAG,
+                "list-len-zero",
+                file.to_str().unwrap(),
+            ],
+            &[],
+        );
+
+        assert!(out.status.success(), "bad status {:?}", out);
+    }
+
+    #[test]
+    fn check_allowed_warnings() {
+        let file = fixture_file("warnings", "Warnings.roc");
+
+        // the second unused definition is allowed by a `# roc-allow:` comment
+        let out = run_roc([CMD_CHECK, file.to_str().unwrap()], &[]);
+
+        assert!(out.stdout.contains("UNUSED DEFINITION"));
+        assert!(!out.stdout.contains("alsoUnused"));
+        assert!(out
+            .stdout
+            .contains("0\x1B[39m errors and \x1B[33m1\x1B[39m warning found"));
+
+        let out = run_roc(
+            [
+                CMD_CHECK,
+                ALLOW_FLAG,
+                "unused-definition",
+                file.to_str().unwrap(),
+            ],
+            &[],
+        );
+
+        assert!(out.status.success(), "bad status {:?}", out);
+        assert!(out
+            .stdout
+            .contains("0\x1B[39m errors and \x1B[32m0\x1B[39m warnings found"));
+    }
+
+    #[test]
+    fn check_deny_warnings() {
+        let file = fixture_file("warnings", "Warnings.roc");
+        let out = run_roc([CMD_CHECK, DENY_WARNINGS_FLAG, file.to_str().unwrap()], &[]);
+
+        assert!(!out.status.success(), "bad status {:?}", out);
+        assert!(out
+            .stdout
+            .contains("1\x1B[39m error and \x1B[32m0\x1B[39m warnings found"));
+    }
+
+    #[test]
+    fn deps_why() {
+        let file = fixture_file("multi-dep-str", "Main.roc");
+        let out = run_roc(
+            [CMD_DEPS, file.to_str().unwrap(), WHY_FLAG, "app", "Dep2"],
+            &[],
+        );
+
+        assert!(out.status.success(), "bad status {:?}", out);
+        assert_eq!(out.stdout, "app -> Dep1 -> Dep2\n");
+    }
+
+    #[test]
+    fn deps_unused() {
+        let file = fixture_file("fix", "Fixable.roc");
+        let out = run_roc([CMD_DEPS, file.to_str().unwrap(), UNUSED_FLAG], &[]);
+
+        assert!(!out.status.success(), "bad status {:?}", out);
+        assert!(out.stdout.starts_with("Fixable ("));
+        assert!(out.stdout.contains("\n    Unused (unused)\n"));
+    }
 }
 
 #[allow(dead_code)]
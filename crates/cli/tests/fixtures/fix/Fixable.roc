@@ -0,0 +1,5 @@
+interface Fixable
+    exposes [greet]
+    imports [Helpers, Unused.{ unused }]
+
+greet = \name, punctuation -> Helpers.hello name
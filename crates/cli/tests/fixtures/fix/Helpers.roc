@@ -0,0 +1,5 @@
+interface Helpers
+    exposes []
+    imports []
+
+hello = \name -> "Hello, \(name)!"
@@ -0,0 +1,7 @@
+interface Lintable
+    exposes [describe]
+    imports []
+
+describe = \list ->
+    when List.len list == 0 is
+        _ -> "empty"
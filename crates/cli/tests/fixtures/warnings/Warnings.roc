@@ -0,0 +1,14 @@
+interface Warnings
+    exposes [double, triple]
+    imports []
+
+double = \x ->
+    unused = 1
+
+    x * 2
+
+triple = \x ->
+    # roc-allow: unused-definition
+    alsoUnused = 1
+
+    x * 3
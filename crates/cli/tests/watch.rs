@@ -0,0 +1,182 @@
+extern crate roc_cli;
+extern crate tempfile;
+
+#[cfg(test)]
+mod watch {
+    use roc_cli::watch::{watch, WatchedFiles};
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::thread;
+    use std::time::Duration;
+
+    /// Returned by the command to stop the watch loop, which otherwise runs forever
+    fn stop() -> io::Error {
+        io::Error::new(io::ErrorKind::Other, "stop watching")
+    }
+
+    fn write_later(path: &Path, delay: Duration, contents: &'static str) {
+        let path = path.to_path_buf();
+
+        thread::spawn(move || {
+            thread::sleep(delay);
+            std::fs::write(path, contents).unwrap();
+        });
+    }
+
+    fn module(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+
+        std::fs::write(&path, contents).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn rerun_after_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = module(dir.path(), "main.roc", "app \"main\"\n");
+        let dep = module(dir.path(), "Dep.roc", "interface Dep\n");
+
+        let mut runs = Vec::new();
+
+        let result = watch(&main, |watched: &mut WatchedFiles| {
+            runs.push(std::fs::read_to_string(&dep).unwrap());
+
+            if runs.len() == 1 {
+                watched.watch_modules([&main, &dep]);
+                write_later(&dep, Duration::from_millis(50), "interface Dep2\n");
+
+                Ok(0)
+            } else {
+                Err(stop())
+            }
+        });
+
+        assert_eq!(result.unwrap_err().to_string(), "stop watching");
+        assert_eq!(runs, ["interface Dep\n", "interface Dep2\n"]);
+    }
+
+    #[test]
+    fn debounce_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = module(dir.path(), "main.roc", "app \"main\"\n");
+
+        let mut runs = Vec::new();
+
+        let result = watch(&main, |watched: &mut WatchedFiles| {
+            runs.push(std::fs::read_to_string(&main).unwrap());
+
+            if runs.len() == 1 {
+                watched.watch_modules([&main]);
+
+                // an editor writing a file in several steps, faster than the debounce interval
+                write_later(&main, Duration::from_millis(50), "app");
+                write_later(&main, Duration::from_millis(150), "app \"main\"");
+                write_later(&main, Duration::from_millis(250), "app \"main\"\n\nx = 1\n");
+
+                Ok(0)
+            } else {
+                Err(stop())
+            }
+        });
+
+        assert!(result.is_err());
+        // a single rerun, which sees the last version of the file
+        assert_eq!(runs, ["app \"main\"\n", "app \"main\"\n\nx = 1\n"]);
+    }
+
+    #[test]
+    fn ignore_unchanged_contents() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = module(dir.path(), "main.roc", "app \"main\"\n");
+
+        let mut runs = Vec::new();
+
+        let result = watch(&main, |watched: &mut WatchedFiles| {
+            runs.push(std::fs::read_to_string(&main).unwrap());
+
+            if runs.len() == 1 {
+                watched.watch_modules([&main]);
+
+                // saving the file without changing it must not cause a rerun
+                write_later(&main, Duration::from_millis(50), "app \"main\"\n");
+                write_later(&main, Duration::from_millis(800), "app \"changed\"\n");
+
+                Ok(0)
+            } else {
+                Err(stop())
+            }
+        });
+
+        assert!(result.is_err());
+        assert_eq!(runs, ["app \"main\"\n", "app \"changed\"\n"]);
+    }
+
+    #[test]
+    fn watch_roc_files_when_loading_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = module(dir.path(), "main.roc", "app \"main\"\n");
+        let dep = module(dir.path(), "Dep.roc", "interface Dep\n");
+
+        let mut runs = 0;
+
+        let result = watch(&main, |_: &mut WatchedFiles| {
+            runs += 1;
+
+            if runs == 1 {
+                // the modules were never reported, so every .roc file next to main.roc is watched
+                write_later(&dep, Duration::from_millis(50), "interface Dep2\n");
+
+                Ok(1)
+            } else {
+                Err(stop())
+            }
+        });
+
+        assert!(result.is_err());
+        assert_eq!(runs, 2);
+    }
+
+    #[test]
+    fn rebuild_host_after_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = module(dir.path(), "main.roc", "app \"main\"\n");
+        let platform = dir.path().join("platform");
+
+        std::fs::create_dir(&platform).unwrap();
+
+        let host = module(&platform, "host.c", "int main() { return 0; }\n");
+
+        let mut host_changed = Vec::new();
+
+        let result = watch(&main, |watched: &mut WatchedFiles| {
+            host_changed.push(watched.host_changed());
+
+            match host_changed.len() {
+                1 => {
+                    watched.watch_modules([&main]);
+                    watched.watch_host(&platform);
+                    write_later(&main, Duration::from_millis(50), "app \"changed\"\n");
+
+                    Ok(0)
+                }
+                2 => {
+                    watched.watch_modules([&main]);
+                    watched.watch_host(&platform);
+                    write_later(
+                        &host,
+                        Duration::from_millis(50),
+                        "int main() { return 1; }\n",
+                    );
+
+                    Ok(0)
+                }
+                _ => Err(stop()),
+            }
+        });
+
+        assert!(result.is_err());
+        // the host is built on the first run, and again only after one of its sources changed
+        assert_eq!(host_changed, [true, false, true]);
+    }
+}
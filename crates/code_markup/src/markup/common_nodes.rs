@@ -130,15 +130,35 @@ pub fn new_module_var_mn(
 }
 
 pub fn if_mn() -> MarkupNode {
-    keyword_mn("if ")
+    keyword_mn(nodes::IF)
 }
 
 pub fn then_mn() -> MarkupNode {
-    keyword_mn(" then ")
+    keyword_mn(nodes::THEN)
 }
 
 pub fn else_mn() -> MarkupNode {
-    keyword_mn(" else ")
+    keyword_mn(nodes::ELSE)
+}
+
+pub fn when_mn() -> MarkupNode {
+    keyword_mn(nodes::WHEN)
+}
+
+// the branches of a when start on the next line
+pub fn is_mn() -> MarkupNode {
+    common_text_node(nodes::IS.to_owned(), HighlightStyle::Keyword, 1)
+}
+
+pub fn guard_if_mn() -> MarkupNode {
+    keyword_mn(nodes::GUARD_IF)
+}
+
+pub fn new_indent_mn(indent_level: usize) -> MarkupNode {
+    MarkupNode::Indent {
+        indent_level,
+        parent_id_opt: None,
+    }
 }
 
 fn keyword_mn(keyword: &str) -> MarkupNode {
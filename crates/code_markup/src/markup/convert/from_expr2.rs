@@ -2,13 +2,15 @@ use crate::{
     markup::{
         attribute::Attributes,
         common_nodes::{
-            new_arg_name_mn, new_arrow_mn, new_blank_mn, new_colon_mn, new_comma_mn, new_equals_mn,
-            new_left_accolade_mn, new_left_square_mn, new_operator_mn, new_right_accolade_mn,
-            new_right_square_mn,
+            else_mn, guard_if_mn, if_mn, is_mn, new_arg_name_mn, new_arrow_mn, new_blank_mn,
+            new_colon_mn, new_comma_mn, new_equals_mn, new_indent_mn, new_left_accolade_mn,
+            new_left_square_mn, new_operator_mn, new_right_accolade_mn, new_right_square_mn,
+            then_mn, when_mn,
         },
         mark_id_ast_id_map::MarkIdAstIdMap,
         nodes::{
-            get_string, join_mark_nodes_commas, join_mark_nodes_spaces, new_markup_node, MarkupNode,
+            self, get_string, join_mark_nodes_commas, join_mark_nodes_spaces, new_markup_node,
+            MarkupNode,
         },
     },
     slow_pool::{MarkNodeId, SlowPool},
@@ -21,10 +23,10 @@ use roc_ast::{
         core::{
             ast::ASTNodeId,
             expr::{
-                expr2::{Expr2, ExprId},
+                expr2::{Expr2, ExprId, FloatVal, IntVal},
                 record_field::RecordField,
             },
-            pattern::{get_identifier_string, Pattern2},
+            pattern::{get_identifier_string, DestructType, Pattern2},
             val_def::ValueDef,
         },
         env::Env,
@@ -311,7 +313,14 @@ pub fn expr2_to_markup<'a>(
 
             let pattern2 = env.pool.get(pattern_id);
 
-            let val_name = get_identifier_string(pattern2, interns)?;
+            let val_name = match pattern2 {
+                // identifiers that were typed in the editor are only in env.ident_ids
+                Pattern2::Identifier(symbol) => env
+                    .ident_ids
+                    .get_name_str_res(symbol.ident_id())?
+                    .to_owned(),
+                _ => get_identifier_string(pattern2, interns)?,
+            };
 
             let val_name_mn = MarkupNode::Text {
                 content: val_name,
@@ -376,7 +385,7 @@ pub fn expr2_to_markup<'a>(
             body_id,
             extra: _,
         } => {
-            let backslash_mn = new_operator_mn("\\".to_string());
+            let backslash_mn = new_operator_mn(nodes::BACKSLASH.to_string());
             let backslash_mn_id = add_node(
                 backslash_mn,
                 ast_node_id,
@@ -455,6 +464,178 @@ pub fn expr2_to_markup<'a>(
 
             add_node(function_mn, ast_node_id, mark_node_pool, mark_id_ast_id_map)
         }
+        Expr2::If {
+            branches,
+            final_else,
+            ..
+        } => {
+            let mut children_ids = Vec::new();
+
+            for (idx, (cond_id, then_id)) in branches.iter(env.pool).copied().enumerate() {
+                // `else if` for every branch after the first
+                if idx > 0 {
+                    children_ids.push(add_node(
+                        else_mn(),
+                        ast_node_id,
+                        mark_node_pool,
+                        mark_id_ast_id_map,
+                    ));
+                }
+
+                children_ids.push(add_node(
+                    if_mn(),
+                    ast_node_id,
+                    mark_node_pool,
+                    mark_id_ast_id_map,
+                ));
+                children_ids.push(expr2_to_markup(
+                    env,
+                    env.pool.get(cond_id),
+                    cond_id,
+                    mark_node_pool,
+                    mark_id_ast_id_map,
+                    interns,
+                    indent_level,
+                )?);
+                children_ids.push(add_node(
+                    then_mn(),
+                    ast_node_id,
+                    mark_node_pool,
+                    mark_id_ast_id_map,
+                ));
+                children_ids.push(expr2_to_markup(
+                    env,
+                    env.pool.get(then_id),
+                    then_id,
+                    mark_node_pool,
+                    mark_id_ast_id_map,
+                    interns,
+                    indent_level,
+                )?);
+            }
+
+            children_ids.push(add_node(
+                else_mn(),
+                ast_node_id,
+                mark_node_pool,
+                mark_id_ast_id_map,
+            ));
+            children_ids.push(expr2_to_markup(
+                env,
+                env.pool.get(*final_else),
+                *final_else,
+                mark_node_pool,
+                mark_id_ast_id_map,
+                interns,
+                indent_level,
+            )?);
+
+            let if_mn = MarkupNode::Nested {
+                children_ids,
+                parent_id_opt: None,
+                newlines_at_end: 0,
+            };
+
+            add_node(if_mn, ast_node_id, mark_node_pool, mark_id_ast_id_map)
+        }
+        Expr2::When { branches, cond, .. } => {
+            let mut children_ids = vec![
+                add_node(when_mn(), ast_node_id, mark_node_pool, mark_id_ast_id_map),
+                expr2_to_markup(
+                    env,
+                    env.pool.get(*cond),
+                    *cond,
+                    mark_node_pool,
+                    mark_id_ast_id_map,
+                    interns,
+                    indent_level,
+                )?,
+                add_node(is_mn(), ast_node_id, mark_node_pool, mark_id_ast_id_map),
+            ];
+
+            // every branch is on its own line: `pattern -> body` or `pattern if guard -> body`
+            for (idx, branch) in branches.iter(env.pool).enumerate() {
+                children_ids.push(add_node(
+                    new_indent_mn(indent_level + 1),
+                    ast_node_id,
+                    mark_node_pool,
+                    mark_id_ast_id_map,
+                ));
+
+                let pattern_strings = branch
+                    .patterns
+                    .iter(env.pool)
+                    .map(|pattern| pattern2_to_string(env, pattern))
+                    .collect::<ModuleResult<Vec<String>>>()?;
+
+                let pattern_style = match branch.patterns.iter(env.pool).next() {
+                    Some(Pattern2::Tag { .. }) => HighlightStyle::Type,
+                    _ => HighlightStyle::LowercaseIdent,
+                };
+
+                children_ids.push(add_node(
+                    MarkupNode::Text {
+                        content: pattern_strings.join(" | "),
+                        syn_high_style: pattern_style,
+                        attributes: Attributes::default(),
+                        parent_id_opt: None,
+                        newlines_at_end: 0,
+                    },
+                    ast_node_id,
+                    mark_node_pool,
+                    mark_id_ast_id_map,
+                ));
+
+                if let Some(guard_id) = branch.guard {
+                    children_ids.push(add_node(
+                        guard_if_mn(),
+                        ast_node_id,
+                        mark_node_pool,
+                        mark_id_ast_id_map,
+                    ));
+                    children_ids.push(expr2_to_markup(
+                        env,
+                        env.pool.get(guard_id),
+                        guard_id,
+                        mark_node_pool,
+                        mark_id_ast_id_map,
+                        interns,
+                        indent_level,
+                    )?);
+                }
+
+                children_ids.push(add_node(
+                    new_arrow_mn(0),
+                    ast_node_id,
+                    mark_node_pool,
+                    mark_id_ast_id_map,
+                ));
+
+                let body_mn_id = expr2_to_markup(
+                    env,
+                    env.pool.get(branch.body),
+                    branch.body,
+                    mark_node_pool,
+                    mark_id_ast_id_map,
+                    interns,
+                    indent_level,
+                )?;
+
+                if idx + 1 < branches.len() {
+                    mark_node_pool.get_mut(body_mn_id).add_newline_at_end();
+                }
+
+                children_ids.push(body_mn_id);
+            }
+
+            let when_mn = MarkupNode::Nested {
+                children_ids,
+                parent_id_opt: None,
+                newlines_at_end: 0,
+            };
+
+            add_node(when_mn, ast_node_id, mark_node_pool, mark_id_ast_id_map)
+        }
         Expr2::RuntimeError() => new_markup_node(
             "RunTimeError".to_string(),
             ast_node_id,
@@ -469,6 +650,74 @@ pub fn expr2_to_markup<'a>(
     Ok(mark_node_id)
 }
 
+fn pattern2_to_string(env: &Env, pattern: &Pattern2) -> ModuleResult<String> {
+    let pattern_string = match pattern {
+        Pattern2::Identifier(symbol) => env
+            .ident_ids
+            .get_name_str_res(symbol.ident_id())?
+            .to_owned(),
+        Pattern2::Shadowed { shadowed_ident } => shadowed_ident.as_str(env.pool).to_owned(),
+        Pattern2::NumLiteral(_, num) => num.to_string(),
+        Pattern2::StrLiteral(text) => format!("\"{}\"", text.as_str(env.pool)),
+        Pattern2::CharacterLiteral(ch) => format!("'{}'", ch),
+        Pattern2::Underscore => "_".to_owned(),
+        Pattern2::Tag {
+            tag_name,
+            arguments,
+            ..
+        } => {
+            let mut tag_strings = vec![tag_name.as_str(env.pool).to_owned()];
+
+            for (_, arg_pattern_id) in arguments.iter(env.pool) {
+                tag_strings.push(pattern2_to_string(env, env.pool.get(*arg_pattern_id))?);
+            }
+
+            tag_strings.join(" ")
+        }
+        Pattern2::IntLiteral(int_val) => match int_val {
+            IntVal::I64(num) => num.to_string(),
+            IntVal::U64(num) => num.to_string(),
+            IntVal::I32(num) => num.to_string(),
+            IntVal::U32(num) => num.to_string(),
+            IntVal::I16(num) => num.to_string(),
+            IntVal::U16(num) => num.to_string(),
+            IntVal::I8(num) => num.to_string(),
+            IntVal::U8(num) => num.to_string(),
+        },
+        Pattern2::FloatLiteral(float_val) => match float_val {
+            FloatVal::F64(num) => num.to_string(),
+            FloatVal::F32(num) => num.to_string(),
+        },
+        Pattern2::RecordDestructure { destructs, .. } => {
+            let mut field_strings = Vec::new();
+
+            for destruct in destructs.iter(env.pool) {
+                let label = destruct.label.as_str(env.pool);
+
+                let field_string = match env.pool.get(destruct.typ) {
+                    DestructType::Guard(_, pattern_id) => format!(
+                        "{}: {}",
+                        label,
+                        pattern2_to_string(env, env.pool.get(*pattern_id))?
+                    ),
+                    // TODO show the default value of an optional field
+                    DestructType::Required | DestructType::Optional(..) => label.to_owned(),
+                };
+
+                field_strings.push(field_string);
+            }
+
+            format!("{{ {} }}", field_strings.join(", "))
+        }
+        // these patterns did not canonicalize, they are shown as a blank that can be typed over
+        Pattern2::UnsupportedPattern(_) | Pattern2::MalformedPattern(..) => {
+            nodes::BLANK_PLACEHOLDER.to_owned()
+        }
+    };
+
+    Ok(pattern_string)
+}
+
 fn with_indent(indent_level: usize, some_str: &str) -> String {
     let full_indent = std::iter::repeat(" ").take(indent_level * 4);
     let mut full_string: String = full_indent.collect();
@@ -273,6 +273,13 @@ pub const DOT: &str = ".";
 pub const STRING_QUOTES: &str = "\"\"";
 pub const EQUALS: &str = " = ";
 pub const ARROW: &str = " -> ";
+pub const BACKSLASH: &str = "\\";
+pub const IF: &str = "if ";
+pub const THEN: &str = " then ";
+pub const ELSE: &str = " else ";
+pub const WHEN: &str = "when ";
+pub const IS: &str = " is";
+pub const GUARD_IF: &str = " if ";
 pub const SINGLE_INDENT: &str = "    "; // 4 spaces
 
 pub fn new_markup_node(
@@ -455,7 +462,7 @@ pub fn join_mark_nodes_spaces(
 
 // put comma mark nodes between each node in mark_nodes
 pub fn join_mark_nodes_commas(mark_nodes: Vec<MarkupNode>) -> Vec<MarkupNode> {
-    let join_nodes: Vec<MarkupNode> = (0..(mark_nodes.len() - 1))
+    let join_nodes: Vec<MarkupNode> = (0..mark_nodes.len().saturating_sub(1))
         .map(|_| new_comma_mn())
         .collect();
 
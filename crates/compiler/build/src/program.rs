@@ -4,7 +4,8 @@ use roc_gen_llvm::llvm::externs::add_default_roc_externs;
 use roc_load::{EntryPoint, LoadedModule, MonomorphizedModule};
 use roc_module::symbol::{Interns, ModuleId};
 use roc_mono::ir::OptLevel;
-use roc_region::all::LineInfo;
+use roc_region::all::{LineInfo, Region};
+use roc_reporting::warnings::{Suppressions, WarningConfig, WarningKind};
 use roc_solve_problem::TypeError;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
@@ -19,23 +20,35 @@ pub struct CodeGenTiming {
     pub emit_o_file: Duration,
 }
 
-pub fn report_problems_monomorphized(loaded: &mut MonomorphizedModule) -> Problems {
+pub fn report_problems_monomorphized(
+    loaded: &mut MonomorphizedModule,
+    warning_config: &WarningConfig,
+) -> Problems {
     report_problems_help(
         loaded.total_problems(),
         &loaded.sources,
+        &loaded.suppressions,
         &loaded.interns,
+        &mut loaded.syntax_problems,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        warning_config,
     )
 }
 
-pub fn report_problems_typechecked(loaded: &mut LoadedModule) -> Problems {
+pub fn report_problems_typechecked(
+    loaded: &mut LoadedModule,
+    warning_config: &WarningConfig,
+) -> Problems {
     report_problems_help(
         loaded.total_problems(),
         &loaded.sources,
+        &loaded.suppressions,
         &loaded.interns,
+        &mut loaded.syntax_problems,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        warning_config,
     )
 }
 
@@ -56,12 +69,16 @@ impl Problems {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn report_problems_help(
     total_problems: usize,
     sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    suppressions: &MutMap<ModuleId, Suppressions>,
     interns: &Interns,
+    syntax_problems: &mut MutMap<ModuleId, Vec<String>>,
     can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    warning_config: &WarningConfig,
 ) -> Problems {
     use roc_reporting::report::{
         can_problem, type_problem, Report, RocDocAllocator, Severity::*, DEFAULT_PALETTE,
@@ -82,21 +99,37 @@ fn report_problems_help(
 
         // Report parsing and canonicalization problems
         let alloc = RocDocAllocator::new(&src_lines, *home, interns);
+        let module_suppressions = suppressions.get(home);
+
+        // Whether a warning was allowed by a flag, or by a `# roc-allow:` comment
+        let is_allowed = |kind: Option<WarningKind>, region: Option<Region>| {
+            let line = region.map(|region| lines.convert_pos(region.start()).line);
+
+            warning_config.allows_problem(kind, module_suppressions, line)
+        };
+
+        // Syntax errors are always errors, and are already rendered.
+        errors.extend(syntax_problems.remove(home).unwrap_or_default());
 
         let problems = can_problems.remove(home).unwrap_or_default();
 
         for problem in problems.into_iter() {
+            if is_allowed(problem.warning_kind(), problem.region()) {
+                continue;
+            }
+
             let report = can_problem(&alloc, &lines, module_path.clone(), problem);
             let severity = report.severity;
+
             let mut buf = String::new();
 
             report.render_color_terminal(&mut buf, &alloc, &palette);
 
             match severity {
-                Warning => {
+                Warning if !warning_config.deny_warnings => {
                     warnings.push(buf);
                 }
-                RuntimeError => {
+                Warning | RuntimeError => {
                     errors.push(buf);
                 }
             }
@@ -105,17 +138,22 @@ fn report_problems_help(
         let problems = type_problems.remove(home).unwrap_or_default();
 
         for problem in problems {
+            if is_allowed(problem.warning_kind(), problem.region()) {
+                continue;
+            }
+
             if let Some(report) = type_problem(&alloc, &lines, module_path.clone(), problem) {
                 let severity = report.severity;
+
                 let mut buf = String::new();
 
                 report.render_color_terminal(&mut buf, &alloc, &palette);
 
                 match severity {
-                    Warning => {
+                    Warning if !warning_config.deny_warnings => {
                         warnings.push(buf);
                     }
-                    RuntimeError => {
+                    Warning | RuntimeError => {
                         errors.push(buf);
                     }
                 }
@@ -222,6 +222,14 @@ pub const RocDec = extern struct {
         return if (negated) |n| .{ .num = n } else null;
     }
 
+    pub fn abs(self: RocDec) ?RocDec {
+        return if (self.num < 0) self.negate() else self;
+    }
+
+    pub fn toF64(self: RocDec) f64 {
+        return @intToFloat(f64, self.num) / comptime @intToFloat(f64, one_point_zero_i128);
+    }
+
     pub fn addWithOverflow(self: RocDec, other: RocDec) WithOverflow(RocDec) {
         var answer: i128 = undefined;
         const overflowed = @addWithOverflow(i128, self.num, other.num, &answer);
@@ -733,6 +741,20 @@ test "fromF64 overflow" {
     try expectEqual(dec, null);
 }
 
+test "toF64" {
+    var dec = RocDec{ .num = -25500000000000000000 };
+    try expectEqual(@as(f64, -25.5), dec.toF64());
+}
+
+test "abs" {
+    var dec = RocDec{ .num = -25500000000000000000 };
+    try expectEqual(RocDec{ .num = 25500000000000000000 }, dec.abs().?);
+}
+
+test "abs: min" {
+    try expectEqual(RocDec.min.abs(), null);
+}
+
 test "fromStr: empty" {
     var roc_str = RocStr.init("", 0);
     var dec = RocDec.fromStr(roc_str);
@@ -1073,6 +1095,10 @@ pub fn fromF64C(arg: f64) callconv(.C) i128 {
     return if (@call(.{ .modifier = always_inline }, RocDec.fromF64, .{arg})) |dec| dec.num else @panic("TODO runtime exception failing convert f64 to RocDec");
 }
 
+pub fn toF64C(arg: RocDec) callconv(.C) f64 {
+    return @call(.{ .modifier = always_inline }, RocDec.toF64, .{arg});
+}
+
 pub fn eqC(arg1: RocDec, arg2: RocDec) callconv(.C) bool {
     return @call(.{ .modifier = always_inline }, RocDec.eq, .{ arg1, arg2 });
 }
@@ -1085,6 +1111,10 @@ pub fn negateC(arg: RocDec) callconv(.C) i128 {
     return if (@call(.{ .modifier = always_inline }, RocDec.negate, .{arg})) |dec| dec.num else @panic("TODO overflow for negating RocDec");
 }
 
+pub fn absC(arg: RocDec) callconv(.C) i128 {
+    return if (@call(.{ .modifier = always_inline }, RocDec.abs, .{arg})) |dec| dec.num else @panic("TODO overflow for RocDec absolute value");
+}
+
 pub fn addC(arg1: RocDec, arg2: RocDec) callconv(.C) WithOverflow(RocDec) {
     return @call(.{ .modifier = always_inline }, RocDec.addWithOverflow, .{ arg1, arg2 });
 }
@@ -2,6 +2,7 @@ const std = @import("std");
 const builtin = @import("builtin");
 const math = std.math;
 const utils = @import("utils.zig");
+const hash = @import("hash.zig");
 const expect = @import("expect.zig");
 
 const ROC_BUILTINS = "roc_builtins";
@@ -15,9 +16,11 @@ comptime {
     exportDecFn(dec.fromStr, "from_str");
     exportDecFn(dec.toStr, "to_str");
     exportDecFn(dec.fromF64C, "from_f64");
+    exportDecFn(dec.toF64C, "to_f64");
     exportDecFn(dec.eqC, "eq");
     exportDecFn(dec.neqC, "neq");
     exportDecFn(dec.negateC, "negate");
+    exportDecFn(dec.absC, "abs");
     exportDecFn(dec.divC, "div");
 
     exportDecFn(dec.addC, "add_with_overflow");
@@ -70,21 +73,45 @@ comptime {
     inline for (INTEGERS) |T, i| {
         num.exportPow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".pow_int.");
         num.exportDivCeil(T, ROC_BUILTINS ++ "." ++ NUM ++ ".div_ceil.");
+        num.exportDivTrunc(T, ROC_BUILTINS ++ "." ++ NUM ++ ".div_trunc.");
+        num.exportRem(T, ROC_BUILTINS ++ "." ++ NUM ++ ".rem.");
+        num.exportIsMultipleOf(T, ROC_BUILTINS ++ "." ++ NUM ++ ".is_multiple_of.");
+        num.exportNegOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".neg_or_panic.");
+        num.exportAbsOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".abs_or_panic.");
 
         num.exportRoundF32(T, ROC_BUILTINS ++ "." ++ NUM ++ ".round_f32.");
         num.exportRoundF64(T, ROC_BUILTINS ++ "." ++ NUM ++ ".round_f64.");
 
         num.exportAddWithOverflow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_with_overflow.");
         num.exportAddOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_or_panic.");
+        num.exportAddWrap(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_wrap.");
         num.exportAddSaturatedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_saturated.");
 
         num.exportSubWithOverflow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_with_overflow.");
         num.exportSubOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_or_panic.");
+        num.exportSubWrap(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_wrap.");
         num.exportSubSaturatedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_saturated.");
 
         num.exportMulWithOverflow(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_with_overflow.");
         num.exportMulOrPanic(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_or_panic.");
+        num.exportMulWrap(T, ROC_BUILTINS ++ "." ++ NUM ++ ".mul_wrap.");
         num.exportMulSaturatedInt(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_saturated.");
+
+        num.exportCountLeadingZeroBits(T, ROC_BUILTINS ++ "." ++ NUM ++ ".count_leading_zero_bits.");
+        num.exportCountTrailingZeroBits(T, ROC_BUILTINS ++ "." ++ NUM ++ ".count_trailing_zero_bits.");
+        num.exportCountOneBits(T, ROC_BUILTINS ++ "." ++ NUM ++ ".count_one_bits.");
+
+        num.exportToBytesLe(T, ROC_BUILTINS ++ "." ++ NUM ++ ".to_bytes_le.");
+        num.exportToBytesBe(T, ROC_BUILTINS ++ "." ++ NUM ++ ".to_bytes_be.");
+        num.exportFromBytesLe(T, ROC_BUILTINS ++ "." ++ NUM ++ ".from_bytes_le.");
+        num.exportFromBytesBe(T, ROC_BUILTINS ++ "." ++ NUM ++ ".from_bytes_be.");
+
+        num.exportShiftLeftBy(T, ROC_BUILTINS ++ "." ++ NUM ++ ".shift_left_by.");
+        num.exportShiftRightBy(T, ROC_BUILTINS ++ "." ++ NUM ++ ".shift_right_by.");
+        num.exportShiftRightZfBy(T, ROC_BUILTINS ++ "." ++ NUM ++ ".shift_right_zf_by.");
+
+        num.exportToFloat(T, f32, ROC_BUILTINS ++ "." ++ NUM ++ ".int_to_f32.");
+        num.exportToFloat(T, f64, ROC_BUILTINS ++ "." ++ NUM ++ ".int_to_f64.");
     }
 
     inline for (INTEGERS) |FROM| {
@@ -163,6 +190,8 @@ comptime {
     exportUtilsFn(utils.decrefC, "decref");
     exportUtilsFn(utils.decrefCheckNullC, "decref_check_null");
     exportUtilsFn(utils.allocateWithRefcountC, "allocate_with_refcount");
+    exportUtilsFn(hash.wyhash, "wyhash");
+    exportUtilsFn(hash.wyhash_rocstr, "wyhash_rocstr");
 
     @export(utils.panic, .{ .name = "roc_builtins.utils." ++ "panic", .linkage = .Weak });
 
@@ -185,6 +185,141 @@ pub fn exportDivCeil(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
+fn isSigned(comptime T: type) bool {
+    return @typeInfo(T).Int.signedness == .signed;
+}
+
+fn remInt(comptime T: type, a: T, b: T) T {
+    if (b == 0) {
+        @panic("TODO runtime exception for dividing by 0!");
+    }
+
+    // @rem requires a positive denominator, but the sign of the remainder
+    // only depends on the numerator, so we can flip the denominator's sign.
+    if (comptime isSigned(T)) {
+        if (b == math.minInt(T)) {
+            return if (a == b) 0 else a;
+        } else if (b < 0) {
+            return @rem(a, -b);
+        }
+    }
+
+    return @rem(a, b);
+}
+
+pub fn exportDivTrunc(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(a: T, b: T) callconv(.C) T {
+            return math.divTrunc(T, a, b) catch @panic("TODO runtime exception for dividing by 0!");
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportRem(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(a: T, b: T) callconv(.C) T {
+            return @call(.{ .modifier = always_inline }, remInt, .{ T, a, b });
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportIsMultipleOf(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(a: T, b: T) callconv(.C) bool {
+            if (b == 0) {
+                return a == 0;
+            }
+            return @call(.{ .modifier = always_inline }, remInt, .{ T, a, b }) == 0;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportNegOrPanic(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) T {
+            if (comptime isSigned(T)) {
+                if (self == math.minInt(T)) {
+                    roc_panic("integer negation overflowed because its argument is the minimum value", 1);
+                    unreachable;
+                }
+            }
+            return 0 -% self;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportAbsOrPanic(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) T {
+            if (comptime isSigned(T)) {
+                if (self == math.minInt(T)) {
+                    roc_panic("integer absolute overflowed because its argument is the minimum value", 1);
+                    unreachable;
+                }
+                return if (self < 0) -self else self;
+            }
+            return self;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportShiftLeftBy(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, bits: T) callconv(.C) T {
+            const U = std.meta.Int(.unsigned, @bitSizeOf(T));
+            const amount = @bitCast(U, bits);
+            if (amount >= @bitSizeOf(T)) {
+                return 0;
+            }
+            return self << @intCast(math.Log2Int(T), amount);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportShiftRightBy(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, bits: T) callconv(.C) T {
+            // Roc defines this on all integers, treating the argument as signed
+            const S = std.meta.Int(.signed, @bitSizeOf(T));
+            const U = std.meta.Int(.unsigned, @bitSizeOf(T));
+            const shift = @bitCast(U, bits);
+            const amount = if (shift < @bitSizeOf(T)) shift else @bitSizeOf(T) - 1;
+            return @bitCast(T, @bitCast(S, self) >> @intCast(math.Log2Int(S), amount));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportShiftRightZfBy(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, bits: T) callconv(.C) T {
+            // Roc defines this on all integers, treating the argument as unsigned
+            const U = std.meta.Int(.unsigned, @bitSizeOf(T));
+            const amount = @bitCast(U, bits);
+            if (amount >= @bitSizeOf(T)) {
+                return 0;
+            }
+            return @bitCast(T, @bitCast(U, self) >> @intCast(math.Log2Int(U), amount));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportToFloat(comptime T: type, comptime F: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) F {
+            return @intToFloat(F, self);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
 pub fn ToIntCheckedResult(comptime T: type) type {
     // On the Roc side we sort by alignment; putting the errorcode last
     // always works out (no number with smaller alignment than 1).
@@ -236,6 +371,92 @@ fn bytesToU32(arg: RocList, position: usize) u32 {
     return @bitCast(u32, [_]u8{ bytes[position], bytes[position + 1], bytes[position + 2], bytes[position + 3] });
 }
 
+pub fn exportCountLeadingZeroBits(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) u8 {
+            return @as(u8, @clz(T, self));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportCountTrailingZeroBits(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) u8 {
+            return @as(u8, @ctz(T, self));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportCountOneBits(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) u8 {
+            return @as(u8, @popCount(T, self));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+fn toBytes(comptime T: type, value: T, endian: std.builtin.Endian) RocList {
+    const width = @sizeOf(T);
+    const list = RocList.allocate(@alignOf(u8), width, @sizeOf(u8));
+    const bytes = @ptrCast([*]u8, list.bytes);
+
+    std.mem.writeInt(T, bytes[0..width], value, endian);
+
+    return list;
+}
+
+pub fn exportToBytesLe(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) RocList {
+            return @call(.{ .modifier = always_inline }, toBytes, .{ T, self, .Little });
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportToBytesBe(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) RocList {
+            return @call(.{ .modifier = always_inline }, toBytes, .{ T, self, .Big });
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+fn fromBytes(comptime T: type, list: RocList, index: usize, endian: std.builtin.Endian) ToIntCheckedResult(T) {
+    const width = @sizeOf(T);
+
+    if (index > list.len() or list.len() - index < width) {
+        return .{ .out_of_bounds = true, .value = 0 };
+    }
+
+    const bytes = @ptrCast([*]const u8, list.bytes);
+    const value = std.mem.readInt(T, bytes[index..][0..width], endian);
+
+    return .{ .out_of_bounds = false, .value = value };
+}
+
+pub fn exportFromBytesLe(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(list: RocList, index: usize) callconv(.C) ToIntCheckedResult(T) {
+            return @call(.{ .modifier = always_inline }, fromBytes, .{ T, list, index, .Little });
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportFromBytesBe(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(list: RocList, index: usize) callconv(.C) ToIntCheckedResult(T) {
+            return @call(.{ .modifier = always_inline }, fromBytes, .{ T, list, index, .Big });
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
 fn addWithOverflow(comptime T: type, self: T, other: T) WithOverflow(T) {
     switch (@typeInfo(T)) {
         .Int => {
@@ -279,6 +500,15 @@ pub fn exportAddSaturatedInt(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
+pub fn exportAddWrap(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) T {
+            return self +% other;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
 pub fn exportAddOrPanic(comptime T: type, comptime name: []const u8) void {
     comptime var f = struct {
         fn func(self: T, other: T) callconv(.C) T {
@@ -338,6 +568,15 @@ pub fn exportSubSaturatedInt(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
+pub fn exportSubWrap(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) T {
+            return self -% other;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
 pub fn exportSubOrPanic(comptime T: type, comptime name: []const u8) void {
     comptime var f = struct {
         fn func(self: T, other: T) callconv(.C) T {
@@ -446,6 +685,15 @@ pub fn exportMulSaturatedInt(comptime T: type, comptime W: type, comptime name:
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
+pub fn exportMulWrap(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) T {
+            return self *% other;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
 pub fn exportMulOrPanic(comptime T: type, comptime W: type, comptime name: []const u8) void {
     comptime var f = struct {
         fn func(self: T, other: T) callconv(.C) T {
@@ -86,6 +86,15 @@ interface Num
         intCast,
         bytesToU16,
         bytesToU32,
+        toBytesLe,
+        toBytesBe,
+        fromBytesLe,
+        fromBytesBe,
+        countLeadingZeroBits,
+        countTrailingZeroBits,
+        countOneBits,
+        f64ToBits,
+        f64FromBits,
         divCeil,
         divCeilChecked,
         divTrunc,
@@ -532,6 +541,79 @@ bytesToU32 = \bytes, index ->
     else
         Err OutOfBounds
 
+## Convert an integer to its bytes, least significant byte first.
+##
+## The returned list always has as many bytes as the integer's type is wide,
+## so a [U16] gives 2 bytes and an [I128] gives 16.
+##
+## >>> Num.toBytesLe 0x0102u16
+toBytesLe : Int a -> List U8
+
+## Convert an integer to its bytes, most significant byte first.
+##
+## >>> Num.toBytesBe 0x0102u16
+toBytesBe : Int a -> List U8
+
+## Read an integer from the bytes starting at the given index, least
+## significant byte first. This is the inverse of [Num.toBytesLe].
+##
+## Returns `Err OutOfBounds` if there are not enough bytes from the index
+## onwards to fill the integer's type.
+fromBytesLe : List U8, Nat -> Result (Int a) [OutOfBounds]
+fromBytesLe = \bytes, index ->
+    result = fromBytesLeLowlevel bytes index
+
+    if result.b then
+        Err OutOfBounds
+    else
+        Ok result.a
+
+## Read an integer from the bytes starting at the given index, most
+## significant byte first. This is the inverse of [Num.toBytesBe].
+##
+## Returns `Err OutOfBounds` if there are not enough bytes from the index
+## onwards to fill the integer's type.
+fromBytesBe : List U8, Nat -> Result (Int a) [OutOfBounds]
+fromBytesBe = \bytes, index ->
+    result = fromBytesBeLowlevel bytes index
+
+    if result.b then
+        Err OutOfBounds
+    else
+        Ok result.a
+
+fromBytesLeLowlevel : List U8, Nat -> { b : Bool, a : Int a }
+fromBytesBeLowlevel : List U8, Nat -> { b : Bool, a : Int a }
+
+## Counts the number of most-significant (leading in a big-Endian sense) zeroes in an integer.
+##
+## >>> Num.countLeadingZeroBits 0b0001_1100u8
+##
+## >>> Num.countLeadingZeroBits 0b0000_0000u8
+countLeadingZeroBits : Int a -> U8
+
+## Counts the number of least-significant (trailing in a big-Endian sense) zeroes in an integer.
+##
+## >>> Num.countTrailingZeroBits 0b0001_1100u8
+##
+## >>> Num.countTrailingZeroBits 0b0000_0000u8
+countTrailingZeroBits : Int a -> U8
+
+## Counts the number of set bits in an integer.
+##
+## >>> Num.countOneBits 0b0001_1100u8
+##
+## >>> Num.countOneBits 0b0000_0000u8
+countOneBits : Int a -> U8
+
+## Reinterpret the bits of an [F64] as a [U64], without any numeric conversion.
+##
+## >>> Num.f64ToBits 1.0f64
+f64ToBits : F64 -> U64
+
+## Reinterpret the bits of a [U64] as an [F64]. This is the inverse of [Num.f64ToBits].
+f64FromBits : U64 -> F64
+
 compare : Num a, Num a -> [LT, EQ, GT]
 
 ## Returns `Bool.true` if the first number is less than the second.
@@ -131,6 +131,23 @@ pub enum ResolvedImpl {
 pub struct IAbilitiesStore<Phase: ResolvePhase> {
     /// Maps an ability to the members defining it.
     members_of_ability: MutMap<Symbol, Vec<Symbol>>,
+
+    /// Maps an ability to the abilities every type implementing it must also implement.
+    /// For example, `Ord has Eq` maps `Ord` to `[Eq]`.
+    superabilities: MutMap<Symbol, Vec<Symbol>>,
+
+    /// Maps an ability member to the default implementation given in the ability's definition,
+    /// which opaque types use when they don't implement the member themselves. For example,
+    ///
+    ///   Eq has
+    ///     isEq : a, a -> Bool | a has Eq
+    ///     isNotEq : a, a -> Bool | a has Eq
+    ///     isNotEq = \x, y -> !(isEq x y)
+    ///
+    /// maps `isNotEq` to the (unnamed) symbol of the default `isNotEq` body.
+    member_defaults: MutMap<Symbol, Symbol>,
+    /// The reverse of `member_defaults`: maps a default implementation to its member.
+    default_to_member: MutMap<Symbol, Symbol>,
     /// Map of symbols that specialize an ability member to the root ability symbol name,
     /// and the type the specialization claims to implement the ability for.
     ///
@@ -165,6 +182,9 @@ impl<Phase: ResolvePhase> Default for IAbilitiesStore<Phase> {
     fn default() -> Self {
         Self {
             members_of_ability: Default::default(),
+            superabilities: Default::default(),
+            member_defaults: Default::default(),
+            default_to_member: Default::default(),
             specialization_to_root: Default::default(),
             ability_members: Default::default(),
             declared_implementations: Default::default(),
@@ -201,6 +221,45 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
         );
     }
 
+    /// Records the abilities that every type implementing `ability` must also implement.
+    pub fn register_superabilities(&mut self, ability: Symbol, superabilities: Vec<Symbol>) {
+        if !superabilities.is_empty() {
+            self.superabilities.insert(ability, superabilities);
+        }
+    }
+
+    /// The abilities a type implementing `ability` must also implement, e.g. `Eq` for `Ord has Eq`.
+    pub fn superabilities_of(&self, ability: Symbol) -> &[Symbol] {
+        self.superabilities
+            .get(&ability)
+            .map(|v| v.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Records that `default` implements `member` for opaque types that don't implement it
+    /// themselves.
+    pub fn register_member_default(&mut self, member: Symbol, default: Symbol) {
+        let old_default = self.member_defaults.insert(member, default);
+        debug_assert!(old_default.is_none(), "Replacing existing member default");
+
+        self.default_to_member.insert(default, member);
+    }
+
+    /// The default implementation of an ability member, if its ability gives one.
+    pub fn member_default(&self, member: Symbol) -> Option<Symbol> {
+        self.member_defaults.get(&member).copied()
+    }
+
+    /// Returns whether a symbol is the default implementation of an ability member.
+    pub fn is_member_default(&self, symbol: Symbol) -> bool {
+        self.member_of_default(symbol).is_some()
+    }
+
+    /// Finds the ability member `default` is the default implementation of, if any.
+    pub fn member_of_default(&self, default: Symbol) -> Option<Symbol> {
+        self.default_to_member.get(&default).copied()
+    }
+
     /// Checks if `name` is a root ability member symbol name.
     /// Note that this will return `false` for specializations of an ability member, which have
     /// different symbols from the root.
@@ -227,8 +286,12 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
     #[inline(always)]
     fn register_one_declared_impl(&mut self, impl_key: ImplKey, member_impl: MemberImpl) {
         if let MemberImpl::Impl(specialization_symbol) = member_impl {
-            self.specialization_to_root
-                .insert(specialization_symbol, impl_key);
+            // A member default is shared by all the types that use it, so it doesn't specialize
+            // the member for any one of them.
+            if !self.is_member_default(specialization_symbol) {
+                self.specialization_to_root
+                    .insert(specialization_symbol, impl_key);
+            }
         }
         self.declared_implementations.insert(impl_key, member_impl);
     }
@@ -306,10 +369,15 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
     pub fn closure_from_imported(&self, symbols: &VecSet<Symbol>) -> PendingAbilitiesStore {
         let Self {
             members_of_ability,
+            superabilities,
+            member_defaults,
             ability_members,
             declared_implementations,
             specializations,
 
+            // Covered by `member_defaults`
+            default_to_member: _,
+
             // Covered by `declared_implementations`
             specialization_to_root: _,
 
@@ -332,6 +400,18 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
             }
         });
 
+        // Types implementing an ability must implement its superabilities too, so we need to
+        // know about those as well.
+        let mut stack: Vec<_> = abilities_to_introduce.iter().copied().collect();
+        while let Some(ability) = stack.pop() {
+            for superability in superabilities.get(&ability).into_iter().flatten() {
+                if !abilities_to_introduce.contains(superability) {
+                    abilities_to_introduce.insert(*superability);
+                    stack.push(*superability);
+                }
+            }
+        }
+
         // 2. Add each ability, and any specializations of its members we know about.
         for ability in abilities_to_introduce.into_iter() {
             let members = members_of_ability.get(&ability).unwrap();
@@ -352,10 +432,18 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
                 };
 
                 imported_member_data.push((*member, imported_data));
+
+                if let Some(default) = member_defaults.get(member) {
+                    new.register_member_default(*member, *default);
+                }
             }
 
             new.register_ability(ability, imported_member_data);
 
+            if let Some(supers) = superabilities.get(&ability) {
+                new.register_superabilities(ability, supers.clone());
+            }
+
             // Add any specializations of the ability's members we know about.
             declared_implementations
                 .iter()
@@ -364,6 +452,11 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
                     new.register_one_declared_impl(impl_key, *member_impl);
 
                     if let MemberImpl::Impl(spec_symbol) = member_impl {
+                        // Each module resolves the lambda sets of member defaults for itself.
+                        if new.is_member_default(*spec_symbol) {
+                            return;
+                        }
+
                         if let Some(specialization_info) = specializations.get(spec_symbol) {
                             new.import_specialization(specialization_info);
                         }
@@ -449,6 +542,28 @@ impl IAbilitiesStore<Resolved> {
         }
     }
 
+    /// Marks the default implementation of an ability member as either properly implementing the
+    /// member, or as erroring. In the latter case, all implementations using the default are
+    /// marked as erroring.
+    pub fn mark_member_default(
+        &mut self,
+        default: Symbol,
+        mark: Result<MemberSpecializationInfo<Resolved>, ()>,
+    ) {
+        match mark {
+            Ok(specialization_info) => {
+                self.specializations.insert(default, specialization_info);
+            }
+            Err(()) => {
+                for member_impl in self.declared_implementations.values_mut() {
+                    if *member_impl == MemberImpl::Impl(default) {
+                        *member_impl = MemberImpl::Error;
+                    }
+                }
+            }
+        }
+    }
+
     pub fn specialization_info(
         &self,
         specialization_symbol: Symbol,
@@ -475,7 +590,10 @@ impl IAbilitiesStore<Pending> {
     pub fn import_implementation(&mut self, impl_key: ImplKey, resolved_impl: &ResolvedImpl) {
         let member_impl = match resolved_impl {
             ResolvedImpl::Impl(specialization) => {
-                self.import_specialization(specialization);
+                // The lambda sets of a member default are resolved by every module using it.
+                if !self.is_member_default(specialization.symbol) {
+                    self.import_specialization(specialization);
+                }
                 MemberImpl::Impl(specialization.symbol)
             }
             ResolvedImpl::Derived => MemberImpl::Derived,
@@ -513,6 +631,9 @@ impl IAbilitiesStore<Pending> {
     pub fn union(&mut self, other: Self) {
         let Self {
             members_of_ability: other_members_of_ability,
+            superabilities,
+            member_defaults,
+            default_to_member: _,
             ability_members: mut other_ability_members,
             specialization_to_root,
             declared_implementations,
@@ -534,6 +655,18 @@ impl IAbilitiesStore<Pending> {
             self.register_ability(ability, member_data);
         }
 
+        for (ability, supers) in superabilities.into_iter() {
+            let old_supers = self.superabilities.insert(ability, supers.clone());
+            debug_assert!(old_supers.is_none() || old_supers.unwrap() == supers);
+        }
+
+        for (member, default) in member_defaults.into_iter() {
+            let old_default = self.member_defaults.insert(member, default);
+            debug_assert!(old_default.is_none() || old_default.unwrap() == default);
+
+            self.default_to_member.insert(default, member);
+        }
+
         for (specialization, member) in specialization_to_root.into_iter() {
             let old_root = self.specialization_to_root.insert(specialization, member);
             debug_assert!(old_root.is_none() || old_root.unwrap() == member);
@@ -573,6 +706,9 @@ impl IAbilitiesStore<Pending> {
     {
         let Self {
             members_of_ability,
+            superabilities,
+            member_defaults,
+            default_to_member,
             ability_members,
             specialization_to_root,
             declared_implementations,
@@ -581,6 +717,14 @@ impl IAbilitiesStore<Pending> {
             specializations,
         } = self;
 
+        // Defaults from other modules are checked against their member's signature in this
+        // module too, so that the lambda sets they specialize to are known here.
+        for default in member_defaults.values() {
+            if default.module_id() != my_module {
+                variable_of_symbol(my_module_ctx, *default);
+            }
+        }
+
         let ability_members = ability_members
             .into_iter()
             .map(|(member_symbol, member_data)| {
@@ -651,6 +795,9 @@ impl IAbilitiesStore<Pending> {
 
         AbilitiesStore {
             members_of_ability,
+            superabilities,
+            member_defaults,
+            default_to_member,
             ability_members,
             specialization_to_root,
             declared_implementations,
@@ -186,6 +186,15 @@ map_symbol_to_lowlevel_and_arity! {
     NumShiftLeftBy; NUM_SHIFT_LEFT; 2,
     NumShiftRightBy; NUM_SHIFT_RIGHT; 2,
     NumShiftRightZfBy; NUM_SHIFT_RIGHT_ZERO_FILL; 2,
+    NumCountLeadingZeroBits; NUM_COUNT_LEADING_ZERO_BITS; 1,
+    NumCountTrailingZeroBits; NUM_COUNT_TRAILING_ZERO_BITS; 1,
+    NumCountOneBits; NUM_COUNT_ONE_BITS; 1,
+    NumToBytesLe; NUM_TO_BYTES_LE; 1,
+    NumToBytesBe; NUM_TO_BYTES_BE; 1,
+    NumFromBytesLe; NUM_FROM_BYTES_LE_LOWLEVEL; 2,
+    NumFromBytesBe; NUM_FROM_BYTES_BE_LOWLEVEL; 2,
+    NumF64ToBits; NUM_F64_TO_BITS; 1,
+    NumF64FromBits; NUM_F64_FROM_BITS; 1,
     NumToStr; NUM_TO_STR; 1,
 
     Eq; BOOL_EQ; 2,
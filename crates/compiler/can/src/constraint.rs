@@ -25,6 +25,7 @@ pub struct Constraints {
     pub eq: Vec<Eq>,
     pub pattern_eq: Vec<PatternEq>,
     pub cycles: Vec<Cycle>,
+    pub typed_holes: Vec<TypedHole>,
 }
 
 impl Default for Constraints {
@@ -50,6 +51,7 @@ impl Constraints {
         let eq = Vec::new();
         let pattern_eq = Vec::new();
         let cycles = Vec::new();
+        let typed_holes = Vec::new();
 
         types.extend([
             Type::EmptyRec,
@@ -104,6 +106,7 @@ impl Constraints {
             eq,
             pattern_eq,
             cycles,
+            typed_holes,
         }
     }
 
@@ -586,7 +589,8 @@ impl Constraints {
             | Constraint::PatternPresence(_, _, _, _)
             | Constraint::Exhaustive { .. }
             | Constraint::Resolve(..)
-            | Constraint::CheckCycle(..) => false,
+            | Constraint::CheckCycle(..)
+            | Constraint::TypedHole(..) => false,
         }
     }
 
@@ -675,6 +679,22 @@ impl Constraints {
 
         Constraint::CheckCycle(cycle_index, cycle_mark)
     }
+
+    pub fn typed_hole(
+        &mut self,
+        var: Variable,
+        region: Region,
+        candidates: Vec<Symbol>,
+    ) -> Constraint {
+        let hole = TypedHole {
+            var,
+            region,
+            candidates,
+        };
+        let hole_index = Index::push_new(&mut self.typed_holes, hole);
+
+        Constraint::TypedHole(hole_index)
+    }
 }
 
 roc_error_macros::assert_sizeof_default!(Constraint, 3 * 8);
@@ -764,6 +784,8 @@ pub enum Constraint {
     /// Attempt to resolve a specialization.
     Resolve(OpportunisticResolve),
     CheckCycle(Index<Cycle>, IllegalCycleMark),
+    /// Report the type of a typed hole once solving is done.
+    TypedHole(Index<TypedHole>),
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -795,6 +817,14 @@ pub struct Cycle {
     pub expr_regions: Slice<Region>,
 }
 
+#[derive(Debug, Clone)]
+pub struct TypedHole {
+    pub var: Variable,
+    pub region: Region,
+    /// The values in scope at the hole; the ones whose type fits are suggested.
+    pub candidates: Vec<Symbol>,
+}
+
 /// Custom impl to limit vertical space used by the debug output
 impl std::fmt::Debug for Constraint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -837,6 +867,9 @@ impl std::fmt::Debug for Constraint {
             Self::CheckCycle(arg0, arg1) => {
                 write!(f, "CheckCycle({:?}, {:?})", arg0, arg1)
             }
+            Self::TypedHole(arg0) => {
+                write!(f, "TypedHole({:?})", arg0)
+            }
         }
     }
 }
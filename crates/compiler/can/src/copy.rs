@@ -623,7 +623,10 @@ fn deep_copy_expr_help<C: CopyEnv>(env: &mut C, copied: &mut Vec<Variable>, expr
             lookups_in_cond: lookups_in_cond.to_vec(),
         },
 
-        TypedHole(v) => TypedHole(sub!(*v)),
+        TypedHole { var, candidates } => TypedHole {
+            var: sub!(*var),
+            candidates: candidates.clone(),
+        },
 
         RuntimeError(err) => RuntimeError(err.clone()),
     }
@@ -726,6 +729,23 @@ fn deep_copy_pattern_help<C: CopyEnv>(
         }
         StrLiteral(s) => StrLiteral(s.clone()),
         SingleQuote(c) => SingleQuote(*c),
+        NumRange {
+            num_var,
+            precision_var,
+            start,
+            end,
+            end_kind,
+            first,
+            last,
+        } => NumRange {
+            num_var: sub!(*num_var),
+            precision_var: sub!(*precision_var),
+            start: Box::new(start.map(|p| go_help!(p))),
+            end: Box::new(end.map(|p| go_help!(p))),
+            end_kind: *end_kind,
+            first: *first,
+            last: *last,
+        },
         Underscore => Underscore,
         AbilityMemberSpecialization { ident, specializes } => AbilityMemberSpecialization {
             ident: *ident,
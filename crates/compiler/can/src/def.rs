@@ -13,7 +13,7 @@ use crate::expr::AnnotatedMark;
 use crate::expr::ClosureData;
 use crate::expr::Declarations;
 use crate::expr::Expr::{self, *};
-use crate::expr::{canonicalize_expr, Output, Recursive};
+use crate::expr::{canonicalize_expr, get_lookup_symbols, Output, Recursive};
 use crate::pattern::{canonicalize_def_header_pattern, BindingsFromPattern, Pattern};
 use crate::procedure::References;
 use crate::scope::create_alias;
@@ -139,6 +139,9 @@ enum PendingValueDef<'a> {
         &'a Loc<ast::TypeAnnotation<'a>>,
         &'a Loc<ast::Expr<'a>>,
     ),
+    /// A def that failed to parse, but whose name could still be made out, so that uses of it
+    /// crash at runtime instead of being reported as not defined
+    Malformed(Loc<Pattern>, Region),
 }
 
 impl PendingValueDef<'_> {
@@ -147,6 +150,7 @@ impl PendingValueDef<'_> {
             PendingValueDef::AnnotationOnly(_, loc_pattern, _) => loc_pattern,
             PendingValueDef::Body(_, loc_pattern, _) => loc_pattern,
             PendingValueDef::TypedBody(_, loc_pattern, _, _) => loc_pattern,
+            PendingValueDef::Malformed(loc_pattern, _) => loc_pattern,
         }
     }
 }
@@ -155,6 +159,15 @@ impl PendingValueDef<'_> {
 struct PendingAbilityMember<'a> {
     name: Loc<Symbol>,
     typ: Loc<ast::TypeAnnotation<'a>>,
+    default: Option<PendingMemberDefault<'a>>,
+}
+
+/// The default implementation of an ability member, given in the ability's definition.
+#[derive(Debug, Clone, Copy)]
+struct PendingMemberDefault<'a> {
+    symbol: Symbol,
+    ast_pattern: &'a Loc<ast::Pattern<'a>>,
+    body: &'a Loc<ast::Expr<'a>>,
 }
 
 #[derive(Debug, Clone)]
@@ -176,6 +189,7 @@ enum PendingTypeDef<'a> {
 
     Ability {
         name: Loc<Symbol>,
+        superabilities: &'a [Loc<ast::TypeAnnotation<'a>>],
         members: Vec<PendingAbilityMember<'a>>,
     },
 
@@ -786,6 +800,21 @@ fn canonicalize_opaque<'a>(
                     });
                 }
 
+                // Members the ability gives a default implementation for don't need to be
+                // implemented.
+                let not_implemented: Vec<_> = not_implemented
+                    .into_iter()
+                    .filter(
+                        |member| match scope.abilities_store.member_default(*member) {
+                            Some(default) => {
+                                impl_map.insert(*member, Loc::at_zero(MemberImpl::Impl(default)));
+                                false
+                            }
+                            None => true,
+                        },
+                    )
+                    .collect();
+
                 if !not_implemented.is_empty() {
                     // We'll generate runtime errors for the members that are needed but
                     // unspecified.
@@ -888,11 +917,30 @@ pub(crate) fn canonicalize_defs<'a>(
         if let Ok(type_index) = either_index.split() {
             let type_def = &loc_defs.type_defs[type_index.index()];
             let pending_type_def = to_pending_type_def(env, type_def, scope, pattern_type);
-            if let PendingTypeDef::Ability { name, members } = &pending_type_def {
+            if let PendingTypeDef::Ability { name, members, .. } = &pending_type_def {
                 pending_abilities_in_scope.insert(
                     name.value,
                     members.iter().map(|mem| mem.name.value).collect(),
                 );
+
+                // Default implementations of the members are checked and generated like any
+                // other value def.
+                for member in members.iter() {
+                    if let Some(default) = member.default {
+                        let PendingMemberDefault {
+                            symbol,
+                            ast_pattern,
+                            body,
+                        } = default;
+                        let pending = PendingValueDef::Body(
+                            ast_pattern,
+                            Loc::at(ast_pattern.region, Pattern::Identifier(symbol)),
+                            body,
+                        );
+
+                        pending_value_defs.push(Loc::at(body.region, PendingValue::Def(pending)));
+                    }
+                }
             }
             pending_type_defs.push(pending_type_def);
         }
@@ -907,6 +955,7 @@ pub(crate) fn canonicalize_defs<'a>(
                 env,
                 var_store,
                 value_def,
+                region,
                 scope,
                 &pending_abilities_in_scope,
                 &mut output,
@@ -971,7 +1020,7 @@ fn canonicalize_value_defs<'a>(
                 // that get would have gotten added later in the defs list!
                 pending_value_defs.push(pending_def);
             }
-            PendingValue::SignatureDefMismatch => { /* skip */ }
+            PendingValue::SignatureDefMismatch | PendingValue::Malformed => { /* skip */ }
             PendingValue::Expect(pending_expect) => {
                 pending_expects.push(pending_expect);
             }
@@ -1001,7 +1050,10 @@ fn canonicalize_value_defs<'a>(
                 env.top_level_symbols.insert(s);
             }
 
-            symbols_introduced.insert(s, r);
+            // A def that failed to parse was already reported; it isn't also reported as unused.
+            if !matches!(pending_def, PendingValueDef::Malformed(..)) {
+                symbols_introduced.insert(s, r);
+            }
 
             debug_assert_eq!(env.home, s.module_id());
             debug_assert!(
@@ -1098,7 +1150,11 @@ fn canonicalize_type_defs<'a>(
             &'a Loc<ast::TypeAnnotation<'a>>,
             Option<&'a Loc<ast::HasAbilities<'a>>>,
         ),
-        Ability(Loc<Symbol>, Vec<PendingAbilityMember<'a>>),
+        Ability(
+            Loc<Symbol>,
+            &'a [Loc<ast::TypeAnnotation<'a>>],
+            Vec<PendingAbilityMember<'a>>,
+        ),
     }
 
     let mut type_defs = MutMap::default();
@@ -1135,9 +1191,17 @@ fn canonicalize_type_defs<'a>(
 
                 type_defs.insert(name.value, TypeDef::Opaque(name, vars, ann, derived));
             }
-            PendingTypeDef::Ability { name, members } => {
+            PendingTypeDef::Ability {
+                name,
+                superabilities,
+                members,
+            } => {
                 let mut referenced_symbols = Vec::with_capacity(2);
 
+                for superability in superabilities.iter() {
+                    referenced_symbols.extend(find_type_def_symbols(scope, &superability.value));
+                }
+
                 for member in members.iter() {
                     // Add the referenced type symbols of each member function. We need to make
                     // sure those are processed first before we resolve the whole ability
@@ -1146,7 +1210,7 @@ fn canonicalize_type_defs<'a>(
                 }
 
                 referenced_type_symbols.insert(name.value, referenced_symbols);
-                type_defs.insert(name.value, TypeDef::Ability(name, members));
+                type_defs.insert(name.value, TypeDef::Ability(name, superabilities, members));
             }
             PendingTypeDef::InvalidAlias { .. }
             | PendingTypeDef::InvalidAbility { .. }
@@ -1198,10 +1262,10 @@ fn canonicalize_type_defs<'a>(
                 }
             }
 
-            TypeDef::Ability(name, members) => {
+            TypeDef::Ability(name, superabilities, members) => {
                 // For now we enforce that aliases cannot reference abilities, so let's wait to
                 // resolve ability definitions until aliases are resolved and in scope below.
-                abilities.insert(name.value, members);
+                abilities.insert(name.value, (superabilities, members));
             }
         }
     }
@@ -1240,10 +1304,46 @@ fn resolve_abilities<'a>(
     output: &mut Output,
     var_store: &mut VarStore,
     scope: &mut Scope,
-    abilities: MutMap<Symbol, Vec<PendingAbilityMember>>,
+    abilities: MutMap<
+        Symbol,
+        (
+            &'a [Loc<ast::TypeAnnotation<'a>>],
+            Vec<PendingAbilityMember>,
+        ),
+    >,
     pending_abilities_in_scope: &PendingAbilitiesInScope,
 ) {
-    for (ability, members) in abilities {
+    for (ability, (superabilities, members)) in abilities {
+        let mut can_superabilities = Vec::with_capacity(superabilities.len());
+
+        for superability in superabilities.iter() {
+            let region = superability.region;
+
+            match superability.value {
+                ast::TypeAnnotation::Apply(module_name, ident, []) => {
+                    match make_apply_symbol(env, region, scope, module_name, ident) {
+                        Ok(symbol)
+                            if pending_abilities_in_scope.contains_key(&symbol)
+                                || scope.abilities_store.is_ability(symbol) =>
+                        {
+                            output.references.insert_type_lookup(symbol);
+                            can_superabilities.push(symbol);
+                        }
+                        Ok(_) => env.problem(Problem::NotAnAbility(region)),
+                        Err(_) => {
+                            // This is bad apply; an error will have been reported for it
+                            // already.
+                        }
+                    }
+                }
+                _ => env.problem(Problem::NotAnAbility(region)),
+            }
+        }
+
+        scope
+            .abilities_store
+            .register_superabilities(ability, can_superabilities);
+
         let mut can_members = Vec::with_capacity(members.len());
 
         for PendingAbilityMember {
@@ -1253,6 +1353,7 @@ fn resolve_abilities<'a>(
                     region: member_name_region,
                 },
             typ,
+            default: _,
         } in members
         {
             let member_annot = canonicalize_annotation(
@@ -1860,6 +1961,7 @@ fn pattern_to_vars_by_symbol(
         | FloatLiteral(..)
         | StrLiteral(_)
         | SingleQuote(_)
+        | NumRange { .. }
         | Underscore
         | MalformedPattern(_, _)
         | UnsupportedPattern(_)
@@ -2036,6 +2138,34 @@ fn canonicalize_pending_value_def<'a>(
             }
         }
 
+        Malformed(loc_can_pattern, region) => {
+            let expr_var = var_store.fresh();
+            let mut vars_by_symbol = SendMap::default();
+
+            pattern_to_vars_by_symbol(&mut vars_by_symbol, &loc_can_pattern.value, expr_var);
+
+            let problem = match &loc_can_pattern.value {
+                Pattern::Identifier(symbol) => RuntimeError::NoImplementationNamed {
+                    def_symbol: *symbol,
+                },
+                _ => RuntimeError::NoImplementation,
+            };
+
+            let def = single_can_def(
+                loc_can_pattern,
+                Loc::at(region, Expr::RuntimeError(problem)),
+                expr_var,
+                None,
+                vars_by_symbol,
+            );
+
+            DefOutput {
+                output,
+                references: DefReferences::Value(References::new()),
+                def,
+            }
+        }
+
         TypedBody(_loc_pattern, loc_can_pattern, loc_ann, loc_expr) => {
             let type_annotation = canonicalize_annotation(
                 env,
@@ -2257,13 +2387,13 @@ pub fn can_defs_with_return<'a>(
     let mut loc_expr: Loc<Expr> = ret_expr;
 
     for declaration in declarations.into_iter().rev() {
-        loc_expr = decl_to_let(declaration, loc_expr);
+        loc_expr = decl_to_let(var_store, declaration, loc_expr);
     }
 
     (loc_expr.value, output)
 }
 
-fn decl_to_let(decl: Declaration, loc_ret: Loc<Expr>) -> Loc<Expr> {
+fn decl_to_let(var_store: &mut VarStore, decl: Declaration, loc_ret: Loc<Expr>) -> Loc<Expr> {
     match decl {
         Declaration::Declare(def) => {
             let region = Region::span_across(&def.loc_pattern.region, &loc_ret.region);
@@ -2283,8 +2413,23 @@ fn decl_to_let(decl: Declaration, loc_ret: Loc<Expr>) -> Loc<Expr> {
             unreachable!()
         }
         Declaration::Expects(expects) => {
-            // Expects should only be added to top-level decls, not to let-exprs!
-            unreachable!("{:?}", &expects)
+            // The expects come after all the other defs, so they can use all of them.
+            let mut loc_expr = loc_ret;
+
+            for (condition, region) in expects.conditions.into_iter().zip(expects.regions).rev() {
+                let lookups_in_cond = get_lookup_symbols(&condition, var_store);
+                let expr_region = Region::span_across(&region, &loc_expr.region);
+
+                let expr = Expect {
+                    loc_condition: Box::new(Loc::at(region, condition)),
+                    loc_continuation: Box::new(loc_expr),
+                    lookups_in_cond,
+                };
+
+                loc_expr = Loc::at(expr_region, expr);
+            }
+
+            loc_expr
         }
         Declaration::ExpectsFx(expects) => {
             // Expects should only be added to top-level decls, not to let-exprs!
@@ -2415,6 +2560,7 @@ fn to_pending_type_def<'a>(
 
         Ability {
             header: TypeHeader { name, vars },
+            superabilities,
             members,
             loc_has: _,
         } => {
@@ -2465,9 +2611,31 @@ fn to_pending_type_def<'a>(
                     }
                 };
 
+                // The default isn't in scope under the member's name; it's only reachable through
+                // the types that use it.
+                let default = member.default.map(|body| {
+                    let symbol = scope.scopeless_symbol(&Ident::from(member_name), body.region);
+                    scope
+                        .abilities_store
+                        .register_member_default(member_sym, symbol);
+
+                    if pattern_type == PatternType::TopLevelDef {
+                        env.top_level_symbols.insert(symbol);
+                    }
+
+                    PendingMemberDefault {
+                        symbol,
+                        ast_pattern: env
+                            .arena
+                            .alloc(Loc::at(body.region, ast::Pattern::Identifier(member_name))),
+                        body,
+                    }
+                });
+
                 named_members.push(PendingAbilityMember {
                     name: Loc::at(name_region, member_sym),
                     typ: member.typ,
+                    default,
                 });
 
                 if pattern_type == PatternType::TopLevelDef {
@@ -2477,6 +2645,7 @@ fn to_pending_type_def<'a>(
 
             PendingTypeDef::Ability {
                 name,
+                superabilities,
                 members: named_members,
             }
         }
@@ -2488,6 +2657,8 @@ enum PendingValue<'a> {
     Expect(PendingExpect<'a>),
     ExpectFx(PendingExpect<'a>),
     SignatureDefMismatch,
+    /// The def failed to parse; the syntax error is reported by the parser.
+    Malformed,
 }
 
 struct PendingExpect<'a> {
@@ -2499,6 +2670,7 @@ fn to_pending_value_def<'a>(
     env: &mut Env<'a>,
     var_store: &mut VarStore,
     def: &'a ast::ValueDef<'a>,
+    region: Region,
     scope: &mut Scope,
     pending_abilities_in_scope: &PendingAbilitiesInScope,
     output: &mut Output,
@@ -2607,6 +2779,51 @@ fn to_pending_value_def<'a>(
             condition,
             preceding_comment: *preceding_comment,
         }),
+
+        Malformed(text) => {
+            // The syntax error was already reported by the parser. If the def still starts with
+            // a name, bind it, so that uses of the name don't also report it as not defined.
+            let name = match malformed_def_name(text) {
+                Some(name) => name,
+                None => return PendingValue::Malformed,
+            };
+            let name_region = Region::new(
+                region.start(),
+                region.start().bump_column(name.len() as u32),
+            );
+
+            match scope.introduce_str(name, name_region) {
+                Ok(symbol) => {
+                    output.references.insert_bound(symbol);
+
+                    PendingValue::Def(PendingValueDef::Malformed(
+                        Loc::at(name_region, Pattern::Identifier(symbol)),
+                        region,
+                    ))
+                }
+                // The name is already bound, e.g. by this def's annotation
+                Err(_) => PendingValue::Malformed,
+            }
+        }
+    }
+}
+
+/// The name defined by the source of a def that failed to parse, e.g. `foo` in `foo = 1 +`
+fn malformed_def_name(text: &str) -> Option<&str> {
+    let name_len = text
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(text.len());
+    let (name, rest) = text.split_at(name_len);
+
+    let starts_lowercase = name.starts_with(|c: char| c.is_ascii_lowercase());
+    let is_keyword = roc_parse::keyword::KEYWORDS.contains(&name);
+    let rest = rest.trim_start();
+    let defines_name = rest.starts_with(':') || (rest.starts_with('=') && !rest.starts_with("=="));
+
+    if starts_lowercase && !is_keyword && defines_name {
+        Some(name)
+    } else {
+        None
     }
 }
 
@@ -4,10 +4,13 @@ use roc_collections::all::HumanIndex;
 use roc_collections::VecMap;
 use roc_error_macros::internal_error;
 use roc_exhaustive::{
-    is_useful, Ctor, CtorName, Error, Guard, Literal, Pattern, RenderAs, TagId, Union,
+    is_useful, Ctor, CtorName, Error, Guard, IntBound, IntDomain, IntRange, IntRender, Literal,
+    Pattern, RenderAs, TagId, Union,
 };
 use roc_module::ident::{TagIdIntType, TagName};
+use roc_module::symbol::Symbol;
 use roc_region::all::{Loc, Region};
+use roc_types::num::IntLitWidth;
 use roc_types::subs::{Content, FlatType, RedundantMark, Subs, SubsFmtContent, Variable};
 use roc_types::types::AliasKind;
 
@@ -57,6 +60,8 @@ pub fn check(
 enum SketchedPattern {
     Anything,
     Literal(Literal),
+    /// An inclusive range of integers or characters.
+    IntRange(IntBound, IntBound, IntRender),
     /// A constructor whose expected union is not yet known.
     /// We'll know the whole union when reifying the sketched pattern against an expected case type.
     Ctor(TagName, Vec<SketchedPattern>),
@@ -189,7 +194,17 @@ impl SketchedPattern {
     fn reify(self, subs: &Subs, real_var: Variable) -> Pattern {
         match self {
             Self::Anything => Pattern::Anything,
-            Self::Literal(lit) => Pattern::Literal(lit),
+            Self::Literal(lit) => reify_literal(subs, real_var, lit),
+            Self::IntRange(lo, hi, render_as) => {
+                let domain = int_domain(subs, real_var, render_as);
+
+                match IntRange::inclusive(domain, lo, hi) {
+                    Some(range) => Pattern::IntRange(range),
+                    // None of the values fit the type, so like a literal that does not fit, the
+                    // range matches nothing.
+                    None => Pattern::Literal(int_bound_literal(lo)),
+                }
+            }
             Self::KnownCtor(union, index_ctor, tag_id, patterns) => {
                 let arg_vars = index_var(subs, real_var, index_ctor, &union.render_as);
 
@@ -220,6 +235,92 @@ impl SketchedPattern {
     }
 }
 
+/// Integer literals become ranges, so that they line up with range patterns, and so that
+/// covering every value of e.g. a `U8` counts as exhaustive.
+fn reify_literal(subs: &Subs, var: Variable, lit: Literal) -> Pattern {
+    let (value, render_as) = match lit {
+        Literal::Int(n) => (IntBound::Signed(i128::from_ne_bytes(n)), IntRender::Int),
+        Literal::U128(n) => (IntBound::Unsigned(u128::from_ne_bytes(n)), IntRender::Int),
+        Literal::Char(c) => (IntBound::Unsigned(c as u128), IntRender::Char),
+        _ => return Pattern::Literal(lit),
+    };
+
+    match IntRange::singleton(int_domain(subs, var, render_as), value) {
+        Some(range) => Pattern::IntRange(range),
+        None => Pattern::Literal(lit),
+    }
+}
+
+fn int_bound_literal(value: IntBound) -> Literal {
+    match value {
+        IntBound::Signed(n) => Literal::Int(n.to_ne_bytes()),
+        IntBound::Unsigned(n) => Literal::U128(n.to_ne_bytes()),
+    }
+}
+
+fn int_value_bound(value: IntValue) -> IntBound {
+    match value {
+        IntValue::I128(n) => IntBound::Signed(i128::from_ne_bytes(n)),
+        IntValue::U128(n) => IntBound::Unsigned(u128::from_ne_bytes(n)),
+    }
+}
+
+fn int_domain(subs: &Subs, var: Variable, render_as: IntRender) -> IntDomain {
+    match int_lit_width(subs, var) {
+        Some(width) => IntDomain {
+            min: width.min_value(),
+            max: width.max_value(),
+            render_as,
+            bounded: true,
+        },
+        None => IntDomain {
+            min: i128::MIN,
+            max: i128::MAX as u128,
+            render_as,
+            bounded: false,
+        },
+    }
+}
+
+/// The width of the integer type `var` has been solved to, if it is known.
+fn int_lit_width(subs: &Subs, mut var: Variable) -> Option<IntLitWidth> {
+    loop {
+        match subs.get_content_without_compacting(var) {
+            Content::Alias(symbol, args, real_var, _) => {
+                let width = match *symbol {
+                    Symbol::NUM_I8 | Symbol::NUM_SIGNED8 => IntLitWidth::I8,
+                    Symbol::NUM_U8 | Symbol::NUM_UNSIGNED8 => IntLitWidth::U8,
+                    Symbol::NUM_I16 | Symbol::NUM_SIGNED16 => IntLitWidth::I16,
+                    Symbol::NUM_U16 | Symbol::NUM_UNSIGNED16 => IntLitWidth::U16,
+                    Symbol::NUM_I32 | Symbol::NUM_SIGNED32 => IntLitWidth::I32,
+                    Symbol::NUM_U32 | Symbol::NUM_UNSIGNED32 => IntLitWidth::U32,
+                    Symbol::NUM_I64 | Symbol::NUM_SIGNED64 => IntLitWidth::I64,
+                    Symbol::NUM_U64 | Symbol::NUM_UNSIGNED64 => IntLitWidth::U64,
+                    Symbol::NUM_I128 | Symbol::NUM_SIGNED128 => IntLitWidth::I128,
+                    Symbol::NUM_U128 | Symbol::NUM_UNSIGNED128 => IntLitWidth::U128,
+                    // The width of a Nat depends on the target, so we treat it as unbounded.
+                    Symbol::NUM_NAT | Symbol::NUM_NATURAL => return None,
+                    Symbol::NUM_NUM | Symbol::NUM_INTEGER | Symbol::NUM_INT => {
+                        // `Num range` and `Integer range` carry their width in the argument
+                        let arg = args.all_variables().into_iter().next()?;
+                        var = subs[arg];
+                        continue;
+                    }
+                    _ => {
+                        var = *real_var;
+                        continue;
+                    }
+                };
+
+                return Some(width);
+            }
+            // A width that is not decided yet, e.g. of a number in a generic function, can be
+            // any width, so we treat it as unbounded.
+            _ => return None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct SketchedRow {
     patterns: Vec<SketchedPattern>,
@@ -253,7 +354,17 @@ fn sketch_pattern(pattern: &crate::pattern::Pattern) -> SketchedPattern {
         }
         &FloatLiteral(_, _, _, f, _) => SP::Literal(Literal::Float(f64::to_bits(f))),
         StrLiteral(v) => SP::Literal(Literal::Str(v.clone())),
-        &SingleQuote(c) => SP::Literal(Literal::Byte(c as u8)),
+        &SingleQuote(c) => SP::Literal(Literal::Char(c)),
+        NumRange {
+            start, first, last, ..
+        } => {
+            let render_as = match start.value {
+                SingleQuote(_) => IntRender::Char,
+                _ => IntRender::Int,
+            };
+
+            SP::IntRange(int_value_bound(*first), int_value_bound(*last), render_as)
+        }
         RecordDestructure { destructs, .. } => {
             let tag_id = TagId(0);
             let mut patterns = std::vec::Vec::with_capacity(destructs.len());
@@ -515,7 +626,7 @@ fn is_inhabited_pattern(pat: &Pattern) -> bool {
     while let Some(pat) = stack.pop() {
         match pat {
             Pattern::Anything => {}
-            Pattern::Literal(_) => {}
+            Pattern::Literal(_) | Pattern::IntRange(_) => {}
             Pattern::Ctor(union, id, pats) => {
                 if !union.alternatives.iter().any(|alt| alt.tag_id == *id) {
                     return false;
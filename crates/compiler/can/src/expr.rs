@@ -238,8 +238,12 @@ pub enum Expr {
         lookups_in_cond: Vec<(Symbol, Variable)>,
     },
 
-    /// Rendered as empty box in editor
-    TypedHole(Variable),
+    /// A hole, written `_` or `_name`. Its type is reported, along with the values in scope that
+    /// would fit in it. Rendered as empty box in editor.
+    TypedHole {
+        var: Variable,
+        candidates: Vec<Symbol>,
+    },
 
     /// Compiles, but will crash if reached
     RuntimeError(RuntimeError),
@@ -287,7 +291,7 @@ impl Expr {
             Self::ExpectFx { .. } => Category::Expect,
 
             // these nodes place no constraints on the expression's type
-            Self::TypedHole(_) | Self::RuntimeError(..) => Category::Unknown,
+            Self::TypedHole { .. } | Self::RuntimeError(..) => Category::Unknown,
         }
     }
 }
@@ -822,17 +826,17 @@ pub fn canonicalize_expr<'a>(
         ast::Expr::Var { module_name, ident } => {
             canonicalize_var_lookup(env, var_store, scope, module_name, ident, region)
         }
-        ast::Expr::Underscore(name) => {
-            // we parse underscores, but they are not valid expression syntax
-            let problem = roc_problem::can::RuntimeError::MalformedIdentifier(
-                (*name).into(),
-                roc_parse::ident::BadIdent::Underscore(region.start()),
-                region,
-            );
-
-            env.problem(Problem::RuntimeError(problem.clone()));
+        ast::Expr::Underscore(_) => {
+            // A typed hole. Any value in scope may fit in it, but the hole doesn't use them, so
+            // no lookups are recorded; otherwise unused imports would go unreported. That means
+            // imported values are only suggested when something else uses them, since only then
+            // do they get a type.
+            let expr = TypedHole {
+                var: var_store.fresh(),
+                candidates: scope.values_in_scope(),
+            };
 
-            (RuntimeError(problem), Output::default())
+            (expr, Output::default())
         }
         ast::Expr::Defs(loc_defs, loc_ret) => {
             // The body expression gets a new scope for canonicalization,
@@ -2605,7 +2609,7 @@ pub struct DestructureDef {
     pub pattern_vars: VecMap<Symbol, Variable>,
 }
 
-fn get_lookup_symbols(expr: &Expr, var_store: &mut VarStore) -> Vec<(Symbol, Variable)> {
+pub(crate) fn get_lookup_symbols(expr: &Expr, var_store: &mut VarStore) -> Vec<(Symbol, Variable)> {
     let mut stack: Vec<&Expr> = vec![expr];
     let mut symbols = Vec::new();
 
@@ -2705,7 +2709,7 @@ fn get_lookup_symbols(expr: &Expr, var_store: &mut VarStore) -> Vec<(Symbol, Var
             | Expr::Accessor(_)
             | Expr::SingleQuote(_)
             | Expr::EmptyRecord
-            | Expr::TypedHole(_)
+            | Expr::TypedHole { .. }
             | Expr::RuntimeError(_)
             | Expr::OpaqueWrapFunction(_) => {}
         }
@@ -384,6 +384,7 @@ pub fn canonicalize_module_defs<'a>(
         if !output.references.has_type_or_value_lookup(symbol)
             && !exposed_symbols.contains(&symbol)
             && !scope.abilities_store.is_specialization_name(symbol)
+            && !scope.abilities_store.is_member_default(symbol)
         {
             env.problem(Problem::UnusedDef(symbol, region));
         }
@@ -911,6 +912,7 @@ fn fix_values_captured_in_closure_pattern(
         | FloatLiteral(..)
         | StrLiteral(_)
         | SingleQuote(_)
+        | NumRange { .. }
         | Underscore
         | Shadowed(..)
         | MalformedPattern(_, _)
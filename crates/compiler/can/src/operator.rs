@@ -6,7 +6,7 @@ use roc_module::called_via::BinOp::Pizza;
 use roc_module::called_via::{BinOp, CalledVia};
 use roc_module::ident::ModuleName;
 use roc_parse::ast::Expr::{self, *};
-use roc_parse::ast::{AssignedField, ValueDef, WhenBranch};
+use roc_parse::ast::{AbilityMember, AssignedField, TypeDef, ValueDef, WhenBranch};
 use roc_region::all::{Loc, Region};
 
 // BinOp precedence logic adapted from Gluon by Markus Westerlind
@@ -102,10 +102,43 @@ fn desugar_value_def<'a>(arena: &'a Bump, def: &'a ValueDef<'a>) -> ValueDef<'a>
                 preceding_comment: *preceding_comment,
             }
         }
+        Malformed(text) => Malformed(text),
+    }
+}
+
+fn desugar_type_def<'a>(arena: &'a Bump, def: &'a TypeDef<'a>) -> TypeDef<'a> {
+    match def {
+        TypeDef::Ability {
+            header,
+            loc_has,
+            superabilities,
+            members,
+        } => {
+            // The default implementations of ability members are expressions too.
+            let members = Vec::from_iter_in(
+                members.iter().map(|member| AbilityMember {
+                    default: member.default.map(|expr| desugar_expr(arena, expr)),
+                    ..*member
+                }),
+                arena,
+            );
+
+            TypeDef::Ability {
+                header: *header,
+                loc_has: *loc_has,
+                superabilities,
+                members: members.into_bump_slice(),
+            }
+        }
+        alias_or_opaque @ (TypeDef::Alias { .. } | TypeDef::Opaque { .. }) => *alias_or_opaque,
     }
 }
 
 pub fn desugar_defs<'a>(arena: &'a Bump, defs: &mut roc_parse::ast::Defs<'a>) {
+    for type_def in defs.type_defs.iter_mut() {
+        *type_def = desugar_type_def(arena, arena.alloc(*type_def));
+    }
+
     for value_def in defs.value_defs.iter_mut() {
         *value_def = desugar_value_def(arena, arena.alloc(*value_def));
     }
@@ -8,12 +8,13 @@ use crate::num::{
 use crate::scope::{PendingAbilitiesInScope, Scope};
 use roc_module::ident::{Ident, Lowercase, TagName};
 use roc_module::symbol::Symbol;
-use roc_parse::ast::{self, StrLiteral, StrSegment};
+use roc_parse::ast::{self, RangeEnd, StrLiteral, StrSegment};
 use roc_parse::pattern::PatternType;
 use roc_problem::can::{MalformedPatternProblem, Problem, RuntimeError, ShadowKind};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{VarStore, Variable};
 use roc_types::types::{LambdaSet, OptAbleVar, PatternCategory, Type};
+use std::cmp::Ordering;
 
 /// A pattern, including possible problems (e.g. shadowing) so that
 /// codegen can generate a runtime error if this pattern is reached.
@@ -60,6 +61,18 @@ pub enum Pattern {
     FloatLiteral(Variable, Variable, Box<str>, f64, FloatBound),
     StrLiteral(Box<str>),
     SingleQuote(char),
+    /// A range of integers or characters, e.g. `0..10` or `'a'..='z'`. The ends are always
+    /// number, integer or character literals; `first` and `last` are the values they bound,
+    /// so `last` is one less than `end` when the end is exclusive.
+    NumRange {
+        num_var: Variable,
+        precision_var: Variable,
+        start: Box<Loc<Pattern>>,
+        end: Box<Loc<Pattern>>,
+        end_kind: RangeEnd,
+        first: IntValue,
+        last: IntValue,
+    },
     Underscore,
 
     /// An identifier that marks a specialization of an ability member.
@@ -96,6 +109,7 @@ impl Pattern {
             FloatLiteral(var, ..) => Some(*var),
             StrLiteral(_) => None,
             SingleQuote(_) => None,
+            NumRange { num_var, .. } => Some(*num_var),
             Underscore => None,
 
             AbilityMemberSpecialization { .. } => None,
@@ -123,7 +137,8 @@ impl Pattern {
             | IntLiteral(..)
             | FloatLiteral(..)
             | StrLiteral(..)
-            | SingleQuote(..) => false,
+            | SingleQuote(..)
+            | NumRange { .. } => false,
             UnwrappedOpaque { argument, .. } => {
                 // Opaques can only match against one constructor (the opaque symbol), so this is
                 // surely exhaustive against T if the inner pattern is surely exhaustive against
@@ -149,6 +164,7 @@ impl Pattern {
             FloatLiteral(..) => C::Float,
             StrLiteral(_) => C::Str,
             SingleQuote(_) => C::Character,
+            NumRange { .. } => C::Int,
             Underscore => C::PatternDefault,
 
             AbilityMemberSpecialization { .. } => C::PatternDefault,
@@ -469,6 +485,21 @@ pub fn canonicalize_pattern<'a>(
             }
         }
 
+        Range(loc_start, end_kind, loc_end) => match pattern_type {
+            WhenBranch => canonicalize_range(
+                env,
+                var_store,
+                scope,
+                output,
+                loc_start,
+                *end_kind,
+                loc_end,
+                region,
+                permit_shadows,
+            ),
+            ptype => unsupported_pattern(env, ptype, region),
+        },
+
         SpaceBefore(sub_pattern, _) | SpaceAfter(sub_pattern, _) => {
             return canonicalize_pattern(
                 env,
@@ -637,6 +668,99 @@ pub fn canonicalize_pattern<'a>(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+fn canonicalize_range<'a>(
+    env: &mut Env<'a>,
+    var_store: &mut VarStore,
+    scope: &mut Scope,
+    output: &mut Output,
+    loc_start: &Loc<ast::Pattern<'a>>,
+    end_kind: RangeEnd,
+    loc_end: &Loc<ast::Pattern<'a>>,
+    region: Region,
+    permit_shadows: PermitShadows,
+) -> Pattern {
+    let mut canonicalize_end = |loc_pattern: &Loc<ast::Pattern<'a>>| {
+        canonicalize_pattern(
+            env,
+            var_store,
+            scope,
+            output,
+            PatternType::WhenBranch,
+            &loc_pattern.value,
+            loc_pattern.region,
+            permit_shadows,
+        )
+    };
+
+    let start = canonicalize_end(loc_start);
+    let end = canonicalize_end(loc_end);
+
+    let (first, end_value) = match (range_end_value(&start.value), range_end_value(&end.value)) {
+        (Some(first), Some(end_value)) => (first, end_value),
+        _ => {
+            return match (&start.value, &end.value) {
+                // the problem with this end was already reported
+                (malformed @ Pattern::MalformedPattern(..), _)
+                | (_, malformed @ Pattern::MalformedPattern(..)) => malformed.clone(),
+                _ => malformed_pattern(env, MalformedPatternProblem::FloatRangeEnd, region),
+            };
+        }
+    };
+
+    let last = match (end_kind, cmp_int_values(first, end_value)) {
+        (RangeEnd::Inclusive, Ordering::Less | Ordering::Equal) => end_value,
+        (RangeEnd::Exclusive, Ordering::Less) => int_value_predecessor(end_value),
+        _ => return malformed_pattern(env, MalformedPatternProblem::EmptyRange, region),
+    };
+
+    Pattern::NumRange {
+        num_var: var_store.fresh(),
+        precision_var: var_store.fresh(),
+        start: Box::new(start),
+        end: Box::new(end),
+        end_kind,
+        first,
+        last,
+    }
+}
+
+/// The value of an integer or character literal at the end of a range.
+fn range_end_value(pattern: &Pattern) -> Option<IntValue> {
+    match pattern {
+        Pattern::NumLiteral(_, _, value, _) | Pattern::IntLiteral(_, _, _, value, _) => {
+            Some(*value)
+        }
+        Pattern::SingleQuote(c) => Some(IntValue::I128((*c as i128).to_ne_bytes())),
+        _ => None,
+    }
+}
+
+fn cmp_int_values(a: IntValue, b: IntValue) -> Ordering {
+    use IntValue::*;
+
+    match (a, b) {
+        (I128(a), I128(b)) => i128::from_ne_bytes(a).cmp(&i128::from_ne_bytes(b)),
+        (U128(a), U128(b)) => u128::from_ne_bytes(a).cmp(&u128::from_ne_bytes(b)),
+        (I128(a), U128(b)) => match i128::from_ne_bytes(a) {
+            a if a < 0 => Ordering::Less,
+            a => (a as u128).cmp(&u128::from_ne_bytes(b)),
+        },
+        (U128(_), I128(_)) => cmp_int_values(b, a).reverse(),
+    }
+}
+
+/// The value one less than `value`, which must not be the smallest `I128`.
+fn int_value_predecessor(value: IntValue) -> IntValue {
+    match value {
+        IntValue::I128(n) => IntValue::I128((i128::from_ne_bytes(n) - 1).to_ne_bytes()),
+        IntValue::U128(n) => match u128::from_ne_bytes(n) {
+            0 => IntValue::I128((-1i128).to_ne_bytes()),
+            n => IntValue::U128((n - 1).to_ne_bytes()),
+        },
+    }
+}
+
 /// When we detect an unsupported pattern type (e.g. 5 = 1 + 2 is unsupported because you can't
 /// assign to Int patterns), report it to Env and return an UnsupportedPattern runtime error pattern.
 fn unsupported_pattern(env: &mut Env, pattern_type: PatternType, region: Region) -> Pattern {
@@ -725,6 +849,7 @@ impl<'a> BindingsFromPattern<'a> {
                         | FloatLiteral(..)
                         | StrLiteral(_)
                         | SingleQuote(_)
+                        | NumRange { .. }
                         | Underscore
                         | Shadowed(_, _, _)
                         | MalformedPattern(_, _)
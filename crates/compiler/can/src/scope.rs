@@ -104,6 +104,29 @@ impl Scope {
         it2.chain(it1)
     }
 
+    /// The values (not types, abilities or tags) that are in scope, most recently introduced
+    /// first. Values imported into the module come last.
+    pub fn values_in_scope(&self) -> Vec<Symbol> {
+        let is_value = |ident: &str| ident.starts_with(|c: char| c.is_lowercase());
+
+        let mut values: Vec<Symbol> = self
+            .locals
+            .symbols_in_scope()
+            .filter(|(_, ident)| is_value(ident))
+            .map(|(symbol, _)| symbol)
+            .collect();
+        values.reverse();
+
+        let imported = self
+            .imports
+            .iter()
+            .filter(|(ident, _, _)| is_value(ident.as_str()))
+            .map(|(_, symbol, _)| *symbol);
+        values.extend(imported);
+
+        values
+    }
+
     /// Check if there is an opaque type alias referenced by `opaque_ref` referenced in the
     /// current scope. E.g. `@Age` must reference an opaque `Age` declared in this module, not any
     /// other!
@@ -549,6 +572,19 @@ impl ScopedIdentIds {
             })
     }
 
+    fn symbols_in_scope(&self) -> impl Iterator<Item = (Symbol, &str)> + '_ {
+        self.ident_ids
+            .ident_strs()
+            .zip(self.in_scope.iter())
+            .filter_map(|((ident_id, string), keep)| {
+                if *keep {
+                    Some((Symbol::new(self.home, ident_id), string))
+                } else {
+                    None
+                }
+            })
+    }
+
     fn introduce_into_scope(&mut self, ident_name: &str, region: Region) -> IdentId {
         let id = self.ident_ids.add_str(ident_name);
 
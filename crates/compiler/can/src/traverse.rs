@@ -289,7 +289,7 @@ pub fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Expr, var: Variable) {
                 Variable::NULL,
             );
         }
-        Expr::TypedHole(_) => { /* terminal */ }
+        Expr::TypedHole { .. } => { /* terminal */ }
         Expr::RuntimeError(..) => { /* terminal */ }
     }
 }
@@ -477,6 +477,10 @@ pub fn walk_pattern<V: Visitor>(visitor: &mut V, pattern: &Pattern) {
         FloatLiteral(..) => { /* terminal */ }
         StrLiteral(..) => { /* terminal */ }
         SingleQuote(..) => { /* terminal */ }
+        NumRange { start, end, .. } => {
+            visitor.visit_pattern(&start.value, start.region, None);
+            visitor.visit_pattern(&end.value, end.region, None);
+        }
         Underscore => { /* terminal */ }
         AbilityMemberSpecialization { .. } => { /* terminal */ }
         Shadowed(..) => { /* terminal */ }
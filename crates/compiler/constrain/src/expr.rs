@@ -1338,14 +1338,17 @@ pub fn constrain_expr(
             arg_cons.push(eq);
             constraints.exists_many(vars, arg_cons)
         }
-        TypedHole(var) => {
+        TypedHole { var, candidates } => {
             // store the expected type for this position
-            constraints.equal_types_var(
+            let store_expected = constraints.equal_types_var(
                 *var,
                 expected,
                 Category::Storage(std::file!(), std::line!()),
                 region,
-            )
+            );
+            let report_hole = constraints.typed_hole(*var, region, candidates.clone());
+
+            constraints.and_constraint([store_expected, report_hole])
         }
         RuntimeError(_) => {
             // Runtime Errors have no constraints because they're going to crash.
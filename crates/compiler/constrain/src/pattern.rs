@@ -72,6 +72,7 @@ fn headers_from_annotation_help(
         | IntLiteral(..)
         | FloatLiteral(..)
         | SingleQuote(_)
+        | NumRange { .. }
         | StrLiteral(_) => true,
 
         RecordDestructure { destructs, .. } => match annotation.value.shallow_dealias() {
@@ -329,6 +330,46 @@ pub fn constrain_pattern(
             ));
         }
 
+        NumRange {
+            num_var,
+            precision_var,
+            start,
+            end,
+            ..
+        } => {
+            state.vars.push(*num_var);
+            state.vars.push(*precision_var);
+
+            let num_type = Type::Variable(*num_var);
+            let int_type = builtins::num_int(Type::Variable(*precision_var));
+
+            state.constraints.push(constraints.equal_types(
+                num_type.clone(),
+                Expected::NoExpectation(int_type),
+                Category::Int,
+                region,
+            ));
+
+            // Both ends of the range have the type of the whole range.
+            for end in [start, end] {
+                constrain_pattern(
+                    constraints,
+                    env,
+                    &end.value,
+                    end.region,
+                    PExpected::NoExpectation(num_type.clone()),
+                    state,
+                );
+            }
+
+            state.constraints.push(constraints.equal_pattern_types(
+                num_type,
+                expected,
+                PatternCategory::Int,
+                region,
+            ));
+        }
+
         RecordDestructure {
             whole_var,
             ext_var,
@@ -69,6 +69,143 @@ pub enum Pattern {
     Anything,
     Literal(Literal),
     Ctor(Union, TagId, std::vec::Vec<Pattern>),
+    /// An integer pattern. Unlike literals, a column of these can be exhaustive without a
+    /// catch-all pattern, if their type has a known width.
+    IntRange(IntRange),
+}
+
+/// The values an integer type can take.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IntDomain {
+    pub min: i128,
+    pub max: u128,
+    pub render_as: IntRender,
+    /// Whether `min` and `max` are the bounds of the type. When the width of the type is not
+    /// known, the domain only orders the values, and no set of ranges covers all of them.
+    pub bounded: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntRender {
+    Int,
+    Char,
+}
+
+/// A value of an integer type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntBound {
+    Signed(i128),
+    Unsigned(u128),
+}
+
+impl IntDomain {
+    /// Integer values are stored as their offset from the domain's minimum, so that values of
+    /// all widths and signednesses order the same way.
+    fn key_of(&self, value: IntBound) -> Option<u128> {
+        let in_range = match value {
+            IntBound::Signed(n) => n >= self.min && (n < 0 || n as u128 <= self.max),
+            IntBound::Unsigned(n) => n <= self.max,
+        };
+
+        if !in_range {
+            return None;
+        }
+
+        let bits = match value {
+            IntBound::Signed(n) => n as u128,
+            IntBound::Unsigned(n) => n,
+        };
+
+        Some(bits.wrapping_sub(self.min as u128))
+    }
+
+    fn is_below(&self, value: IntBound) -> bool {
+        matches!(value, IntBound::Signed(n) if n < self.min)
+    }
+
+    fn value_of(&self, key: u128) -> IntBound {
+        let bits = key.wrapping_add(self.min as u128);
+
+        if self.min < 0 {
+            IntBound::Signed(bits as i128)
+        } else {
+            IntBound::Unsigned(bits)
+        }
+    }
+
+    fn max_key(&self) -> u128 {
+        self.max.wrapping_sub(self.min as u128)
+    }
+}
+
+/// An inclusive range of values of an integer type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntRange {
+    pub domain: IntDomain,
+    lo: u128,
+    hi: u128,
+}
+
+impl IntRange {
+    /// The range holding just `value`, or `None` if `value` does not fit the domain.
+    pub fn singleton(domain: IntDomain, value: IntBound) -> Option<Self> {
+        let key = domain.key_of(value)?;
+
+        Some(IntRange {
+            domain,
+            lo: key,
+            hi: key,
+        })
+    }
+
+    /// The values from `lo` to `hi`, clamped to the domain, or `None` if none of them fit it.
+    pub fn inclusive(domain: IntDomain, lo: IntBound, hi: IntBound) -> Option<Self> {
+        let lo = match domain.key_of(lo) {
+            Some(key) => key,
+            None if domain.is_below(lo) => 0,
+            None => return None,
+        };
+
+        let hi = match domain.key_of(hi) {
+            Some(key) => key,
+            None if domain.is_below(hi) => return None,
+            None => domain.max_key(),
+        };
+
+        (lo <= hi).then_some(IntRange { domain, lo, hi })
+    }
+
+    pub fn is_singleton(&self) -> bool {
+        self.lo == self.hi
+    }
+
+    pub fn lo(&self) -> IntBound {
+        self.domain.value_of(self.lo)
+    }
+
+    pub fn hi(&self) -> IntBound {
+        self.domain.value_of(self.hi)
+    }
+
+    fn whole(domain: IntDomain) -> Self {
+        IntRange {
+            domain,
+            lo: 0,
+            hi: domain.max_key(),
+        }
+    }
+
+    fn with_keys(&self, lo: u128, hi: u128) -> Self {
+        IntRange {
+            domain: self.domain,
+            lo,
+            hi,
+        }
+    }
+
+    fn contains(&self, lo: u128, hi: u128) -> bool {
+        self.lo <= lo && hi <= self.hi
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -77,6 +214,7 @@ pub enum Literal {
     U128([u8; 16]),
     Bit(bool),
     Byte(u8),
+    Char(char),
     /// Stores the float bits
     Float(u64),
     Decimal([u8; 16]),
@@ -145,6 +283,8 @@ fn is_exhaustive(matrix: &RefPatternMatrix, n: usize) -> PatternMatrix {
         vec![std::iter::repeat(Anything).take(n).collect()]
     } else if n == 0 {
         vec![]
+    } else if let Some(domain) = collect_int_domain(matrix) {
+        is_int_exhaustive(matrix, n, domain)
     } else {
         let ctors = collect_ctors(matrix);
         let num_seen = ctors.len();
@@ -215,6 +355,76 @@ fn is_exhaustive(matrix: &RefPatternMatrix, n: usize) -> PatternMatrix {
     }
 }
 
+/// Split the whole domain of an integer column into segments that every range in the column
+/// either covers completely or misses completely, and check each segment on its own. Segments
+/// that no range covers are the missing ranges.
+fn is_int_exhaustive(matrix: &RefPatternMatrix, n: usize, domain: IntDomain) -> PatternMatrix {
+    let segments: Vec<_> = split_int_range(IntRange::whole(domain), int_range_heads(matrix))
+        .into_iter()
+        .map(|segment| {
+            let is_covered =
+                int_range_heads(matrix).any(|range| range.contains(segment.lo, segment.hi));
+            (segment, is_covered)
+        })
+        .collect();
+
+    // When most of the domain is missing (e.g. a single literal of an I64), listing the missing
+    // ranges is just noise; report them as a single `_` instead.
+    let (covered, missing) = segments
+        .iter()
+        .fold((0u128, 0u128), |(c, m), (seg, covered)| {
+            let len = (seg.hi - seg.lo).saturating_add(1);
+            if *covered {
+                (c.saturating_add(len), m)
+            } else {
+                (c, m.saturating_add(len))
+            }
+        });
+    let missing_as_anything = missing > covered;
+
+    let mut uncovered_rest: Option<PatternMatrix> = None;
+    let mut result = Vec::new();
+
+    for (segment, is_covered) in segments {
+        let rest = if is_covered {
+            let new_matrix: Vec<_> = matrix
+                .iter()
+                .filter_map(|row| specialize_row_by_int_range(segment, row))
+                .collect();
+
+            is_exhaustive(&new_matrix, n - 1)
+        } else if missing_as_anything && uncovered_rest.is_some() {
+            // already reported as `_`
+            continue;
+        } else {
+            // only the rows that start with an Anything can match this segment
+            uncovered_rest
+                .get_or_insert_with(|| {
+                    let new_matrix: Vec<_> = matrix
+                        .iter()
+                        .filter_map(|row| specialize_row_by_anything(row))
+                        .collect();
+
+                    is_exhaustive(&new_matrix, n - 1)
+                })
+                .clone()
+        };
+
+        let head = if !is_covered && missing_as_anything {
+            Anything
+        } else {
+            IntRange(segment)
+        };
+
+        for mut row in rest {
+            row.push(head.clone());
+            result.push(row);
+        }
+    }
+
+    result
+}
+
 fn is_missing<T>(union: Union, ctors: &MutMap<TagId, T>, ctor: &Ctor) -> Option<Pattern> {
     let Ctor { arity, tag_id, .. } = ctor;
 
@@ -260,6 +470,11 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
                 // NOTE: if there are bugs in this code, look at the ordering of the row/matrix
 
                 match first_pattern {
+                    // keep checking rows that overlap with this range, or start with Anything
+                    IntRange(range) => {
+                        break is_useful_int_range(&old_matrix, range, &vector);
+                    }
+
                     // keep checking rows that start with this Ctor or Anything
                     Ctor(_, id, args) => {
                         specialize_row_by_ctor2(id, args.len(), &mut old_matrix, &mut matrix);
@@ -270,6 +485,14 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
                     }
 
                     Anything => {
+                        if let Some(domain) = collect_int_domain(&old_matrix) {
+                            // an integer column can be complete without an Anything, so check
+                            // this like a range spanning the whole domain
+                            let whole = IntRange::whole(domain);
+
+                            break is_useful_int_range(&old_matrix, whole, &vector);
+                        }
+
                         // check if all alternatives appear in matrix
                         match is_complete(&old_matrix) {
                             Complete::No => {
@@ -330,6 +553,9 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
                                 }
                                 Some(Anything) => matrix.push(patterns),
 
+                                // the literal does not fit the domain of the range
+                                Some(IntRange(_)) => {}
+
                                 Some(Ctor(_, _, _)) => panic!(
                                     r#"Compiler bug! After type checking, constructors and literals should never align in pattern match exhaustiveness checks."#
                                 ),
@@ -371,7 +597,7 @@ fn specialize_row_by_ctor2(
             patterns.extend(std::iter::repeat(Anything).take(arity));
             matrix.push(patterns);
             }
-        Some(Literal(_)) => panic!( "Compiler bug! After type checking, constructors and literal should never align in pattern match exhaustiveness checks."),
+        Some(Literal(_) | IntRange(_)) => panic!( "Compiler bug! After type checking, constructors and literal should never align in pattern match exhaustiveness checks."),
         None => panic!("Compiler error! Empty matrices should not get specialized."),
     }
     }
@@ -404,13 +630,51 @@ fn specialize_row_by_ctor(tag_id: TagId, arity: usize, row: &RefRow) -> Option<R
                 .collect();
             Some(new_patterns)
         }
-        Some(Literal(_)) => unreachable!(
+        Some(Literal(_) | IntRange(_)) => unreachable!(
             r#"Compiler bug! After type checking, a constructor can never align with a literal: that should be a type error!"#
         ),
         None => panic!("Compiler error! Empty matrices should not get specialized."),
     }
 }
 
+/// Is some value of `range` useful, given the previous rows "matrix"?
+fn is_useful_int_range(matrix: &RefPatternMatrix, range: IntRange, vector: &RefRow) -> bool {
+    split_int_range(range, int_range_heads(matrix))
+        .into_iter()
+        .any(|segment| {
+            let new_matrix = matrix
+                .iter()
+                .filter_map(|row| specialize_row_by_int_range(segment, row))
+                .collect();
+
+            is_useful(new_matrix, vector.to_vec())
+        })
+}
+
+/// INVARIANT: (length row == N) ==> (length result == N-1)
+///
+/// The segment must be wholly covered or wholly missed by every range in the column.
+fn specialize_row_by_int_range(segment: IntRange, row: &RefRow) -> Option<Row> {
+    let mut row = row.to_vec();
+
+    match row.pop() {
+        Some(Anything) => Some(row),
+        Some(IntRange(range)) => {
+            if range.contains(segment.lo, segment.hi) {
+                Some(row)
+            } else {
+                None
+            }
+        }
+        // An integer literal that does not fit the domain matches none of its values
+        Some(Literal(_)) => None,
+        Some(Ctor(_, _, _)) => unreachable!(
+            r#"Compiler bug! After type checking, an integer range can only align with integers!"#
+        ),
+        None => panic!("Compiler error! Empty matrices should not get specialized."),
+    }
+}
+
 /// INVARIANT: (length row == N) ==> (length result == N-1)
 fn specialize_row_by_anything(row: &RefRow) -> Option<Row> {
     let mut row = row.to_vec();
@@ -452,6 +716,56 @@ type PatternMatrix = Vec<Vec<Pattern>>;
 type RefRow = [Pattern];
 type Row = Vec<Pattern>;
 
+/// The domain of an integer column that can be exhaustive without an Anything.
+fn collect_int_domain(matrix: &RefPatternMatrix) -> Option<IntDomain> {
+    int_range_heads(matrix)
+        .next()
+        .map(|range| range.domain)
+        .filter(|domain| domain.bounded)
+}
+
+fn int_range_heads(matrix: &RefPatternMatrix) -> impl Iterator<Item = &IntRange> {
+    matrix.iter().filter_map(|row| match row.last() {
+        Some(IntRange(range)) => Some(range),
+        _ => None,
+    })
+}
+
+/// Split `within` into consecutive segments, such that each of the given ranges either contains
+/// a segment or is disjoint from it.
+fn split_int_range<'a>(
+    within: IntRange,
+    ranges: impl Iterator<Item = &'a IntRange>,
+) -> Vec<IntRange> {
+    let mut starts = vec![within.lo];
+
+    for range in ranges {
+        if within.lo < range.lo && range.lo <= within.hi {
+            starts.push(range.lo);
+        }
+
+        if within.lo <= range.hi && range.hi < within.hi {
+            starts.push(range.hi + 1);
+        }
+    }
+
+    starts.sort_unstable();
+    starts.dedup();
+
+    let mut segments = Vec::with_capacity(starts.len());
+
+    for (i, lo) in starts.iter().enumerate() {
+        let hi = match starts.get(i + 1) {
+            Some(next) => next - 1,
+            None => within.hi,
+        };
+
+        segments.push(within.with_keys(*lo, hi));
+    }
+
+    segments
+}
+
 fn collect_ctors(matrix: &RefPatternMatrix) -> MutMap<TagId, Union> {
     let mut ctors = MutMap::default();
 
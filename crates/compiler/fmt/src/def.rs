@@ -45,7 +45,11 @@ impl<'a> Formattable for TypeDef<'a> {
         match self {
             Alias { ann, .. } => ann.is_multiline(),
             Opaque { typ, .. } => typ.is_multiline(),
-            Ability { members, .. } => members.iter().any(|d| d.is_multiline()),
+            Ability {
+                superabilities,
+                members,
+                ..
+            } => !superabilities.is_empty() || members.iter().any(|d| d.is_multiline()),
         }
     }
 
@@ -121,6 +125,7 @@ impl<'a> Formattable for TypeDef<'a> {
             Ability {
                 header: TypeHeader { name, vars },
                 loc_has: _,
+                superabilities,
                 members,
             } => {
                 buf.indent(indent);
@@ -132,6 +137,15 @@ impl<'a> Formattable for TypeDef<'a> {
 
                 buf.push_str(" has");
 
+                for (index, superability) in superabilities.iter().enumerate() {
+                    if index > 0 {
+                        buf.push(',');
+                    }
+
+                    buf.spaces(1);
+                    superability.value.format(buf, indent + INDENT);
+                }
+
                 if !self.is_multiline() {
                     debug_assert_eq!(members.len(), 1);
                     buf.push_str(" ");
@@ -160,6 +174,7 @@ impl<'a> Formattable for ValueDef<'a> {
             AnnotatedBody { .. } => true,
             Expect { condition, .. } => condition.is_multiline(),
             ExpectFx { condition, .. } => condition.is_multiline(),
+            Malformed(text) => text.contains('\n'),
         }
     }
 
@@ -237,6 +252,16 @@ impl<'a> Formattable for ValueDef<'a> {
             ExpectFx { condition, .. } => {
                 fmt_expect_fx(buf, condition, self.is_multiline(), indent)
             }
+            Malformed(text) => {
+                // We don't know the structure of a def that failed to parse, so leave it as written.
+                for (index, line) in text.lines().enumerate() {
+                    if index > 0 {
+                        buf.newline();
+                    }
+                    buf.indent(indent);
+                    buf.push_str_allow_spaces(line);
+                }
+            }
             AnnotatedBody {
                 ann_pattern,
                 ann_type,
@@ -405,14 +430,23 @@ pub fn fmt_body<'a, 'buf>(
 
 impl<'a> Formattable for AbilityMember<'a> {
     fn is_multiline(&self) -> bool {
-        self.name.value.is_multiline() || self.typ.is_multiline()
+        // A default is always on the line after the demand
+        self.name.value.is_multiline() || self.typ.is_multiline() || self.default.is_some()
     }
 
     fn format<'buf>(&self, buf: &mut Buf<'buf>, indent: u16) {
-        buf.push_str(self.name.value.extract_spaces().item);
+        let name = self.name.value.extract_spaces().item;
+
+        buf.push_str(name);
         buf.spaces(1);
         buf.push(':');
         buf.spaces(1);
         self.typ.value.format(buf, indent + INDENT);
+
+        if let Some(default) = self.default {
+            buf.newline();
+            buf.indent(indent);
+            fmt_body(buf, &Pattern::Identifier(name), &default.value, indent);
+        }
     }
 }
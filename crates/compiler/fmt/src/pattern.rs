@@ -2,7 +2,7 @@ use crate::annotation::{Formattable, Newlines, Parens};
 use crate::expr::fmt_str_literal;
 use crate::spaces::{fmt_comments_only, fmt_spaces, NewlineAt};
 use crate::Buf;
-use roc_parse::ast::{Base, CommentOrNewline, Pattern};
+use roc_parse::ast::{Base, CommentOrNewline, Pattern, RangeEnd};
 
 pub fn fmt_pattern<'a, 'buf>(
     buf: &mut Buf<'buf>,
@@ -37,6 +37,7 @@ impl<'a> Formattable for Pattern<'a> {
             | Pattern::FloatLiteral(..)
             | Pattern::StrLiteral(_)
             | Pattern::SingleQuote(_)
+            | Pattern::Range(..)
             | Pattern::Underscore(_)
             | Pattern::Malformed(_)
             | Pattern::MalformedIdent(_, _)
@@ -158,6 +159,17 @@ impl<'a> Formattable for Pattern<'a> {
                 buf.push('_');
                 buf.push_str(name);
             }
+            Range(start, end_kind, end) => {
+                buf.indent(indent);
+                start.format(buf, indent);
+
+                match end_kind {
+                    RangeEnd::Exclusive => buf.push_str(".."),
+                    RangeEnd::Inclusive => buf.push_str("..="),
+                }
+
+                end.format(buf, indent);
+            }
 
             // Space
             SpaceBefore(sub_pattern, spaces) => {
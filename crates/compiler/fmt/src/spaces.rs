@@ -504,6 +504,7 @@ impl<'a> RemoveSpaces<'a> for TypeDef<'a> {
             Ability {
                 header: TypeHeader { name, vars },
                 loc_has,
+                superabilities,
                 members,
             } => Ability {
                 header: TypeHeader {
@@ -511,6 +512,7 @@ impl<'a> RemoveSpaces<'a> for TypeDef<'a> {
                     vars: vars.remove_spaces(arena),
                 },
                 loc_has: loc_has.remove_spaces(arena),
+                superabilities: superabilities.remove_spaces(arena),
                 members: members.remove_spaces(arena),
             },
         }
@@ -554,6 +556,7 @@ impl<'a> RemoveSpaces<'a> for ValueDef<'a> {
                 condition: arena.alloc(condition.remove_spaces(arena)),
                 preceding_comment: Region::zero(),
             },
+            Malformed(text) => Malformed(text),
         }
     }
 }
@@ -569,6 +572,7 @@ impl<'a> RemoveSpaces<'a> for AbilityMember<'a> {
         AbilityMember {
             name: self.name.remove_spaces(arena),
             typ: self.typ.remove_spaces(arena),
+            default: self.default.remove_spaces(arena),
         }
     }
 }
@@ -749,6 +753,11 @@ impl<'a> RemoveSpaces<'a> for Pattern<'a> {
             Pattern::SpaceBefore(a, _) => a.remove_spaces(arena),
             Pattern::SpaceAfter(a, _) => a.remove_spaces(arena),
             Pattern::SingleQuote(a) => Pattern::NumLiteral(a),
+            Pattern::Range(a, b, c) => Pattern::Range(
+                arena.alloc(a.remove_spaces(arena)),
+                b,
+                arena.alloc(c.remove_spaces(arena)),
+            ),
         }
     }
 }
@@ -3479,6 +3479,19 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn when_with_ranges() {
+        expr_formats_same(indoc!(
+            r#"
+                when 0 is
+                    0..10 -> "a"
+                    10..=19 | 100 -> "b"
+                    'a'..='z' -> "c"
+                    _ -> "d"
+            "#
+        ));
+    }
+
     #[test]
     fn nested_when() {
         expr_formats_same(indoc!(
@@ -5315,6 +5328,71 @@ mod test_fmt {
         );
     }
 
+    #[test]
+    fn ability_superabilities() {
+        expr_formats_same(indoc!(
+            r#"
+            Ord has Eq, Hash
+                compare : a, a -> [LT, EQ, GT] | a has Ord
+
+            0
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                Ord has Eq ,Hash
+                  compare : a, a -> [LT, EQ, GT] | a has Ord
+
+                0
+                "#
+            ),
+            indoc!(
+                r#"
+                Ord has Eq, Hash
+                    compare : a, a -> [LT, EQ, GT] | a has Ord
+
+                0
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn ability_member_default() {
+        expr_formats_same(indoc!(
+            r#"
+            Eq has
+                isEq : a, a -> Bool | a has Eq
+                isNotEq : a, a -> Bool | a has Eq
+                isNotEq = \x, y -> !(isEq x y)
+
+            0
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                Eq has isNotEq : a, a -> Bool | a has Eq
+                       isNotEq = \x, y ->   !(isEq x y)
+
+                0
+                "#
+            ),
+            indoc!(
+                r#"
+                Eq has
+                    isNotEq : a, a -> Bool | a has Eq
+                    isNotEq = \x, y -> !(isEq x y)
+
+                0
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn opaque_has_with_impls() {
         expr_formats_same(indoc!(
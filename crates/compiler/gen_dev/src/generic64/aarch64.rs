@@ -1,10 +1,17 @@
 use crate::generic64::{storage::StorageManager, Assembler, CallConv, RegTrait};
-use crate::Relocation;
+use crate::{
+    single_register_floats, single_register_int_builtins, single_register_integers,
+    single_register_layouts, Relocation,
+};
 use bumpalo::collections::Vec;
 use packed_struct::prelude::*;
+use roc_builtins::bitcode::{FloatWidth, IntWidth};
 use roc_error_macros::internal_error;
 use roc_module::symbol::Symbol;
-use roc_mono::layout::Layout;
+use roc_mono::layout::{Builtin, Layout, STLayoutInterner};
+use roc_target::TargetInfo;
+
+const TARGET_INFO: TargetInfo = TargetInfo::default_aarch64();
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
 #[allow(dead_code)]
@@ -101,7 +108,40 @@ impl AArch64GeneralReg {
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
 #[allow(dead_code)]
-pub enum AArch64FloatReg {}
+pub enum AArch64FloatReg {
+    V0 = 0,
+    V1 = 1,
+    V2 = 2,
+    V3 = 3,
+    V4 = 4,
+    V5 = 5,
+    V6 = 6,
+    V7 = 7,
+    V8 = 8,
+    V9 = 9,
+    V10 = 10,
+    V11 = 11,
+    V12 = 12,
+    V13 = 13,
+    V14 = 14,
+    V15 = 15,
+    V16 = 16,
+    V17 = 17,
+    V18 = 18,
+    V19 = 19,
+    V20 = 20,
+    V21 = 21,
+    V22 = 22,
+    V23 = 23,
+    V24 = 24,
+    V25 = 25,
+    V26 = 26,
+    V27 = 27,
+    V28 = 28,
+    V29 = 29,
+    V30 = 30,
+    V31 = 31,
+}
 impl RegTrait for AArch64FloatReg {
     fn value(&self) -> u8 {
         *self as u8
@@ -109,7 +149,14 @@ impl RegTrait for AArch64FloatReg {
 }
 impl std::fmt::Display for AArch64FloatReg {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "TODO",)
+        write!(f, "v{}", self.id())
+    }
+}
+
+impl AArch64FloatReg {
+    #[inline(always)]
+    fn id(&self) -> u8 {
+        *self as u8
     }
 }
 
@@ -147,6 +194,8 @@ impl CallConv<AArch64GeneralReg, AArch64FloatReg, AArch64Assembler> for AArch64C
         // Don't use platform register: AArch64GeneralReg::PR,
         // Don't use link register: AArch64GeneralReg::LR,
         // Don't use zero register/stack pointer: AArch64GeneralReg::ZRSP,
+        // Don't use intra-procedure-call scratch registers, the assembler uses them as temporaries:
+        //     AArch64GeneralReg::IP0, AArch64GeneralReg::IP1,
 
         // Use callee saved regs last.
         AArch64GeneralReg::X19,
@@ -175,12 +224,57 @@ impl CallConv<AArch64GeneralReg, AArch64FloatReg, AArch64Assembler> for AArch64C
         AArch64GeneralReg::X13,
         AArch64GeneralReg::X14,
         AArch64GeneralReg::X15,
-        AArch64GeneralReg::IP0,
-        AArch64GeneralReg::IP1,
     ];
-    const FLOAT_PARAM_REGS: &'static [AArch64FloatReg] = &[];
+    const FLOAT_PARAM_REGS: &'static [AArch64FloatReg] = &[
+        AArch64FloatReg::V0,
+        AArch64FloatReg::V1,
+        AArch64FloatReg::V2,
+        AArch64FloatReg::V3,
+        AArch64FloatReg::V4,
+        AArch64FloatReg::V5,
+        AArch64FloatReg::V6,
+        AArch64FloatReg::V7,
+    ];
     const FLOAT_RETURN_REGS: &'static [AArch64FloatReg] = Self::FLOAT_PARAM_REGS;
-    const FLOAT_DEFAULT_FREE_REGS: &'static [AArch64FloatReg] = &[];
+    const FLOAT_DEFAULT_FREE_REGS: &'static [AArch64FloatReg] = &[
+        // The regs we want to use first should be at the end of this vec.
+        // We will use pop to get which reg to use next
+
+        // Use callee saved regs last.
+        AArch64FloatReg::V8,
+        AArch64FloatReg::V9,
+        AArch64FloatReg::V10,
+        AArch64FloatReg::V11,
+        AArch64FloatReg::V12,
+        AArch64FloatReg::V13,
+        AArch64FloatReg::V14,
+        AArch64FloatReg::V15,
+        // Use caller saved regs first.
+        AArch64FloatReg::V0,
+        AArch64FloatReg::V1,
+        AArch64FloatReg::V2,
+        AArch64FloatReg::V3,
+        AArch64FloatReg::V4,
+        AArch64FloatReg::V5,
+        AArch64FloatReg::V6,
+        AArch64FloatReg::V7,
+        AArch64FloatReg::V16,
+        AArch64FloatReg::V17,
+        AArch64FloatReg::V18,
+        AArch64FloatReg::V19,
+        AArch64FloatReg::V20,
+        AArch64FloatReg::V21,
+        AArch64FloatReg::V22,
+        AArch64FloatReg::V23,
+        AArch64FloatReg::V24,
+        AArch64FloatReg::V25,
+        AArch64FloatReg::V26,
+        AArch64FloatReg::V27,
+        AArch64FloatReg::V28,
+        AArch64FloatReg::V29,
+        AArch64FloatReg::V30,
+        AArch64FloatReg::V31,
+    ];
 
     const SHADOW_SPACE_SIZE: u8 = 0;
 
@@ -201,8 +295,19 @@ impl CallConv<AArch64GeneralReg, AArch64FloatReg, AArch64Assembler> for AArch64C
         )
     }
     #[inline(always)]
-    fn float_callee_saved(_reg: &AArch64FloatReg) -> bool {
-        todo!("AArch64 FloatRegs");
+    fn float_callee_saved(reg: &AArch64FloatReg) -> bool {
+        // Only the bottom 64 bits of these are callee saved, but that is all we use.
+        matches!(
+            reg,
+            AArch64FloatReg::V8
+                | AArch64FloatReg::V9
+                | AArch64FloatReg::V10
+                | AArch64FloatReg::V11
+                | AArch64FloatReg::V12
+                | AArch64FloatReg::V13
+                | AArch64FloatReg::V14
+                | AArch64FloatReg::V15
+        )
     }
 
     #[inline(always)]
@@ -213,9 +318,22 @@ impl CallConv<AArch64GeneralReg, AArch64FloatReg, AArch64Assembler> for AArch64C
         requested_stack_size: i32,
         fn_call_stack_size: i32,
     ) -> i32 {
+        // Save the frame pointer and link register, then point the frame pointer at them.
+        // This could be optimized by using `STP` with pre-indexing to store the pair.
+        AArch64Assembler::sub_reg64_reg64_imm32(
+            buf,
+            AArch64GeneralReg::ZRSP,
+            AArch64GeneralReg::ZRSP,
+            16,
+        );
+        AArch64Assembler::mov_stack32_reg64(buf, 0, AArch64GeneralReg::FP);
+        AArch64Assembler::mov_stack32_reg64(buf, 8, AArch64GeneralReg::LR);
+        // `MOV FP, SP` has to be an add, the register move treats ZRSP as the zero register.
+        add_reg64_reg64_imm12(buf, AArch64GeneralReg::FP, AArch64GeneralReg::ZRSP, 0);
+
         // Full size is upcast to i64 to make sure we don't overflow here.
         let full_stack_size = match requested_stack_size
-            .checked_add(8 * (saved_general_regs.len() + saved_float_regs.len()) as i32 + 8) // The extra 8 is space to store the frame pointer.
+            .checked_add(8 * (saved_general_regs.len() + saved_float_regs.len()) as i32)
             .and_then(|size| size.checked_add(fn_call_stack_size))
         {
             Some(size) => size,
@@ -233,11 +351,6 @@ impl CallConv<AArch64GeneralReg, AArch64FloatReg, AArch64Assembler> for AArch64C
         };
         if let Some(aligned_stack_size) = full_stack_size.checked_add(offset as i32) {
             if aligned_stack_size > 0 {
-                AArch64Assembler::mov_reg64_reg64(
-                    buf,
-                    AArch64GeneralReg::FP,
-                    AArch64GeneralReg::ZRSP,
-                );
                 AArch64Assembler::sub_reg64_reg64_imm32(
                     buf,
                     AArch64GeneralReg::ZRSP,
@@ -245,21 +358,16 @@ impl CallConv<AArch64GeneralReg, AArch64FloatReg, AArch64Assembler> for AArch64C
                     aligned_stack_size,
                 );
 
+                // Put values at the top of the stack to avoid conflicts with previously saved variables.
                 // All the following stores could be optimized by using `STP` to store pairs.
-                let mut offset = aligned_stack_size;
-                offset -= 8;
-                AArch64Assembler::mov_stack32_reg64(buf, offset, AArch64GeneralReg::LR);
-                offset -= 8;
-                AArch64Assembler::mov_stack32_reg64(buf, offset, AArch64GeneralReg::FP);
-
-                offset = aligned_stack_size - fn_call_stack_size;
+                let mut offset = aligned_stack_size - fn_call_stack_size;
                 for reg in saved_general_regs {
+                    AArch64Assembler::mov_base32_reg64(buf, -offset, *reg);
                     offset -= 8;
-                    AArch64Assembler::mov_base32_reg64(buf, offset, *reg);
                 }
                 for reg in saved_float_regs {
+                    AArch64Assembler::mov_base32_freg64(buf, -offset, *reg);
                     offset -= 8;
-                    AArch64Assembler::mov_base32_freg64(buf, offset, *reg);
                 }
                 aligned_stack_size
             } else {
@@ -279,21 +387,15 @@ impl CallConv<AArch64GeneralReg, AArch64FloatReg, AArch64Assembler> for AArch64C
         fn_call_stack_size: i32,
     ) {
         if aligned_stack_size > 0 {
-            // All the following stores could be optimized by using `STP` to store pairs.
-            let mut offset = aligned_stack_size;
-            offset -= 8;
-            AArch64Assembler::mov_reg64_stack32(buf, AArch64GeneralReg::LR, offset);
-            offset -= 8;
-            AArch64Assembler::mov_reg64_stack32(buf, AArch64GeneralReg::FP, offset);
-
-            offset = aligned_stack_size - fn_call_stack_size;
+            // All the following loads could be optimized by using `LDP` to load pairs.
+            let mut offset = aligned_stack_size - fn_call_stack_size;
             for reg in saved_general_regs {
+                AArch64Assembler::mov_reg64_base32(buf, *reg, -offset);
                 offset -= 8;
-                AArch64Assembler::mov_reg64_base32(buf, *reg, offset);
             }
             for reg in saved_float_regs {
+                AArch64Assembler::mov_freg64_base32(buf, *reg, -offset);
                 offset -= 8;
-                AArch64Assembler::mov_freg64_base32(buf, *reg, offset);
             }
             AArch64Assembler::add_reg64_reg64_imm32(
                 buf,
@@ -302,87 +404,420 @@ impl CallConv<AArch64GeneralReg, AArch64FloatReg, AArch64Assembler> for AArch64C
                 aligned_stack_size,
             );
         }
+        AArch64Assembler::mov_reg64_stack32(buf, AArch64GeneralReg::FP, 0);
+        AArch64Assembler::mov_reg64_stack32(buf, AArch64GeneralReg::LR, 8);
+        AArch64Assembler::add_reg64_reg64_imm32(
+            buf,
+            AArch64GeneralReg::ZRSP,
+            AArch64GeneralReg::ZRSP,
+            16,
+        );
     }
 
     #[inline(always)]
     fn load_args<'a>(
-        _buf: &mut Vec<'a, u8>,
-        _storage_manager: &mut StorageManager<
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<
             'a,
             AArch64GeneralReg,
             AArch64FloatReg,
             AArch64Assembler,
             AArch64Call,
         >,
-        _args: &'a [(Layout<'a>, Symbol)],
-        _ret_layout: &Layout<'a>,
+        args: &'a [(Layout<'a>, Symbol)],
+        ret_layout: &Layout<'a>,
     ) {
-        todo!("Loading args for AArch64");
+        let mut arg_offset = Self::SHADOW_SPACE_SIZE as i32 + 16; // 16 is the size of the saved frame pointer and link register.
+        let mut general_i = 0;
+        let mut float_i = 0;
+        if Self::returns_via_arg_pointer(storage_manager.env.layout_interner, ret_layout) {
+            storage_manager.ret_pointer_arg(AArch64GeneralReg::XR);
+        }
+        for (layout, sym) in args.iter() {
+            let stack_size = layout.stack_size(storage_manager.env.layout_interner, TARGET_INFO);
+            match layout {
+                single_register_integers!() => {
+                    if general_i < Self::GENERAL_PARAM_REGS.len() {
+                        storage_manager.general_reg_arg(sym, Self::GENERAL_PARAM_REGS[general_i]);
+                        general_i += 1;
+                    } else {
+                        storage_manager.primitive_stack_arg(sym, arg_offset);
+                        arg_offset += 8;
+                    }
+                }
+                single_register_floats!() => {
+                    if float_i < Self::FLOAT_PARAM_REGS.len() {
+                        storage_manager.float_reg_arg(sym, Self::FLOAT_PARAM_REGS[float_i]);
+                        float_i += 1;
+                    } else {
+                        storage_manager.primitive_stack_arg(sym, arg_offset);
+                        arg_offset += 8;
+                    }
+                }
+                _ if stack_size == 0 => {
+                    storage_manager.no_data_arg(sym);
+                }
+                _ if stack_size > 16 => {
+                    // Large values are passed as a pointer to a copy made by the caller.
+                    // Copy the data into this stack frame, so it can be used like any other stack value.
+                    // This runs before anything else in the function, so the caller saved regs that are not args are free.
+                    let ptr_reg = if general_i < Self::GENERAL_PARAM_REGS.len() {
+                        general_i += 1;
+                        Self::GENERAL_PARAM_REGS[general_i - 1]
+                    } else {
+                        AArch64Assembler::mov_reg64_base32(buf, AArch64GeneralReg::X9, arg_offset);
+                        arg_offset += 8;
+                        AArch64GeneralReg::X9
+                    };
+                    let base_offset = storage_manager.claim_stack_area(sym, stack_size);
+                    for i in (0..stack_size as i32).step_by(8) {
+                        AArch64Assembler::mov_reg64_mem64_offset32(
+                            buf,
+                            AArch64GeneralReg::IP0,
+                            ptr_reg,
+                            i,
+                        );
+                        AArch64Assembler::mov_base32_reg64(
+                            buf,
+                            base_offset + i,
+                            AArch64GeneralReg::IP0,
+                        );
+                    }
+                }
+                _ => {
+                    // Values up to 16 bytes are passed in one or two general registers.
+                    // TODO: Homogeneous float aggregates should be passed in float registers.
+                    let reg_count = (stack_size as usize + 7) / 8;
+                    let aligned_to_16 = layout
+                        .alignment_bytes(storage_manager.env.layout_interner, TARGET_INFO)
+                        == 16;
+                    if aligned_to_16 {
+                        // 16 byte aligned values start at an even register.
+                        general_i += general_i % 2;
+                    }
+                    if general_i + reg_count <= Self::GENERAL_PARAM_REGS.len() {
+                        let base_offset = storage_manager.claim_stack_area(sym, stack_size);
+                        for i in 0..reg_count {
+                            AArch64Assembler::mov_base32_reg64(
+                                buf,
+                                base_offset + 8 * i as i32,
+                                Self::GENERAL_PARAM_REGS[general_i],
+                            );
+                            general_i += 1;
+                        }
+                    } else {
+                        // Once a value does not fit in the remaining registers, the rest go on the stack.
+                        general_i = Self::GENERAL_PARAM_REGS.len();
+                        if aligned_to_16 && arg_offset % 16 != 0 {
+                            arg_offset += 8;
+                        }
+                        storage_manager.complex_stack_arg(sym, arg_offset, stack_size);
+                        arg_offset += 8 * reg_count as i32;
+                    }
+                }
+            }
+        }
     }
 
     #[inline(always)]
     fn store_args<'a>(
-        _buf: &mut Vec<'a, u8>,
-        _storage_manager: &mut StorageManager<
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<
             'a,
             AArch64GeneralReg,
             AArch64FloatReg,
             AArch64Assembler,
             AArch64Call,
         >,
-        _dst: &Symbol,
-        _args: &[Symbol],
-        _arg_layouts: &[Layout<'a>],
-        _ret_layout: &Layout<'a>,
+        dst: &Symbol,
+        args: &[Symbol],
+        arg_layouts: &[Layout<'a>],
+        ret_layout: &Layout<'a>,
     ) {
-        todo!("Storing args for AArch64");
+        let mut tmp_stack_offset = Self::SHADOW_SPACE_SIZE as i32;
+        let mut general_i = 0;
+        let mut float_i = 0;
+        if Self::returns_via_arg_pointer(storage_manager.env.layout_interner, ret_layout) {
+            // Save space on the stack for the result we will be return.
+            let base_offset = storage_manager.claim_stack_area(
+                dst,
+                ret_layout.stack_size(storage_manager.env.layout_interner, TARGET_INFO),
+            );
+            // Set the indirect result register to the address base + offset.
+            AArch64Assembler::add_reg64_reg64_imm32(
+                buf,
+                AArch64GeneralReg::XR,
+                AArch64GeneralReg::FP,
+                base_offset,
+            );
+        }
+        // Large values are passed as a pointer to a copy placed after the args on the stack.
+        // Where that copy goes is only known once all args are placed, so the pointers are filled in last.
+        // Each entry is the symbol and either the register or the stack offset that gets the pointer.
+        let mut large_args = bumpalo::vec![in storage_manager.env.arena];
+        for (sym, layout) in args.iter().zip(arg_layouts.iter()) {
+            match layout {
+                single_register_integers!() => {
+                    if general_i < Self::GENERAL_PARAM_REGS.len() {
+                        storage_manager.load_to_specified_general_reg(
+                            buf,
+                            sym,
+                            Self::GENERAL_PARAM_REGS[general_i],
+                        );
+                        general_i += 1;
+                    } else {
+                        // Copy to stack using IP0 as buffer.
+                        storage_manager.load_to_specified_general_reg(
+                            buf,
+                            sym,
+                            AArch64GeneralReg::IP0,
+                        );
+                        AArch64Assembler::mov_stack32_reg64(
+                            buf,
+                            tmp_stack_offset,
+                            AArch64GeneralReg::IP0,
+                        );
+                        tmp_stack_offset += 8;
+                    }
+                }
+                single_register_floats!() => {
+                    if float_i < Self::FLOAT_PARAM_REGS.len() {
+                        storage_manager.load_to_specified_float_reg(
+                            buf,
+                            sym,
+                            Self::FLOAT_PARAM_REGS[float_i],
+                        );
+                        float_i += 1;
+                    } else {
+                        // Copy to stack using V16 as buffer.
+                        // It is caller saved and not used for args, so it is free during a call.
+                        storage_manager.load_to_specified_float_reg(buf, sym, AArch64FloatReg::V16);
+                        AArch64Assembler::mov_stack32_freg64(
+                            buf,
+                            tmp_stack_offset,
+                            AArch64FloatReg::V16,
+                        );
+                        tmp_stack_offset += 8;
+                    }
+                }
+                x if x.stack_size(storage_manager.env.layout_interner, TARGET_INFO) == 0 => {}
+                x if x.stack_size(storage_manager.env.layout_interner, TARGET_INFO) > 16 => {
+                    if general_i < Self::GENERAL_PARAM_REGS.len() {
+                        large_args.push((*sym, Some(Self::GENERAL_PARAM_REGS[general_i]), 0));
+                        general_i += 1;
+                    } else {
+                        large_args.push((*sym, None, tmp_stack_offset));
+                        tmp_stack_offset += 8;
+                    }
+                }
+                x => {
+                    // Values up to 16 bytes are passed in one or two general registers.
+                    let (base_offset, size) = storage_manager.stack_offset_and_size(sym);
+                    debug_assert_eq!(base_offset % 8, 0);
+                    let reg_count = (size as usize + 7) / 8;
+                    let aligned_to_16 =
+                        x.alignment_bytes(storage_manager.env.layout_interner, TARGET_INFO) == 16;
+                    if aligned_to_16 {
+                        // 16 byte aligned values start at an even register.
+                        general_i += general_i % 2;
+                    }
+                    if general_i + reg_count <= Self::GENERAL_PARAM_REGS.len() {
+                        for i in 0..reg_count {
+                            AArch64Assembler::mov_reg64_base32(
+                                buf,
+                                Self::GENERAL_PARAM_REGS[general_i],
+                                base_offset + 8 * i as i32,
+                            );
+                            general_i += 1;
+                        }
+                    } else {
+                        // Once a value does not fit in the remaining registers, the rest go on the stack.
+                        general_i = Self::GENERAL_PARAM_REGS.len();
+                        if aligned_to_16 && tmp_stack_offset % 16 != 0 {
+                            tmp_stack_offset += 8;
+                        }
+                        for i in (0..size as i32).step_by(8) {
+                            AArch64Assembler::mov_reg64_base32(
+                                buf,
+                                AArch64GeneralReg::IP0,
+                                base_offset + i,
+                            );
+                            AArch64Assembler::mov_stack32_reg64(
+                                buf,
+                                tmp_stack_offset + i,
+                                AArch64GeneralReg::IP0,
+                            );
+                        }
+                        tmp_stack_offset += 8 * reg_count as i32;
+                    }
+                }
+            }
+        }
+        for (sym, ptr_reg, ptr_stack_offset) in large_args.into_iter() {
+            // Copy the value onto the stack and pass a pointer to the copy.
+            // Use IP0 as buffer because it will be empty right now.
+            let (base_offset, size) = storage_manager.stack_offset_and_size(&sym);
+            debug_assert_eq!(base_offset % 8, 0);
+            for i in (0..size as i32).step_by(8) {
+                AArch64Assembler::mov_reg64_base32(buf, AArch64GeneralReg::IP0, base_offset + i);
+                AArch64Assembler::mov_stack32_reg64(
+                    buf,
+                    tmp_stack_offset + i,
+                    AArch64GeneralReg::IP0,
+                );
+            }
+            match ptr_reg {
+                Some(reg) => {
+                    AArch64Assembler::add_reg64_reg64_imm32(
+                        buf,
+                        reg,
+                        AArch64GeneralReg::ZRSP,
+                        tmp_stack_offset,
+                    );
+                }
+                None => {
+                    AArch64Assembler::add_reg64_reg64_imm32(
+                        buf,
+                        AArch64GeneralReg::IP0,
+                        AArch64GeneralReg::ZRSP,
+                        tmp_stack_offset,
+                    );
+                    AArch64Assembler::mov_stack32_reg64(
+                        buf,
+                        ptr_stack_offset,
+                        AArch64GeneralReg::IP0,
+                    );
+                }
+            }
+            tmp_stack_offset += (size as i32 + 7) / 8 * 8;
+        }
+        storage_manager.update_fn_call_stack_size(tmp_stack_offset as u32);
     }
 
     fn return_complex_symbol<'a>(
-        _buf: &mut Vec<'a, u8>,
-        _storage_manager: &mut StorageManager<
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<
             'a,
             AArch64GeneralReg,
             AArch64FloatReg,
             AArch64Assembler,
             AArch64Call,
         >,
-        _sym: &Symbol,
-        _layout: &Layout<'a>,
+        sym: &Symbol,
+        layout: &Layout<'a>,
     ) {
-        todo!("Returning complex symbols for AArch64");
+        match layout {
+            single_register_layouts!() => {
+                internal_error!("single register layouts are not complex symbols");
+            }
+            x if x.stack_size(storage_manager.env.layout_interner, TARGET_INFO) == 0 => {}
+            x if !Self::returns_via_arg_pointer(storage_manager.env.layout_interner, x) => {
+                let (base_offset, size) = storage_manager.stack_offset_and_size(sym);
+                debug_assert_eq!(base_offset % 8, 0);
+                if size <= 8 {
+                    AArch64Assembler::mov_reg64_base32(
+                        buf,
+                        Self::GENERAL_RETURN_REGS[0],
+                        base_offset,
+                    );
+                } else if size <= 16 {
+                    AArch64Assembler::mov_reg64_base32(
+                        buf,
+                        Self::GENERAL_RETURN_REGS[0],
+                        base_offset,
+                    );
+                    AArch64Assembler::mov_reg64_base32(
+                        buf,
+                        Self::GENERAL_RETURN_REGS[1],
+                        base_offset + 8,
+                    );
+                } else {
+                    internal_error!(
+                        "types that don't return via arg pointer must be less than 16 bytes"
+                    );
+                }
+            }
+            _ => {
+                // This is a large type returned via the arg pointer.
+                // Unlike x86, the callee does not need to return the pointer.
+                storage_manager.copy_symbol_to_arg_pointer(buf, sym, layout);
+            }
+        }
     }
 
     fn load_returned_complex_symbol<'a>(
-        _buf: &mut Vec<'a, u8>,
-        _storage_manager: &mut StorageManager<
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<
             'a,
             AArch64GeneralReg,
             AArch64FloatReg,
             AArch64Assembler,
             AArch64Call,
         >,
-        _sym: &Symbol,
-        _layout: &Layout<'a>,
+        sym: &Symbol,
+        layout: &Layout<'a>,
     ) {
-        todo!("Loading returned complex symbols for AArch64");
+        match layout {
+            single_register_layouts!() => {
+                internal_error!("single register layouts are not complex symbols");
+            }
+            x if x.stack_size(storage_manager.env.layout_interner, TARGET_INFO) == 0 => {}
+            x if !Self::returns_via_arg_pointer(storage_manager.env.layout_interner, x) => {
+                let size = layout.stack_size(storage_manager.env.layout_interner, TARGET_INFO);
+                let offset = storage_manager.claim_stack_area(sym, size);
+                if size <= 8 {
+                    AArch64Assembler::mov_base32_reg64(buf, offset, Self::GENERAL_RETURN_REGS[0]);
+                } else if size <= 16 {
+                    AArch64Assembler::mov_base32_reg64(buf, offset, Self::GENERAL_RETURN_REGS[0]);
+                    AArch64Assembler::mov_base32_reg64(
+                        buf,
+                        offset + 8,
+                        Self::GENERAL_RETURN_REGS[1],
+                    );
+                } else {
+                    internal_error!(
+                        "types that don't return via arg pointer must be less than 16 bytes"
+                    );
+                }
+            }
+            _ => {
+                // This should have been recieved via an arg pointer.
+                // That means the value is already loaded onto the stack area we allocated before the call.
+                // Nothing to do.
+            }
+        }
+    }
+}
+
+impl AArch64Call {
+    fn returns_via_arg_pointer<'a>(
+        interner: &STLayoutInterner<'a>,
+        ret_layout: &Layout<'a>,
+    ) -> bool {
+        // TODO: This will need to be more complex/extended to fully support the calling convention.
+        // details here: https://github.com/ARM-software/abi-aa/blob/main/aapcs64/aapcs64.rst
+        ret_layout.stack_size(interner, TARGET_INFO) > 16
     }
 }
 
 impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
+    // IP0 and IP1 are never given out by the storage manager.
+    // IP1 is used within this impl as a temporary for addresses and large immediates.
+    // IP0 is only used by instructions that need a second temporary.
     #[inline(always)]
-    fn abs_reg64_reg64(_buf: &mut Vec<'_, u8>, _dst: AArch64GeneralReg, _src: AArch64GeneralReg) {
-        todo!("abs_reg64_reg64 for AArch64");
+    fn abs_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+        cmp_reg64_imm12(buf, src, 0);
+        cneg_reg64_reg64_cond(buf, dst, src, ConditionCode::MI);
     }
 
     #[inline(always)]
     fn abs_freg64_freg64(
-        _buf: &mut Vec<'_, u8>,
+        buf: &mut Vec<'_, u8>,
         _relocs: &mut Vec<'_, Relocation>,
-        _dst: AArch64FloatReg,
-        _src: AArch64FloatReg,
+        dst: AArch64FloatReg,
+        src: AArch64FloatReg,
     ) {
-        todo!("abs_reg64_reg64 for AArch64");
+        fabs_freg64_freg64(buf, dst, src);
     }
 
     #[inline(always)]
@@ -392,13 +827,7 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         src: AArch64GeneralReg,
         imm32: i32,
     ) {
-        if imm32 < 0 {
-            todo!("immediate addition with values less than 0");
-        } else if imm32 < 0xFFF {
-            add_reg64_reg64_imm12(buf, dst, src, imm32 as u16);
-        } else {
-            todo!("immediate additions with values greater than 12bits");
-        }
+        Self::add_sub_reg64_reg64_imm32(buf, false, dst, src, imm32);
     }
     #[inline(always)]
     fn add_reg64_reg64_reg64(
@@ -407,157 +836,183 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         src1: AArch64GeneralReg,
         src2: AArch64GeneralReg,
     ) {
-        add_reg64_reg64_reg64(buf, dst, src1, src2);
+        // Set the flags, so that set_if_overflow can follow an addition.
+        adds_reg64_reg64_reg64(buf, dst, src1, src2);
     }
     #[inline(always)]
     fn add_freg32_freg32_freg32(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64FloatReg,
-        _src1: AArch64FloatReg,
-        _src2: AArch64FloatReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64FloatReg,
+        src1: AArch64FloatReg,
+        src2: AArch64FloatReg,
     ) {
-        todo!("adding floats for AArch64");
+        fadd_freg32_freg32_freg32(buf, dst, src1, src2);
     }
     #[inline(always)]
     fn add_freg64_freg64_freg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64FloatReg,
-        _src1: AArch64FloatReg,
-        _src2: AArch64FloatReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64FloatReg,
+        src1: AArch64FloatReg,
+        src2: AArch64FloatReg,
     ) {
-        todo!("adding floats for AArch64");
+        fadd_freg64_freg64_freg64(buf, dst, src1, src2);
     }
 
     #[inline(always)]
-    fn call(_buf: &mut Vec<'_, u8>, _relocs: &mut Vec<'_, Relocation>, _fn_name: String) {
-        todo!("calling functions literal for AArch64");
+    fn call(buf: &mut Vec<'_, u8>, relocs: &mut Vec<'_, Relocation>, fn_name: String) {
+        bl_imm26(buf, 0);
+        // The relocation applies to the whole instruction.
+        relocs.push(Relocation::LinkedFunction {
+            offset: buf.len() as u64 - 4,
+            name: fn_name,
+        });
     }
 
     #[inline(always)]
     fn imul_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("register signed multiplication for AArch64");
+        mul_reg64_reg64_reg64(buf, dst, src1, src2);
     }
 
     fn umul_reg64_reg64_reg64<'a, ASM, CC>(
-        _buf: &mut Vec<'a, u8>,
+        buf: &mut Vec<'a, u8>,
         _storage_manager: &mut StorageManager<'a, AArch64GeneralReg, AArch64FloatReg, ASM, CC>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) where
         ASM: Assembler<AArch64GeneralReg, AArch64FloatReg>,
         CC: CallConv<AArch64GeneralReg, AArch64FloatReg, ASM>,
     {
-        todo!("register unsigned multiplication for AArch64");
+        // The low 64 bits of a multiplication are the same for signed and unsigned values.
+        mul_reg64_reg64_reg64(buf, dst, src1, src2);
     }
 
     fn idiv_reg64_reg64_reg64<'a, ASM, CC>(
-        _buf: &mut Vec<'a, u8>,
+        buf: &mut Vec<'a, u8>,
         _storage_manager: &mut StorageManager<'a, AArch64GeneralReg, AArch64FloatReg, ASM, CC>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) where
         ASM: Assembler<AArch64GeneralReg, AArch64FloatReg>,
         CC: CallConv<AArch64GeneralReg, AArch64FloatReg, ASM>,
     {
-        todo!("register signed division for AArch64");
+        sdiv_reg64_reg64_reg64(buf, dst, src1, src2);
     }
 
     fn udiv_reg64_reg64_reg64<'a, ASM, CC>(
-        _buf: &mut Vec<'a, u8>,
+        buf: &mut Vec<'a, u8>,
         _storage_manager: &mut StorageManager<'a, AArch64GeneralReg, AArch64FloatReg, ASM, CC>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) where
         ASM: Assembler<AArch64GeneralReg, AArch64FloatReg>,
         CC: CallConv<AArch64GeneralReg, AArch64FloatReg, ASM>,
     {
-        todo!("register unsigned division for AArch64");
+        udiv_reg64_reg64_reg64(buf, dst, src1, src2);
     }
 
     #[inline(always)]
     fn mul_freg32_freg32_freg32(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64FloatReg,
-        _src1: AArch64FloatReg,
-        _src2: AArch64FloatReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64FloatReg,
+        src1: AArch64FloatReg,
+        src2: AArch64FloatReg,
     ) {
-        todo!("multiplication for floats for AArch64");
+        fmul_freg32_freg32_freg32(buf, dst, src1, src2);
     }
     #[inline(always)]
     fn mul_freg64_freg64_freg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64FloatReg,
-        _src1: AArch64FloatReg,
-        _src2: AArch64FloatReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64FloatReg,
+        src1: AArch64FloatReg,
+        src2: AArch64FloatReg,
     ) {
-        todo!("multiplication for floats for AArch64");
+        fmul_freg64_freg64_freg64(buf, dst, src1, src2);
     }
 
     #[inline(always)]
     fn div_freg32_freg32_freg32(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64FloatReg,
-        _src1: AArch64FloatReg,
-        _src2: AArch64FloatReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64FloatReg,
+        src1: AArch64FloatReg,
+        src2: AArch64FloatReg,
     ) {
-        todo!("division for floats for AArch64");
+        fdiv_freg32_freg32_freg32(buf, dst, src1, src2);
     }
     #[inline(always)]
     fn div_freg64_freg64_freg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64FloatReg,
-        _src1: AArch64FloatReg,
-        _src2: AArch64FloatReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64FloatReg,
+        src1: AArch64FloatReg,
+        src2: AArch64FloatReg,
     ) {
-        todo!("division for floats for AArch64");
+        fdiv_freg64_freg64_freg64(buf, dst, src1, src2);
     }
 
     #[inline(always)]
-    fn jmp_imm32(_buf: &mut Vec<'_, u8>, _offset: i32) -> usize {
-        todo!("jump instructions for AArch64");
+    fn jmp_imm32(buf: &mut Vec<'_, u8>, offset: i32) -> usize {
+        // Branches on AArch64 are relative to the branch instruction itself.
+        debug_assert!(offset % 4 == 0);
+        let base_offset = buf.len();
+        b_imm26(buf, offset >> 2);
+        base_offset
     }
 
     #[inline(always)]
     fn tail_call(buf: &mut Vec<'_, u8>) -> u64 {
         Self::jmp_imm32(buf, 0);
-        buf.len() as u64 - 4 // TODO is 4 the correct offset in ARM?
+        // The relocation applies to the whole instruction.
+        buf.len() as u64 - 4
     }
 
     #[inline(always)]
     fn jne_reg64_imm64_imm32(
-        _buf: &mut Vec<'_, u8>,
-        _reg: AArch64GeneralReg,
-        _imm: u64,
-        _offset: i32,
+        buf: &mut Vec<'_, u8>,
+        reg: AArch64GeneralReg,
+        imm: u64,
+        offset: i32,
     ) -> usize {
-        todo!("jump not equal instructions for AArch64");
+        if imm <= 0xFFF {
+            cmp_reg64_imm12(buf, reg, imm as u16);
+        } else {
+            Self::mov_reg64_imm64(buf, AArch64GeneralReg::IP1, imm as i64);
+            cmp_reg64_reg64(buf, reg, AArch64GeneralReg::IP1);
+        }
+        // Branches on AArch64 are relative to the branch instruction itself.
+        debug_assert!(offset % 4 == 0);
+        let base_offset = buf.len();
+        b_cond_imm19(buf, ConditionCode::NE, offset >> 2);
+        base_offset
     }
 
     #[inline(always)]
     fn mov_freg32_imm32(
-        _buf: &mut Vec<'_, u8>,
+        buf: &mut Vec<'_, u8>,
         _relocs: &mut Vec<'_, Relocation>,
-        _dst: AArch64FloatReg,
-        _imm: f32,
+        dst: AArch64FloatReg,
+        imm: f32,
     ) {
-        todo!("loading f32 literal for AArch64");
+        // Build the bits in a general register instead of loading them from data.
+        Self::mov_reg64_imm64(buf, AArch64GeneralReg::IP1, imm.to_bits() as i64);
+        fmov_freg32_reg32(buf, dst, AArch64GeneralReg::IP1);
     }
     #[inline(always)]
     fn mov_freg64_imm64(
-        _buf: &mut Vec<'_, u8>,
+        buf: &mut Vec<'_, u8>,
         _relocs: &mut Vec<'_, Relocation>,
-        _dst: AArch64FloatReg,
-        _imm: f64,
+        dst: AArch64FloatReg,
+        imm: f64,
     ) {
-        todo!("loading f64 literal for AArch64");
+        // Build the bits in a general register instead of loading them from data.
+        Self::mov_reg64_imm64(buf, AArch64GeneralReg::IP1, imm.to_bits() as i64);
+        fmov_freg64_reg64(buf, dst, AArch64GeneralReg::IP1);
     }
     #[inline(always)]
     fn mov_reg64_imm64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, imm: i64) {
@@ -577,8 +1032,8 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         }
     }
     #[inline(always)]
-    fn mov_freg64_freg64(_buf: &mut Vec<'_, u8>, _dst: AArch64FloatReg, _src: AArch64FloatReg) {
-        todo!("moving data between float registers for AArch64");
+    fn mov_freg64_freg64(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64FloatReg) {
+        fmov_freg64_freg64(buf, dst, src);
     }
     #[inline(always)]
     fn mov_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
@@ -586,34 +1041,20 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
     }
 
     #[inline(always)]
-    fn mov_freg64_base32(_buf: &mut Vec<'_, u8>, _dst: AArch64FloatReg, _offset: i32) {
-        todo!("loading floating point reg from base offset for AArch64");
+    fn mov_freg64_base32(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, offset: i32) {
+        Self::mov_freg64_mem64_offset32(buf, dst, AArch64GeneralReg::FP, offset);
     }
     #[inline(always)]
     fn mov_reg64_base32(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, offset: i32) {
-        if offset < 0 {
-            todo!("negative base offsets for AArch64");
-        } else if offset < (0xFFF << 8) {
-            debug_assert!(offset % 8 == 0);
-            ldr_reg64_reg64_imm12(buf, dst, AArch64GeneralReg::FP, (offset as u16) >> 3);
-        } else {
-            todo!("base offsets over 32k for AArch64");
-        }
+        Self::mov_reg64_mem64_offset32(buf, dst, AArch64GeneralReg::FP, offset);
     }
     #[inline(always)]
-    fn mov_base32_freg64(_buf: &mut Vec<'_, u8>, _offset: i32, _src: AArch64FloatReg) {
-        todo!("saving floating point reg to base offset for AArch64");
+    fn mov_base32_freg64(buf: &mut Vec<'_, u8>, offset: i32, src: AArch64FloatReg) {
+        Self::mov_mem64_offset32_freg64(buf, AArch64GeneralReg::FP, offset, src);
     }
     #[inline(always)]
     fn mov_base32_reg64(buf: &mut Vec<'_, u8>, offset: i32, src: AArch64GeneralReg) {
-        if offset < 0 {
-            todo!("negative base offsets for AArch64");
-        } else if offset < (0xFFF << 8) {
-            debug_assert!(offset % 8 == 0);
-            str_reg64_reg64_imm12(buf, src, AArch64GeneralReg::FP, (offset as u16) >> 3);
-        } else {
-            todo!("base offsets over 32k for AArch64");
-        }
+        Self::mov_mem64_offset32_reg64(buf, AArch64GeneralReg::FP, offset, src);
     }
 
     #[inline(always)]
@@ -623,14 +1064,13 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         src: AArch64GeneralReg,
         offset: i32,
     ) {
-        if offset < 0 {
-            todo!("negative mem offsets for AArch64");
-        } else if offset < (0xFFF << 8) {
-            debug_assert!(offset % 8 == 0);
-            ldr_reg64_reg64_imm12(buf, dst, src, (offset as u16) >> 3);
-        } else {
-            todo!("mem offsets over 32k for AArch64");
-        }
+        Self::mem64_offset32_help(
+            buf,
+            src,
+            offset,
+            |buf, base, imm12| ldr_reg64_reg64_imm12(buf, dst, base, imm12),
+            |buf, base, imm9| ldur_reg64_reg64_imm9(buf, dst, base, imm9),
+        );
     }
     #[inline(always)]
     fn mov_mem64_offset32_reg64(
@@ -639,80 +1079,68 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         offset: i32,
         src: AArch64GeneralReg,
     ) {
-        if offset < 0 {
-            todo!("negative mem offsets for AArch64");
-        } else if offset < (0xFFF << 8) {
-            debug_assert!(offset % 8 == 0);
-            str_reg64_reg64_imm12(buf, src, dst, (offset as u16) >> 3);
-        } else {
-            todo!("mem offsets over 32k for AArch64");
-        }
+        Self::mem64_offset32_help(
+            buf,
+            dst,
+            offset,
+            |buf, base, imm12| str_reg64_reg64_imm12(buf, src, base, imm12),
+            |buf, base, imm9| stur_reg64_reg64_imm9(buf, src, base, imm9),
+        );
     }
 
     #[inline(always)]
     fn movsx_reg64_base32(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, offset: i32, size: u8) {
         debug_assert!(size <= 8);
-        if size == 8 {
-            Self::mov_reg64_base32(buf, dst, offset);
-        } else if size == 4 {
-            todo!("sign extending 4 byte values");
-        } else if size == 2 {
-            todo!("sign extending 2 byte values");
-        } else if size == 1 {
-            todo!("sign extending 1 byte values");
-        } else {
-            internal_error!("Invalid size for sign extension: {}", size);
+        match size {
+            8 => Self::mov_reg64_base32(buf, dst, offset),
+            4 | 2 | 1 => {
+                let (base, imm9) = Self::unscaled_offset32(buf, AArch64GeneralReg::FP, offset);
+                match size {
+                    4 => ldursw_reg64_reg64_imm9(buf, dst, base, imm9),
+                    2 => ldursh_reg64_reg64_imm9(buf, dst, base, imm9),
+                    _ => ldursb_reg64_reg64_imm9(buf, dst, base, imm9),
+                }
+            }
+            _ => internal_error!("Invalid size for sign extension: {size}"),
         }
     }
     #[inline(always)]
     fn movzx_reg64_base32(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, offset: i32, size: u8) {
         debug_assert!(size <= 8);
-        if size == 8 {
-            Self::mov_reg64_base32(buf, dst, offset);
-        } else if size == 4 {
-            todo!("zero extending 4 byte values");
-        } else if size == 2 {
-            todo!("zero extending 2 byte values");
-        } else if size == 1 {
-            todo!("zero extending 1 byte values");
-        } else {
-            internal_error!("Invalid size for zero extension: {}", size);
+        match size {
+            8 => Self::mov_reg64_base32(buf, dst, offset),
+            4 | 2 | 1 => {
+                // Writing to the 32 bit form of a register clears the upper 32 bits.
+                let (base, imm9) = Self::unscaled_offset32(buf, AArch64GeneralReg::FP, offset);
+                match size {
+                    4 => ldur_reg32_reg64_imm9(buf, dst, base, imm9),
+                    2 => ldurh_reg32_reg64_imm9(buf, dst, base, imm9),
+                    _ => ldurb_reg32_reg64_imm9(buf, dst, base, imm9),
+                }
+            }
+            _ => internal_error!("Invalid size for zero extension: {size}"),
         }
     }
 
     #[inline(always)]
-    fn mov_freg64_stack32(_buf: &mut Vec<'_, u8>, _dst: AArch64FloatReg, _offset: i32) {
-        todo!("loading floating point reg from stack for AArch64");
+    fn mov_freg64_stack32(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, offset: i32) {
+        Self::mov_freg64_mem64_offset32(buf, dst, AArch64GeneralReg::ZRSP, offset);
     }
     #[inline(always)]
     fn mov_reg64_stack32(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, offset: i32) {
-        if offset < 0 {
-            todo!("negative stack offsets for AArch64");
-        } else if offset < (0xFFF << 8) {
-            debug_assert!(offset % 8 == 0);
-            ldr_reg64_reg64_imm12(buf, dst, AArch64GeneralReg::ZRSP, (offset as u16) >> 3);
-        } else {
-            todo!("stack offsets over 32k for AArch64");
-        }
+        Self::mov_reg64_mem64_offset32(buf, dst, AArch64GeneralReg::ZRSP, offset);
     }
     #[inline(always)]
-    fn mov_stack32_freg64(_buf: &mut Vec<'_, u8>, _offset: i32, _src: AArch64FloatReg) {
-        todo!("saving floating point reg to stack for AArch64");
+    fn mov_stack32_freg64(buf: &mut Vec<'_, u8>, offset: i32, src: AArch64FloatReg) {
+        Self::mov_mem64_offset32_freg64(buf, AArch64GeneralReg::ZRSP, offset, src);
     }
     #[inline(always)]
     fn mov_stack32_reg64(buf: &mut Vec<'_, u8>, offset: i32, src: AArch64GeneralReg) {
-        if offset < 0 {
-            todo!("negative stack offsets for AArch64");
-        } else if offset < (0xFFF << 8) {
-            debug_assert!(offset % 8 == 0);
-            str_reg64_reg64_imm12(buf, src, AArch64GeneralReg::ZRSP, (offset as u16) >> 3);
-        } else {
-            todo!("stack offsets over 32k for AArch64");
-        }
+        Self::mov_mem64_offset32_reg64(buf, AArch64GeneralReg::ZRSP, offset, src);
     }
     #[inline(always)]
-    fn neg_reg64_reg64(_buf: &mut Vec<'_, u8>, _dst: AArch64GeneralReg, _src: AArch64GeneralReg) {
-        todo!("neg for AArch64");
+    fn neg_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+        neg_reg64_reg64(buf, dst, src);
     }
 
     #[inline(always)]
@@ -722,112 +1150,95 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         src: AArch64GeneralReg,
         imm32: i32,
     ) {
-        if imm32 < 0 {
-            todo!("immediate subtractions with values less than 0");
-        } else if imm32 < 0xFFF {
-            sub_reg64_reg64_imm12(buf, dst, src, imm32 as u16);
-        } else {
-            todo!("immediate subtractions with values greater than 12bits");
-        }
+        Self::add_sub_reg64_reg64_imm32(buf, true, dst, src, imm32);
     }
     #[inline(always)]
     fn sub_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("registers subtractions for AArch64");
+        sub_reg64_reg64_reg64(buf, dst, src1, src2);
     }
 
     #[inline(always)]
     fn eq_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("registers equality for AArch64");
+        cmp_reg64_reg64(buf, src1, src2);
+        cset_reg64_cond(buf, dst, ConditionCode::EQ);
     }
 
     #[inline(always)]
     fn neq_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("registers non-equality for AArch64");
+        cmp_reg64_reg64(buf, src1, src2);
+        cset_reg64_cond(buf, dst, ConditionCode::NE);
     }
 
     #[inline(always)]
     fn lt_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("registers less than for AArch64");
+        cmp_reg64_reg64(buf, src1, src2);
+        cset_reg64_cond(buf, dst, ConditionCode::LT);
     }
 
     #[inline(always)]
-    fn to_float_freg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64FloatReg,
-        _src: AArch64GeneralReg,
-    ) {
-        todo!("registers to float for AArch64");
+    fn to_float_freg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64GeneralReg) {
+        scvtf_freg64_reg64(buf, dst, src);
     }
 
     #[inline(always)]
-    fn to_float_freg32_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64FloatReg,
-        _src: AArch64GeneralReg,
-    ) {
-        todo!("registers to float for AArch64");
+    fn to_float_freg32_reg64(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64GeneralReg) {
+        scvtf_freg32_reg64(buf, dst, src);
     }
 
     #[inline(always)]
-    fn to_float_freg32_freg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64FloatReg,
-        _src: AArch64FloatReg,
-    ) {
-        todo!("registers to float for AArch64");
+    fn to_float_freg32_freg64(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64FloatReg) {
+        fcvt_freg32_freg64(buf, dst, src);
     }
 
     #[inline(always)]
-    fn to_float_freg64_freg32(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64FloatReg,
-        _src: AArch64FloatReg,
-    ) {
-        todo!("registers to float for AArch64");
+    fn to_float_freg64_freg32(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64FloatReg) {
+        fcvt_freg64_freg32(buf, dst, src);
     }
 
     #[inline(always)]
     fn lte_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("registers less than or equal for AArch64");
+        cmp_reg64_reg64(buf, src1, src2);
+        cset_reg64_cond(buf, dst, ConditionCode::LE);
     }
 
     #[inline(always)]
     fn gte_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("registers greater than or equal for AArch64");
+        cmp_reg64_reg64(buf, src1, src2);
+        cset_reg64_cond(buf, dst, ConditionCode::GE);
     }
 
-    fn set_if_overflow(_buf: &mut Vec<'_, u8>, _dst: AArch64GeneralReg) {
-        todo!("set if overflow for AArch64");
+    fn set_if_overflow(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg) {
+        cset_reg64_cond(buf, dst, ConditionCode::VS);
     }
 
     #[inline(always)]
@@ -836,34 +1247,179 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
     }
 
     fn and_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("bitwise and for AArch64")
+        and_reg64_reg64_reg64(buf, dst, src1, src2);
     }
 
     fn or_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("bitwise or for AArch64")
+        orr_reg64_reg64_reg64(buf, dst, src1, src2);
     }
 
     fn xor_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("bitwise xor for AArch64")
+        eor_reg64_reg64_reg64(buf, dst, src1, src2);
+    }
+
+    fn lzcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+        clz_reg64_reg64(buf, dst, src);
+    }
+
+    fn tzcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+        // Reversing the bits turns trailing zeros into leading zeros.
+        rbit_reg64_reg64(buf, dst, src);
+        clz_reg64_reg64(buf, dst, dst);
+    }
+
+    fn popcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+        // There is no general register popcount before FEAT_CSSC, so count the bits in parallel.
+        // First, count the bits in each pair of bits.
+        Self::mov_reg64_imm64(buf, AArch64GeneralReg::IP1, 0x5555_5555_5555_5555);
+        and_reg64_reg64_reg64_lsr(buf, AArch64GeneralReg::IP0, AArch64GeneralReg::IP1, src, 1);
+        sub_reg64_reg64_reg64(buf, dst, src, AArch64GeneralReg::IP0);
+        // Then, sum pairs into each nibble.
+        Self::mov_reg64_imm64(buf, AArch64GeneralReg::IP1, 0x3333_3333_3333_3333);
+        and_reg64_reg64_reg64_lsr(buf, AArch64GeneralReg::IP0, AArch64GeneralReg::IP1, dst, 2);
+        and_reg64_reg64_reg64(buf, dst, dst, AArch64GeneralReg::IP1);
+        add_reg64_reg64_reg64(buf, dst, dst, AArch64GeneralReg::IP0);
+        // Then, sum nibbles into each byte.
+        add_reg64_reg64_reg64_lsr(buf, dst, dst, dst, 4);
+        Self::mov_reg64_imm64(buf, AArch64GeneralReg::IP1, 0x0F0F_0F0F_0F0F_0F0F);
+        and_reg64_reg64_reg64(buf, dst, dst, AArch64GeneralReg::IP1);
+        // Finally, sum all of the bytes into the top byte and shift it down.
+        Self::mov_reg64_imm64(buf, AArch64GeneralReg::IP1, 0x0101_0101_0101_0101);
+        mul_reg64_reg64_reg64(buf, dst, dst, AArch64GeneralReg::IP1);
+        lsr_reg64_reg64_imm6(buf, dst, dst, 56);
     }
 }
 
-impl AArch64Assembler {}
+impl AArch64Assembler {
+    /// Adds or subtracts an immediate of any size.
+    /// Unlike the register forms, ZRSP is SP for both dst and src.
+    #[inline(always)]
+    fn add_sub_reg64_reg64_imm32(
+        buf: &mut Vec<'_, u8>,
+        sub: bool,
+        dst: AArch64GeneralReg,
+        src: AArch64GeneralReg,
+        imm32: i32,
+    ) {
+        let (sub, imm) = if imm32 < 0 {
+            (!sub, imm32.unsigned_abs())
+        } else {
+            (sub, imm32 as u32)
+        };
+        let (op_imm12, op_imm12_lsl12, op_reg64_extended): (
+            fn(&mut Vec<'_, u8>, AArch64GeneralReg, AArch64GeneralReg, u16),
+            fn(&mut Vec<'_, u8>, AArch64GeneralReg, AArch64GeneralReg, u16),
+            fn(&mut Vec<'_, u8>, AArch64GeneralReg, AArch64GeneralReg, AArch64GeneralReg),
+        ) = if sub {
+            (
+                sub_reg64_reg64_imm12,
+                sub_reg64_reg64_imm12_lsl12,
+                sub_reg64_reg64_reg64_extended,
+            )
+        } else {
+            (
+                add_reg64_reg64_imm12,
+                add_reg64_reg64_imm12_lsl12,
+                add_reg64_reg64_reg64_extended,
+            )
+        };
+        if imm <= 0xFFF {
+            op_imm12(buf, dst, src, imm as u16);
+        } else if imm <= 0xFF_FFFF {
+            op_imm12_lsl12(buf, dst, src, (imm >> 12) as u16);
+            if imm & 0xFFF != 0 {
+                op_imm12(buf, dst, dst, (imm & 0xFFF) as u16);
+            }
+        } else {
+            Self::mov_reg64_imm64(buf, AArch64GeneralReg::IP1, imm as i64);
+            op_reg64_extended(buf, dst, src, AArch64GeneralReg::IP1);
+        }
+    }
+
+    /// Gets a base register and signed 9 bit offset for unscaled loads and stores.
+    /// If the offset is too large, the address is calculated into IP1.
+    #[inline(always)]
+    fn unscaled_offset32(
+        buf: &mut Vec<'_, u8>,
+        base: AArch64GeneralReg,
+        offset: i32,
+    ) -> (AArch64GeneralReg, i16) {
+        if (-256..256).contains(&offset) {
+            (base, offset as i16)
+        } else {
+            Self::add_reg64_reg64_imm32(buf, AArch64GeneralReg::IP1, base, offset);
+            (AArch64GeneralReg::IP1, 0)
+        }
+    }
+
+    /// Generates a 64 bit load or store at `base + offset` with the smallest encoding that fits the offset.
+    /// `scaled` takes the offset divided by 8, `unscaled` takes the raw offset.
+    #[inline(always)]
+    fn mem64_offset32_help<'a, S, U>(
+        buf: &mut Vec<'a, u8>,
+        base: AArch64GeneralReg,
+        offset: i32,
+        scaled: S,
+        unscaled: U,
+    ) where
+        S: FnOnce(&mut Vec<'a, u8>, AArch64GeneralReg, u16),
+        U: FnOnce(&mut Vec<'a, u8>, AArch64GeneralReg, i16),
+    {
+        if offset >= 0 && offset % 8 == 0 && offset >> 3 <= 0xFFF {
+            scaled(buf, base, (offset >> 3) as u16);
+        } else {
+            let (base, imm9) = Self::unscaled_offset32(buf, base, offset);
+            unscaled(buf, base, imm9);
+        }
+    }
+
+    #[inline(always)]
+    fn mov_freg64_mem64_offset32(
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64FloatReg,
+        src: AArch64GeneralReg,
+        offset: i32,
+    ) {
+        Self::mem64_offset32_help(
+            buf,
+            src,
+            offset,
+            |buf, base, imm12| ldr_freg64_reg64_imm12(buf, dst, base, imm12),
+            |buf, base, imm9| ldur_freg64_reg64_imm9(buf, dst, base, imm9),
+        );
+    }
+
+    #[inline(always)]
+    fn mov_mem64_offset32_freg64(
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        offset: i32,
+        src: AArch64FloatReg,
+    ) {
+        Self::mem64_offset32_help(
+            buf,
+            dst,
+            offset,
+            |buf, base, imm12| str_freg64_reg64_imm12(buf, src, base, imm12),
+            |buf, base, imm9| stur_freg64_reg64_imm9(buf, src, base, imm9),
+        );
+    }
+}
 
 // Instructions
 // ARM manual section C3
@@ -1128,13 +1684,14 @@ impl UnconditionalBranchRegister {
 // Uses unsigned Offset
 // opc = 0b01 means load
 // opc = 0b00 means store
+// v = true means a SIMD&FP register
 #[derive(PackedStruct, Debug)]
 #[packed_struct(endian = "msb")]
 pub struct LoadStoreRegisterImmediate {
     size: Integer<u8, packed_bits::Bits<2>>,
     fixed: Integer<u8, packed_bits::Bits<3>>, // = 0b111,
-    fixed2: bool,
-    fixed3: Integer<u8, packed_bits::Bits<2>>,
+    v: bool,
+    fixed2: Integer<u8, packed_bits::Bits<2>>, // = 0b01,
     opc: Integer<u8, packed_bits::Bits<2>>,
     imm12: Integer<u16, packed_bits::Bits<12>>,
     rn: Integer<u8, packed_bits::Bits<5>>,
@@ -1145,17 +1702,17 @@ impl Aarch64Bytes for LoadStoreRegisterImmediate {}
 
 impl LoadStoreRegisterImmediate {
     #[inline(always)]
-    fn new(size: u8, opc: u8, imm12: u16, rn: AArch64GeneralReg, rt: AArch64GeneralReg) -> Self {
+    fn new(size: u8, v: bool, opc: u8, imm12: u16, rn: AArch64GeneralReg, rt: u8) -> Self {
         debug_assert!(size <= 0b11);
         debug_assert!(imm12 <= 0xFFF);
 
         Self {
-            rt: rt.id().into(),
+            rt: rt.into(),
             rn: rn.id().into(),
             imm12: imm12.into(),
             opc: opc.into(),
-            fixed3: 0b01.into(),
-            fixed2: false,
+            fixed2: 0b01.into(),
+            v,
             fixed: 0b111.into(),
             size: size.into(),
         }
@@ -1163,255 +1720,2058 @@ impl LoadStoreRegisterImmediate {
 
     #[inline(always)]
     fn new_load(size: u8, imm12: u16, rn: AArch64GeneralReg, rt: AArch64GeneralReg) -> Self {
-        Self::new(size, 0b01, imm12, rn, rt)
+        Self::new(size, false, 0b01, imm12, rn, rt.id())
     }
 
     #[inline(always)]
     fn new_store(size: u8, imm12: u16, rn: AArch64GeneralReg, rt: AArch64GeneralReg) -> Self {
-        Self::new(size, 0b00, imm12, rn, rt)
+        Self::new(size, false, 0b00, imm12, rn, rt.id())
     }
-}
-
-// Below here are the functions for all of the assembly instructions.
-// Their names are based on the instruction and operators combined.
-// You should call `buf.reserve()` if you push or extend more than once.
-// Unit tests are added at the bottom of the file to ensure correct asm generation.
-// Please keep these in alphanumeric order.
 
-/// `ADD Xd, Xn, imm12` -> Add Xn and imm12 and place the result into Xd.
-#[inline(always)]
-fn add_reg64_reg64_imm12(
-    buf: &mut Vec<'_, u8>,
-    dst: AArch64GeneralReg,
-    src: AArch64GeneralReg,
-    imm12: u16,
-) {
-    let inst = ArithmeticImmediate::new(false, false, dst, src, imm12, false);
+    #[inline(always)]
+    fn new_float_load(size: u8, imm12: u16, rn: AArch64GeneralReg, rt: AArch64FloatReg) -> Self {
+        Self::new(size, true, 0b01, imm12, rn, rt.id())
+    }
 
-    buf.extend(inst.bytes());
+    #[inline(always)]
+    fn new_float_store(size: u8, imm12: u16, rn: AArch64GeneralReg, rt: AArch64FloatReg) -> Self {
+        Self::new(size, true, 0b00, imm12, rn, rt.id())
+    }
 }
 
-/// `ADD Xd, Xm, Xn` -> Add Xm and Xn and place the result into Xd.
-#[inline(always)]
-fn add_reg64_reg64_reg64(
-    buf: &mut Vec<'_, u8>,
-    dst: AArch64GeneralReg,
-    src1: AArch64GeneralReg,
-    src2: AArch64GeneralReg,
-) {
-    let inst = ArithmeticShifted::new(false, false, ShiftType::LSL, 0, src2, src1, dst);
-
-    buf.extend(inst.bytes());
+// Uses a signed, unscaled offset
+// opc = 0b00 means store
+// opc = 0b01 means load with zero extension
+// opc = 0b10 means load with sign extension to 64 bits
+// v = true means a SIMD&FP register
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct LoadStoreRegisterUnscaled {
+    size: Integer<u8, packed_bits::Bits<2>>,
+    fixed: Integer<u8, packed_bits::Bits<3>>, // = 0b111,
+    v: bool,
+    fixed2: Integer<u8, packed_bits::Bits<2>>, // = 0b00,
+    opc: Integer<u8, packed_bits::Bits<2>>,
+    fixed3: bool, // = 0b0,
+    imm9: Integer<u16, packed_bits::Bits<9>>,
+    fixed4: Integer<u8, packed_bits::Bits<2>>, // = 0b00,
+    rn: Integer<u8, packed_bits::Bits<5>>,
+    rt: Integer<u8, packed_bits::Bits<5>>,
 }
 
-/// `LDR Xt, [Xn, #offset]` -> Load Xn + Offset Xt. ZRSP is SP.
-/// Note: imm12 is the offest divided by 8.
-#[inline(always)]
-fn ldr_reg64_reg64_imm12(
-    buf: &mut Vec<'_, u8>,
-    dst: AArch64GeneralReg,
-    base: AArch64GeneralReg,
-    imm12: u16,
-) {
-    let inst = LoadStoreRegisterImmediate::new_load(0b11, imm12, base, dst);
+impl Aarch64Bytes for LoadStoreRegisterUnscaled {}
 
-    buf.extend(inst.bytes());
+impl LoadStoreRegisterUnscaled {
+    #[inline(always)]
+    fn new(size: u8, v: bool, opc: u8, imm9: i16, rn: AArch64GeneralReg, rt: u8) -> Self {
+        debug_assert!(size <= 0b11);
+        debug_assert!(opc <= 0b11);
+        debug_assert!((-256..256).contains(&imm9));
+
+        Self {
+            rt: rt.into(),
+            rn: rn.id().into(),
+            fixed4: 0b00.into(),
+            imm9: ((imm9 as u16) & 0x1FF).into(),
+            fixed3: false,
+            opc: opc.into(),
+            fixed2: 0b00.into(),
+            v,
+            fixed: 0b111.into(),
+            size: size.into(),
+        }
+    }
+
+    #[inline(always)]
+    fn new_load(
+        size: u8,
+        opc: u8,
+        imm9: i16,
+        rn: AArch64GeneralReg,
+        rt: AArch64GeneralReg,
+    ) -> Self {
+        Self::new(size, false, opc, imm9, rn, rt.id())
+    }
+
+    #[inline(always)]
+    fn new_store(size: u8, imm9: i16, rn: AArch64GeneralReg, rt: AArch64GeneralReg) -> Self {
+        Self::new(size, false, 0b00, imm9, rn, rt.id())
+    }
+
+    #[inline(always)]
+    fn new_float_load(size: u8, imm9: i16, rn: AArch64GeneralReg, rt: AArch64FloatReg) -> Self {
+        Self::new(size, true, 0b01, imm9, rn, rt.id())
+    }
+
+    #[inline(always)]
+    fn new_float_store(size: u8, imm9: i16, rn: AArch64GeneralReg, rt: AArch64FloatReg) -> Self {
+        Self::new(size, true, 0b00, imm9, rn, rt.id())
+    }
 }
 
-/// `MOV Xd, Xm` -> Move Xm to Xd.
-#[inline(always)]
-fn mov_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
-    // MOV is equvalent to `ORR Xd, XZR, XM` in AARCH64.
-    let inst = LogicalShiftedRegister::new(
-        LogicalOp::ORR,
-        ShiftType::LSL,
-        0,
-        src,
-        AArch64GeneralReg::ZRSP,
-        dst,
-    );
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct ArithmeticExtended {
+    sf: bool,
+    op: bool, // add or subtract
+    s: bool,
+    fixed: Integer<u8, packed_bits::Bits<5>>, // = 0b01011,
+    opt: Integer<u8, packed_bits::Bits<2>>,   // = 0b00,
+    fixed2: bool,                             // = 0b1,
+    reg_m: Integer<u8, packed_bits::Bits<5>>,
+    option: Integer<u8, packed_bits::Bits<3>>,
+    imm3: Integer<u8, packed_bits::Bits<3>>,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
 
-    buf.extend(inst.bytes());
+impl Aarch64Bytes for ArithmeticExtended {}
+
+impl ArithmeticExtended {
+    /// Always uses the UXTX extension with no shift.
+    /// Unlike the shifted register form, ZRSP is SP for rn and rd.
+    #[inline(always)]
+    fn new(op: bool, rm: AArch64GeneralReg, rn: AArch64GeneralReg, rd: AArch64GeneralReg) -> Self {
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            imm3: 0.into(),
+            option: 0b011.into(),
+            reg_m: rm.id().into(),
+            fixed2: true,
+            opt: 0b00.into(),
+            fixed: 0b01011.into(),
+            s: false,
+            op,
+            sf: true,
+        }
+    }
 }
 
-/// `MOVK Xd, imm16` -> Keeps Xd and moves an optionally shifted imm16 to Xd.
-#[inline(always)]
-fn movk_reg64_imm16(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, imm16: u16, hw: u8) {
-    let inst = MoveWideImmediate::new(0b11, dst, imm16, hw, true);
+#[derive(Clone, Copy, Debug)]
+#[allow(dead_code)]
+enum ConditionCode {
+    EQ = 0b0000,
+    NE = 0b0001,
+    HS = 0b0010,
+    LO = 0b0011,
+    MI = 0b0100,
+    PL = 0b0101,
+    VS = 0b0110,
+    VC = 0b0111,
+    HI = 0b1000,
+    LS = 0b1001,
+    GE = 0b1010,
+    LT = 0b1011,
+    GT = 0b1100,
+    LE = 0b1101,
+    AL = 0b1110,
+}
 
-    buf.extend(inst.bytes());
+impl ConditionCode {
+    #[inline(always)]
+    fn id(&self) -> u8 {
+        *self as u8
+    }
+
+    /// The opposite condition. Flipping the lowest bit inverts every condition but AL.
+    #[inline(always)]
+    fn invert(&self) -> u8 {
+        debug_assert!(!matches!(self, ConditionCode::AL));
+        self.id() ^ 1
+    }
 }
 
-/// `MOVZ Xd, imm16` -> Zeros Xd and moves an optionally shifted imm16 to Xd.
-#[inline(always)]
-fn movz_reg64_imm16(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, imm16: u16, hw: u8) {
-    let inst = MoveWideImmediate::new(0b10, dst, imm16, hw, true);
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct ConditionalSelect {
+    sf: bool,
+    op: bool,
+    s: bool,
+    fixed: Integer<u8, packed_bits::Bits<8>>, // = 0b11010100,
+    reg_m: Integer<u8, packed_bits::Bits<5>>,
+    cond: Integer<u8, packed_bits::Bits<4>>,
+    op2: Integer<u8, packed_bits::Bits<2>>,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
 
-    buf.extend(inst.bytes());
+impl Aarch64Bytes for ConditionalSelect {}
+
+impl ConditionalSelect {
+    #[inline(always)]
+    fn new(
+        op: bool,
+        op2: u8,
+        cond: u8,
+        rm: AArch64GeneralReg,
+        rn: AArch64GeneralReg,
+        rd: AArch64GeneralReg,
+    ) -> Self {
+        debug_assert!(op2 <= 0b11);
+        debug_assert!(cond <= 0b1111);
+
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            op2: op2.into(),
+            cond: cond.into(),
+            reg_m: rm.id().into(),
+            fixed: 0b11010100.into(),
+            s: false,
+            op,
+            sf: true,
+        }
+    }
 }
 
-/// `STR Xt, [Xn, #offset]` -> Store Xt to Xn + Offset. ZRSP is SP.
-/// Note: imm12 is the offest divided by 8.
-#[inline(always)]
-fn str_reg64_reg64_imm12(
-    buf: &mut Vec<'_, u8>,
-    src: AArch64GeneralReg,
-    base: AArch64GeneralReg,
-    imm12: u16,
-) {
-    let inst = LoadStoreRegisterImmediate::new_store(0b11, imm12, base, src);
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct DataProcessingOneSource {
+    sf: bool,
+    fixed: bool, // = 0b1,
+    s: bool,
+    fixed2: Integer<u8, packed_bits::Bits<8>>, // = 0b11010110,
+    opcode2: Integer<u8, packed_bits::Bits<5>>,
+    opcode: Integer<u8, packed_bits::Bits<6>>,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
 
-    buf.extend(inst.bytes());
+impl Aarch64Bytes for DataProcessingOneSource {}
+
+impl DataProcessingOneSource {
+    #[inline(always)]
+    fn new(opcode: u8, rn: AArch64GeneralReg, rd: AArch64GeneralReg) -> Self {
+        debug_assert!(opcode <= 0b111111);
+
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            opcode: opcode.into(),
+            opcode2: 0b00000.into(),
+            fixed2: 0b11010110.into(),
+            s: false,
+            fixed: true,
+            sf: true,
+        }
+    }
 }
 
-/// `SUB Xd, Xn, imm12` -> Subtract Xn and imm12 and place the result into Xd.
-#[inline(always)]
-fn sub_reg64_reg64_imm12(
-    buf: &mut Vec<'_, u8>,
-    dst: AArch64GeneralReg,
-    src: AArch64GeneralReg,
-    imm12: u16,
-) {
-    let inst = ArithmeticImmediate::new(true, false, dst, src, imm12, false);
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct DataProcessingTwoSource {
+    sf: bool,
+    fixed: bool, // = 0b0,
+    s: bool,
+    fixed2: Integer<u8, packed_bits::Bits<8>>, // = 0b11010110,
+    reg_m: Integer<u8, packed_bits::Bits<5>>,
+    opcode: Integer<u8, packed_bits::Bits<6>>,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
 
-    buf.extend(inst.bytes());
+impl Aarch64Bytes for DataProcessingTwoSource {}
+
+impl DataProcessingTwoSource {
+    #[inline(always)]
+    fn new(
+        opcode: u8,
+        rm: AArch64GeneralReg,
+        rn: AArch64GeneralReg,
+        rd: AArch64GeneralReg,
+    ) -> Self {
+        debug_assert!(opcode <= 0b111111);
+
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            opcode: opcode.into(),
+            reg_m: rm.id().into(),
+            fixed2: 0b11010110.into(),
+            s: false,
+            fixed: false,
+            sf: true,
+        }
+    }
 }
 
-/// `RET Xn` -> Return to the address stored in Xn.
-#[inline(always)]
-fn ret_reg64(buf: &mut Vec<'_, u8>, xn: AArch64GeneralReg) {
-    let inst = UnconditionalBranchRegister::new(0b10, xn);
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct DataProcessingThreeSource {
+    sf: bool,
+    op54: Integer<u8, packed_bits::Bits<2>>,
+    fixed: Integer<u8, packed_bits::Bits<5>>, // = 0b11011,
+    op31: Integer<u8, packed_bits::Bits<3>>,
+    reg_m: Integer<u8, packed_bits::Bits<5>>,
+    o0: bool,
+    reg_a: Integer<u8, packed_bits::Bits<5>>,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
 
-    buf.extend(inst.bytes());
+impl Aarch64Bytes for DataProcessingThreeSource {}
+
+impl DataProcessingThreeSource {
+    #[inline(always)]
+    fn new(
+        op31: u8,
+        o0: bool,
+        rm: AArch64GeneralReg,
+        ra: AArch64GeneralReg,
+        rn: AArch64GeneralReg,
+        rd: AArch64GeneralReg,
+    ) -> Self {
+        debug_assert!(op31 <= 0b111);
+
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            reg_a: ra.id().into(),
+            o0,
+            reg_m: rm.id().into(),
+            op31: op31.into(),
+            fixed: 0b11011.into(),
+            op54: 0b00.into(),
+            sf: true,
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::disassembler_test;
-    use capstone::prelude::*;
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct Bitfield {
+    sf: bool,
+    opc: Integer<u8, packed_bits::Bits<2>>,
+    fixed: Integer<u8, packed_bits::Bits<6>>, // = 0b100110,
+    n: bool,
+    immr: Integer<u8, packed_bits::Bits<6>>,
+    imms: Integer<u8, packed_bits::Bits<6>>,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
 
-    enum ZRSPKind {
-        UsesZR,
-        UsesSP,
+impl Aarch64Bytes for Bitfield {}
+
+impl Bitfield {
+    #[inline(always)]
+    fn new(opc: u8, immr: u8, imms: u8, rn: AArch64GeneralReg, rd: AArch64GeneralReg) -> Self {
+        debug_assert!(opc <= 0b11);
+        debug_assert!(immr <= 0b111111);
+        debug_assert!(imms <= 0b111111);
+
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            imms: imms.into(),
+            immr: immr.into(),
+            // must match sf
+            n: true,
+            fixed: 0b100110.into(),
+            opc: opc.into(),
+            sf: true,
+        }
     }
-    use ZRSPKind::*;
+}
 
-    impl AArch64GeneralReg {
-        fn capstone_string(&self, zrsp_kind: ZRSPKind) -> String {
-            match self {
-                AArch64GeneralReg::XR => "x8".to_owned(),
-                AArch64GeneralReg::IP0 => "x16".to_owned(),
-                AArch64GeneralReg::IP1 => "x17".to_owned(),
-                AArch64GeneralReg::PR => "x18".to_owned(),
-                AArch64GeneralReg::FP => "x29".to_owned(),
-                AArch64GeneralReg::LR => "x30".to_owned(),
-                AArch64GeneralReg::ZRSP => match zrsp_kind {
-                    UsesZR => "xzr".to_owned(),
-                    UsesSP => "sp".to_owned(),
-                },
-                _ => format!("{}", self),
-            }
+// op = false means B
+// op = true means BL
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct UnconditionalBranchImmediate {
+    op: bool,
+    fixed: Integer<u8, packed_bits::Bits<5>>, // = 0b00101,
+    imm26: Integer<u32, packed_bits::Bits<26>>,
+}
+
+impl Aarch64Bytes for UnconditionalBranchImmediate {}
+
+impl UnconditionalBranchImmediate {
+    /// imm26 is the offset from this instruction divided by 4.
+    #[inline(always)]
+    fn new(op: bool, imm26: i32) -> Self {
+        debug_assert!(
+            (-(1 << 25)..(1 << 25)).contains(&imm26),
+            "branch offset {imm26} does not fit in imm26"
+        );
+
+        Self {
+            imm26: ((imm26 as u32) & 0x3FF_FFFF).into(),
+            fixed: 0b00101.into(),
+            op,
         }
     }
+}
+
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct ConditionalBranchImmediate {
+    fixed: Integer<u8, packed_bits::Bits<7>>, // = 0b0101010,
+    o1: bool,
+    imm19: Integer<u32, packed_bits::Bits<19>>,
+    o0: bool,
+    cond: Integer<u8, packed_bits::Bits<4>>,
+}
+
+impl Aarch64Bytes for ConditionalBranchImmediate {}
+
+impl ConditionalBranchImmediate {
+    /// imm19 is the offset from this instruction divided by 4.
+    #[inline(always)]
+    fn new(cond: ConditionCode, imm19: i32) -> Self {
+        debug_assert!(
+            (-(1 << 18)..(1 << 18)).contains(&imm19),
+            "branch offset {imm19} does not fit in imm19"
+        );
+
+        Self {
+            cond: cond.id().into(),
+            o0: false,
+            imm19: ((imm19 as u32) & 0x7_FFFF).into(),
+            o1: false,
+            fixed: 0b0101010.into(),
+        }
+    }
+}
+
+// ftype = 0b00 means single precision
+// ftype = 0b01 means double precision
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct FloatingPointDataProcessingOneSource {
+    m: bool,
+    fixed: bool, // = 0b0,
+    s: bool,
+    fixed2: Integer<u8, packed_bits::Bits<5>>, // = 0b11110,
+    ftype: Integer<u8, packed_bits::Bits<2>>,
+    fixed3: bool, // = 0b1,
+    opcode: Integer<u8, packed_bits::Bits<6>>,
+    fixed4: Integer<u8, packed_bits::Bits<5>>, // = 0b10000,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
+
+impl Aarch64Bytes for FloatingPointDataProcessingOneSource {}
+
+impl FloatingPointDataProcessingOneSource {
+    #[inline(always)]
+    fn new(ftype: u8, opcode: u8, rn: AArch64FloatReg, rd: AArch64FloatReg) -> Self {
+        debug_assert!(ftype <= 0b11);
+        debug_assert!(opcode <= 0b111111);
+
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            fixed4: 0b10000.into(),
+            opcode: opcode.into(),
+            fixed3: true,
+            ftype: ftype.into(),
+            fixed2: 0b11110.into(),
+            s: false,
+            fixed: false,
+            m: false,
+        }
+    }
+}
+
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct FloatingPointDataProcessingTwoSource {
+    m: bool,
+    fixed: bool, // = 0b0,
+    s: bool,
+    fixed2: Integer<u8, packed_bits::Bits<5>>, // = 0b11110,
+    ftype: Integer<u8, packed_bits::Bits<2>>,
+    fixed3: bool, // = 0b1,
+    reg_m: Integer<u8, packed_bits::Bits<5>>,
+    opcode: Integer<u8, packed_bits::Bits<4>>,
+    fixed4: Integer<u8, packed_bits::Bits<2>>, // = 0b10,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
+
+impl Aarch64Bytes for FloatingPointDataProcessingTwoSource {}
+
+impl FloatingPointDataProcessingTwoSource {
+    #[inline(always)]
+    fn new(
+        ftype: u8,
+        opcode: u8,
+        rm: AArch64FloatReg,
+        rn: AArch64FloatReg,
+        rd: AArch64FloatReg,
+    ) -> Self {
+        debug_assert!(ftype <= 0b11);
+        debug_assert!(opcode <= 0b1111);
+
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            fixed4: 0b10.into(),
+            opcode: opcode.into(),
+            reg_m: rm.id().into(),
+            fixed3: true,
+            ftype: ftype.into(),
+            fixed2: 0b11110.into(),
+            s: false,
+            fixed: false,
+            m: false,
+        }
+    }
+}
+
+#[derive(PackedStruct, Debug)]
+#[packed_struct(endian = "msb")]
+pub struct FloatingPointIntegerConversion {
+    sf: bool,
+    fixed: bool, // = 0b0,
+    s: bool,
+    fixed2: Integer<u8, packed_bits::Bits<5>>, // = 0b11110,
+    ftype: Integer<u8, packed_bits::Bits<2>>,
+    fixed3: bool, // = 0b1,
+    rmode: Integer<u8, packed_bits::Bits<2>>,
+    opcode: Integer<u8, packed_bits::Bits<3>>,
+    fixed4: Integer<u8, packed_bits::Bits<6>>, // = 0b000000,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
+
+impl Aarch64Bytes for FloatingPointIntegerConversion {}
+
+impl FloatingPointIntegerConversion {
+    /// `rn` and `rd` are raw register ids, since one side is a general register and the other a float register.
+    #[inline(always)]
+    fn new(sf: bool, ftype: u8, rmode: u8, opcode: u8, rn: u8, rd: u8) -> Self {
+        debug_assert!(ftype <= 0b11);
+        debug_assert!(rmode <= 0b11);
+        debug_assert!(opcode <= 0b111);
+
+        Self {
+            reg_d: rd.into(),
+            reg_n: rn.into(),
+            fixed4: 0b000000.into(),
+            opcode: opcode.into(),
+            rmode: rmode.into(),
+            fixed3: true,
+            ftype: ftype.into(),
+            fixed2: 0b11110.into(),
+            s: false,
+            fixed: false,
+            sf,
+        }
+    }
+}
+
+// Below here are the functions for all of the assembly instructions.
+// Their names are based on the instruction and operators combined.
+// You should call `buf.reserve()` if you push or extend more than once.
+// Unit tests are added at the bottom of the file to ensure correct asm generation.
+// Please keep these in alphanumeric order.
+
+/// `ADD Xd, Xn, imm12` -> Add Xn and imm12 and place the result into Xd.
+#[inline(always)]
+fn add_reg64_reg64_imm12(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src: AArch64GeneralReg,
+    imm12: u16,
+) {
+    let inst = ArithmeticImmediate::new(false, false, dst, src, imm12, false);
+
+    buf.extend(inst.bytes());
+}
+
+/// `ADD Xd, Xn, imm12, LSL #12` -> Add Xn and imm12 shifted left by 12 and place the result into Xd.
+#[inline(always)]
+fn add_reg64_reg64_imm12_lsl12(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src: AArch64GeneralReg,
+    imm12: u16,
+) {
+    let inst = ArithmeticImmediate::new(false, false, dst, src, imm12, true);
+
+    buf.extend(inst.bytes());
+}
+
+/// `ADD Xd, Xm, Xn` -> Add Xm and Xn and place the result into Xd.
+#[inline(always)]
+fn add_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = ArithmeticShifted::new(false, false, ShiftType::LSL, 0, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `ADD Xd, Xn, Xm, UXTX` -> Add Xn and Xm and place the result into Xd. ZRSP is SP for Xd and Xn.
+#[inline(always)]
+fn add_reg64_reg64_reg64_extended(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = ArithmeticExtended::new(false, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `ADD Xd, Xn, Xm, LSR #imm6` -> Add Xn and Xm shifted right by imm6 and place the result into Xd.
+#[inline(always)]
+fn add_reg64_reg64_reg64_lsr(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+    imm6: u8,
+) {
+    let inst = ArithmeticShifted::new(false, false, ShiftType::LSR, imm6, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `ADDS Xd, Xn, Xm` -> Add Xn and Xm, place the result into Xd, and set the flags.
+#[inline(always)]
+fn adds_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = ArithmeticShifted::new(false, true, ShiftType::LSL, 0, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `AND Xd, Xn, Xm` -> Bitwise and Xn and Xm and place the result into Xd.
+#[inline(always)]
+fn and_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = LogicalShiftedRegister::new(LogicalOp::AND, ShiftType::LSL, 0, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `AND Xd, Xn, Xm, LSR #imm6` -> Bitwise and Xn and Xm shifted right by imm6 and place the result into Xd.
+#[inline(always)]
+fn and_reg64_reg64_reg64_lsr(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+    imm6: u8,
+) {
+    let inst = LogicalShiftedRegister::new(LogicalOp::AND, ShiftType::LSR, imm6, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `B.cond imm19` -> Branch to the offset imm19 * 4 from this instruction if cond holds.
+#[inline(always)]
+fn b_cond_imm19(buf: &mut Vec<'_, u8>, cond: ConditionCode, imm19: i32) {
+    let inst = ConditionalBranchImmediate::new(cond, imm19);
+
+    buf.extend(inst.bytes());
+}
+
+/// `B imm26` -> Branch to the offset imm26 * 4 from this instruction.
+#[inline(always)]
+fn b_imm26(buf: &mut Vec<'_, u8>, imm26: i32) {
+    let inst = UnconditionalBranchImmediate::new(false, imm26);
+
+    buf.extend(inst.bytes());
+}
+
+/// `BL imm26` -> Branch to the offset imm26 * 4 from this instruction and store the return address in LR.
+#[inline(always)]
+fn bl_imm26(buf: &mut Vec<'_, u8>, imm26: i32) {
+    let inst = UnconditionalBranchImmediate::new(true, imm26);
+
+    buf.extend(inst.bytes());
+}
+
+/// `CLZ Xd, Xn` -> Count the leading zeros of Xn and place the result into Xd.
+#[inline(always)]
+fn clz_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+    let inst = DataProcessingOneSource::new(0b000100, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `CMP Xn, imm12` -> Compare Xn and imm12, setting the flags. ZRSP is SP.
+#[inline(always)]
+fn cmp_reg64_imm12(buf: &mut Vec<'_, u8>, src: AArch64GeneralReg, imm12: u16) {
+    // CMP is equivalent to `SUBS XZR, Xn, imm12` in AARCH64.
+    let inst = ArithmeticImmediate::new(true, true, AArch64GeneralReg::ZRSP, src, imm12, false);
+
+    buf.extend(inst.bytes());
+}
+
+/// `CMP Xn, Xm` -> Compare Xn and Xm, setting the flags.
+#[inline(always)]
+fn cmp_reg64_reg64(buf: &mut Vec<'_, u8>, src1: AArch64GeneralReg, src2: AArch64GeneralReg) {
+    // CMP is equivalent to `SUBS XZR, Xn, Xm` in AARCH64.
+    let inst = ArithmeticShifted::new(
+        true,
+        true,
+        ShiftType::LSL,
+        0,
+        src2,
+        src1,
+        AArch64GeneralReg::ZRSP,
+    );
+
+    buf.extend(inst.bytes());
+}
+
+/// `CNEG Xd, Xn, cond` -> Place the negation of Xn into Xd if cond holds, otherwise Xn.
+#[inline(always)]
+fn cneg_reg64_reg64_cond(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src: AArch64GeneralReg,
+    cond: ConditionCode,
+) {
+    // CNEG is equivalent to `CSNEG Xd, Xn, Xn, invert(cond)` in AARCH64.
+    let inst = ConditionalSelect::new(true, 0b01, cond.invert(), src, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `CSET Xd, cond` -> Place 1 into Xd if cond holds, otherwise 0.
+#[inline(always)]
+fn cset_reg64_cond(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, cond: ConditionCode) {
+    // CSET is equivalent to `CSINC Xd, XZR, XZR, invert(cond)` in AARCH64.
+    let inst = ConditionalSelect::new(
+        false,
+        0b01,
+        cond.invert(),
+        AArch64GeneralReg::ZRSP,
+        AArch64GeneralReg::ZRSP,
+        dst,
+    );
+
+    buf.extend(inst.bytes());
+}
+
+/// `EOR Xd, Xn, Xm` -> Bitwise exclusive or Xn and Xm and place the result into Xd.
+#[inline(always)]
+fn eor_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = LogicalShiftedRegister::new(LogicalOp::EOR, ShiftType::LSL, 0, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `FABS Dd, Dn` -> Place the absolute value of Dn into Dd.
+#[inline(always)]
+fn fabs_freg64_freg64(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64FloatReg) {
+    let inst = FloatingPointDataProcessingOneSource::new(0b01, 0b000001, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `FADD Sd, Sn, Sm` -> Add Sn and Sm and place the result into Sd.
+#[inline(always)]
+fn fadd_freg32_freg32_freg32(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64FloatReg,
+    src1: AArch64FloatReg,
+    src2: AArch64FloatReg,
+) {
+    let inst = FloatingPointDataProcessingTwoSource::new(0b00, 0b0010, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `FADD Dd, Dn, Dm` -> Add Dn and Dm and place the result into Dd.
+#[inline(always)]
+fn fadd_freg64_freg64_freg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64FloatReg,
+    src1: AArch64FloatReg,
+    src2: AArch64FloatReg,
+) {
+    let inst = FloatingPointDataProcessingTwoSource::new(0b01, 0b0010, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `FCVT Sd, Dn` -> Convert the double Dn to a single and place the result into Sd.
+#[inline(always)]
+fn fcvt_freg32_freg64(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64FloatReg) {
+    let inst = FloatingPointDataProcessingOneSource::new(0b01, 0b000100, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `FCVT Dd, Sn` -> Convert the single Sn to a double and place the result into Dd.
+#[inline(always)]
+fn fcvt_freg64_freg32(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64FloatReg) {
+    let inst = FloatingPointDataProcessingOneSource::new(0b00, 0b000101, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `FDIV Sd, Sn, Sm` -> Divide Sn by Sm and place the result into Sd.
+#[inline(always)]
+fn fdiv_freg32_freg32_freg32(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64FloatReg,
+    src1: AArch64FloatReg,
+    src2: AArch64FloatReg,
+) {
+    let inst = FloatingPointDataProcessingTwoSource::new(0b00, 0b0001, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `FDIV Dd, Dn, Dm` -> Divide Dn by Dm and place the result into Dd.
+#[inline(always)]
+fn fdiv_freg64_freg64_freg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64FloatReg,
+    src1: AArch64FloatReg,
+    src2: AArch64FloatReg,
+) {
+    let inst = FloatingPointDataProcessingTwoSource::new(0b01, 0b0001, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `FMOV Sd, Wn` -> Move the bits of Wn to Sd.
+#[inline(always)]
+fn fmov_freg32_reg32(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64GeneralReg) {
+    let inst = FloatingPointIntegerConversion::new(false, 0b00, 0b00, 0b111, src.id(), dst.id());
+
+    buf.extend(inst.bytes());
+}
+
+/// `FMOV Dd, Dn` -> Move Dn to Dd.
+#[inline(always)]
+fn fmov_freg64_freg64(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64FloatReg) {
+    let inst = FloatingPointDataProcessingOneSource::new(0b01, 0b000000, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `FMOV Dd, Xn` -> Move the bits of Xn to Dd.
+#[inline(always)]
+fn fmov_freg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64GeneralReg) {
+    let inst = FloatingPointIntegerConversion::new(true, 0b01, 0b00, 0b111, src.id(), dst.id());
+
+    buf.extend(inst.bytes());
+}
+
+/// `FMUL Sd, Sn, Sm` -> Multiply Sn and Sm and place the result into Sd.
+#[inline(always)]
+fn fmul_freg32_freg32_freg32(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64FloatReg,
+    src1: AArch64FloatReg,
+    src2: AArch64FloatReg,
+) {
+    let inst = FloatingPointDataProcessingTwoSource::new(0b00, 0b0000, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `FMUL Dd, Dn, Dm` -> Multiply Dn and Dm and place the result into Dd.
+#[inline(always)]
+fn fmul_freg64_freg64_freg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64FloatReg,
+    src1: AArch64FloatReg,
+    src2: AArch64FloatReg,
+) {
+    let inst = FloatingPointDataProcessingTwoSource::new(0b01, 0b0000, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LDR Dt, [Xn, #offset]` -> Load Xn + Offset to Dt. ZRSP is SP.
+/// Note: imm12 is the offest divided by 8.
+#[inline(always)]
+fn ldr_freg64_reg64_imm12(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64FloatReg,
+    base: AArch64GeneralReg,
+    imm12: u16,
+) {
+    let inst = LoadStoreRegisterImmediate::new_float_load(0b11, imm12, base, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LDR Xt, [Xn, #offset]` -> Load Xn + Offset Xt. ZRSP is SP.
+/// Note: imm12 is the offest divided by 8.
+#[inline(always)]
+fn ldr_reg64_reg64_imm12(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    base: AArch64GeneralReg,
+    imm12: u16,
+) {
+    let inst = LoadStoreRegisterImmediate::new_load(0b11, imm12, base, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LDUR Dt, [Xn, #offset]` -> Load Xn + Offset to Dt. ZRSP is SP.
+#[inline(always)]
+fn ldur_freg64_reg64_imm9(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64FloatReg,
+    base: AArch64GeneralReg,
+    imm9: i16,
+) {
+    let inst = LoadStoreRegisterUnscaled::new_float_load(0b11, imm9, base, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LDUR Wt, [Xn, #offset]` -> Load and zero extend the 32 bits at Xn + Offset to Xt. ZRSP is SP.
+#[inline(always)]
+fn ldur_reg32_reg64_imm9(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    base: AArch64GeneralReg,
+    imm9: i16,
+) {
+    let inst = LoadStoreRegisterUnscaled::new_load(0b10, 0b01, imm9, base, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LDUR Xt, [Xn, #offset]` -> Load Xn + Offset to Xt. ZRSP is SP.
+#[inline(always)]
+fn ldur_reg64_reg64_imm9(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    base: AArch64GeneralReg,
+    imm9: i16,
+) {
+    let inst = LoadStoreRegisterUnscaled::new_load(0b11, 0b01, imm9, base, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LDURB Wt, [Xn, #offset]` -> Load and zero extend the byte at Xn + Offset to Xt. ZRSP is SP.
+#[inline(always)]
+fn ldurb_reg32_reg64_imm9(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    base: AArch64GeneralReg,
+    imm9: i16,
+) {
+    let inst = LoadStoreRegisterUnscaled::new_load(0b00, 0b01, imm9, base, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LDURH Wt, [Xn, #offset]` -> Load and zero extend the 16 bits at Xn + Offset to Xt. ZRSP is SP.
+#[inline(always)]
+fn ldurh_reg32_reg64_imm9(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    base: AArch64GeneralReg,
+    imm9: i16,
+) {
+    let inst = LoadStoreRegisterUnscaled::new_load(0b01, 0b01, imm9, base, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LDURSB Xt, [Xn, #offset]` -> Load and sign extend the byte at Xn + Offset to Xt. ZRSP is SP.
+#[inline(always)]
+fn ldursb_reg64_reg64_imm9(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    base: AArch64GeneralReg,
+    imm9: i16,
+) {
+    let inst = LoadStoreRegisterUnscaled::new_load(0b00, 0b10, imm9, base, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LDURSH Xt, [Xn, #offset]` -> Load and sign extend the 16 bits at Xn + Offset to Xt. ZRSP is SP.
+#[inline(always)]
+fn ldursh_reg64_reg64_imm9(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    base: AArch64GeneralReg,
+    imm9: i16,
+) {
+    let inst = LoadStoreRegisterUnscaled::new_load(0b01, 0b10, imm9, base, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LDURSW Xt, [Xn, #offset]` -> Load and sign extend the 32 bits at Xn + Offset to Xt. ZRSP is SP.
+#[inline(always)]
+fn ldursw_reg64_reg64_imm9(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    base: AArch64GeneralReg,
+    imm9: i16,
+) {
+    let inst = LoadStoreRegisterUnscaled::new_load(0b10, 0b10, imm9, base, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LSR Xd, Xn, imm6` -> Shift Xn right by imm6 and place the result into Xd.
+#[inline(always)]
+fn lsr_reg64_reg64_imm6(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src: AArch64GeneralReg,
+    imm6: u8,
+) {
+    // LSR is equivalent to `UBFM Xd, Xn, #imm6, #63` in AARCH64.
+    let inst = Bitfield::new(0b10, imm6, 0b111111, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `MOV Xd, Xm` -> Move Xm to Xd.
+#[inline(always)]
+fn mov_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+    // MOV is equvalent to `ORR Xd, XZR, XM` in AARCH64.
+    let inst = LogicalShiftedRegister::new(
+        LogicalOp::ORR,
+        ShiftType::LSL,
+        0,
+        src,
+        AArch64GeneralReg::ZRSP,
+        dst,
+    );
+
+    buf.extend(inst.bytes());
+}
+
+/// `MOVK Xd, imm16` -> Keeps Xd and moves an optionally shifted imm16 to Xd.
+#[inline(always)]
+fn movk_reg64_imm16(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, imm16: u16, hw: u8) {
+    let inst = MoveWideImmediate::new(0b11, dst, imm16, hw, true);
+
+    buf.extend(inst.bytes());
+}
+
+/// `MOVZ Xd, imm16` -> Zeros Xd and moves an optionally shifted imm16 to Xd.
+#[inline(always)]
+fn movz_reg64_imm16(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, imm16: u16, hw: u8) {
+    let inst = MoveWideImmediate::new(0b10, dst, imm16, hw, true);
+
+    buf.extend(inst.bytes());
+}
+
+/// `MUL Xd, Xn, Xm` -> Multiply Xn and Xm and place the result into Xd.
+#[inline(always)]
+fn mul_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    // MUL is equivalent to `MADD Xd, Xn, Xm, XZR` in AARCH64.
+    let inst =
+        DataProcessingThreeSource::new(0b000, false, src2, AArch64GeneralReg::ZRSP, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `NEG Xd, Xm` -> Negate Xm and place the result into Xd.
+#[inline(always)]
+fn neg_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+    // NEG is equivalent to `SUB Xd, XZR, Xm` in AARCH64.
+    sub_reg64_reg64_reg64(buf, dst, AArch64GeneralReg::ZRSP, src);
+}
+
+/// `ORR Xd, Xn, Xm` -> Bitwise or Xn and Xm and place the result into Xd.
+#[inline(always)]
+fn orr_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = LogicalShiftedRegister::new(LogicalOp::ORR, ShiftType::LSL, 0, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `RBIT Xd, Xn` -> Reverse the bits of Xn and place the result into Xd.
+#[inline(always)]
+fn rbit_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+    let inst = DataProcessingOneSource::new(0b000000, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `SCVTF Sd, Xn` -> Convert the signed integer Xn to a single and place the result into Sd.
+#[inline(always)]
+fn scvtf_freg32_reg64(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64GeneralReg) {
+    let inst = FloatingPointIntegerConversion::new(true, 0b00, 0b00, 0b010, src.id(), dst.id());
+
+    buf.extend(inst.bytes());
+}
+
+/// `SCVTF Dd, Xn` -> Convert the signed integer Xn to a double and place the result into Dd.
+#[inline(always)]
+fn scvtf_freg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64FloatReg, src: AArch64GeneralReg) {
+    let inst = FloatingPointIntegerConversion::new(true, 0b01, 0b00, 0b010, src.id(), dst.id());
+
+    buf.extend(inst.bytes());
+}
+
+/// `SDIV Xd, Xn, Xm` -> Divide the signed Xn by Xm and place the result into Xd.
+#[inline(always)]
+fn sdiv_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = DataProcessingTwoSource::new(0b000011, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `STR Dt, [Xn, #offset]` -> Store Dt to Xn + Offset. ZRSP is SP.
+/// Note: imm12 is the offest divided by 8.
+#[inline(always)]
+fn str_freg64_reg64_imm12(
+    buf: &mut Vec<'_, u8>,
+    src: AArch64FloatReg,
+    base: AArch64GeneralReg,
+    imm12: u16,
+) {
+    let inst = LoadStoreRegisterImmediate::new_float_store(0b11, imm12, base, src);
+
+    buf.extend(inst.bytes());
+}
+
+/// `STR Xt, [Xn, #offset]` -> Store Xt to Xn + Offset. ZRSP is SP.
+/// Note: imm12 is the offest divided by 8.
+#[inline(always)]
+fn str_reg64_reg64_imm12(
+    buf: &mut Vec<'_, u8>,
+    src: AArch64GeneralReg,
+    base: AArch64GeneralReg,
+    imm12: u16,
+) {
+    let inst = LoadStoreRegisterImmediate::new_store(0b11, imm12, base, src);
+
+    buf.extend(inst.bytes());
+}
+
+/// `STUR Dt, [Xn, #offset]` -> Store Dt to Xn + Offset. ZRSP is SP.
+#[inline(always)]
+fn stur_freg64_reg64_imm9(
+    buf: &mut Vec<'_, u8>,
+    src: AArch64FloatReg,
+    base: AArch64GeneralReg,
+    imm9: i16,
+) {
+    let inst = LoadStoreRegisterUnscaled::new_float_store(0b11, imm9, base, src);
+
+    buf.extend(inst.bytes());
+}
+
+/// `STUR Xt, [Xn, #offset]` -> Store Xt to Xn + Offset. ZRSP is SP.
+#[inline(always)]
+fn stur_reg64_reg64_imm9(
+    buf: &mut Vec<'_, u8>,
+    src: AArch64GeneralReg,
+    base: AArch64GeneralReg,
+    imm9: i16,
+) {
+    let inst = LoadStoreRegisterUnscaled::new_store(0b11, imm9, base, src);
+
+    buf.extend(inst.bytes());
+}
+
+/// `SUB Xd, Xn, imm12` -> Subtract Xn and imm12 and place the result into Xd.
+#[inline(always)]
+fn sub_reg64_reg64_imm12(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src: AArch64GeneralReg,
+    imm12: u16,
+) {
+    let inst = ArithmeticImmediate::new(true, false, dst, src, imm12, false);
+
+    buf.extend(inst.bytes());
+}
+
+/// `SUB Xd, Xn, imm12, LSL #12` -> Subtract imm12 shifted left by 12 from Xn and place the result into Xd.
+#[inline(always)]
+fn sub_reg64_reg64_imm12_lsl12(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src: AArch64GeneralReg,
+    imm12: u16,
+) {
+    let inst = ArithmeticImmediate::new(true, false, dst, src, imm12, true);
+
+    buf.extend(inst.bytes());
+}
+
+/// `SUB Xd, Xn, Xm` -> Subtract Xm from Xn and place the result into Xd.
+#[inline(always)]
+fn sub_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = ArithmeticShifted::new(true, false, ShiftType::LSL, 0, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `SUB Xd, Xn, Xm, UXTX` -> Subtract Xm from Xn and place the result into Xd. ZRSP is SP for Xd and Xn.
+#[inline(always)]
+fn sub_reg64_reg64_reg64_extended(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = ArithmeticExtended::new(true, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `UDIV Xd, Xn, Xm` -> Divide the unsigned Xn by Xm and place the result into Xd.
+#[inline(always)]
+fn udiv_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = DataProcessingTwoSource::new(0b000010, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `RET Xn` -> Return to the address stored in Xn.
+#[inline(always)]
+fn ret_reg64(buf: &mut Vec<'_, u8>, xn: AArch64GeneralReg) {
+    let inst = UnconditionalBranchRegister::new(0b10, xn);
+
+    buf.extend(inst.bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disassembler_test;
+    use capstone::prelude::*;
+
+    enum ZRSPKind {
+        UsesZR,
+        UsesSP,
+    }
+    use ZRSPKind::*;
+
+    impl AArch64GeneralReg {
+        fn capstone_string(&self, zrsp_kind: ZRSPKind) -> String {
+            match self {
+                AArch64GeneralReg::XR => "x8".to_owned(),
+                AArch64GeneralReg::IP0 => "x16".to_owned(),
+                AArch64GeneralReg::IP1 => "x17".to_owned(),
+                AArch64GeneralReg::PR => "x18".to_owned(),
+                AArch64GeneralReg::FP => "x29".to_owned(),
+                AArch64GeneralReg::LR => "x30".to_owned(),
+                AArch64GeneralReg::ZRSP => match zrsp_kind {
+                    UsesZR => "xzr".to_owned(),
+                    UsesSP => "sp".to_owned(),
+                },
+                _ => format!("{}", self),
+            }
+        }
+    }
+
+    impl AArch64GeneralReg {
+        fn capstone_string_32bit(&self, zrsp_kind: ZRSPKind) -> String {
+            match self {
+                AArch64GeneralReg::ZRSP => match zrsp_kind {
+                    UsesZR => "wzr".to_owned(),
+                    UsesSP => "wsp".to_owned(),
+                },
+                _ => format!("w{}", self.id()),
+            }
+        }
+    }
+
+    impl AArch64FloatReg {
+        fn capstone_string(&self, float_type: FloatType) -> String {
+            match float_type {
+                FloatType::Single => format!("s{}", self.id()),
+                FloatType::Double => format!("d{}", self.id()),
+            }
+        }
+    }
+
+    enum FloatType {
+        Single,
+        Double,
+    }
+
+    const TEST_U16: u16 = 0x1234;
+    //const TEST_I32: i32 = 0x12345678;
+    //const TEST_I64: i64 = 0x12345678_9ABCDEF0;
+
+    const ALL_GENERAL_REGS: &[AArch64GeneralReg] = &[
+        AArch64GeneralReg::X0,
+        AArch64GeneralReg::X1,
+        AArch64GeneralReg::X2,
+        AArch64GeneralReg::X3,
+        AArch64GeneralReg::X4,
+        AArch64GeneralReg::X5,
+        AArch64GeneralReg::X6,
+        AArch64GeneralReg::X7,
+        AArch64GeneralReg::XR,
+        AArch64GeneralReg::X9,
+        AArch64GeneralReg::X10,
+        AArch64GeneralReg::X11,
+        AArch64GeneralReg::X12,
+        AArch64GeneralReg::X13,
+        AArch64GeneralReg::X14,
+        AArch64GeneralReg::X15,
+        AArch64GeneralReg::IP0,
+        AArch64GeneralReg::IP1,
+        AArch64GeneralReg::PR,
+        AArch64GeneralReg::X19,
+        AArch64GeneralReg::X20,
+        AArch64GeneralReg::X21,
+        AArch64GeneralReg::X22,
+        AArch64GeneralReg::X23,
+        AArch64GeneralReg::X24,
+        AArch64GeneralReg::X25,
+        AArch64GeneralReg::X26,
+        AArch64GeneralReg::X27,
+        AArch64GeneralReg::X28,
+        AArch64GeneralReg::FP,
+        AArch64GeneralReg::LR,
+        AArch64GeneralReg::ZRSP,
+    ];
+
+    const ALL_FLOAT_REGS: &[AArch64FloatReg] = &[
+        AArch64FloatReg::V0,
+        AArch64FloatReg::V1,
+        AArch64FloatReg::V2,
+        AArch64FloatReg::V3,
+        AArch64FloatReg::V4,
+        AArch64FloatReg::V5,
+        AArch64FloatReg::V6,
+        AArch64FloatReg::V7,
+        AArch64FloatReg::V8,
+        AArch64FloatReg::V9,
+        AArch64FloatReg::V10,
+        AArch64FloatReg::V11,
+        AArch64FloatReg::V12,
+        AArch64FloatReg::V13,
+        AArch64FloatReg::V14,
+        AArch64FloatReg::V15,
+        AArch64FloatReg::V16,
+        AArch64FloatReg::V17,
+        AArch64FloatReg::V18,
+        AArch64FloatReg::V19,
+        AArch64FloatReg::V20,
+        AArch64FloatReg::V21,
+        AArch64FloatReg::V22,
+        AArch64FloatReg::V23,
+        AArch64FloatReg::V24,
+        AArch64FloatReg::V25,
+        AArch64FloatReg::V26,
+        AArch64FloatReg::V27,
+        AArch64FloatReg::V28,
+        AArch64FloatReg::V29,
+        AArch64FloatReg::V30,
+        AArch64FloatReg::V31,
+    ];
+
+    const ALL_CONDITIONS: &[ConditionCode] = &[
+        ConditionCode::EQ,
+        ConditionCode::NE,
+        ConditionCode::HS,
+        ConditionCode::LO,
+        ConditionCode::MI,
+        ConditionCode::PL,
+        ConditionCode::VS,
+        ConditionCode::VC,
+        ConditionCode::HI,
+        ConditionCode::LS,
+        ConditionCode::GE,
+        ConditionCode::LT,
+        ConditionCode::GT,
+        ConditionCode::LE,
+    ];
+
+    impl ConditionCode {
+        fn capstone_string(&self) -> String {
+            format!("{:?}", self).to_lowercase()
+        }
+    }
+
+    fn signed_hex(imm: i16) -> String {
+        if imm < 0 {
+            format!("-0x{:x}", imm.unsigned_abs())
+        } else {
+            format!("0x{:x}", imm)
+        }
+    }
+
+    fn setup_capstone_and_arena<T>(
+        arena: &bumpalo::Bump,
+    ) -> (bumpalo::collections::Vec<T>, Capstone) {
+        let buf = bumpalo::vec![in arena];
+        let cs = Capstone::new()
+            .arm64()
+            .mode(arch::arm64::ArchMode::Arm)
+            .detail(true)
+            .build()
+            .expect("Failed to create Capstone object");
+        (buf, cs)
+    }
+
+    #[test]
+    fn test_add_reg64_reg64_imm12() {
+        disassembler_test!(
+            add_reg64_reg64_imm12,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "add {}, {}, #0x{:x}",
+                reg1.capstone_string(UsesSP),
+                reg2.capstone_string(UsesSP),
+                imm
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [0x123]
+        );
+    }
+
+    #[test]
+    fn test_add_reg64_reg64_imm12_lsl12() {
+        disassembler_test!(
+            add_reg64_reg64_imm12_lsl12,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "add {}, {}, #0x{:x}, lsl #12",
+                reg1.capstone_string(UsesSP),
+                reg2.capstone_string(UsesSP),
+                imm
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [0x123]
+        );
+    }
+
+    #[test]
+    fn test_add_reg64_reg64_reg64() {
+        disassembler_test!(
+            add_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
+                "add {}, {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                reg3.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_add_reg64_reg64_reg64_extended() {
+        disassembler_test!(
+            add_reg64_reg64_reg64_extended,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| {
+                // UXTX is shown as LSL when either of the first two registers is SP.
+                let extend = if reg1 == AArch64GeneralReg::ZRSP || reg2 == AArch64GeneralReg::ZRSP {
+                    ""
+                } else {
+                    ", uxtx"
+                };
+                format!(
+                    "add {}, {}, {}{}",
+                    reg1.capstone_string(UsesSP),
+                    reg2.capstone_string(UsesSP),
+                    reg3.capstone_string(UsesZR),
+                    extend
+                )
+            },
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_add_reg64_reg64_reg64_lsr() {
+        disassembler_test!(
+            |buf: &mut Vec<'_, u8>, reg1, reg2, reg3| add_reg64_reg64_reg64_lsr(
+                buf, reg1, reg2, reg3, 4
+            ),
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
+                "add {}, {}, {}, lsr #4",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                reg3.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_adds_reg64_reg64_reg64() {
+        disassembler_test!(
+            adds_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| {
+                if reg1 == AArch64GeneralReg::ZRSP {
+                    format!(
+                        "cmn {}, {}",
+                        reg2.capstone_string(UsesZR),
+                        reg3.capstone_string(UsesZR)
+                    )
+                } else {
+                    format!(
+                        "adds {}, {}, {}",
+                        reg1.capstone_string(UsesZR),
+                        reg2.capstone_string(UsesZR),
+                        reg3.capstone_string(UsesZR)
+                    )
+                }
+            },
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_and_reg64_reg64_reg64() {
+        disassembler_test!(
+            and_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
+                "and {}, {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                reg3.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_and_reg64_reg64_reg64_lsr() {
+        disassembler_test!(
+            |buf: &mut Vec<'_, u8>, reg1, reg2, reg3| and_reg64_reg64_reg64_lsr(
+                buf, reg1, reg2, reg3, 2
+            ),
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
+                "and {}, {}, {}, lsr #2",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                reg3.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_b_cond_imm19() {
+        disassembler_test!(
+            b_cond_imm19,
+            |cond: ConditionCode, imm: i32| format!(
+                "b.{} #0x{:x}",
+                cond.capstone_string(),
+                imm << 2
+            ),
+            ALL_CONDITIONS,
+            [0x1234]
+        );
+    }
+
+    #[test]
+    fn test_b_imm26() {
+        disassembler_test!(b_imm26, |imm: i32| format!("b #0x{:x}", imm << 2), [0x1234]);
+    }
+
+    #[test]
+    fn test_bl_imm26() {
+        disassembler_test!(
+            bl_imm26,
+            |imm: i32| format!("bl #0x{:x}", imm << 2),
+            [0x1234]
+        );
+    }
+
+    #[test]
+    fn test_clz_reg64_reg64() {
+        disassembler_test!(
+            clz_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg| format!(
+                "clz {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_cmp_reg64_imm12() {
+        disassembler_test!(
+            cmp_reg64_imm12,
+            |reg1: AArch64GeneralReg, imm| format!(
+                "cmp {}, #0x{:x}",
+                reg1.capstone_string(UsesSP),
+                imm
+            ),
+            ALL_GENERAL_REGS,
+            [0x123]
+        );
+    }
+
+    #[test]
+    fn test_cmp_reg64_reg64() {
+        disassembler_test!(
+            cmp_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg| format!(
+                "cmp {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_cneg_reg64_reg64_cond() {
+        disassembler_test!(
+            cneg_reg64_reg64_cond,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, cond: ConditionCode| format!(
+                "cneg {}, {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                cond.capstone_string()
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_CONDITIONS
+        );
+    }
+
+    #[test]
+    fn test_cset_reg64_cond() {
+        disassembler_test!(
+            cset_reg64_cond,
+            |reg1: AArch64GeneralReg, cond: ConditionCode| format!(
+                "cset {}, {}",
+                reg1.capstone_string(UsesZR),
+                cond.capstone_string()
+            ),
+            ALL_GENERAL_REGS,
+            ALL_CONDITIONS
+        );
+    }
+
+    #[test]
+    fn test_eor_reg64_reg64_reg64() {
+        disassembler_test!(
+            eor_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
+                "eor {}, {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                reg3.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_fabs_freg64_freg64() {
+        disassembler_test!(
+            fabs_freg64_freg64,
+            |reg1: AArch64FloatReg, reg2: AArch64FloatReg| format!(
+                "fabs {}, {}",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(FloatType::Double)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_fadd_freg32_freg32_freg32() {
+        disassembler_test!(
+            fadd_freg32_freg32_freg32,
+            |reg1: AArch64FloatReg, reg2: AArch64FloatReg, reg3: AArch64FloatReg| format!(
+                "fadd {}, {}, {}",
+                reg1.capstone_string(FloatType::Single),
+                reg2.capstone_string(FloatType::Single),
+                reg3.capstone_string(FloatType::Single)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_fadd_freg64_freg64_freg64() {
+        disassembler_test!(
+            fadd_freg64_freg64_freg64,
+            |reg1: AArch64FloatReg, reg2: AArch64FloatReg, reg3: AArch64FloatReg| format!(
+                "fadd {}, {}, {}",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(FloatType::Double),
+                reg3.capstone_string(FloatType::Double)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_fcvt_freg32_freg64() {
+        disassembler_test!(
+            fcvt_freg32_freg64,
+            |reg1: AArch64FloatReg, reg2: AArch64FloatReg| format!(
+                "fcvt {}, {}",
+                reg1.capstone_string(FloatType::Single),
+                reg2.capstone_string(FloatType::Double)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_fcvt_freg64_freg32() {
+        disassembler_test!(
+            fcvt_freg64_freg32,
+            |reg1: AArch64FloatReg, reg2: AArch64FloatReg| format!(
+                "fcvt {}, {}",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(FloatType::Single)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_fdiv_freg32_freg32_freg32() {
+        disassembler_test!(
+            fdiv_freg32_freg32_freg32,
+            |reg1: AArch64FloatReg, reg2: AArch64FloatReg, reg3: AArch64FloatReg| format!(
+                "fdiv {}, {}, {}",
+                reg1.capstone_string(FloatType::Single),
+                reg2.capstone_string(FloatType::Single),
+                reg3.capstone_string(FloatType::Single)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_fdiv_freg64_freg64_freg64() {
+        disassembler_test!(
+            fdiv_freg64_freg64_freg64,
+            |reg1: AArch64FloatReg, reg2: AArch64FloatReg, reg3: AArch64FloatReg| format!(
+                "fdiv {}, {}, {}",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(FloatType::Double),
+                reg3.capstone_string(FloatType::Double)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_fmov_freg32_reg32() {
+        disassembler_test!(
+            fmov_freg32_reg32,
+            |reg1: AArch64FloatReg, reg2: AArch64GeneralReg| format!(
+                "fmov {}, {}",
+                reg1.capstone_string(FloatType::Single),
+                reg2.capstone_string_32bit(UsesZR)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_fmov_freg64_freg64() {
+        disassembler_test!(
+            fmov_freg64_freg64,
+            |reg1: AArch64FloatReg, reg2: AArch64FloatReg| format!(
+                "fmov {}, {}",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(FloatType::Double)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_fmov_freg64_reg64() {
+        disassembler_test!(
+            fmov_freg64_reg64,
+            |reg1: AArch64FloatReg, reg2: AArch64GeneralReg| format!(
+                "fmov {}, {}",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_fmul_freg32_freg32_freg32() {
+        disassembler_test!(
+            fmul_freg32_freg32_freg32,
+            |reg1: AArch64FloatReg, reg2: AArch64FloatReg, reg3: AArch64FloatReg| format!(
+                "fmul {}, {}, {}",
+                reg1.capstone_string(FloatType::Single),
+                reg2.capstone_string(FloatType::Single),
+                reg3.capstone_string(FloatType::Single)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_fmul_freg64_freg64_freg64() {
+        disassembler_test!(
+            fmul_freg64_freg64_freg64,
+            |reg1: AArch64FloatReg, reg2: AArch64FloatReg, reg3: AArch64FloatReg| format!(
+                "fmul {}, {}, {}",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(FloatType::Double),
+                reg3.capstone_string(FloatType::Double)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_ldr_freg64_reg64_imm12() {
+        disassembler_test!(
+            ldr_freg64_reg64_imm12,
+            |reg1: AArch64FloatReg, reg2: AArch64GeneralReg, imm| format!(
+                "ldr {}, [{}, #0x{:x}]",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(UsesSP),
+                imm << 3
+            ),
+            ALL_FLOAT_REGS,
+            ALL_GENERAL_REGS,
+            [0x123]
+        );
+    }
+
+    #[test]
+    fn test_ldr_reg64_reg64_imm12() {
+        disassembler_test!(
+            ldr_reg64_reg64_imm12,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "ldr {}, [{}, #0x{:x}]",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesSP),
+                imm << 3
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [0x123]
+        );
+    }
+
+    #[test]
+    fn test_ldur_freg64_reg64_imm9() {
+        disassembler_test!(
+            ldur_freg64_reg64_imm9,
+            |reg1: AArch64FloatReg, reg2: AArch64GeneralReg, imm| format!(
+                "ldur {}, [{}, #{}]",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(UsesSP),
+                signed_hex(imm)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_GENERAL_REGS,
+            [-0x100, -0x10, 0xff]
+        );
+    }
+
+    #[test]
+    fn test_ldur_reg32_reg64_imm9() {
+        disassembler_test!(
+            ldur_reg32_reg64_imm9,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "ldur {}, [{}, #{}]",
+                reg1.capstone_string_32bit(UsesZR),
+                reg2.capstone_string(UsesSP),
+                signed_hex(imm)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [-0x100, -0x10, 0xff]
+        );
+    }
 
-    const TEST_U16: u16 = 0x1234;
-    //const TEST_I32: i32 = 0x12345678;
-    //const TEST_I64: i64 = 0x12345678_9ABCDEF0;
+    #[test]
+    fn test_ldur_reg64_reg64_imm9() {
+        disassembler_test!(
+            ldur_reg64_reg64_imm9,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "ldur {}, [{}, #{}]",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesSP),
+                signed_hex(imm)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [-0x100, -0x10, 0xff]
+        );
+    }
 
-    const ALL_GENERAL_REGS: &[AArch64GeneralReg] = &[
-        AArch64GeneralReg::X0,
-        AArch64GeneralReg::X1,
-        AArch64GeneralReg::X2,
-        AArch64GeneralReg::X3,
-        AArch64GeneralReg::X4,
-        AArch64GeneralReg::X5,
-        AArch64GeneralReg::X6,
-        AArch64GeneralReg::X7,
-        AArch64GeneralReg::XR,
-        AArch64GeneralReg::X9,
-        AArch64GeneralReg::X10,
-        AArch64GeneralReg::X11,
-        AArch64GeneralReg::X12,
-        AArch64GeneralReg::X13,
-        AArch64GeneralReg::X14,
-        AArch64GeneralReg::X15,
-        AArch64GeneralReg::IP0,
-        AArch64GeneralReg::IP1,
-        AArch64GeneralReg::PR,
-        AArch64GeneralReg::X19,
-        AArch64GeneralReg::X20,
-        AArch64GeneralReg::X21,
-        AArch64GeneralReg::X22,
-        AArch64GeneralReg::X23,
-        AArch64GeneralReg::X24,
-        AArch64GeneralReg::X25,
-        AArch64GeneralReg::X26,
-        AArch64GeneralReg::X27,
-        AArch64GeneralReg::X28,
-        AArch64GeneralReg::FP,
-        AArch64GeneralReg::LR,
-        AArch64GeneralReg::ZRSP,
-    ];
+    #[test]
+    fn test_ldurb_reg32_reg64_imm9() {
+        disassembler_test!(
+            ldurb_reg32_reg64_imm9,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "ldurb {}, [{}, #{}]",
+                reg1.capstone_string_32bit(UsesZR),
+                reg2.capstone_string(UsesSP),
+                signed_hex(imm)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [-0x100, -0x10, 0xff]
+        );
+    }
 
-    fn setup_capstone_and_arena<T>(
-        arena: &bumpalo::Bump,
-    ) -> (bumpalo::collections::Vec<T>, Capstone) {
-        let buf = bumpalo::vec![in arena];
-        let cs = Capstone::new()
-            .arm64()
-            .mode(arch::arm64::ArchMode::Arm)
-            .detail(true)
-            .build()
-            .expect("Failed to create Capstone object");
-        (buf, cs)
+    #[test]
+    fn test_ldurh_reg32_reg64_imm9() {
+        disassembler_test!(
+            ldurh_reg32_reg64_imm9,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "ldurh {}, [{}, #{}]",
+                reg1.capstone_string_32bit(UsesZR),
+                reg2.capstone_string(UsesSP),
+                signed_hex(imm)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [-0x100, -0x10, 0xff]
+        );
     }
 
     #[test]
-    fn test_add_reg64_reg64_reg64() {
+    fn test_ldursb_reg64_reg64_imm9() {
         disassembler_test!(
-            add_reg64_reg64_reg64,
-            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
-                "add {}, {}, {}",
+            ldursb_reg64_reg64_imm9,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "ldursb {}, [{}, #{}]",
                 reg1.capstone_string(UsesZR),
-                reg2.capstone_string(UsesZR),
-                reg3.capstone_string(UsesZR)
+                reg2.capstone_string(UsesSP),
+                signed_hex(imm)
             ),
             ALL_GENERAL_REGS,
             ALL_GENERAL_REGS,
-            ALL_GENERAL_REGS
+            [-0x100, -0x10, 0xff]
         );
     }
 
     #[test]
-    fn test_add_reg64_reg64_imm12() {
+    fn test_ldursh_reg64_reg64_imm9() {
         disassembler_test!(
-            add_reg64_reg64_imm12,
+            ldursh_reg64_reg64_imm9,
             |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
-                "add {}, {}, #0x{:x}",
-                reg1.capstone_string(UsesSP),
+                "ldursh {}, [{}, #{}]",
+                reg1.capstone_string(UsesZR),
                 reg2.capstone_string(UsesSP),
-                imm
+                signed_hex(imm)
             ),
             ALL_GENERAL_REGS,
             ALL_GENERAL_REGS,
-            [0x123]
+            [-0x100, -0x10, 0xff]
         );
     }
 
     #[test]
-    fn test_ldr_reg64_reg64_imm12() {
+    fn test_ldursw_reg64_reg64_imm9() {
         disassembler_test!(
-            ldr_reg64_reg64_imm12,
+            ldursw_reg64_reg64_imm9,
             |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
-                "ldr {}, [{}, #0x{:x}]",
+                "ldursw {}, [{}, #{}]",
                 reg1.capstone_string(UsesZR),
                 reg2.capstone_string(UsesSP),
-                imm << 3
+                signed_hex(imm)
             ),
             ALL_GENERAL_REGS,
             ALL_GENERAL_REGS,
-            [0x123]
+            [-0x100, -0x10, 0xff]
+        );
+    }
+
+    #[test]
+    fn test_lsr_reg64_reg64_imm6() {
+        disassembler_test!(
+            lsr_reg64_reg64_imm6,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "lsr {}, {}, #0x{:x}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                imm
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [56]
         );
     }
 
@@ -1465,6 +3825,136 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mul_reg64_reg64_reg64() {
+        disassembler_test!(
+            mul_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
+                "mul {}, {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                reg3.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_neg_reg64_reg64() {
+        disassembler_test!(
+            neg_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg| format!(
+                "neg {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_orr_reg64_reg64_reg64() {
+        disassembler_test!(
+            orr_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| {
+                if reg2 == AArch64GeneralReg::ZRSP {
+                    format!(
+                        "mov {}, {}",
+                        reg1.capstone_string(UsesZR),
+                        reg3.capstone_string(UsesZR)
+                    )
+                } else {
+                    format!(
+                        "orr {}, {}, {}",
+                        reg1.capstone_string(UsesZR),
+                        reg2.capstone_string(UsesZR),
+                        reg3.capstone_string(UsesZR)
+                    )
+                }
+            },
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_rbit_reg64_reg64() {
+        disassembler_test!(
+            rbit_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg| format!(
+                "rbit {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_scvtf_freg32_reg64() {
+        disassembler_test!(
+            scvtf_freg32_reg64,
+            |reg1: AArch64FloatReg, reg2: AArch64GeneralReg| format!(
+                "scvtf {}, {}",
+                reg1.capstone_string(FloatType::Single),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_scvtf_freg64_reg64() {
+        disassembler_test!(
+            scvtf_freg64_reg64,
+            |reg1: AArch64FloatReg, reg2: AArch64GeneralReg| format!(
+                "scvtf {}, {}",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_sdiv_reg64_reg64_reg64() {
+        disassembler_test!(
+            sdiv_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
+                "sdiv {}, {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                reg3.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_str_freg64_reg64_imm12() {
+        disassembler_test!(
+            str_freg64_reg64_imm12,
+            |reg1: AArch64FloatReg, reg2: AArch64GeneralReg, imm| format!(
+                "str {}, [{}, #0x{:x}]",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(UsesSP),
+                imm << 3
+            ),
+            ALL_FLOAT_REGS,
+            ALL_GENERAL_REGS,
+            [0x123]
+        );
+    }
+
     #[test]
     fn test_str_reg64_reg64_imm12() {
         disassembler_test!(
@@ -1481,6 +3971,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stur_freg64_reg64_imm9() {
+        disassembler_test!(
+            stur_freg64_reg64_imm9,
+            |reg1: AArch64FloatReg, reg2: AArch64GeneralReg, imm| format!(
+                "stur {}, [{}, #{}]",
+                reg1.capstone_string(FloatType::Double),
+                reg2.capstone_string(UsesSP),
+                signed_hex(imm)
+            ),
+            ALL_FLOAT_REGS,
+            ALL_GENERAL_REGS,
+            [-0x100, -0x10, 0xff]
+        );
+    }
+
+    #[test]
+    fn test_stur_reg64_reg64_imm9() {
+        disassembler_test!(
+            stur_reg64_reg64_imm9,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "stur {}, [{}, #{}]",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesSP),
+                signed_hex(imm)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [-0x100, -0x10, 0xff]
+        );
+    }
+
     #[test]
     fn test_sub_reg64_reg64_imm12() {
         disassembler_test!(
@@ -1497,6 +4019,89 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sub_reg64_reg64_imm12_lsl12() {
+        disassembler_test!(
+            sub_reg64_reg64_imm12_lsl12,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "sub {}, {}, #0x{:x}, lsl #12",
+                reg1.capstone_string(UsesSP),
+                reg2.capstone_string(UsesSP),
+                imm
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [0x123]
+        );
+    }
+
+    #[test]
+    fn test_sub_reg64_reg64_reg64() {
+        disassembler_test!(
+            sub_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| {
+                if reg2 == AArch64GeneralReg::ZRSP {
+                    format!(
+                        "neg {}, {}",
+                        reg1.capstone_string(UsesZR),
+                        reg3.capstone_string(UsesZR)
+                    )
+                } else {
+                    format!(
+                        "sub {}, {}, {}",
+                        reg1.capstone_string(UsesZR),
+                        reg2.capstone_string(UsesZR),
+                        reg3.capstone_string(UsesZR)
+                    )
+                }
+            },
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_sub_reg64_reg64_reg64_extended() {
+        disassembler_test!(
+            sub_reg64_reg64_reg64_extended,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| {
+                // UXTX is shown as LSL when either of the first two registers is SP.
+                let extend = if reg1 == AArch64GeneralReg::ZRSP || reg2 == AArch64GeneralReg::ZRSP {
+                    ""
+                } else {
+                    ", uxtx"
+                };
+                format!(
+                    "sub {}, {}, {}{}",
+                    reg1.capstone_string(UsesSP),
+                    reg2.capstone_string(UsesSP),
+                    reg3.capstone_string(UsesZR),
+                    extend
+                )
+            },
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_udiv_reg64_reg64_reg64() {
+        disassembler_test!(
+            udiv_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
+                "udiv {}, {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                reg3.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
     #[test]
     fn test_ret_reg64() {
         disassembler_test!(
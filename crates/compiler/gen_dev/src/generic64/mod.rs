@@ -164,6 +164,10 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
         src2: GeneralReg,
     );
 
+    fn lzcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
+    fn tzcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
+    fn popcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
+
     fn call(buf: &mut Vec<'_, u8>, relocs: &mut Vec<'_, Relocation>, fn_name: String);
 
     /// Jumps by an offset of offset bytes unconditionally.
@@ -683,7 +687,7 @@ impl<
             // Build unconditional jump to the end of this switch.
             // Since we don't know the offset yet, set it to 0 and overwrite later.
             let jmp_location = self.buf.len();
-            let jmp_offset = ASM::jmp_imm32(&mut self.buf, 0x1234_5678);
+            let jmp_offset = ASM::jmp_imm32(&mut self.buf, 0);
             ret_jumps.push((jmp_location, jmp_offset));
 
             // Overwrite the original jne with the correct offset.
@@ -767,7 +771,7 @@ impl<
             .setup_jump(&mut self.buf, id, args, arg_layouts);
 
         let jmp_location = self.buf.len();
-        let start_offset = ASM::jmp_imm32(&mut self.buf, 0x1234_5678);
+        let start_offset = ASM::jmp_imm32(&mut self.buf, 0);
 
         if let Some(vec) = self.join_map.get_mut(id) {
             vec.push((jmp_location as u64, start_offset as u64))
@@ -1614,7 +1618,7 @@ impl<
             CC::return_complex_symbol(&mut self.buf, &mut self.storage_manager, sym, layout)
         }
         let inst_loc = self.buf.len() as u64;
-        let offset = ASM::jmp_imm32(&mut self.buf, 0x1234_5678) as u64;
+        let offset = ASM::jmp_imm32(&mut self.buf, 0) as u64;
         self.relocs.push(Relocation::JmpToReturn {
             inst_loc,
             inst_size: self.buf.len() as u64 - inst_loc,
@@ -1681,6 +1685,118 @@ impl<
             }
         }
     }
+
+    fn build_int_count_leading_zero_bits(
+        &mut self,
+        dst: &Symbol,
+        src: &Symbol,
+        int_width: IntWidth,
+    ) {
+        let buf = &mut self.buf;
+
+        match int_width {
+            IntWidth::U128 | IntWidth::I128 => {
+                internal_error!("128-bit ints are counted by a zig builtin")
+            }
+            _ => {
+                let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(buf, src);
+                let bits = 8 * int_width.stack_size() as i32;
+                if bits < 64 {
+                    // Clear the upper bits, count, and then discount the zeros above the int's width.
+                    self.storage_manager.with_tmp_general_reg(
+                        buf,
+                        |_storage_manager, buf, tmp_reg| {
+                            ASM::mov_reg64_imm64(buf, tmp_reg, (1 << bits) - 1_i64);
+                            ASM::and_reg64_reg64_reg64(buf, dst_reg, src_reg, tmp_reg);
+                        },
+                    );
+                    ASM::lzcnt_reg64_reg64(buf, dst_reg, dst_reg);
+                    ASM::sub_reg64_reg64_imm32(buf, dst_reg, dst_reg, 64 - bits);
+                } else {
+                    ASM::lzcnt_reg64_reg64(buf, dst_reg, src_reg);
+                }
+            }
+        }
+    }
+
+    fn build_int_count_trailing_zero_bits(
+        &mut self,
+        dst: &Symbol,
+        src: &Symbol,
+        int_width: IntWidth,
+    ) {
+        let buf = &mut self.buf;
+
+        match int_width {
+            IntWidth::U128 | IntWidth::I128 => {
+                internal_error!("128-bit ints are counted by a zig builtin")
+            }
+            _ => {
+                let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(buf, src);
+                let bits = 8 * int_width.stack_size() as i32;
+                if bits < 64 {
+                    // Set the bit just above the int's width, so that zero gives the bit width.
+                    self.storage_manager.with_tmp_general_reg(
+                        buf,
+                        |_storage_manager, buf, tmp_reg| {
+                            ASM::mov_reg64_imm64(buf, tmp_reg, 1_i64 << bits);
+                            ASM::or_reg64_reg64_reg64(buf, dst_reg, src_reg, tmp_reg);
+                        },
+                    );
+                    ASM::tzcnt_reg64_reg64(buf, dst_reg, dst_reg);
+                } else {
+                    ASM::tzcnt_reg64_reg64(buf, dst_reg, src_reg);
+                }
+            }
+        }
+    }
+
+    fn build_int_count_one_bits(&mut self, dst: &Symbol, src: &Symbol, int_width: IntWidth) {
+        let buf = &mut self.buf;
+
+        match int_width {
+            IntWidth::U128 | IntWidth::I128 => {
+                internal_error!("128-bit ints are counted by a zig builtin")
+            }
+            _ => {
+                let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(buf, src);
+                let bits = 8 * int_width.stack_size() as i32;
+                if bits < 64 {
+                    // Clear the upper bits, which are sign extended for signed ints.
+                    self.storage_manager.with_tmp_general_reg(
+                        buf,
+                        |_storage_manager, buf, tmp_reg| {
+                            ASM::mov_reg64_imm64(buf, tmp_reg, (1 << bits) - 1_i64);
+                            ASM::and_reg64_reg64_reg64(buf, dst_reg, src_reg, tmp_reg);
+                        },
+                    );
+                    ASM::popcnt_reg64_reg64(buf, dst_reg, dst_reg);
+                } else {
+                    ASM::popcnt_reg64_reg64(buf, dst_reg, src_reg);
+                }
+            }
+        }
+    }
+
+    fn build_num_f64_to_bits(&mut self, dst: &Symbol, src: &Symbol) {
+        // Move the bits through the stack, since there is no generic float to general reg move.
+        self.storage_manager
+            .ensure_symbol_on_stack(&mut self.buf, src);
+        let (offset, _) = self.storage_manager.stack_offset_and_size(src);
+        let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+        ASM::mov_reg64_base32(&mut self.buf, dst_reg, offset);
+    }
+
+    fn build_num_f64_from_bits(&mut self, dst: &Symbol, src: &Symbol) {
+        self.storage_manager
+            .ensure_symbol_on_stack(&mut self.buf, src);
+        let (offset, _) = self.storage_manager.stack_offset_and_size(src);
+        let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+        ASM::mov_freg64_base32(&mut self.buf, dst_reg, offset);
+    }
 }
 
 /// This impl block is for ir related instructions that need backend specific information.
@@ -1415,6 +1415,21 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
     fn xor_reg64_reg64_reg64(buf: &mut Vec<'_, u8>, dst: Reg64, src1: Reg64, src2: Reg64) {
         binop_move_src_to_dst_reg64(buf, xor_reg64_reg64, dst, src1, src2)
     }
+
+    #[inline(always)]
+    fn lzcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: Reg64, src: Reg64) {
+        lzcnt_reg64_reg64(buf, dst, src)
+    }
+
+    #[inline(always)]
+    fn tzcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: Reg64, src: Reg64) {
+        tzcnt_reg64_reg64(buf, dst, src)
+    }
+
+    #[inline(always)]
+    fn popcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: Reg64, src: Reg64) {
+        popcnt_reg64_reg64(buf, dst, src)
+    }
 }
 
 impl X86_64Assembler {
@@ -1728,6 +1743,28 @@ fn imul_reg64_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64Gen
     extended_binop_reg64_reg64(0x0F, 0xAF, buf, src, dst);
 }
 
+/// `LZCNT r64,r/m64` -> Count the number of leading zero bits of r/m64, return in r64.
+#[inline(always)]
+fn lzcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
+    // Like IMUL, the final encoding is (src, dst).
+    buf.push(0xF3);
+    extended_binop_reg64_reg64(0x0F, 0xBD, buf, src, dst);
+}
+
+/// `TZCNT r64,r/m64` -> Count the number of trailing zero bits of r/m64, return in r64.
+#[inline(always)]
+fn tzcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
+    buf.push(0xF3);
+    extended_binop_reg64_reg64(0x0F, 0xBC, buf, src, dst);
+}
+
+/// `POPCNT r64,r/m64` -> POPCNT on r/m64.
+#[inline(always)]
+fn popcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
+    buf.push(0xF3);
+    extended_binop_reg64_reg64(0x0F, 0xB8, buf, src, dst);
+}
+
 /// `MUL r/m64` -> Unsigned Multiply r/m64 to r64.
 #[inline(always)]
 fn mul_reg64_reg64(buf: &mut Vec<'_, u8>, src: X86_64GeneralReg) {
@@ -2422,6 +2459,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lzcnt_reg64_reg64() {
+        disassembler_test!(
+            lzcnt_reg64_reg64,
+            |reg1, reg2| format!("lzcnt {}, {}", reg1, reg2),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_tzcnt_reg64_reg64() {
+        disassembler_test!(
+            tzcnt_reg64_reg64,
+            |reg1, reg2| format!("tzcnt {}, {}", reg1, reg2),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_popcnt_reg64_reg64() {
+        disassembler_test!(
+            popcnt_reg64_reg64,
+            |reg1, reg2| format!("popcnt {}, {}", reg1, reg2),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
     #[test]
     fn test_mul_reg64_reg64() {
         disassembler_test!(
@@ -520,6 +520,106 @@ trait Backend<'a> {
                     internal_error!("bitwise xor on a non-integer")
                 }
             }
+            LowLevel::NumCountLeadingZeroBits => match arg_layouts[0] {
+                Layout::Builtin(Builtin::Int(int_width @ (IntWidth::U128 | IntWidth::I128))) => {
+                    self.build_fn_call(
+                        sym,
+                        bitcode::NUM_COUNT_LEADING_ZERO_BITS[int_width].to_string(),
+                        args,
+                        arg_layouts,
+                        ret_layout,
+                    )
+                }
+                Layout::Builtin(Builtin::Int(int_width)) => {
+                    self.build_int_count_leading_zero_bits(sym, &args[0], int_width)
+                }
+                _ => internal_error!("count leading zero bits on a non-integer"),
+            },
+            LowLevel::NumCountTrailingZeroBits => match arg_layouts[0] {
+                Layout::Builtin(Builtin::Int(int_width @ (IntWidth::U128 | IntWidth::I128))) => {
+                    self.build_fn_call(
+                        sym,
+                        bitcode::NUM_COUNT_TRAILING_ZERO_BITS[int_width].to_string(),
+                        args,
+                        arg_layouts,
+                        ret_layout,
+                    )
+                }
+                Layout::Builtin(Builtin::Int(int_width)) => {
+                    self.build_int_count_trailing_zero_bits(sym, &args[0], int_width)
+                }
+                _ => internal_error!("count trailing zero bits on a non-integer"),
+            },
+            LowLevel::NumCountOneBits => match arg_layouts[0] {
+                Layout::Builtin(Builtin::Int(int_width @ (IntWidth::U128 | IntWidth::I128))) => {
+                    self.build_fn_call(
+                        sym,
+                        bitcode::NUM_COUNT_ONE_BITS[int_width].to_string(),
+                        args,
+                        arg_layouts,
+                        ret_layout,
+                    )
+                }
+                Layout::Builtin(Builtin::Int(int_width)) => {
+                    self.build_int_count_one_bits(sym, &args[0], int_width)
+                }
+                _ => internal_error!("count one bits on a non-integer"),
+            },
+            LowLevel::NumToBytesLe | LowLevel::NumToBytesBe => {
+                let int_width = match arg_layouts[0] {
+                    Layout::Builtin(Builtin::Int(int_width)) => int_width,
+                    _ => internal_error!("to bytes on a non-integer"),
+                };
+                let intrinsic = if *lowlevel == LowLevel::NumToBytesLe {
+                    &bitcode::NUM_TO_BYTES_LE
+                } else {
+                    &bitcode::NUM_TO_BYTES_BE
+                };
+                self.build_fn_call(
+                    sym,
+                    intrinsic[int_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                )
+            }
+            LowLevel::NumFromBytesLe | LowLevel::NumFromBytesBe => {
+                let int_width = match ret_layout {
+                    Layout::Struct {
+                        field_layouts: [Layout::Builtin(Builtin::Int(int_width)), ..],
+                        ..
+                    } => *int_width,
+                    _ => internal_error!("from bytes must return an integer and a Bool"),
+                };
+                let intrinsic = if *lowlevel == LowLevel::NumFromBytesLe {
+                    &bitcode::NUM_FROM_BYTES_LE
+                } else {
+                    &bitcode::NUM_FROM_BYTES_BE
+                };
+                self.build_fn_call(
+                    sym,
+                    intrinsic[int_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                )
+            }
+            LowLevel::NumF64ToBits => {
+                debug_assert_eq!(
+                    1,
+                    args.len(),
+                    "NumF64ToBits: expected to have exactly one argument"
+                );
+                self.build_num_f64_to_bits(sym, &args[0])
+            }
+            LowLevel::NumF64FromBits => {
+                debug_assert_eq!(
+                    1,
+                    args.len(),
+                    "NumF64FromBits: expected to have exactly one argument"
+                );
+                self.build_num_f64_from_bits(sym, &args[0])
+            }
             LowLevel::Eq => {
                 debug_assert_eq!(2, args.len(), "Eq: expected to have exactly two argument");
                 debug_assert_eq!(
@@ -808,6 +908,31 @@ trait Backend<'a> {
         int_width: IntWidth,
     );
 
+    /// stores the number of leading zero bits of src into dst.
+    fn build_int_count_leading_zero_bits(
+        &mut self,
+        dst: &Symbol,
+        src: &Symbol,
+        int_width: IntWidth,
+    );
+
+    /// stores the number of trailing zero bits of src into dst.
+    fn build_int_count_trailing_zero_bits(
+        &mut self,
+        dst: &Symbol,
+        src: &Symbol,
+        int_width: IntWidth,
+    );
+
+    /// stores the number of one bits of src into dst.
+    fn build_int_count_one_bits(&mut self, dst: &Symbol, src: &Symbol, int_width: IntWidth);
+
+    /// stores the bits of the F64 src as a U64 into dst.
+    fn build_num_f64_to_bits(&mut self, dst: &Symbol, src: &Symbol);
+
+    /// stores the U64 src reinterpreted as an F64 into dst.
+    fn build_num_f64_from_bits(&mut self, dst: &Symbol, src: &Symbol);
+
     /// build_eq stores the result of `src1 == src2` into dst.
     fn build_eq(&mut self, dst: &Symbol, src1: &Symbol, src2: &Symbol, arg_layout: &Layout<'a>);
 
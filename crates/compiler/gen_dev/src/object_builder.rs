@@ -144,14 +144,7 @@ fn generate_wrapper<'a, B: Backend<'a>>(
     };
     output.add_symbol(symbol);
     if let Some(sym_id) = output.symbol_id(name) {
-        let reloc = write::Relocation {
-            offset: offset + proc_offset,
-            size: 32,
-            kind: RelocationKind::PltRelative,
-            encoding: RelocationEncoding::X86Branch,
-            symbol: sym_id,
-            addend: -4,
-        };
+        let reloc = function_call_relocation(output, offset + proc_offset, sym_id);
 
         match output.add_relocation(text_section, reloc) {
             Ok(obj) => obj,
@@ -162,6 +155,40 @@ fn generate_wrapper<'a, B: Backend<'a>>(
     }
 }
 
+/// Generates the relocation for a direct call or tail call to `symbol`.
+/// On x86_64, `offset` points to the 32 bit displacement after the opcode.
+/// On aarch64, `offset` points to the whole B or BL instruction.
+fn function_call_relocation(output: &Object, offset: u64, symbol: SymbolId) -> write::Relocation {
+    match output.architecture() {
+        Architecture::X86_64 => write::Relocation {
+            offset,
+            size: 32,
+            kind: RelocationKind::PltRelative,
+            encoding: RelocationEncoding::X86Branch,
+            symbol,
+            addend: -4,
+        },
+        Architecture::Aarch64 => {
+            let kind = match output.format() {
+                BinaryFormat::MachO => RelocationKind::MachO {
+                    value: object::macho::ARM64_RELOC_BRANCH26,
+                    relative: true,
+                },
+                _ => RelocationKind::PltRelative,
+            };
+            write::Relocation {
+                offset,
+                size: 26,
+                kind,
+                encoding: RelocationEncoding::AArch64Call,
+                symbol,
+                addend: 0,
+            }
+        }
+        arch => internal_error!("unsupported architecture for function calls: {:?}", arch),
+    }
+}
+
 fn build_object<'a, B: Backend<'a>>(
     procedures: MutMap<(symbol::Symbol, ProcLayout<'a>), Proc<'a>>,
     mut backend: B,
@@ -459,14 +486,7 @@ fn build_proc<'a, B: Backend<'a>>(
                     }
                 }
                 if let Some(sym_id) = output.symbol_id(name.as_bytes()) {
-                    write::Relocation {
-                        offset: offset + proc_offset,
-                        size: 32,
-                        kind: RelocationKind::PltRelative,
-                        encoding: RelocationEncoding::X86Branch,
-                        symbol: sym_id,
-                        addend: -4,
-                    }
+                    function_call_relocation(output, offset + proc_offset, sym_id)
                 } else {
                     internal_error!("failed to find fn symbol for {:?}", name);
                 }
@@ -638,6 +638,31 @@ fn add_intrinsics<'ctx>(ctx: &'ctx Context, module: &Module<'ctx>) {
     add_int_intrinsic(ctx, module, &LLVM_SUB_SATURATED, |t| {
         t.fn_type(&[t.into(), t.into()], false)
     });
+
+    add_int_intrinsic(ctx, module, &LLVM_CTLZ, |t| {
+        t.fn_type(&[t.into(), i1_type.into()], false)
+    });
+
+    add_int_intrinsic(ctx, module, &LLVM_CTTZ, |t| {
+        t.fn_type(&[t.into(), i1_type.into()], false)
+    });
+
+    add_int_intrinsic(ctx, module, &LLVM_CTPOP, |t| t.fn_type(&[t.into()], false));
+
+    // llvm.bswap is only defined for types with an even number of bytes
+    for (int_width, int_type) in [
+        (IntWidth::U16, ctx.i16_type()),
+        (IntWidth::U32, ctx.i32_type()),
+        (IntWidth::U64, ctx.i64_type()),
+        (IntWidth::U128, ctx.i128_type()),
+    ] {
+        add_intrinsic(
+            ctx,
+            module,
+            &LLVM_BSWAP[int_width],
+            int_type.fn_type(&[int_type.into()], false),
+        );
+    }
 }
 
 const LLVM_POW: IntrinsicName = float_intrinsic!("llvm.pow");
@@ -670,6 +695,11 @@ const LLVM_MUL_WITH_OVERFLOW: IntrinsicName =
 const LLVM_ADD_SATURATED: IntrinsicName = llvm_int_intrinsic!("llvm.sadd.sat", "llvm.uadd.sat");
 const LLVM_SUB_SATURATED: IntrinsicName = llvm_int_intrinsic!("llvm.ssub.sat", "llvm.usub.sat");
 
+const LLVM_CTLZ: IntrinsicName = llvm_int_intrinsic!("llvm.ctlz", "llvm.ctlz");
+const LLVM_CTTZ: IntrinsicName = llvm_int_intrinsic!("llvm.cttz", "llvm.cttz");
+const LLVM_CTPOP: IntrinsicName = llvm_int_intrinsic!("llvm.ctpop", "llvm.ctpop");
+const LLVM_BSWAP: IntrinsicName = llvm_int_intrinsic!("llvm.bswap", "llvm.bswap");
+
 fn add_intrinsic<'ctx>(
     context: &Context,
     module: &Module<'ctx>,
@@ -6257,9 +6287,24 @@ fn run_low_level<'a, 'ctx, 'env>(
                 _ => unreachable!(),
             }
         }
-        NumAbs | NumNeg | NumRound | NumSqrtUnchecked | NumLogUnchecked | NumSin | NumCos
-        | NumCeiling | NumFloor | NumToFrac | NumIsFinite | NumAtan | NumAcos | NumAsin
-        | NumToIntChecked => {
+        NumAbs
+        | NumNeg
+        | NumRound
+        | NumSqrtUnchecked
+        | NumLogUnchecked
+        | NumSin
+        | NumCos
+        | NumCeiling
+        | NumFloor
+        | NumToFrac
+        | NumIsFinite
+        | NumAtan
+        | NumAcos
+        | NumAsin
+        | NumToIntChecked
+        | NumCountLeadingZeroBits
+        | NumCountTrailingZeroBits
+        | NumCountOneBits => {
             debug_assert_eq!(args.len(), 1);
 
             let (arg, arg_layout) = load_symbol_and_layout(scope, &args[0]);
@@ -6325,6 +6370,40 @@ fn run_low_level<'a, 'ctx, 'env>(
                 bitcode::NUM_BYTES_TO_U32,
             )
         }
+        NumToBytesLe | NumToBytesBe => {
+            // Num.toBytesLe : Int a -> List U8
+            debug_assert_eq!(args.len(), 1);
+
+            let (arg, arg_layout) = load_symbol_and_layout(scope, &args[0]);
+            let int_width = intwidth_from_layout(*arg_layout);
+
+            int_to_bytes(env, arg.into_int_value(), int_width, op == NumToBytesBe)
+        }
+        NumFromBytesLe | NumFromBytesBe => {
+            // Num.fromBytesLeLowlevel : List U8, Nat -> { b : Bool, a : Int a }
+            debug_assert_eq!(args.len(), 2);
+
+            let list = load_symbol(scope, &args[0]).into_struct_value();
+            let index = load_symbol(scope, &args[1]).into_int_value();
+
+            int_from_bytes(env, parent, list, index, layout, op == NumFromBytesBe)
+        }
+        NumF64ToBits => {
+            debug_assert_eq!(args.len(), 1);
+
+            let arg = load_symbol(scope, &args[0]).into_float_value();
+
+            env.builder
+                .build_bitcast(arg, env.context.i64_type(), "f64_to_bits")
+        }
+        NumF64FromBits => {
+            debug_assert_eq!(args.len(), 1);
+
+            let arg = load_symbol(scope, &args[0]).into_int_value();
+
+            env.builder
+                .build_bitcast(arg, env.context.f64_type(), "f64_from_bits")
+        }
         NumCompare => {
             use inkwell::FloatPredicate;
 
@@ -7842,12 +7921,203 @@ fn build_int_unary_op<'a, 'ctx, 'env>(
                 complex_bitcast_check_size(env, result, return_type.into(), "cast_bitpacked")
             }
         }
+        NumCountLeadingZeroBits | NumCountTrailingZeroBits | NumCountOneBits => {
+            // the second argument of ctlz/cttz says whether a zero input is poison;
+            // we want a zero input to produce the bit width instead
+            let is_zero_poison = env.context.bool_type().const_zero();
+
+            let count = match op {
+                NumCountLeadingZeroBits => {
+                    env.call_intrinsic(&LLVM_CTLZ[arg_width], &[arg.into(), is_zero_poison.into()])
+                }
+                NumCountTrailingZeroBits => {
+                    env.call_intrinsic(&LLVM_CTTZ[arg_width], &[arg.into(), is_zero_poison.into()])
+                }
+                _ => env.call_intrinsic(&LLVM_CTPOP[arg_width], &[arg.into()]),
+            };
+
+            // the intrinsics return the argument's type, but the count always fits in a U8
+            bd.build_int_cast_sign_flag(
+                count.into_int_value(),
+                env.context.i8_type(),
+                false,
+                "count_to_u8",
+            )
+            .into()
+        }
         _ => {
             unreachable!("Unrecognized int unary operation: {:?}", op);
         }
     }
 }
 
+fn swap_bytes<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    value: IntValue<'ctx>,
+    int_width: IntWidth,
+) -> IntValue<'ctx> {
+    if int_width.stack_size() == 1 {
+        value
+    } else {
+        env.call_intrinsic(&LLVM_BSWAP[int_width], &[value.into()])
+            .into_int_value()
+    }
+}
+
+/// Num.toBytesLe : Int a -> List U8
+/// Num.toBytesBe : Int a -> List U8
+fn int_to_bytes<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    value: IntValue<'ctx>,
+    int_width: IntWidth,
+    big_endian: bool,
+) -> BasicValueEnum<'ctx> {
+    let builder = env.builder;
+
+    let len = env
+        .ptr_int()
+        .const_int(int_width.stack_size() as u64, false);
+    let ptr = allocate_list(env, &Layout::u8(), len);
+
+    // all of our targets are little-endian
+    let value = if big_endian {
+        swap_bytes(env, value, int_width)
+    } else {
+        value
+    };
+
+    let int_ptr = builder.build_pointer_cast(
+        ptr,
+        value.get_type().ptr_type(AddressSpace::Generic),
+        "cast_to_int_ptr",
+    );
+
+    // the allocation is only guaranteed to be aligned for bytes
+    builder
+        .build_store(int_ptr, value)
+        .set_alignment(1)
+        .unwrap();
+
+    build_list::store_list(env, ptr, len).into()
+}
+
+/// Num.fromBytesLeLowlevel : List U8, Nat -> { b : Bool, a : Int a }
+/// Num.fromBytesBeLowlevel : List U8, Nat -> { b : Bool, a : Int a }
+fn int_from_bytes<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    parent: FunctionValue<'ctx>,
+    list: StructValue<'ctx>,
+    index: IntValue<'ctx>,
+    return_layout: &Layout<'a>,
+    big_endian: bool,
+) -> BasicValueEnum<'ctx> {
+    let builder = env.builder;
+
+    let int_width = match return_layout {
+        Layout::Struct { field_layouts, .. } if field_layouts.len() == 2 => {
+            debug_assert!(matches!(field_layouts[1], Layout::Builtin(Builtin::Bool)));
+            match field_layouts[0] {
+                Layout::Builtin(Builtin::Int(iw)) => iw,
+                layout => {
+                    internal_error!("There can only be an int layout here, found {:?}!", layout)
+                }
+            }
+        }
+        layout => internal_error!(
+            "There can only be a result layout here, found {:?}!",
+            layout
+        ),
+    };
+
+    let int_type = convert::int_type_from_int_width(env, int_width);
+    let return_type = basic_type_from_layout(env, return_layout).into_struct_type();
+
+    // in bounds when `index <= len && len - index >= width`; the subtraction may wrap when
+    // the first condition is false, but then its result is ignored
+    let len = list_len(builder, list);
+    let width = env
+        .ptr_int()
+        .const_int(int_width.stack_size() as u64, false);
+    let index_in_bounds = builder.build_int_compare(IntPredicate::ULE, index, len, "index_le_len");
+    let remaining = builder.build_int_sub(len, index, "remaining");
+    let enough_bytes = builder.build_int_compare(IntPredicate::UGE, remaining, width, "fits");
+    let in_bounds = builder.build_and(index_in_bounds, enough_bytes, "in_bounds");
+
+    let read_block = env.context.append_basic_block(parent, "read_bytes");
+    let out_of_bounds_block = env.context.append_basic_block(parent, "out_of_bounds");
+    let cont_block = env.context.append_basic_block(parent, "from_bytes_cont");
+
+    builder.build_conditional_branch(in_bounds, read_block, out_of_bounds_block);
+
+    let read_result = {
+        builder.position_at_end(read_block);
+
+        let bytes_ptr = build_list::load_list_ptr(
+            builder,
+            list,
+            env.context.i8_type().ptr_type(AddressSpace::Generic),
+        );
+        let byte_ptr = unsafe { builder.build_in_bounds_gep(bytes_ptr, &[index], "byte_ptr") };
+        let int_ptr = builder.build_pointer_cast(
+            byte_ptr,
+            int_type.ptr_type(AddressSpace::Generic),
+            "cast_to_int_ptr",
+        );
+
+        let loaded = builder.build_load(int_ptr, "load_int");
+
+        // the bytes can start at any index
+        loaded
+            .as_instruction_value()
+            .unwrap()
+            .set_alignment(1)
+            .unwrap();
+
+        // all of our targets are little-endian
+        let value = if big_endian {
+            swap_bytes(env, loaded.into_int_value(), int_width)
+        } else {
+            loaded.into_int_value()
+        };
+
+        let result = builder
+            .build_insert_value(return_type.const_zero(), value, 0, "insert_value")
+            .unwrap();
+
+        builder.build_unconditional_branch(cont_block);
+
+        result.into_struct_value()
+    };
+
+    let out_of_bounds_result = {
+        builder.position_at_end(out_of_bounds_block);
+
+        let result = builder
+            .build_insert_value(
+                return_type.const_zero(),
+                env.context.bool_type().const_int(1, false),
+                1,
+                "insert_out_of_bounds",
+            )
+            .unwrap();
+
+        builder.build_unconditional_branch(cont_block);
+
+        result.into_struct_value()
+    };
+
+    builder.position_at_end(cont_block);
+
+    let phi = builder.build_phi(return_type, "from_bytes_result");
+
+    phi.add_incoming(&[
+        (&read_result, read_block),
+        (&out_of_bounds_result, out_of_bounds_block),
+    ]);
+
+    phi.as_basic_value()
+}
+
 fn int_neg_raise_on_overflow<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     arg: IntValue<'ctx>,
@@ -812,8 +812,7 @@ impl<'a> LowLevelCall<'a> {
             NumAddWrap => match self.ret_layout {
                 Layout::Builtin(Builtin::Int(width)) => match width {
                     IntWidth::I128 | IntWidth::U128 => {
-                        // TODO: don't panic
-                        self.load_args_and_call_zig(backend, &bitcode::NUM_ADD_OR_PANIC_INT[width])
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_ADD_WRAP_INT[width])
                     }
                     IntWidth::I64 | IntWidth::U64 => {
                         self.load_args(backend);
@@ -905,8 +904,7 @@ impl<'a> LowLevelCall<'a> {
             NumSubWrap => match self.ret_layout {
                 Layout::Builtin(Builtin::Int(width)) => match width {
                     IntWidth::I128 | IntWidth::U128 => {
-                        // TODO: don't panic
-                        self.load_args_and_call_zig(backend, &bitcode::NUM_SUB_OR_PANIC_INT[width])
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_SUB_WRAP_INT[width])
                     }
                     IntWidth::I64 | IntWidth::U64 => {
                         self.load_args(backend);
@@ -995,8 +993,7 @@ impl<'a> LowLevelCall<'a> {
             NumMulWrap => match self.ret_layout {
                 Layout::Builtin(Builtin::Int(width)) => match width {
                     IntWidth::I128 | IntWidth::U128 => {
-                        // TODO: don't panic
-                        self.load_args_and_call_zig(backend, &bitcode::NUM_MUL_OR_PANIC_INT[width])
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_MUL_WRAP_INT[width])
                     }
                     IntWidth::I64 | IntWidth::U64 => {
                         self.load_args(backend);
@@ -1062,6 +1059,14 @@ impl<'a> LowLevelCall<'a> {
                     x => internal_error!("NumMulChecked is not defined for {:?}", x),
                 }
             }
+            NumGt | NumGte | NumLt | NumLte | NumCompare
+                if matches!(
+                    CodeGenNumType::for_symbol(backend, self.arguments[0]),
+                    I128 | Decimal
+                ) =>
+            {
+                self.compare_num128(backend)
+            }
             NumGt => {
                 self.load_args(backend);
                 match CodeGenNumType::for_symbol(backend, self.arguments[0]) {
@@ -1228,6 +1233,12 @@ impl<'a> LowLevelCall<'a> {
                             backend.code_builder.i64_div_u()
                         }
                     }
+                    I128 => match self.ret_layout {
+                        Layout::Builtin(Builtin::Int(width)) => {
+                            self.load_args_and_call_zig(backend, &bitcode::NUM_DIV_TRUNC[width])
+                        }
+                        _ => panic_ret_type(),
+                    },
                     x => todo!("{:?} for {:?}", self.lowlevel, x),
                 }
             }
@@ -1243,6 +1254,12 @@ impl<'a> LowLevelCall<'a> {
                 match CodeGenNumType::for_symbol(backend, self.arguments[0]) {
                     I32 => backend.code_builder.i32_rem_s(),
                     I64 => backend.code_builder.i64_rem_s(),
+                    I128 => match self.ret_layout {
+                        Layout::Builtin(Builtin::Int(width)) => {
+                            self.load_args_and_call_zig(backend, &bitcode::NUM_REM[width])
+                        }
+                        _ => panic_ret_type(),
+                    },
                     _ => todo!("{:?} for {:?}", self.lowlevel, self.ret_layout),
                 }
             }
@@ -1342,6 +1359,12 @@ impl<'a> LowLevelCall<'a> {
                         code_builder.get_local(tmp);
                     }
 
+                    I128 => match layout {
+                        Layout::Builtin(Builtin::Int(width)) => self
+                            .load_args_and_call_zig(backend, &bitcode::NUM_IS_MULTIPLE_OF[width]),
+                        _ => panic_ret_type(),
+                    },
+
                     _ => panic_ret_type(),
                 }
             }
@@ -1406,6 +1429,12 @@ impl<'a> LowLevelCall<'a> {
                     }
                     F32 => backend.code_builder.f32_abs(),
                     F64 => backend.code_builder.f64_abs(),
+                    I128 => match self.ret_layout {
+                        Layout::Builtin(Builtin::Int(width)) => self
+                            .load_args_and_call_zig(backend, &bitcode::NUM_ABS_OR_PANIC_INT[width]),
+                        _ => panic_ret_type(),
+                    },
+                    Decimal => self.load_args_and_call_zig(backend, bitcode::DEC_ABS),
                     _ => todo!("{:?} for {:?}", self.lowlevel, self.ret_layout),
                 }
             }
@@ -1439,6 +1468,12 @@ impl<'a> LowLevelCall<'a> {
                     }
                     F32 => backend.code_builder.f32_neg(),
                     F64 => backend.code_builder.f64_neg(),
+                    I128 => match self.ret_layout {
+                        Layout::Builtin(Builtin::Int(width)) => self
+                            .load_args_and_call_zig(backend, &bitcode::NUM_NEG_OR_PANIC_INT[width]),
+                        _ => panic_ret_type(),
+                    },
+                    Decimal => self.load_args_and_call_zig(backend, bitcode::DEC_NEGATE),
                     _ => todo!("{:?} for {:?}", self.lowlevel, self.ret_layout),
                 }
             }
@@ -1476,22 +1511,8 @@ impl<'a> LowLevelCall<'a> {
                 _ => panic_ret_type(),
             },
             NumToFrac => {
-                self.load_args(backend);
                 let ret_type = CodeGenNumType::from(self.ret_layout);
-                let arg_type = CodeGenNumType::for_symbol(backend, self.arguments[0]);
-                match (ret_type, arg_type) {
-                    (F32, I32) => backend.code_builder.f32_convert_s_i32(),
-                    (F32, I64) => backend.code_builder.f32_convert_s_i64(),
-                    (F32, F32) => {}
-                    (F32, F64) => backend.code_builder.f32_demote_f64(),
-
-                    (F64, I32) => backend.code_builder.f64_convert_s_i32(),
-                    (F64, I64) => backend.code_builder.f64_convert_s_i64(),
-                    (F64, F32) => backend.code_builder.f64_promote_f32(),
-                    (F64, F64) => {}
-
-                    _ => todo!("{:?}: {:?} -> {:?}", self.lowlevel, arg_type, ret_type),
-                }
+                Self::load_num_as_float(backend, self.arguments[0], ret_type);
             }
             NumPow => match self.ret_layout {
                 Layout::Builtin(Builtin::Float(width)) => {
@@ -1507,7 +1528,10 @@ impl<'a> LowLevelCall<'a> {
                 let width = match ret_type {
                     CodeGenNumType::I32 => IntWidth::I32,
                     CodeGenNumType::I64 => IntWidth::I64,
-                    CodeGenNumType::I128 => todo!("{:?} for I128", self.lowlevel),
+                    CodeGenNumType::I128 => match self.ret_layout {
+                        Layout::Builtin(Builtin::Int(width)) => width,
+                        _ => internal_error!("Expected an Int layout, found {:?}", self.ret_layout),
+                    },
                     _ => internal_error!("Invalid return type for round: {:?}", ret_type),
                 };
 
@@ -1542,7 +1566,22 @@ impl<'a> LowLevelCall<'a> {
                     (I32, F64) => backend.code_builder.i32_trunc_s_f64(),
                     (I64, F32) => backend.code_builder.i64_trunc_s_f32(),
                     (I64, F64) => backend.code_builder.i64_trunc_s_f64(),
-                    (I128, _) => todo!("{:?} for I128", self.lowlevel),
+                    (I128, F32 | F64) => {
+                        // The float is already integral, so rounding it just converts it
+                        let width = match self.ret_layout {
+                            Layout::Builtin(Builtin::Int(width)) => width,
+                            _ => internal_error!(
+                                "Expected an Int layout, found {:?}",
+                                self.ret_layout
+                            ),
+                        };
+                        let intrinsic = if arg_type == F32 {
+                            &bitcode::NUM_ROUND_F32
+                        } else {
+                            &bitcode::NUM_ROUND_F64
+                        };
+                        backend.call_host_fn_after_loading_args(&intrinsic[width], 2, false);
+                    }
                     _ => panic_ret_type(),
                 }
             }
@@ -1558,7 +1597,10 @@ impl<'a> LowLevelCall<'a> {
                 let width = match ret_type {
                     CodeGenNumType::I32 => IntWidth::I32,
                     CodeGenNumType::I64 => IntWidth::I64,
-                    CodeGenNumType::I128 => todo!("{:?} for I128", self.lowlevel),
+                    CodeGenNumType::I128 => match self.ret_layout {
+                        Layout::Builtin(Builtin::Int(width)) => width,
+                        _ => internal_error!("Expected an Int layout, found {:?}", self.ret_layout),
+                    },
                     _ => internal_error!("Invalid return type for pow: {:?}", ret_type),
                 };
 
@@ -1587,12 +1629,125 @@ impl<'a> LowLevelCall<'a> {
             },
             NumBytesToU16 => self.load_args_and_call_zig(backend, bitcode::NUM_BYTES_TO_U16),
             NumBytesToU32 => self.load_args_and_call_zig(backend, bitcode::NUM_BYTES_TO_U32),
+            NumCountLeadingZeroBits | NumCountTrailingZeroBits | NumCountOneBits => {
+                let num = self.arguments[0];
+                let arg_layout = backend.storage.symbol_layouts[&num];
+                match CodeGenNumType::from(arg_layout) {
+                    I32 => {
+                        let bit_width = 8 * arg_layout
+                            .stack_size(backend.env.layout_interner, TARGET_INFO)
+                            as i32;
+
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num]);
+
+                        // i8 and i16 are sign-extended in Wasm's i32 type.
+                        // Clear the upper bits so they don't get counted.
+                        if bit_width < 32 {
+                            backend.code_builder.i32_const((1 << bit_width) - 1);
+                            backend.code_builder.i32_and();
+                        }
+
+                        match self.lowlevel {
+                            NumCountLeadingZeroBits => {
+                                backend.code_builder.i32_clz();
+                                if bit_width < 32 {
+                                    backend.code_builder.i32_const(32 - bit_width);
+                                    backend.code_builder.i32_sub();
+                                }
+                            }
+                            NumCountTrailingZeroBits => {
+                                // Set the bit just above the number, so that zero gives the bit width
+                                if bit_width < 32 {
+                                    backend.code_builder.i32_const(1 << bit_width);
+                                    backend.code_builder.i32_or();
+                                }
+                                backend.code_builder.i32_ctz();
+                            }
+                            _ => backend.code_builder.i32_popcnt(),
+                        }
+                    }
+                    I64 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num]);
+                        match self.lowlevel {
+                            NumCountLeadingZeroBits => backend.code_builder.i64_clz(),
+                            NumCountTrailingZeroBits => backend.code_builder.i64_ctz(),
+                            _ => backend.code_builder.i64_popcnt(),
+                        }
+                        backend.code_builder.i32_wrap_i64();
+                    }
+                    I128 => {
+                        let width = match arg_layout {
+                            Layout::Builtin(Builtin::Int(width)) => width,
+                            _ => internal_error!("Expected an Int layout, found {:?}", arg_layout),
+                        };
+                        let intrinsic = match self.lowlevel {
+                            NumCountLeadingZeroBits => &bitcode::NUM_COUNT_LEADING_ZERO_BITS,
+                            NumCountTrailingZeroBits => &bitcode::NUM_COUNT_TRAILING_ZERO_BITS,
+                            _ => &bitcode::NUM_COUNT_ONE_BITS,
+                        };
+                        self.load_args_and_call_zig(backend, &intrinsic[width]);
+                    }
+                    _ => internal_error!(
+                        "Invalid argument layout for {:?}: {:?}",
+                        self.lowlevel,
+                        arg_layout
+                    ),
+                }
+            }
+            NumToBytesLe | NumToBytesBe => {
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                let width = match arg_layout {
+                    Layout::Builtin(Builtin::Int(width)) => width,
+                    _ => internal_error!("Expected an Int layout, found {:?}", arg_layout),
+                };
+                let intrinsic = if self.lowlevel == NumToBytesLe {
+                    &bitcode::NUM_TO_BYTES_LE
+                } else {
+                    &bitcode::NUM_TO_BYTES_BE
+                };
+                self.load_args_and_call_zig(backend, &intrinsic[width]);
+            }
+            NumFromBytesLe | NumFromBytesBe => {
+                let width = match self.ret_layout {
+                    Layout::Struct {
+                        field_layouts: &[Layout::Builtin(Builtin::Int(width)), ..],
+                        ..
+                    } => width,
+                    _ => internal_error!(
+                        "Invalid return layout for {:?}: {:?}",
+                        self.lowlevel,
+                        self.ret_layout
+                    ),
+                };
+                let intrinsic = if self.lowlevel == NumFromBytesLe {
+                    &bitcode::NUM_FROM_BYTES_LE
+                } else {
+                    &bitcode::NUM_FROM_BYTES_BE
+                };
+                self.load_args_and_call_zig(backend, &intrinsic[width]);
+            }
+            NumF64ToBits => {
+                self.load_args(backend);
+                backend.code_builder.i64_reinterpret_f64();
+            }
+            NumF64FromBits => {
+                self.load_args(backend);
+                backend.code_builder.f64_reinterpret_i64();
+            }
+            NumBitwiseAnd | NumBitwiseXor | NumBitwiseOr
+                if CodeGenNumType::from(self.ret_layout) == I128 =>
+            {
+                self.bitwise_num128(backend)
+            }
             NumBitwiseAnd => {
                 self.load_args(backend);
                 match CodeGenNumType::from(self.ret_layout) {
                     I32 => backend.code_builder.i32_and(),
                     I64 => backend.code_builder.i64_and(),
-                    I128 => todo!("{:?} for I128", self.lowlevel),
                     _ => panic_ret_type(),
                 }
             }
@@ -1601,7 +1756,6 @@ impl<'a> LowLevelCall<'a> {
                 match CodeGenNumType::from(self.ret_layout) {
                     I32 => backend.code_builder.i32_xor(),
                     I64 => backend.code_builder.i64_xor(),
-                    I128 => todo!("{:?} for I128", self.lowlevel),
                     _ => panic_ret_type(),
                 }
             }
@@ -1610,10 +1764,23 @@ impl<'a> LowLevelCall<'a> {
                 match CodeGenNumType::from(self.ret_layout) {
                     I32 => backend.code_builder.i32_or(),
                     I64 => backend.code_builder.i64_or(),
-                    I128 => todo!("{:?} for I128", self.lowlevel),
                     _ => panic_ret_type(),
                 }
             }
+            NumShiftLeftBy | NumShiftRightBy | NumShiftRightZfBy
+                if CodeGenNumType::from(self.ret_layout) == I128 =>
+            {
+                let width = match self.ret_layout {
+                    Layout::Builtin(Builtin::Int(width)) => width,
+                    _ => internal_error!("Expected an Int layout, found {:?}", self.ret_layout),
+                };
+                let intrinsic = match self.lowlevel {
+                    NumShiftLeftBy => &bitcode::NUM_SHIFT_LEFT_BY,
+                    NumShiftRightBy => &bitcode::NUM_SHIFT_RIGHT_BY,
+                    _ => &bitcode::NUM_SHIFT_RIGHT_ZF_BY,
+                };
+                self.load_args_and_call_zig(backend, &intrinsic[width]);
+            }
             NumShiftLeftBy => {
                 let num = self.arguments[0];
                 let bits = self.arguments[1];
@@ -1623,7 +1790,6 @@ impl<'a> LowLevelCall<'a> {
                 match CodeGenNumType::from(self.ret_layout) {
                     I32 => backend.code_builder.i32_shl(),
                     I64 => backend.code_builder.i64_shl(),
-                    I128 => todo!("{:?} for I128", self.lowlevel),
                     _ => panic_ret_type(),
                 }
             }
@@ -1671,7 +1837,6 @@ impl<'a> LowLevelCall<'a> {
                             .load_symbols(&mut backend.code_builder, &[num, bits]);
                         backend.code_builder.i64_shr_s();
                     }
-                    I128 => todo!("{:?} for I128", self.lowlevel),
                     _ => panic_ret_type(),
                 }
             }
@@ -1713,12 +1878,10 @@ impl<'a> LowLevelCall<'a> {
                             .load_symbols(&mut backend.code_builder, &[num, bits]);
                         backend.code_builder.i64_shr_u();
                     }
-                    I128 => todo!("{:?} for I128", self.lowlevel),
                     _ => panic_ret_type(),
                 }
             }
             NumIntCast => {
-                self.load_args(backend);
                 let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
                 let arg_type = CodeGenNumType::from(arg_layout);
                 let arg_width = match arg_layout {
@@ -1733,69 +1896,49 @@ impl<'a> LowLevelCall<'a> {
                 };
 
                 match (ret_type, arg_type) {
-                    (I32, I32) => self.wrap_small_int(backend, ret_width),
+                    (I32, I32) => {
+                        self.load_args(backend);
+                        self.wrap_small_int(backend, ret_width);
+                    }
                     (I32, I64) => {
+                        self.load_args(backend);
+                        backend.code_builder.i32_wrap_i64();
+                        self.wrap_small_int(backend, ret_width);
+                    }
+                    (I32, I128) => {
+                        Self::load_num128_low_half(backend, self.arguments[0]);
                         backend.code_builder.i32_wrap_i64();
                         self.wrap_small_int(backend, ret_width);
                     }
                     (I64, I32) => {
+                        self.load_args(backend);
                         if arg_width.is_signed() {
                             backend.code_builder.i64_extend_s_i32()
                         } else {
                             backend.code_builder.i64_extend_u_i32()
                         }
                     }
-                    (I64, I64) => {}
+                    (I64, I64) => {
+                        self.load_args(backend);
+                    }
+                    (I64, I128) => Self::load_num128_low_half(backend, self.arguments[0]),
+                    (I128, I32 | I64) => self.int_cast_to_num128(backend, arg_width),
+                    (I128, I128) => {
+                        let (ret_local, ret_offset) = self.ret_local_and_offset(backend);
+                        backend.storage.copy_value_to_memory(
+                            &mut backend.code_builder,
+                            ret_local,
+                            ret_offset,
+                            self.arguments[0],
+                        );
+                    }
 
                     _ => todo!("{:?}: {:?} -> {:?}", self.lowlevel, arg_type, ret_type),
                 }
             }
             NumToFloatCast => {
-                self.load_args(backend);
-                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
-                let arg_signed = match arg_layout {
-                    Layout::Builtin(Builtin::Int(w)) => w.is_signed(),
-                    Layout::Builtin(Builtin::Float(_)) => true, // unused
-                    Layout::Builtin(Builtin::Decimal) => true,
-                    x => internal_error!("Num.intCast is not defined for {:?}", x),
-                };
                 let ret_type = CodeGenNumType::from(self.ret_layout);
-                let arg_type = CodeGenNumType::from(arg_layout);
-
-                match (ret_type, arg_type) {
-                    (F32, F32) => {}
-                    (F32, F64) => backend.code_builder.f32_demote_f64(),
-                    (F32, I32) => {
-                        if arg_signed {
-                            backend.code_builder.f32_convert_s_i32()
-                        } else {
-                            backend.code_builder.f32_convert_u_i32()
-                        }
-                    }
-                    (F32, I64) => {
-                        if arg_signed {
-                            backend.code_builder.f32_convert_s_i64()
-                        } else {
-                            backend.code_builder.f32_convert_u_i64()
-                        }
-                    }
-                    (F64, F64) => {}
-                    (F64, I32) => {
-                        if arg_signed {
-                            backend.code_builder.f64_convert_s_i32()
-                        } else {
-                            backend.code_builder.f64_convert_u_i32()
-                        }
-                    }
-                    (F64, I64) => {
-                        if arg_signed {
-                            backend.code_builder.f64_convert_s_i64()
-                        } else {
-                            backend.code_builder.f64_convert_u_i64()
-                        }
-                    }
-                    _ => todo!("{:?}: {:?} -> {:?}", self.lowlevel, arg_type, ret_type),
-                }
+                Self::load_num_as_float(backend, self.arguments[0], ret_type);
             }
             NumToIntChecked => {
                 let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
@@ -1830,7 +1973,74 @@ impl<'a> LowLevelCall<'a> {
                 }
             }
             NumToFloatChecked => {
-                todo!("implement toF32Checked and toF64Checked");
+                // The return value is a struct { a: F32 or F64, b: Bool }, where b means "out of bounds"
+                let (ret_type, float_size) = match self.ret_layout {
+                    Layout::Struct {
+                        field_layouts: &[Layout::Builtin(Builtin::Float(width)), ..],
+                        ..
+                    } => match width {
+                        FloatWidth::F32 => (F32, 4),
+                        FloatWidth::F64 => (F64, 8),
+                        FloatWidth::F128 => todo!("{:?} for f128", self.lowlevel),
+                    },
+                    _ => internal_error!(
+                        "Invalid return layout for {:?}: {:?}",
+                        self.lowlevel,
+                        self.ret_layout
+                    ),
+                };
+                let arg = self.arguments[0];
+                let arg_type = CodeGenNumType::for_symbol(backend, arg);
+                let (ret_local, ret_offset) = self.ret_local_and_offset(backend);
+
+                backend.code_builder.get_local(ret_local);
+                Self::load_num_as_float(backend, arg, ret_type);
+                if ret_type == F32 {
+                    backend.code_builder.f32_store(Align::Bytes4, ret_offset);
+                } else {
+                    backend.code_builder.f64_store(Align::Bytes8, ret_offset);
+                }
+
+                // The number is out of bounds if converting it overflowed to infinity...
+                backend.code_builder.get_local(ret_local);
+                backend.code_builder.get_local(ret_local);
+                if ret_type == F32 {
+                    backend.code_builder.f32_load(Align::Bytes4, ret_offset);
+                    backend.code_builder.f32_abs();
+                    backend.code_builder.f32_const(f32::INFINITY);
+                    backend.code_builder.f32_eq();
+                } else {
+                    backend.code_builder.f64_load(Align::Bytes8, ret_offset);
+                    backend.code_builder.f64_abs();
+                    backend.code_builder.f64_const(f64::INFINITY);
+                    backend.code_builder.f64_eq();
+                }
+
+                // ...unless it was already infinite
+                match arg_type {
+                    F32 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[arg]);
+                        backend.code_builder.f32_abs();
+                        backend.code_builder.f32_const(f32::INFINITY);
+                        backend.code_builder.f32_ne();
+                        backend.code_builder.i32_and();
+                    }
+                    F64 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[arg]);
+                        backend.code_builder.f64_abs();
+                        backend.code_builder.f64_const(f64::INFINITY);
+                        backend.code_builder.f64_ne();
+                        backend.code_builder.i32_and();
+                    }
+                    _ => {}
+                }
+                backend
+                    .code_builder
+                    .i32_store8(Align::Bytes1, ret_offset + float_size);
             }
             And => {
                 self.load_args(backend);
@@ -1852,7 +2062,45 @@ impl<'a> LowLevelCall<'a> {
                 backend.storage.load_symbols(code_builder, self.arguments);
             }
 
-            Hash => todo!("{:?}", self.lowlevel),
+            Hash => {
+                // Arguments are the U64 seed followed by the value to hash
+                let seed = self.arguments[0];
+                let value = self.arguments[1];
+                let value_layout = backend.storage.symbol_layouts[&value];
+                match value_layout {
+                    Layout::Builtin(Builtin::Str) => {
+                        self.load_args_and_call_zig(backend, bitcode::UTILS_WYHASH_ROCSTR)
+                    }
+                    Layout::Builtin(
+                        Builtin::Int(_) | Builtin::Float(_) | Builtin::Decimal | Builtin::Bool,
+                    ) => {
+                        // Hash the bytes of the number, wherever it is stored
+                        let size =
+                            value_layout.stack_size(backend.env.layout_interner, TARGET_INFO);
+                        let (local, offset, _) = ensure_symbol_is_in_memory(
+                            backend,
+                            value,
+                            value_layout,
+                            backend.env.arena,
+                        );
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[seed]);
+                        backend.code_builder.get_local(local);
+                        if offset > 0 {
+                            backend.code_builder.i32_const(offset as i32);
+                            backend.code_builder.i32_add();
+                        }
+                        backend.code_builder.i32_const(size as i32);
+                        backend.call_host_fn_after_loading_args(bitcode::UTILS_WYHASH, 3, true);
+                    }
+                    x => internal_error!(
+                        "{:?} is only generated for numbers and strings, not {:?}",
+                        self.lowlevel,
+                        x
+                    ),
+                }
+            }
 
             Eq | NotEq => self.eq_or_neq(backend),
 
@@ -2031,6 +2279,245 @@ impl<'a> LowLevelCall<'a> {
         backend.code_builder.i32_and();
     }
 
+    /// Get the stack memory location of a 128-bit number
+    fn num128_location(backend: &WasmBackend<'a>, symbol: Symbol) -> StackMemoryLocation {
+        match backend.storage.get(&symbol) {
+            StoredValue::StackMemory { location, .. } => location.clone(),
+            _ => internal_error!("Expected {:?} to be stored in stack memory", symbol),
+        }
+    }
+
+    /// Load the low 64 bits of a 128-bit number, for truncating casts
+    fn load_num128_low_half(backend: &mut WasmBackend<'a>, symbol: Symbol) {
+        let (local_id, offset) = Self::num128_location(backend, symbol)
+            .local_and_offset(backend.storage.stack_frame_pointer);
+        backend.code_builder.get_local(local_id);
+        backend.code_builder.i64_load(Align::Bytes8, offset);
+    }
+
+    /// Get the local and offset for a return value in stack memory
+    fn ret_local_and_offset(&self, backend: &WasmBackend<'a>) -> (LocalId, u32) {
+        match &self.ret_storage {
+            StoredValue::StackMemory { location, .. } => {
+                location.local_and_offset(backend.storage.stack_frame_pointer)
+            }
+            _ => internal_error!(
+                "Expected the return value of {:?} to be stored in stack memory",
+                self.lowlevel
+            ),
+        }
+    }
+
+    /// Less-than for 128-bit numbers. Compares the high halves, then the low halves if those are equal.
+    /// Loads *half* an argument at a time, like `eq_num128_bytes`
+    fn lt_num128(
+        backend: &mut WasmBackend<'a>,
+        locations: [StackMemoryLocation; 2],
+        is_signed: bool,
+    ) {
+        let (local0, offset0) = locations[0].local_and_offset(backend.storage.stack_frame_pointer);
+        let (local1, offset1) = locations[1].local_and_offset(backend.storage.stack_frame_pointer);
+
+        // Only the high half contains a sign bit
+        backend.code_builder.get_local(local0);
+        backend.code_builder.i64_load(Align::Bytes8, offset0 + 8);
+        backend.code_builder.get_local(local1);
+        backend.code_builder.i64_load(Align::Bytes8, offset1 + 8);
+        if is_signed {
+            backend.code_builder.i64_lt_s();
+        } else {
+            backend.code_builder.i64_lt_u();
+        }
+
+        backend.code_builder.get_local(local0);
+        backend.code_builder.i64_load(Align::Bytes8, offset0 + 8);
+        backend.code_builder.get_local(local1);
+        backend.code_builder.i64_load(Align::Bytes8, offset1 + 8);
+        backend.code_builder.i64_eq();
+
+        backend.code_builder.get_local(local0);
+        backend.code_builder.i64_load(Align::Bytes8, offset0);
+        backend.code_builder.get_local(local1);
+        backend.code_builder.i64_load(Align::Bytes8, offset1);
+        backend.code_builder.i64_lt_u();
+
+        // high0 < high1 || (high0 == high1 && low0 < low1)
+        backend.code_builder.i32_and();
+        backend.code_builder.i32_or();
+    }
+
+    /// Comparisons for I128, U128 and Dec
+    fn compare_num128(&self, backend: &mut WasmBackend<'a>) {
+        let is_signed = match backend.storage.symbol_layouts[&self.arguments[0]] {
+            Layout::Builtin(Builtin::Int(width)) => width.is_signed(),
+            _ => true, // Dec is a signed integer, scaled by 10^18
+        };
+        let x = Self::num128_location(backend, self.arguments[0]);
+        let y = Self::num128_location(backend, self.arguments[1]);
+
+        match self.lowlevel {
+            LowLevel::NumLt => Self::lt_num128(backend, [x, y], is_signed),
+            LowLevel::NumGt => Self::lt_num128(backend, [y, x], is_signed),
+            LowLevel::NumGte => {
+                Self::lt_num128(backend, [x, y], is_signed);
+                backend.code_builder.i32_eqz();
+            }
+            LowLevel::NumLte => {
+                Self::lt_num128(backend, [y, x], is_signed);
+                backend.code_builder.i32_eqz();
+            }
+            LowLevel::NumCompare => {
+                // (x != y) as u8 + (x < y) as u8, as for the other number types
+                Self::eq_num128_bytes(backend, [x.clone(), y.clone()]);
+                backend.code_builder.i32_eqz();
+                Self::lt_num128(backend, [x, y], is_signed);
+                backend.code_builder.i32_add();
+            }
+            _ => internal_error!("{:?} is not a comparison", self.lowlevel),
+        }
+    }
+
+    /// Bitwise operations for I128 and U128, one half at a time
+    fn bitwise_num128(&self, backend: &mut WasmBackend<'a>) {
+        let (local0, offset0) = Self::num128_location(backend, self.arguments[0])
+            .local_and_offset(backend.storage.stack_frame_pointer);
+        let (local1, offset1) = Self::num128_location(backend, self.arguments[1])
+            .local_and_offset(backend.storage.stack_frame_pointer);
+        let (ret_local, ret_offset) = self.ret_local_and_offset(backend);
+
+        for half in [0, 8] {
+            backend.code_builder.get_local(ret_local);
+            backend.code_builder.get_local(local0);
+            backend.code_builder.i64_load(Align::Bytes8, offset0 + half);
+            backend.code_builder.get_local(local1);
+            backend.code_builder.i64_load(Align::Bytes8, offset1 + half);
+            match self.lowlevel {
+                LowLevel::NumBitwiseAnd => backend.code_builder.i64_and(),
+                LowLevel::NumBitwiseXor => backend.code_builder.i64_xor(),
+                LowLevel::NumBitwiseOr => backend.code_builder.i64_or(),
+                _ => internal_error!("{:?} is not a bitwise operation", self.lowlevel),
+            }
+            backend
+                .code_builder
+                .i64_store(Align::Bytes8, ret_offset + half);
+        }
+    }
+
+    /// Widen a 32 or 64-bit integer to 128 bits
+    fn int_cast_to_num128(&self, backend: &mut WasmBackend<'a>, arg_width: IntWidth) {
+        let arg = self.arguments[0];
+        let arg_is_i32 = CodeGenNumType::for_symbol(backend, arg) == CodeGenNumType::I32;
+        let (ret_local, ret_offset) = self.ret_local_and_offset(backend);
+
+        let load_arg_as_i64 = |backend: &mut WasmBackend<'a>| {
+            backend
+                .storage
+                .load_symbols(&mut backend.code_builder, &[arg]);
+            if arg_is_i32 {
+                if arg_width.is_signed() {
+                    backend.code_builder.i64_extend_s_i32();
+                } else {
+                    backend.code_builder.i64_extend_u_i32();
+                }
+            }
+        };
+
+        backend.code_builder.get_local(ret_local);
+        load_arg_as_i64(backend);
+        backend.code_builder.i64_store(Align::Bytes8, ret_offset);
+
+        // The high half is all sign bits
+        backend.code_builder.get_local(ret_local);
+        if arg_width.is_signed() {
+            load_arg_as_i64(backend);
+            backend.code_builder.i64_const(63);
+            backend.code_builder.i64_shr_s();
+        } else {
+            backend.code_builder.i64_const(0);
+        }
+        backend
+            .code_builder
+            .i64_store(Align::Bytes8, ret_offset + 8);
+    }
+
+    /// Load a number onto the value stack, converted to F32 or F64
+    fn load_num_as_float(backend: &mut WasmBackend<'a>, arg: Symbol, ret_type: CodeGenNumType) {
+        use CodeGenNumType::*;
+
+        let arg_layout = backend.storage.symbol_layouts[&arg];
+        let arg_signed = match arg_layout {
+            Layout::Builtin(Builtin::Int(w)) => w.is_signed(),
+            Layout::Builtin(Builtin::Float(_)) => true, // unused
+            Layout::Builtin(Builtin::Decimal) => true,
+            x => internal_error!("Cannot convert {:?} to a float", x),
+        };
+        let arg_type = CodeGenNumType::from(arg_layout);
+
+        backend
+            .storage
+            .load_symbols(&mut backend.code_builder, &[arg]);
+
+        match (ret_type, arg_type) {
+            (F32, F32) => {}
+            (F32, F64) => backend.code_builder.f32_demote_f64(),
+            (F32, I32) => {
+                if arg_signed {
+                    backend.code_builder.f32_convert_s_i32()
+                } else {
+                    backend.code_builder.f32_convert_u_i32()
+                }
+            }
+            (F32, I64) => {
+                if arg_signed {
+                    backend.code_builder.f32_convert_s_i64()
+                } else {
+                    backend.code_builder.f32_convert_u_i64()
+                }
+            }
+            (F64, F64) => {}
+            (F64, F32) => backend.code_builder.f64_promote_f32(),
+            (F64, I32) => {
+                if arg_signed {
+                    backend.code_builder.f64_convert_s_i32()
+                } else {
+                    backend.code_builder.f64_convert_u_i32()
+                }
+            }
+            (F64, I64) => {
+                if arg_signed {
+                    backend.code_builder.f64_convert_s_i64()
+                } else {
+                    backend.code_builder.f64_convert_u_i64()
+                }
+            }
+            (F32 | F64, I128) => {
+                let width = match arg_layout {
+                    Layout::Builtin(Builtin::Int(width)) => width,
+                    x => internal_error!("Expected an Int layout, found {:?}", x),
+                };
+                let intrinsic = if ret_type == F32 {
+                    &bitcode::NUM_INT_TO_F32
+                } else {
+                    &bitcode::NUM_INT_TO_F64
+                };
+                backend.call_host_fn_after_loading_args(&intrinsic[width], 2, true);
+            }
+            (F32 | F64, Decimal) => {
+                backend.call_host_fn_after_loading_args(bitcode::DEC_TO_F64, 2, true);
+                if ret_type == F32 {
+                    backend.code_builder.f32_demote_f64();
+                }
+            }
+            (F128, _) | (_, F128) => {
+                internal_error!("Roc has no f128 type, so there is nothing to convert")
+            }
+            (I32 | I64 | I128 | Decimal, _) => internal_error!(
+                "load_num_as_float expects a float return type, not {:?}",
+                ret_type
+            ),
+        }
+    }
+
     fn num_to_str(&self, backend: &mut WasmBackend<'a>) {
         let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
         match arg_layout {
@@ -16,8 +16,9 @@ const SKIP_SUBS_CACHE: bool = {
 
 pub use roc_load_internal::docs;
 pub use roc_load_internal::file::{
-    EntryPoint, ExecutionMode, Expectations, LoadConfig, LoadResult, LoadStart, LoadedModule,
-    LoadingProblem, MonomorphizedModule, Phase, Threading,
+    EntryPoint, ExecutionMode, Expectations, GraphModule, LoadConfig, LoadResult, LoadStart,
+    LoadedModule, LoadingProblem, ModuleGraph, ModuleTiming, MonomorphizedModule, OptLevel, Phase,
+    PhaseSpan, Threading,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -40,6 +41,7 @@ pub fn load_single_threaded<'a>(
     target_info: TargetInfo,
     render: RenderTarget,
     exec_mode: ExecutionMode,
+    opt_level: OptLevel,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let cached_subs = read_cached_subs();
 
@@ -51,6 +53,8 @@ pub fn load_single_threaded<'a>(
         cached_subs,
         render,
         exec_mode,
+        opt_level,
+        Default::default(),
     )
 }
 
@@ -92,7 +96,7 @@ pub fn load_and_monomorphize_from_str<'a>(
 
     match load(arena, load_start, exposed_types, load_config)? {
         Monomorphized(module) => Ok(module),
-        TypeChecked(_) => unreachable!(""),
+        TypeChecked(_) | Headers(_) => unreachable!(""),
     }
 }
 
@@ -109,6 +113,7 @@ pub fn load_and_monomorphize(
     match load(arena, load_start, exposed_types, load_config)? {
         Monomorphized(module) => Ok(module),
         TypeChecked(module) => Err(LoadMonomorphizedError::ErrorModule(module)),
+        Headers(_) => unreachable!(""),
     }
 }
 
@@ -123,11 +128,28 @@ pub fn load_and_typecheck(
     let load_start = LoadStart::from_path(arena, filename, load_config.render)?;
 
     match load(arena, load_start, exposed_types, load_config)? {
-        Monomorphized(_) => unreachable!(""),
+        Monomorphized(_) | Headers(_) => unreachable!(""),
         TypeChecked(module) => Ok(module),
     }
 }
 
+/// Loads only the headers of the given module and the modules it depends on, which is enough to
+/// know which modules import which. The `exec_mode` of the config must be `ExecutionMode::Headers`.
+pub fn load_module_graph(
+    arena: &Bump,
+    filename: PathBuf,
+    load_config: LoadConfig,
+) -> Result<ModuleGraph, LoadingProblem<'_>> {
+    use LoadResult::*;
+
+    let load_start = LoadStart::from_path(arena, filename, load_config.render)?;
+
+    match load(arena, load_start, Default::default(), load_config)? {
+        Headers(graph) => Ok(graph),
+        TypeChecked(_) | Monomorphized(_) => unreachable!(""),
+    }
+}
+
 pub fn load_and_typecheck_str<'a>(
     arena: &'a Bump,
     filename: PathBuf,
@@ -151,8 +173,9 @@ pub fn load_and_typecheck_str<'a>(
         target_info,
         render,
         ExecutionMode::Check,
+        OptLevel::Development,
     )? {
-        Monomorphized(_) => unreachable!(""),
+        Monomorphized(_) | Headers(_) => unreachable!(""),
         TypeChecked(module) => Ok(module),
     }
 }
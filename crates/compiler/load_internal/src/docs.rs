@@ -2,11 +2,13 @@ use crate::docs::DocEntry::DetachedDoc;
 use crate::docs::TypeAnnotation::{Apply, BoundVariable, Function, NoTypeAnn, Record, TagUnion};
 use crate::file::LoadedModule;
 use roc_can::scope::Scope;
+use roc_collections::VecSet;
 use roc_module::ident::ModuleName;
-use roc_module::symbol::IdentIds;
+use roc_module::symbol::Symbol;
 use roc_parse::ast::AssignedField;
 use roc_parse::ast::{self, ExtractSpaces, TypeHeader};
 use roc_parse::ast::{CommentOrNewline, TypeDef, ValueDef};
+use roc_region::all::{Position, Region};
 
 // Documentation generation requirements
 
@@ -23,20 +25,36 @@ pub struct ModuleDocumentation {
     pub name: String,
     pub entries: Vec<DocEntry>,
     pub scope: Scope,
+    /// The names of the values, types and abilities the module's header exposes
+    pub exposed: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
 pub enum DocEntry {
     DocDef(DocDef),
-    DetachedDoc(String),
+    /// Docs that aren't attached to a def, and where they are in the module's source
+    DetachedDoc(String, Region),
 }
 
 #[derive(Debug, Clone)]
 pub struct DocDef {
     pub name: String,
+    pub kind: DocDefKind,
     pub type_vars: Vec<String>,
     pub type_annotation: TypeAnnotation,
     pub docs: Option<String>,
+    /// Where the def is in its module's source
+    pub region: Region,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocDefKind {
+    Value,
+    Alias,
+    /// An opaque type; its type annotation is always `NoTypeAnn`, since it is hidden
+    Opaque,
+    /// An ability; its type annotation is always `Ability`
+    Ability,
 }
 
 #[derive(Debug, Clone)]
@@ -99,47 +117,82 @@ pub struct Tag {
 pub fn generate_module_docs(
     scope: Scope,
     module_name: ModuleName,
+    src: &str,
     parsed_defs: &roc_parse::ast::Defs,
+    exposed_symbols: &VecSet<Symbol>,
 ) -> ModuleDocumentation {
-    let entries = generate_entry_docs(&scope.locals.ident_ids, parsed_defs);
+    let entries = generate_entry_docs(src, parsed_defs, |ident| {
+        scope.locals.ident_ids.get_id(ident).is_some()
+    });
+
+    let exposed = exposed_symbols
+        .iter()
+        .filter_map(|symbol| scope.locals.ident_ids.get_name(symbol.ident_id()))
+        .map(|name| name.to_string())
+        .collect();
 
     ModuleDocumentation {
         name: module_name.as_str().to_string(),
         scope,
         entries,
+        exposed,
     }
 }
 
+/// The comments and newlines start at `offset` in `src`, and each of them ends a line.
 fn detached_docs_from_comments_and_new_lines<'a>(
+    src: &str,
+    offset: usize,
     comments_or_new_lines: impl Iterator<Item = &'a roc_parse::ast::CommentOrNewline<'a>>,
-) -> Vec<String> {
-    let mut detached_docs: Vec<String> = Vec::new();
+) -> Vec<(String, Region)> {
+    let mut detached_docs: Vec<(String, Region)> = Vec::new();
 
     let mut docs = String::new();
+    let mut docs_start = offset;
+    let mut docs_end = offset;
+    let mut line_start = offset;
 
     for comment_or_new_line in comments_or_new_lines {
+        let line_end = match src[line_start..].find('\n') {
+            Some(index) => line_start + index,
+            None => src.len(),
+        };
+
         match comment_or_new_line {
             CommentOrNewline::DocComment(doc_str) => {
+                if docs.is_empty() {
+                    docs_start = line_start;
+                }
+
                 docs.push_str(doc_str);
                 docs.push('\n');
+                docs_end = line_end;
             }
 
             CommentOrNewline::LineComment(_) | CommentOrNewline::Newline => {
                 if !docs.is_empty() {
-                    detached_docs.push(docs.clone());
+                    let region = Region::new(
+                        Position::new(docs_start as u32),
+                        Position::new(docs_end as u32),
+                    );
+                    detached_docs.push((docs.clone(), region));
                 }
 
                 docs = String::new();
             }
         }
+
+        line_start = (line_end + 1).min(src.len());
     }
 
     detached_docs
 }
 
-fn generate_entry_docs<'a>(
-    ident_ids: &'a IdentIds,
-    defs: &roc_parse::ast::Defs<'a>,
+/// The docs of the defs whose identifier `is_in_scope`, and the docs after the last def.
+fn generate_entry_docs(
+    src: &str,
+    defs: &roc_parse::ast::Defs,
+    is_in_scope: impl Fn(&str) -> bool,
 ) -> Vec<DocEntry> {
     use roc_parse::ast::Pattern;
 
@@ -160,19 +213,22 @@ fn generate_entry_docs<'a>(
         scratchpad.extend(spaces_before);
 
         let docs = comments_or_new_lines_to_docs(&scratchpad);
+        let region = defs.regions[index];
 
         match either_index.split() {
             Err(value_index) => match &defs.value_defs[value_index.index()] {
                 ValueDef::Annotation(loc_pattern, loc_ann) => {
                     if let Pattern::Identifier(identifier) = loc_pattern.value {
                         // Check if the definition is exposed
-                        if ident_ids.get_id(identifier).is_some() {
+                        if is_in_scope(identifier) {
                             let name = identifier.to_string();
                             let doc_def = DocDef {
                                 name,
+                                kind: DocDefKind::Value,
                                 type_annotation: type_to_docs(false, loc_ann.value),
                                 type_vars: Vec::new(),
                                 docs,
+                                region,
                             };
                             acc.push(DocEntry::DocDef(doc_def));
                         }
@@ -186,12 +242,14 @@ fn generate_entry_docs<'a>(
                 } => {
                     if let Pattern::Identifier(identifier) = ann_pattern.value {
                         // Check if the definition is exposed
-                        if ident_ids.get_id(identifier).is_some() {
+                        if is_in_scope(identifier) {
                             let doc_def = DocDef {
                                 name: identifier.to_string(),
+                                kind: DocDefKind::Value,
                                 type_annotation: type_to_docs(false, ann_type.value),
                                 type_vars: Vec::new(),
                                 docs,
+                                region,
                             };
                             acc.push(DocEntry::DocDef(doc_def));
                         }
@@ -207,6 +265,10 @@ fn generate_entry_docs<'a>(
                 ValueDef::ExpectFx { .. } => {
                     // Don't generate docs for `expect-fx`s
                 }
+
+                ValueDef::Malformed(_) => {
+                    // Don't generate docs for defs that failed to parse
+                }
             },
             Ok(type_index) => match &defs.type_defs[type_index.index()] {
                 TypeDef::Alias {
@@ -223,9 +285,11 @@ fn generate_entry_docs<'a>(
 
                     let doc_def = DocDef {
                         name: name.value.to_string(),
+                        kind: DocDefKind::Alias,
                         type_annotation: type_to_docs(false, ann.value),
                         type_vars,
                         docs,
+                        region,
                     };
                     acc.push(DocEntry::DocDef(doc_def));
                 }
@@ -244,9 +308,11 @@ fn generate_entry_docs<'a>(
 
                     let doc_def = DocDef {
                         name: name.value.to_string(),
+                        kind: DocDefKind::Opaque,
                         type_annotation: TypeAnnotation::NoTypeAnn,
                         type_vars,
                         docs,
+                        region,
                     };
                     acc.push(DocEntry::DocDef(doc_def));
                 }
@@ -282,9 +348,11 @@ fn generate_entry_docs<'a>(
 
                     let doc_def = DocDef {
                         name: name.value.to_string(),
+                        kind: DocDefKind::Ability,
                         type_annotation: TypeAnnotation::Ability { members },
                         type_vars,
                         docs,
+                        region,
                     };
                     acc.push(DocEntry::DocDef(doc_def));
                 }
@@ -296,9 +364,14 @@ fn generate_entry_docs<'a>(
     }
 
     let it = before_comments_or_new_lines.iter().flat_map(|e| e.iter());
-
-    for detached_doc in detached_docs_from_comments_and_new_lines(it) {
-        acc.push(DetachedDoc(detached_doc));
+    let after_last_def = defs
+        .regions
+        .last()
+        .map_or(0, |region| region.end().offset as usize);
+
+    for (detached_doc, region) in detached_docs_from_comments_and_new_lines(src, after_last_def, it)
+    {
+        acc.push(DetachedDoc(detached_doc, region));
     }
 
     acc
@@ -474,3 +547,184 @@ fn comments_or_new_lines_to_docs<'a>(
         Some(docs)
     }
 }
+
+/// Turns the `roc` code blocks with `expect`s in them in the docs of a module into top-level
+/// `expect`s, so that `roc test --doc` runs them. For example
+///
+/// ```text
+/// ## ```roc
+/// ## x = 1
+/// ## expect x == 1
+/// ## ```
+/// ```
+///
+/// becomes a top-level `expect` whose body defines `x` and ends with `x == 1`. Every other
+/// `expect` in the code block becomes an inline `expect` in that body.
+///
+/// The code blocks are taken from the same docs that the module's [ModuleDocumentation] has. The
+/// rewritten source has exactly the same length and lines as the original, and the code of a doc
+/// test stays where it was, so regions in the rewritten source (e.g. the region of an `expect`
+/// that failed) point at the doc comment in the original source.
+///
+/// Returns `None` if the module has no doc tests.
+pub fn doc_tests_to_expects(src: &str, parsed_defs: &roc_parse::ast::Defs) -> Option<String> {
+    // Once the module is canonicalized, all of its top-level defs are in scope.
+    let entries = generate_entry_docs(src, parsed_defs, |_| true);
+
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(src.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let line_of = |offset: u32| line_starts.partition_point(|start| *start <= offset as usize) - 1;
+
+    let mut rewritten = src.as_bytes().to_vec();
+    let mut found_doc_tests = false;
+
+    for entry in entries {
+        let doc_lines = match &entry {
+            DocEntry::DocDef(DocDef {
+                docs: Some(docs),
+                region,
+                ..
+            }) => {
+                // the docs of a def are on the lines right above it
+                let def_line = line_of(region.start().offset);
+
+                match def_line.checked_sub(docs.lines().count()) {
+                    Some(first_line) => first_line..def_line,
+                    None => continue,
+                }
+            }
+            DocEntry::DocDef(_) => continue,
+            DocEntry::DetachedDoc(_, region) => {
+                line_of(region.start().offset)..line_of(region.end().offset) + 1
+            }
+        };
+
+        let lines: Vec<(usize, &str)> = doc_lines
+            .map(|line| {
+                let start = line_starts[line];
+                let end = line_starts.get(line + 1).copied().unwrap_or(src.len());
+
+                (start, src[start..end].trim_end_matches(&['\n', '\r'][..]))
+            })
+            .collect();
+
+        if rewrite_doc_tests(&mut rewritten, &lines) {
+            found_doc_tests = true;
+        }
+    }
+
+    if found_doc_tests {
+        // we only replaced whole characters with ASCII characters
+        Some(String::from_utf8(rewritten).unwrap())
+    } else {
+        None
+    }
+}
+
+/// Rewrites the code blocks with `expect`s in them in the lines of one doc comment.
+fn rewrite_doc_tests(rewritten: &mut [u8], lines: &[(usize, &str)]) -> bool {
+    // A doc comment that shares its line with code (e.g. `x = 1 ## ...`) is left alone, since
+    // rewriting its line would change that code.
+    if !lines.iter().all(|(_, line)| line.starts_with("##")) {
+        return false;
+    }
+
+    let mut found_doc_tests = false;
+
+    // the lines of the code block we're in, starting with its opening fence
+    let mut code_block: Option<Vec<(usize, &str)>> = None;
+
+    for &(offset, line) in lines {
+        let doc = line[2..].trim();
+
+        match code_block.as_mut() {
+            None => {
+                // Like `markdown_to_html` in the docs, we take code without a language to be Roc.
+                if matches!(doc, "```" | "```roc") {
+                    code_block = Some(vec![(offset, line)]);
+                }
+            }
+            Some(code_lines) if doc == "```" => {
+                code_lines.push((offset, line));
+
+                if rewrite_doc_test(rewritten, code_lines) {
+                    found_doc_tests = true;
+                }
+
+                code_block = None;
+            }
+            Some(code_lines) => code_lines.push((offset, line)),
+        }
+    }
+
+    found_doc_tests
+}
+
+/// Rewrites one code block (including its fences) into a top-level `expect`, if it has any
+/// `expect`s in it.
+fn rewrite_doc_test(rewritten: &mut [u8], lines: &[(usize, &str)]) -> bool {
+    let expect_keyword = |line: &str| {
+        let code = &line[2..];
+        let keyword = code.len() - code.trim_start().len();
+
+        match code.trim_start().strip_prefix("expect") {
+            // the condition can also start on the next line
+            Some(rest) if rest.is_empty() || rest.starts_with(' ') => Some(2 + keyword),
+            _ => None,
+        }
+    };
+
+    let (opening_fence, code, closing_fence) = match lines {
+        [opening_fence, code @ .., closing_fence] => (opening_fence, code, closing_fence),
+        _ => return false,
+    };
+
+    // The condition of the last `expect` is the final expression of the body.
+    let last_expect = match code
+        .iter()
+        .rposition(|(_, line)| expect_keyword(line).is_some())
+    {
+        Some(last_expect) => last_expect,
+        None => return false,
+    };
+
+    let (opening_offset, opening_line) = opening_fence;
+
+    if opening_line.len() < "expect".len() {
+        return false;
+    }
+
+    let mut replace = |offset: usize, with: &[u8]| {
+        rewritten[offset..offset + with.len()].copy_from_slice(with);
+    };
+
+    // "## ```roc" => "expect   "
+    replace(*opening_offset, b"expect");
+    replace(
+        opening_offset + "expect".len(),
+        &vec![b' '; opening_line.len() - "expect".len()],
+    );
+
+    for (index, (line_offset, line)) in code.iter().enumerate() {
+        if index == last_expect {
+            // "## expect x ==" => "   x ==         ", so that the lines after it continue the
+            // condition like they did before
+            let keyword = expect_keyword(line).unwrap();
+            let condition = line[keyword + "expect".len()..].trim_start();
+
+            let mut new_line = vec![b' '; line.len()];
+            new_line[keyword..keyword + condition.len()].copy_from_slice(condition.as_bytes());
+
+            replace(*line_offset, &new_line);
+        } else {
+            // "## x = 1" => "   x = 1"
+            replace(*line_offset, b"  ");
+        }
+    }
+
+    let (closing_offset, closing_line) = closing_fence;
+    replace(*closing_offset, &vec![b' '; closing_line.len()]);
+
+    true
+}
@@ -18,8 +18,8 @@ use roc_constrain::module::constrain_module;
 use roc_debug_flags::dbg_do;
 #[cfg(debug_assertions)]
 use roc_debug_flags::{
-    ROC_PRINT_IR_AFTER_REFCOUNT, ROC_PRINT_IR_AFTER_RESET_REUSE, ROC_PRINT_IR_AFTER_SPECIALIZATION,
-    ROC_PRINT_LOAD_LOG,
+    ROC_PRINT_IR_AFTER_INLINE, ROC_PRINT_IR_AFTER_REFCOUNT, ROC_PRINT_IR_AFTER_RESET_REUSE,
+    ROC_PRINT_IR_AFTER_SPECIALIZATION, ROC_PRINT_LOAD_LOG,
 };
 use roc_derive::SharedDerivedModule;
 use roc_error_macros::internal_error;
@@ -41,10 +41,11 @@ use roc_parse::ast::{self, Defs, ExtractSpaces, Spaced, StrLiteral, TypeAnnotati
 use roc_parse::header::{ExposedName, ImportsEntry, PackageEntry, PlatformHeader, To, TypedIdent};
 use roc_parse::header::{HeaderFor, ModuleNameEnum, PackageName};
 use roc_parse::ident::UppercaseIdent;
-use roc_parse::module::module_defs;
-use roc_parse::parser::{FileError, Parser, SyntaxError};
+use roc_parse::module::module_defs_with_recovery;
+use roc_parse::parser::{FileError, SyntaxError};
 use roc_region::all::{LineInfo, Loc, Region};
 use roc_reporting::report::{Annotation, RenderTarget};
+use roc_reporting::warnings::{Suppressions, WarningConfig};
 use roc_solve::module::{extract_module_owned_implementations, Solved, SolvedModule};
 use roc_solve_problem::TypeError;
 use roc_target::TargetInfo;
@@ -63,6 +64,7 @@ use std::{env, fs};
 
 use crate::work::Dependencies;
 pub use crate::work::Phase;
+pub use roc_mono::ir::OptLevel;
 
 #[cfg(target_family = "wasm")]
 use crate::wasm_instant::{Duration, Instant};
@@ -142,21 +144,33 @@ pub struct LoadConfig {
     pub render: RenderTarget,
     pub threading: Threading,
     pub exec_mode: ExecutionMode,
+    pub opt_level: OptLevel,
+    /// The warnings that are not reported. With `ExecutionMode::ExecutableIfCheck`, only the
+    /// problems that are reported stop the program from being built.
+    pub warning_config: WarningConfig,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum ExecutionMode {
     Test,
+    /// Like [`ExecutionMode::Test`], but also runs the `roc` code blocks in doc comments that
+    /// have `expect`s in them.
+    DocTest,
     Check,
     Executable,
     /// Like [`ExecutionMode::Executable`], but stops in the presence of type errors.
     ExecutableIfCheck,
+    /// Only loads the headers of the modules, to find out which modules import which.
+    Headers,
 }
 
 impl ExecutionMode {
     fn goal_phase(&self) -> Phase {
         match self {
-            ExecutionMode::Test | ExecutionMode::Executable => Phase::MakeSpecializations,
+            ExecutionMode::Headers => Phase::LoadHeader,
+            ExecutionMode::Test | ExecutionMode::DocTest | ExecutionMode::Executable => {
+                Phase::MakeSpecializations
+            }
             ExecutionMode::Check | ExecutionMode::ExecutableIfCheck => Phase::SolveTypes,
         }
     }
@@ -183,16 +197,23 @@ struct ModuleCache<'a> {
     imports: MutMap<ModuleId, MutSet<ModuleId>>,
     top_level_thunks: MutMap<ModuleId, MutSet<Symbol>>,
     documentation: MutMap<ModuleId, ModuleDocumentation>,
+    /// Rendered reports of the syntax errors the parser recovered from
+    syntax_problems: MutMap<ModuleId, Vec<String>>,
     can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: MutMap<ModuleId, Vec<TypeError>>,
 
     sources: MutMap<ModuleId, (PathBuf, &'a str)>,
+    suppressions: MutMap<ModuleId, Suppressions>,
 }
 
 impl<'a> ModuleCache<'a> {
     pub fn total_problems(&self) -> usize {
         let mut total = 0;
 
+        for problems in self.syntax_problems.values() {
+            total += problems.len();
+        }
+
         for problems in self.can_problems.values() {
             total += problems.len();
         }
@@ -203,6 +224,42 @@ impl<'a> ModuleCache<'a> {
 
         total
     }
+
+    /// Whether any of the problems will be reported, i.e. is not a warning that is allowed
+    fn has_reported_problems(&self, warning_config: &WarningConfig) -> bool {
+        if self
+            .syntax_problems
+            .values()
+            .any(|problems| !problems.is_empty())
+        {
+            return true;
+        }
+
+        let mut module_ids = self.can_problems.keys().chain(self.type_problems.keys());
+
+        module_ids.any(|module_id| {
+            let suppressions = self.suppressions.get(module_id);
+            let lines = self
+                .sources
+                .get(module_id)
+                .map(|(_, src)| LineInfo::new(src));
+            let is_reported = |kind, region: Option<Region>| {
+                let line = region
+                    .zip(lines.as_ref())
+                    .map(|(region, lines)| lines.convert_pos(region.start()).line);
+
+                !warning_config.allows_problem(kind, suppressions, line)
+            };
+
+            let can_problems = self.can_problems.get(module_id).into_iter().flatten();
+            let type_problems = self.type_problems.get(module_id).into_iter().flatten();
+
+            can_problems
+                .map(|problem| (problem.warning_kind(), problem.region()))
+                .chain(type_problems.map(|problem| (problem.warning_kind(), problem.region())))
+                .any(|(kind, region)| is_reported(kind, region))
+        })
+    }
 }
 
 impl Default for ModuleCache<'_> {
@@ -246,9 +303,11 @@ impl Default for ModuleCache<'_> {
             imports: Default::default(),
             top_level_thunks: Default::default(),
             documentation: Default::default(),
+            syntax_problems: Default::default(),
             can_problems: Default::default(),
             type_problems: Default::default(),
             sources: Default::default(),
+            suppressions: Default::default(),
             expectations: Default::default(),
         }
     }
@@ -308,7 +367,15 @@ fn start_phase<'a>(
                 // parse the file
                 let header = state.module_cache.headers.remove(&module_id).unwrap();
 
-                BuildTask::Parse { header }
+                // only the expects of the root package are run
+                let doc_tests = matches!(state.exec_mode, ExecutionMode::DocTest)
+                    && state
+                        .arc_modules
+                        .lock()
+                        .package_eq(module_id, state.root_id)
+                        .expect("root or this module is not yet known - that's a bug!");
+
+                BuildTask::Parse { header, doc_tests }
             }
             Phase::CanonicalizeAndConstrain => {
                 // canonicalize the file
@@ -480,8 +547,10 @@ fn start_phase<'a>(
 
                 let derived_module = SharedDerivedModule::clone(&state.derived_module);
 
-                let build_expects = matches!(state.exec_mode, ExecutionMode::Test)
-                    && state.module_cache.expectations.contains_key(&module_id);
+                let build_expects = matches!(
+                    state.exec_mode,
+                    ExecutionMode::Test | ExecutionMode::DocTest
+                ) && state.module_cache.expectations.contains_key(&module_id);
 
                 BuildTask::BuildPendingSpecializations {
                     layout_cache,
@@ -621,6 +690,8 @@ pub struct LoadedModule {
     pub module_id: ModuleId,
     pub interns: Interns,
     pub solved: Solved<Subs>,
+    /// Rendered reports of the syntax errors the parser recovered from
+    pub syntax_problems: MutMap<ModuleId, Vec<String>>,
     pub can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     pub type_problems: MutMap<ModuleId, Vec<TypeError>>,
     pub declarations_by_id: MutMap<ModuleId, Declarations>,
@@ -631,6 +702,8 @@ pub struct LoadedModule {
     pub exposed_types_storage: ExposedTypesStorageSubs,
     pub resolved_implementations: ResolvedImplementations,
     pub sources: MutMap<ModuleId, (PathBuf, Box<str>)>,
+    /// The warnings that `# roc-allow:` comments allow, for the modules that have such comments
+    pub suppressions: MutMap<ModuleId, Suppressions>,
     pub timings: MutMap<ModuleId, ModuleTiming>,
     pub documentation: MutMap<ModuleId, ModuleDocumentation>,
     pub abilities_store: AbilitiesStore,
@@ -640,6 +713,10 @@ impl LoadedModule {
     pub fn total_problems(&self) -> usize {
         let mut total = 0;
 
+        for problems in self.syntax_problems.values() {
+            total += problems.len();
+        }
+
         for problems in self.can_problems.values() {
             total += problems.len();
         }
@@ -659,6 +736,24 @@ impl LoadedModule {
     }
 }
 
+/// The modules of a program and the modules they import, as found by loading only their headers
+#[derive(Debug)]
+pub struct ModuleGraph {
+    pub root_id: ModuleId,
+    pub modules: MutMap<ModuleId, GraphModule>,
+}
+
+#[derive(Debug)]
+pub struct GraphModule {
+    /// The name of the module, qualified with the shorthand of its package, e.g. `pf.Task`
+    pub name: String,
+    pub path: PathBuf,
+    pub exposes: Vec<String>,
+    /// The modules this module imports, and where. This includes the builtin modules that every
+    /// module imports implicitly, which are imported at `Region::zero()`.
+    pub imports: MutMap<ModuleId, Region>,
+}
+
 #[derive(Debug)]
 pub enum BuildProblem<'a> {
     FileNotFound(&'a Path),
@@ -742,6 +837,8 @@ pub struct MonomorphizedModule<'a> {
     pub subs: Subs,
     pub layout_interner: SingleThreadedInterner<'a, Layout<'a>>,
     pub output_path: Box<Path>,
+    /// Rendered reports of the syntax errors the parser recovered from
+    pub syntax_problems: MutMap<ModuleId, Vec<String>>,
     pub can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     pub type_problems: MutMap<ModuleId, Vec<TypeError>>,
     pub procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
@@ -749,6 +846,8 @@ pub struct MonomorphizedModule<'a> {
     pub entry_point: EntryPoint<'a>,
     pub exposed_to_host: ExposedToHost,
     pub sources: MutMap<ModuleId, (PathBuf, Box<str>)>,
+    /// The warnings that `# roc-allow:` comments allow, for the modules that have such comments
+    pub suppressions: MutMap<ModuleId, Suppressions>,
     pub timings: MutMap<ModuleId, ModuleTiming>,
     pub expectations: VecMap<ModuleId, Expectations>,
 }
@@ -783,6 +882,10 @@ impl<'a> MonomorphizedModule<'a> {
     pub fn total_problems(&self) -> usize {
         let mut total = 0;
 
+        for problems in self.syntax_problems.values() {
+            total += problems.len();
+        }
+
         for problems in self.can_problems.values() {
             total += problems.len();
         }
@@ -800,12 +903,14 @@ struct ParsedModule<'a> {
     module_id: ModuleId,
     module_path: PathBuf,
     src: &'a str,
+    suppressions: Suppressions,
     module_timing: ModuleTiming,
     deps_by_name: MutMap<PQModuleName<'a>, ModuleId>,
     imported_modules: MutMap<ModuleId, Region>,
     exposed_ident_ids: IdentIds,
     exposed_imports: MutMap<Ident, (Symbol, Region)>,
     parsed_defs: Defs<'a>,
+    syntax_errors: Vec<FileError<'a, SyntaxError<'a>>>,
     module_name: ModuleNameEnum<'a>,
     symbols_from_requires: Vec<(Loc<Symbol>, Loc<TypeAnnotation<'a>>)>,
     header_for: HeaderFor<'a>,
@@ -864,6 +969,10 @@ enum Msg<'a> {
         subs: Subs,
     },
 
+    /// The task is to only load the headers of modules; the headers of all modules might be
+    /// loaded now, but packages that were not resolved yet can still add modules.
+    FinishedAllHeaders,
+
     /// The task is to only typecheck AND monomorphize modules
     /// all modules are now monomorphized, we are done
     FinishedAllSpecialization {
@@ -970,6 +1079,8 @@ struct State<'a> {
 
     pub render: RenderTarget,
     pub exec_mode: ExecutionMode,
+    pub opt_level: OptLevel,
+    pub warning_config: WarningConfig,
 
     /// All abilities across all modules.
     pub world_abilities: WorldAbilities,
@@ -1000,6 +1111,8 @@ impl<'a> State<'a> {
         render: RenderTarget,
         number_of_workers: usize,
         exec_mode: ExecutionMode,
+        opt_level: OptLevel,
+        warning_config: WarningConfig,
     ) -> Self {
         let arc_shorthands = Arc::new(Mutex::new(MutMap::default()));
 
@@ -1030,6 +1143,8 @@ impl<'a> State<'a> {
             cached_subs: Arc::new(Mutex::new(cached_subs)),
             render,
             exec_mode,
+            opt_level,
+            warning_config,
             make_specializations_pass: MakeSpecializationsPass::Pass(1),
             world_abilities: Default::default(),
             layout_interner: GlobalInterner::with_capacity(128),
@@ -1037,6 +1152,25 @@ impl<'a> State<'a> {
     }
 }
 
+/// When one phase of compiling a module ran, and on which thread
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseSpan {
+    pub phase: &'static str,
+    pub start: Instant,
+    pub end: Instant,
+    /// Numbers the threads in the order they first recorded a span, starting at 1
+    pub thread: usize,
+}
+
+thread_local! {
+    static THREAD_NUMBER: usize = {
+        static NEXT_THREAD_NUMBER: std::sync::atomic::AtomicUsize =
+            std::sync::atomic::AtomicUsize::new(1);
+
+        NEXT_THREAD_NUMBER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    };
+}
+
 #[derive(Debug)]
 pub struct ModuleTiming {
     pub read_roc_file: Duration,
@@ -1049,6 +1183,8 @@ pub struct ModuleTiming {
     // indexed by make specializations pass
     pub make_specializations: Vec<Duration>,
     // TODO pub monomorphize: Duration,
+    /// Every phase that ran, in the order they ran in, e.g. to make a profile of the compiler
+    pub spans: Vec<PhaseSpan>,
     /// Total duration will always be more than the sum of the other fields, due
     /// to things like state lookups in between phases, waiting on other threads, etc.
     start_time: Instant,
@@ -1066,6 +1202,7 @@ impl ModuleTiming {
             solve: Duration::default(),
             find_specializations: Duration::default(),
             make_specializations: Vec::with_capacity(2),
+            spans: Vec::new(),
             start_time,
             end_time: start_time, // just for now; we'll overwrite this at the end
         }
@@ -1075,6 +1212,20 @@ impl ModuleTiming {
         self.end_time.duration_since(self.start_time)
     }
 
+    /// Records that a phase ran on the current thread, and returns how long it took
+    fn record(&mut self, phase: &'static str, start: Instant, end: Instant) -> Duration {
+        let thread = THREAD_NUMBER.with(|number| *number);
+
+        self.spans.push(PhaseSpan {
+            phase,
+            start,
+            end,
+            thread,
+        });
+
+        end.duration_since(start)
+    }
+
     /// Subtract all the other fields from total_start_to_finish
     pub fn other(&self) -> Duration {
         let Self {
@@ -1086,6 +1237,7 @@ impl ModuleTiming {
             solve,
             find_specializations,
             make_specializations,
+            spans: _,
             start_time,
             end_time,
         } = self;
@@ -1119,6 +1271,8 @@ enum BuildTask<'a> {
     },
     Parse {
         header: ModuleHeader<'a>,
+        /// Whether to turn the code blocks in doc comments into `expect`s
+        doc_tests: bool,
     },
     CanonicalizeAndConstrain {
         parsed: ParsedModule<'a>,
@@ -1243,10 +1397,12 @@ pub fn load_and_typecheck_str<'a>(
         render,
         threading,
         exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Development,
+        warning_config: Default::default(),
     };
 
     match load(arena, load_start, exposed_types, cached_subs, load_config)? {
-        Monomorphized(_) => unreachable!(""),
+        Monomorphized(_) | Headers(_) => unreachable!(""),
         TypeChecked(module) => Ok(module),
     }
 }
@@ -1388,6 +1544,7 @@ impl<'a> LoadStart<'a> {
 }
 
 pub enum LoadResult<'a> {
+    Headers(ModuleGraph),
     TypeChecked(LoadedModule),
     Monomorphized(MonomorphizedModule<'a>),
 }
@@ -1490,6 +1647,8 @@ pub fn load<'a>(
             cached_subs,
             load_config.render,
             load_config.exec_mode,
+            load_config.opt_level,
+            load_config.warning_config,
         ),
         Threads::Many(threads) => load_multi_threaded(
             arena,
@@ -1500,6 +1659,8 @@ pub fn load<'a>(
             load_config.render,
             threads,
             load_config.exec_mode,
+            load_config.opt_level,
+            load_config.warning_config,
         ),
     }
 }
@@ -1514,6 +1675,8 @@ pub fn load_single_threaded<'a>(
     cached_subs: MutMap<ModuleId, (Subs, Vec<(Symbol, Variable)>)>,
     render: RenderTarget,
     exec_mode: ExecutionMode,
+    opt_level: OptLevel,
+    warning_config: WarningConfig,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
         arc_modules,
@@ -1541,6 +1704,8 @@ pub fn load_single_threaded<'a>(
         render,
         number_of_workers,
         exec_mode,
+        opt_level,
+        warning_config,
     );
 
     // We'll add tasks to this, and then worker threads will take tasks from it.
@@ -1629,6 +1794,17 @@ fn state_thread_step<'a>(
 
                     Ok(ControlFlow::Break(LoadResult::TypeChecked(typechecked)))
                 }
+                Msg::FinishedAllHeaders => {
+                    // a header may have been loaded after this message was sent, and that header
+                    // can depend on more modules
+                    if state.dependencies.solved_all() {
+                        let graph = finish_headers(state);
+
+                        Ok(ControlFlow::Break(LoadResult::Headers(graph)))
+                    } else {
+                        Ok(ControlFlow::Continue(state))
+                    }
+                }
                 Msg::FinishedAllSpecialization {
                     subs,
                     layout_interner,
@@ -1718,6 +1894,8 @@ fn load_multi_threaded<'a>(
     render: RenderTarget,
     available_threads: usize,
     exec_mode: ExecutionMode,
+    opt_level: OptLevel,
+    warning_config: WarningConfig,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
         arc_modules,
@@ -1760,6 +1938,8 @@ fn load_multi_threaded<'a>(
         render,
         num_workers,
         exec_mode,
+        opt_level,
+        warning_config,
     );
 
     // an arena for every worker, stored in an arena-allocated bumpalo vec to make the lifetimes work
@@ -2314,14 +2494,47 @@ fn update<'a>(
 
             start_tasks(arena, &mut state, work, injector, worker_listeners)?;
 
+            if state.goal_phase() == Phase::LoadHeader && state.dependencies.solved_all() {
+                msg_tx
+                    .send(Msg::FinishedAllHeaders)
+                    .map_err(|_| LoadingProblem::MsgChannelDied)?;
+            }
+
             Ok(state)
         }
-        Parsed(parsed) => {
+        Parsed(mut parsed) => {
             state
                 .module_cache
                 .sources
                 .insert(parsed.module_id, (parsed.module_path.clone(), parsed.src));
 
+            if !parsed.suppressions.is_empty() {
+                state
+                    .module_cache
+                    .suppressions
+                    .insert(parsed.module_id, std::mem::take(&mut parsed.suppressions));
+            }
+
+            if !parsed.syntax_errors.is_empty() {
+                let module_ids = (*state.arc_modules).lock().clone().into_module_ids();
+                let reports = std::mem::take(&mut parsed.syntax_errors)
+                    .into_iter()
+                    .map(|problem| {
+                        to_parse_problem_report(
+                            problem,
+                            module_ids.clone(),
+                            IdentIds::exposed_builtins(0),
+                            state.render,
+                        )
+                    })
+                    .collect();
+
+                state
+                    .module_cache
+                    .syntax_problems
+                    .insert(parsed.module_id, reports);
+            }
+
             // If this was an app module, set the output path to be
             // the module's declared "name".
             //
@@ -2445,7 +2658,7 @@ fn update<'a>(
 
             let add_to_host_exposed = is_host_exposed &&
                 // During testing, we don't need to expose anything to the host.
-                !matches!(state.exec_mode, ExecutionMode::Test);
+                !matches!(state.exec_mode, ExecutionMode::Test | ExecutionMode::DocTest);
 
             if add_to_host_exposed {
                 state.exposed_to_host.values.extend(
@@ -2453,7 +2666,9 @@ fn update<'a>(
                         .exposed_vars_by_symbol
                         .iter()
                         .filter_map(|(k, v)| {
-                            if abilities_store.is_specialization_name(*k) {
+                            if abilities_store.is_specialization_name(*k)
+                                || abilities_store.is_member_default(*k)
+                            {
                                 None
                             } else {
                                 Some((*k, *v))
@@ -2470,7 +2685,7 @@ fn update<'a>(
             let finish_type_checking = is_host_exposed &&
                 (state.goal_phase() == Phase::SolveTypes)
                 // If we're running in check-and-then-build mode, only exit now there are errors.
-                && (!matches!(state.exec_mode, ExecutionMode::ExecutableIfCheck) || state.module_cache.total_problems() > 0);
+                && (!matches!(state.exec_mode, ExecutionMode::ExecutableIfCheck) || state.module_cache.has_reported_problems(&state.warning_config));
 
             if finish_type_checking {
                 debug_assert!(work.is_empty());
@@ -2754,6 +2969,25 @@ fn update<'a>(
 
                     let ident_ids = state.constrained_ident_ids.get_mut(&module_id).unwrap();
 
+                    Proc::merge_mutually_tail_recursive(
+                        arena,
+                        module_id,
+                        ident_ids,
+                        &mut update_mode_ids,
+                        &mut state.procedures,
+                    );
+
+                    Proc::inline_and_fold(
+                        arena,
+                        module_id,
+                        ident_ids,
+                        &mut update_mode_ids,
+                        state.opt_level,
+                        &mut state.procedures,
+                    );
+
+                    debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_INLINE);
+
                     Proc::insert_reset_reuse_operations(
                         arena,
                         module_id,
@@ -2876,6 +3110,9 @@ fn update<'a>(
                 }
             }
         }
+        Msg::FinishedAllHeaders => {
+            unreachable!();
+        }
         Msg::FinishedAllTypeChecking { .. } => {
             unreachable!();
         }
@@ -2962,7 +3199,9 @@ fn finish_specialization<'a>(
         expectations,
         type_problems,
         can_problems,
+        syntax_problems,
         sources,
+        suppressions,
         ..
     } = module_cache;
 
@@ -2973,7 +3212,7 @@ fn finish_specialization<'a>(
 
     let entry_point = {
         match exec_mode {
-            ExecutionMode::Test => EntryPoint::Test,
+            ExecutionMode::Test | ExecutionMode::DocTest => EntryPoint::Test,
             ExecutionMode::Executable | ExecutionMode::ExecutableIfCheck => {
                 let path_to_platform = {
                     use PlatformPath::*;
@@ -3024,7 +3263,7 @@ fn finish_specialization<'a>(
                     }
                 }
             }
-            ExecutionMode::Check => unreachable!(),
+            ExecutionMode::Check | ExecutionMode::Headers => unreachable!(),
         }
     };
 
@@ -3034,6 +3273,7 @@ fn finish_specialization<'a>(
     };
 
     Ok(MonomorphizedModule {
+        syntax_problems,
         can_problems,
         type_problems,
         output_path,
@@ -3046,6 +3286,7 @@ fn finish_specialization<'a>(
         procedures,
         entry_point,
         sources,
+        suppressions,
         timings: state.timings,
         toplevel_expects,
     })
@@ -3097,6 +3338,7 @@ fn finish(
         module_id: state.root_id,
         interns,
         solved,
+        syntax_problems: state.module_cache.syntax_problems,
         can_problems: state.module_cache.can_problems,
         type_problems: state.module_cache.type_problems,
         declarations_by_id: state.declarations_by_id,
@@ -3107,12 +3349,54 @@ fn finish(
         exposed_types_storage,
         resolved_implementations,
         sources,
+        suppressions: state.module_cache.suppressions,
         timings: state.timings,
         documentation,
         abilities_store,
     }
 }
 
+fn finish_headers(state: State) -> ModuleGraph {
+    let module_ids = (*state.arc_modules).lock();
+
+    let modules = state
+        .module_cache
+        .headers
+        .into_iter()
+        .map(|(module_id, header)| {
+            let name = match module_ids.get_name(module_id) {
+                // the platform module has an empty name, also when it is qualified
+                Some(PackageQualified::Qualified(shorthand, name)) if !name.as_str().is_empty() => {
+                    format!("{}.{}", shorthand, name.as_str())
+                }
+                Some(pq_name) => pq_name.as_inner().as_str().to_string(),
+                None => internal_error!("Module {:?} has a header, but no name", module_id),
+            };
+
+            let exposes = header
+                .exposes
+                .iter()
+                .filter_map(|symbol| header.exposed_ident_ids.get_name(symbol.ident_id()))
+                .map(String::from)
+                .collect();
+
+            let module = GraphModule {
+                name,
+                path: header.module_path,
+                exposes,
+                imports: header.imported_modules,
+            };
+
+            (module_id, module)
+        })
+        .collect();
+
+    ModuleGraph {
+        root_id: state.root_id,
+        modules,
+    }
+}
+
 /// Load a `platform` module
 fn load_platform_module<'a>(
     arena: &'a Bump,
@@ -3133,13 +3417,14 @@ fn load_platform_module<'a>(
             let bytes = arena.alloc(bytes_vec);
             let parse_state = roc_parse::state::State::new(bytes);
             let parsed = roc_parse::module::parse_header(arena, parse_state.clone());
-            let parse_header_duration = parse_start.elapsed();
+            let parse_end = Instant::now();
 
             // Insert the first entries for this module's timings
             let mut pkg_module_timing = ModuleTiming::new(module_start_time);
 
             pkg_module_timing.read_roc_file = file_io_duration;
-            pkg_module_timing.parse_header = parse_header_duration;
+            pkg_module_timing.parse_header =
+                pkg_module_timing.record("parse header", parse_start, parse_end);
 
             match parsed {
                 Ok((ast::Module::Interface { header }, _parse_state)) => {
@@ -3409,13 +3694,13 @@ fn parse_header<'a>(
     let parse_start = Instant::now();
     let parse_state = roc_parse::state::State::new(src_bytes);
     let parsed = roc_parse::module::parse_header(arena, parse_state.clone());
-    let parse_header_duration = parse_start.elapsed();
+    let parse_end = Instant::now();
 
     // Insert the first entries for this module's timings
     let mut module_timing = ModuleTiming::new(start_time);
 
     module_timing.read_roc_file = read_file_duration;
-    module_timing.parse_header = parse_header_duration;
+    module_timing.parse_header = module_timing.record("parse header", parse_start, parse_end);
 
     match parsed {
         Ok((ast::Module::Interface { header }, parse_state)) => {
@@ -4400,10 +4685,14 @@ fn run_solve_solve(
         };
 
         // Expose anything that is explicitly exposed by the header, or is a specialization of an
-        // ability.
+        // ability, or is the default implementation of an ability member.
         let exposed_vars_by_symbol: Vec<_> = solved_env
             .vars_by_symbol()
-            .filter(|(k, _)| exposed_symbols.contains(k) || is_specialization_symbol(*k))
+            .filter(|(k, _)| {
+                exposed_symbols.contains(k)
+                    || is_specialization_symbol(*k)
+                    || (k.module_id() == module_id && abilities_store.is_member_default(*k))
+            })
             .collect();
 
         (
@@ -4506,7 +4795,7 @@ fn run_solve<'a>(
 
     // Record the final timings
     let solve_end = Instant::now();
-    module_timing.solve = solve_end.duration_since(solve_start);
+    module_timing.solve = module_timing.record("solve", solve_start, solve_end);
 
     // Send the subs to the main thread for processing,
     Msg::SolvedTypes {
@@ -4589,6 +4878,7 @@ fn canonicalize_and_constrain<'a>(
     let ParsedModule {
         module_id,
         module_name,
+        src,
         header_for,
         exposed_ident_ids,
         parsed_defs,
@@ -4635,7 +4925,8 @@ fn canonicalize_and_constrain<'a>(
 
     let canonicalize_end = Instant::now();
 
-    module_timing.canonicalize = canonicalize_end.duration_since(canonicalize_start);
+    module_timing.canonicalize =
+        module_timing.record("canonicalize", canonicalize_start, canonicalize_end);
 
     // Generate documentation information
     // TODO: store timing information?
@@ -4648,7 +4939,9 @@ fn canonicalize_and_constrain<'a>(
             let docs = crate::docs::generate_module_docs(
                 scope,
                 name.as_str().into(),
+                src,
                 &parsed_defs_for_docs,
+                &exposed_symbols,
             );
 
             Some(docs)
@@ -4658,6 +4951,7 @@ fn canonicalize_and_constrain<'a>(
     // _before has an underscore because it's unused in --release builds
     let _before = roc_types::types::get_type_clone_count();
 
+    let constrain_start = Instant::now();
     let mut constraints = Constraints::new();
 
     let constraint = if skip_constraint_gen {
@@ -4672,6 +4966,9 @@ fn canonicalize_and_constrain<'a>(
         )
     };
 
+    let constrain_end = Instant::now();
+    module_timing.constrain = module_timing.record("constrain", constrain_start, constrain_end);
+
     // _after has an underscore because it's unused in --release builds
     let _after = roc_types::types::get_type_clone_count();
 
@@ -4741,25 +5038,46 @@ fn canonicalize_and_constrain<'a>(
     }
 }
 
-fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, LoadingProblem<'a>> {
+fn parse<'a>(
+    arena: &'a Bump,
+    header: ModuleHeader<'a>,
+    doc_tests: bool,
+) -> Result<Msg<'a>, LoadingProblem<'a>> {
     let mut module_timing = header.module_timing;
     let parse_start = Instant::now();
     let source = header.parse_state.original_bytes();
-    let parse_state = header.parse_state;
-    let parsed_defs = match module_defs().parse(arena, parse_state) {
-        Ok((_, success, _state)) => success,
-        Err((_, fail, state)) => {
-            return Err(LoadingProblem::ParsingFailed(
-                fail.into_file_error(header.module_path, &state),
-            ));
+    let mut parse_state = header.parse_state;
+
+    // If the source isn't valid UTF-8, parsing it reports that.
+    if let (true, Ok(src)) = (doc_tests, std::str::from_utf8(source)) {
+        // The doc tests are in the docs of the defs, so we find them in the parsed defs.
+        let (defs, _) = module_defs_with_recovery(arena, parse_state.clone());
+
+        // The regions of the rewritten source are the same as those of the original, so we
+        // keep reporting problems against the original source.
+        if let Some(rewritten) = crate::docs::doc_tests_to_expects(src, &defs) {
+            let rewritten = arena.alloc_str(&rewritten).as_bytes();
+            parse_state = parse_state.with_original_bytes(rewritten);
         }
-    };
+    }
+    let parsed_bytes = parse_state.original_bytes();
+    let (parsed_defs, syntax_errors) = module_defs_with_recovery(arena, parse_state);
+    let mut syntax_errors: Vec<_> = syntax_errors
+        .into_iter()
+        .map(|error| error.into_file_error(header.module_path.clone()))
+        .collect();
+
+    // We can only keep going past syntax errors if the source is valid UTF-8; otherwise the
+    // first error is the one that reports that.
+    if !syntax_errors.is_empty() && std::str::from_utf8(source).is_err() {
+        return Err(LoadingProblem::ParsingFailed(syntax_errors.swap_remove(0)));
+    }
 
     // Record the parse end time once, to avoid checking the time a second time
     // immediately afterward (for the beginning of canonicalization).
     let parse_end = Instant::now();
 
-    module_timing.parse_body = parse_end.duration_since(parse_start);
+    module_timing.parse_body = module_timing.record("parse body", parse_start, parse_end);
 
     let imported_modules = header.imported_modules;
 
@@ -4768,6 +5086,13 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
     // we'd have bailed out before now.
     let src = unsafe { from_utf8_unchecked(source) };
 
+    // The comments point into the bytes that were parsed, which may be a rewrite of the source
+    // with the same line structure.
+    let suppressions = match std::str::from_utf8(parsed_bytes) {
+        Ok(parsed_src) => Suppressions::from_defs(parsed_src, &parsed_defs),
+        Err(_) => Suppressions::default(),
+    };
+
     let ModuleHeader {
         module_id,
         module_name,
@@ -4785,12 +5110,14 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
         module_name,
         module_path,
         src,
+        suppressions,
         module_timing,
         deps_by_name,
         imported_modules,
         exposed_ident_ids,
         exposed_imports,
         parsed_defs,
+        syntax_errors,
         symbols_from_requires,
         header_for,
     };
@@ -4898,9 +5225,14 @@ fn make_specializations<'a>(
     mono_env.home.register_debug_idents(mono_env.ident_ids);
 
     let make_specializations_end = Instant::now();
+    let make_specializations_duration = module_timing.record(
+        "make specializations",
+        make_specializations_start,
+        make_specializations_end,
+    );
     module_timing
         .make_specializations
-        .push(make_specializations_end.duration_since(make_specializations_start));
+        .push(make_specializations_duration);
 
     Msg::MadeSpecializations {
         module_id: home,
@@ -5327,8 +5659,11 @@ fn build_pending_specializations<'a>(
     procs_base.module_thunks = module_thunks.into_bump_slice();
 
     let find_specializations_end = Instant::now();
-    module_timing.find_specializations =
-        find_specializations_end.duration_since(find_specializations_start);
+    module_timing.find_specializations = module_timing.record(
+        "find specializations",
+        find_specializations_start,
+        find_specializations_end,
+    );
 
     Msg::FoundSpecializations {
         module_id: home,
@@ -5446,8 +5781,11 @@ fn load_derived_partial_procs<'a>(
 
     let load_derived_procs_end = Instant::now();
 
-    module_timing.find_specializations =
-        load_derived_procs_end.duration_since(load_derived_procs_start);
+    module_timing.find_specializations = module_timing.record(
+        "find specializations",
+        load_derived_procs_start,
+        load_derived_procs_end,
+    );
 }
 
 fn run_task<'a>(
@@ -5474,7 +5812,7 @@ fn run_task<'a>(
             ident_ids_by_module,
         )
         .map(|(_, msg)| msg),
-        Parse { header } => parse(arena, header),
+        Parse { header, doc_tests } => parse(arena, header, doc_tests),
         CanonicalizeAndConstrain {
             parsed,
             module_ids,
@@ -142,15 +142,21 @@ impl<'a> Dependencies<'a> {
                 output.insert((dep, Phase::LoadHeader));
             }
 
-            // to parse and generate constraints, the headers of all dependencies must be loaded!
-            // otherwise, we don't know whether an imported symbol is actually exposed
-            self.add_dependency_help(module_id, dep, Phase::Parse, Phase::LoadHeader);
+            if goal_phase >= Parse {
+                // to parse and generate constraints, the headers of all dependencies must be loaded!
+                // otherwise, we don't know whether an imported symbol is actually exposed
+                self.add_dependency_help(module_id, dep, Phase::Parse, Phase::LoadHeader);
+            }
 
-            // to canonicalize a module, all its dependencies must be canonicalized
-            self.add_dependency(module_id, dep, Phase::CanonicalizeAndConstrain);
+            if goal_phase >= CanonicalizeAndConstrain {
+                // to canonicalize a module, all its dependencies must be canonicalized
+                self.add_dependency(module_id, dep, Phase::CanonicalizeAndConstrain);
+            }
 
-            // to typecheck a module, all its dependencies must be type checked already
-            self.add_dependency(module_id, dep, Phase::SolveTypes);
+            if goal_phase >= SolveTypes {
+                // to typecheck a module, all its dependencies must be type checked already
+                self.add_dependency(module_id, dep, Phase::SolveTypes);
+            }
 
             if goal_phase >= FindSpecializations {
                 self.add_dependency(module_id, dep, Phase::FindSpecializations);
@@ -17,7 +17,7 @@ mod helpers;
 use crate::helpers::fixtures_dir;
 use bumpalo::Bump;
 use roc_can::module::ExposedByModule;
-use roc_load_internal::file::{ExecutionMode, LoadConfig, Threading};
+use roc_load_internal::file::{ExecutionMode, LoadConfig, OptLevel, Threading};
 use roc_load_internal::file::{LoadResult, LoadStart, LoadedModule, LoadingProblem};
 use roc_module::ident::ModuleName;
 use roc_module::symbol::{Interns, ModuleId};
@@ -26,6 +26,7 @@ use roc_region::all::LineInfo;
 use roc_reporting::report::can_problem;
 use roc_reporting::report::RenderTarget;
 use roc_reporting::report::RocDocAllocator;
+use roc_solve_problem::TypeError;
 use roc_target::TargetInfo;
 use roc_types::pretty_print::name_and_print_var;
 use roc_types::pretty_print::DebugPrint;
@@ -46,6 +47,8 @@ fn load_and_typecheck(
         render: RenderTarget::Generic,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Development,
+        warning_config: Default::default(),
     };
 
     match roc_load_internal::file::load(
@@ -55,7 +58,7 @@ fn load_and_typecheck(
         Default::default(), // these tests will re-compile the builtins
         load_config,
     )? {
-        Monomorphized(_) => unreachable!(""),
+        Monomorphized(_) | Headers(_) => unreachable!(""),
         TypeChecked(module) => Ok(module),
     }
 }
@@ -102,6 +105,15 @@ fn multiple_modules(subdir: &str, files: Vec<(&str, &str)>) -> Result<LoadedModu
             let home = loaded_module.module_id;
             let (filepath, src) = loaded_module.sources.get(&home).unwrap();
 
+            let syntax_problems: Vec<String> = loaded_module
+                .syntax_problems
+                .drain()
+                .flat_map(|(_, reports)| reports)
+                .collect();
+            if !syntax_problems.is_empty() {
+                return Err(syntax_problems.concat());
+            }
+
             let can_problems = loaded_module.can_problems.remove(&home).unwrap_or_default();
             if !can_problems.is_empty() {
                 return Err(format_can_problems(
@@ -626,6 +638,277 @@ fn imported_file_not_found() {
     );
 }
 
+#[test]
+fn module_graph() {
+    use LoadResult::*;
+
+    let arena = Bump::new();
+    let filename = fixtures_dir()
+        .join("interface_with_deps")
+        .join("Primary.roc");
+    let load_start = LoadStart::from_path(&arena, filename, RenderTarget::Generic).unwrap();
+    let load_config = LoadConfig {
+        target_info: TARGET_INFO,
+        render: RenderTarget::Generic,
+        threading: Threading::Single,
+        exec_mode: ExecutionMode::Headers,
+        opt_level: OptLevel::Development,
+        warning_config: Default::default(),
+    };
+
+    let graph = match roc_load_internal::file::load(
+        &arena,
+        load_start,
+        Default::default(),
+        Default::default(),
+        load_config,
+    )
+    .unwrap()
+    {
+        Headers(graph) => graph,
+        TypeChecked(_) | Monomorphized(_) => unreachable!(""),
+    };
+
+    let imports_of = |name: &str| {
+        let module = graph
+            .modules
+            .values()
+            .find(|module| module.name == name)
+            .unwrap();
+
+        let mut imports: Vec<&str> = module
+            .imports
+            .keys()
+            .filter(|module_id| !module_id.is_builtin())
+            .map(|module_id| graph.modules[module_id].name.as_str())
+            .collect();
+        imports.sort_unstable();
+
+        imports
+    };
+
+    assert_eq!(graph.modules[&graph.root_id].name, "Primary");
+    assert_eq!(imports_of("Primary"), ["Dep1", "Dep2", "Dep3.Blah", "Res"]);
+    assert_eq!(imports_of("Dep2"), ["Dep3.Blah"]);
+    assert_eq!(imports_of("Res"), Vec::<&str>::new());
+}
+
+fn doc_tests_to_expects(src: &str) -> Option<String> {
+    use roc_parse::module::{module_defs_with_recovery, parse_header};
+    use roc_parse::state::State;
+
+    let arena = Bump::new();
+    let (_, state) = parse_header(&arena, State::new(src.as_bytes())).unwrap();
+    let (defs, _) = module_defs_with_recovery(&arena, state);
+
+    roc_load_internal::docs::doc_tests_to_expects(src, &defs)
+}
+
+/// Rewrites the doc tests of `src`, and checks that the result is a module that loads.
+fn rewrite_doc_tests(subdir: &str, src: &str) -> String {
+    let rewritten = doc_tests_to_expects(src).unwrap();
+
+    assert_eq!(rewritten.len(), src.len());
+    assert!(multiple_modules(subdir, vec![("Main", rewritten.as_str())]).is_ok());
+
+    // the rewritten lines are padded with spaces to keep their length
+    let trimmed: Vec<&str> = rewritten.lines().map(str::trim_end).collect();
+
+    trimmed.join("\n") + "\n"
+}
+
+#[test]
+fn doc_tests_to_expects_rewrite() {
+    let src = indoc!(
+        r#"
+            interface Main exposes [double] imports []
+
+            ## Doubles a number.
+            ##
+            ## ```roc
+            ## two = 1 + 1
+            ## expect double 1 == two
+            ## expect double two == 4
+            ## ```
+            ##
+            ## Only code blocks with an `expect` in them are run.
+            ##
+            ## ```roc
+            ## double 3
+            ## ```
+            double : Num a -> Num a
+            double = \n -> n * 2
+            "#
+    );
+
+    assert_eq!(
+        rewrite_doc_tests("doc_tests_to_expects_rewrite", src),
+        indoc!(
+            r#"
+            interface Main exposes [double] imports []
+
+            ## Doubles a number.
+            ##
+            expect
+               two = 1 + 1
+               expect double 1 == two
+               double two == 4
+
+            ##
+            ## Only code blocks with an `expect` in them are run.
+            ##
+            ## ```roc
+            ## double 3
+            ## ```
+            double : Num a -> Num a
+            double = \n -> n * 2
+            "#
+        )
+    );
+
+    let no_expects = indoc!(
+        r#"
+            interface Main exposes [double] imports []
+
+            ## ```roc
+            ## double 3
+            ## ```
+            double : Num a -> Num a
+            double = \n -> n * 2
+            "#
+    );
+
+    assert_eq!(doc_tests_to_expects(no_expects), None);
+}
+
+#[test]
+fn doc_tests_to_expects_multiline_conditions() {
+    let src = indoc!(
+        r#"
+            interface Main exposes [double] imports []
+
+            ## ```roc
+            ## expect
+            ##     double 1 == 2
+            ## expect double 2 ==
+            ##     4
+            ## ```
+            double : Num a -> Num a
+            double = \n -> n * 2
+
+            ## ```
+            ## expect
+            ##     double 3 == 6
+            ## ```
+
+            "#
+    );
+
+    assert_eq!(
+        rewrite_doc_tests("doc_tests_to_expects_multiline_conditions", src),
+        indoc!(
+            r#"
+            interface Main exposes [double] imports []
+
+            expect
+               expect
+                   double 1 == 2
+               double 2 ==
+                   4
+
+            double : Num a -> Num a
+            double = \n -> n * 2
+
+            expect
+
+                   double 3 == 6
+
+
+            "#
+        )
+    );
+}
+
+#[test]
+fn doc_tests_to_expects_only_documented_roc_code() {
+    // Unannotated defs have no docs, and code in other languages isn't run.
+    let src = indoc!(
+        r#"
+            interface Main exposes [double, triple] imports []
+
+            ## ```roc
+            ## expect double 1 == 2
+            ## ```
+            double = \n -> n * 2
+
+            ## ```elm
+            ## expect 1 == 2
+            ## ```
+            triple : Num a -> Num a
+            triple = \n -> n * 3
+            "#
+    );
+
+    assert_eq!(doc_tests_to_expects(src), None);
+}
+
+#[test]
+fn doc_def_kinds_and_exposed() {
+    use roc_load_internal::docs::{DocDefKind, DocEntry};
+
+    let src = indoc!(
+        r#"
+            interface Main exposes [Age, Id, Show, show, double] imports []
+
+            Age : U32
+
+            Id := U64
+
+            ## Things which can be shown
+            Show has
+                show : a -> Str | a has Show
+
+            two : U8
+            two = 2
+
+            double = \n -> n * two
+            "#
+    );
+
+    let modules = vec![("Main", src)];
+    let loaded_module = multiple_modules("doc_def_kinds_and_exposed", modules).unwrap();
+    let module_docs = &loaded_module.documentation[&loaded_module.module_id];
+    let line_info = LineInfo::new(src);
+
+    let defs: Vec<_> = module_docs
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            DocEntry::DocDef(def) => Some((
+                def.name.as_str(),
+                def.kind,
+                line_info.convert_pos(def.region.start()).line,
+            )),
+            DocEntry::DetachedDoc(..) => None,
+        })
+        .collect();
+
+    assert_eq!(
+        defs,
+        vec![
+            ("Age", DocDefKind::Alias, 2),
+            ("Id", DocDefKind::Opaque, 4),
+            ("Show", DocDefKind::Ability, 7),
+            ("two", DocDefKind::Value, 10),
+        ]
+    );
+
+    let mut exposed = module_docs.exposed.clone();
+    exposed.sort();
+
+    assert_eq!(exposed, vec!["Age", "Id", "Show", "double", "show"]);
+}
+
 #[test]
 fn platform_does_not_exist() {
     let modules = vec![(
@@ -812,6 +1095,147 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
     );
 }
 
+#[test]
+fn typed_hole_suggests_imported_values() {
+    let modules = vec![
+        (
+            "Greetings",
+            indoc!(
+                r#"
+                    interface Greetings exposes [hello, answer] imports []
+
+                    hello = "Hello"
+
+                    answer = 42
+                    "#
+            ),
+        ),
+        (
+            "Main",
+            indoc!(
+                r#"
+                    interface Main exposes [greet, shout] imports [Greetings.{ hello, answer }]
+
+                    name = "World"
+
+                    greet = Str.concat _ name
+
+                    shout = Str.concat hello "!"
+                    "#
+            ),
+        ),
+    ];
+
+    let arena = Bump::new();
+    let mut loaded_module =
+        multiple_modules_help("typed_hole_suggests_imported_values", &arena, modules)
+            .unwrap()
+            .unwrap();
+
+    let home = loaded_module.module_id;
+    let type_problems = loaded_module.type_problems.remove(&home).unwrap();
+
+    match type_problems.as_slice() {
+        [TypeError::TypedHole { fits, .. }] => {
+            let fits: Vec<_> = fits
+                .iter()
+                .map(|symbol| symbol.as_str(&loaded_module.interns))
+                .collect();
+
+            // `hello` only has a type to check because `shout` uses it
+            assert_eq!(fits, ["name", "hello"]);
+        }
+        other => panic!("expected a single typed hole, got {:?}", other),
+    }
+}
+
+#[test]
+fn parse_problems_recover_at_top_level_defs() {
+    let modules = vec![(
+        "Main",
+        indoc!(
+            r#"
+                interface Main exposes [greeting] imports []
+
+                broken = [
+
+                name : Str
+                name = "World"
+
+                alsoBroken = )
+
+                greeting = Str.concat "Hello, " name
+                "#
+        ),
+    )];
+
+    let arena = Bump::new();
+    let mut loaded_module =
+        multiple_modules_help("parse_problems_recover_at_top_level_defs", &arena, modules)
+            .unwrap()
+            .unwrap();
+
+    let home = loaded_module.module_id;
+    let syntax_problems = loaded_module.syntax_problems.remove(&home).unwrap();
+
+    match syntax_problems.as_slice() {
+        [first, second] => {
+            assert!(first.contains("broken = ["), "{}", first);
+            assert!(second.contains("alsoBroken = )"), "{}", second);
+        }
+        other => panic!("expected two syntax problems, got {:?}", other),
+    }
+
+    expect_types(
+        loaded_module,
+        hashmap! {
+            "broken" => "*",
+            "name" => "Str",
+            "alsoBroken" => "*",
+            "greeting" => "Str",
+        },
+    );
+}
+
+#[test]
+fn parse_problems_bind_malformed_def_names() {
+    let modules = vec![(
+        "Main",
+        indoc!(
+            r#"
+                interface Main exposes [greeting, farewell] imports []
+
+                name = "World
+
+                greeting = Str.concat "Hello, " name
+
+                farewell = \{} -> Str.concat "Goodbye, " name
+                "#
+        ),
+    )];
+
+    let arena = Bump::new();
+    let mut loaded_module =
+        multiple_modules_help("parse_problems_bind_malformed_def_names", &arena, modules)
+            .unwrap()
+            .unwrap();
+
+    let home = loaded_module.module_id;
+    let syntax_problems = loaded_module.syntax_problems.remove(&home).unwrap();
+
+    assert_eq!(syntax_problems.len(), 1, "{:?}", syntax_problems);
+
+    // `name` failed to parse, but it's still defined, so its uses aren't reported
+    expect_types(
+        loaded_module,
+        hashmap! {
+            "name" => "*",
+            "greeting" => "Str",
+            "farewell" => "{}* -> Str",
+        },
+    );
+}
+
 #[test]
 fn issue_2863_module_type_does_not_exist() {
     let modules = vec![
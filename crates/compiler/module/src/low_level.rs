@@ -94,6 +94,15 @@ pub enum LowLevel {
     NumShiftLeftBy,
     NumShiftRightBy,
     NumShiftRightZfBy,
+    NumCountLeadingZeroBits,
+    NumCountTrailingZeroBits,
+    NumCountOneBits,
+    NumToBytesLe,
+    NumToBytesBe,
+    NumFromBytesLe,
+    NumFromBytesBe,
+    NumF64ToBits,
+    NumF64FromBits,
     NumIntCast,
     NumToFloatCast,
     NumToIntChecked,
@@ -307,6 +316,15 @@ map_symbol_to_lowlevel! {
     NumShiftLeftBy <= NUM_SHIFT_LEFT,
     NumShiftRightBy <= NUM_SHIFT_RIGHT,
     NumShiftRightZfBy <= NUM_SHIFT_RIGHT_ZERO_FILL,
+    NumCountLeadingZeroBits <= NUM_COUNT_LEADING_ZERO_BITS,
+    NumCountTrailingZeroBits <= NUM_COUNT_TRAILING_ZERO_BITS,
+    NumCountOneBits <= NUM_COUNT_ONE_BITS,
+    NumToBytesLe <= NUM_TO_BYTES_LE,
+    NumToBytesBe <= NUM_TO_BYTES_BE,
+    NumFromBytesLe <= NUM_FROM_BYTES_LE_LOWLEVEL,
+    NumFromBytesBe <= NUM_FROM_BYTES_BE_LOWLEVEL,
+    NumF64ToBits <= NUM_F64_TO_BITS,
+    NumF64FromBits <= NUM_F64_FROM_BITS,
     NumToStr <= NUM_TO_STR,
     Eq <= BOOL_EQ,
     NotEq <= BOOL_NEQ,
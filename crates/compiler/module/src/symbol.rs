@@ -1184,6 +1184,17 @@ define_builtins! {
         143 NUM_MUL_CHECKED_LOWLEVEL: "mulCheckedLowlevel"
         144 NUM_BYTES_TO_U16_LOWLEVEL: "bytesToU16Lowlevel"
         145 NUM_BYTES_TO_U32_LOWLEVEL: "bytesToU32Lowlevel"
+        146 NUM_COUNT_LEADING_ZERO_BITS: "countLeadingZeroBits"
+        147 NUM_COUNT_TRAILING_ZERO_BITS: "countTrailingZeroBits"
+        148 NUM_COUNT_ONE_BITS: "countOneBits"
+        149 NUM_TO_BYTES_LE: "toBytesLe"
+        150 NUM_TO_BYTES_BE: "toBytesBe"
+        151 NUM_FROM_BYTES_LE: "fromBytesLe"
+        152 NUM_FROM_BYTES_BE: "fromBytesBe"
+        153 NUM_FROM_BYTES_LE_LOWLEVEL: "fromBytesLeLowlevel"
+        154 NUM_FROM_BYTES_BE_LOWLEVEL: "fromBytesBeLowlevel"
+        155 NUM_F64_TO_BITS: "f64ToBits"
+        156 NUM_F64_FROM_BITS: "f64FromBits"
     }
     4 BOOL: "Bool" => {
         0 BOOL_BOOL: "Bool" // the Bool.Bool type alias
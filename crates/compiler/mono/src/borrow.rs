@@ -923,8 +923,16 @@ pub fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[bool] {
         | NumAsin | NumIntCast | NumToIntChecked | NumToFloatCast | NumToFloatChecked => {
             arena.alloc_slice_copy(&[irrelevant])
         }
+        NumCountLeadingZeroBits
+        | NumCountTrailingZeroBits
+        | NumCountOneBits
+        | NumToBytesLe
+        | NumToBytesBe
+        | NumF64ToBits
+        | NumF64FromBits => arena.alloc_slice_copy(&[irrelevant]),
         NumBytesToU16 => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         NumBytesToU32 => arena.alloc_slice_copy(&[borrowed, irrelevant]),
+        NumFromBytesLe | NumFromBytesBe => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         StrStartsWith | StrEndsWith => arena.alloc_slice_copy(&[borrowed, borrowed]),
         StrStartsWithScalar => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         StrFromUtf8Range => arena.alloc_slice_copy(&[owned, irrelevant, irrelevant]),
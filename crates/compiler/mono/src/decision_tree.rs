@@ -87,6 +87,8 @@ enum Test<'a> {
         arguments: Vec<(Pattern<'a>, Layout<'a>)>,
     },
     IsInt([u8; 16], IntWidth),
+    /// The value lies in the inclusive range between the two bounds
+    IsIntRange([u8; 16], [u8; 16], IntWidth),
     IsFloat(u64, FloatWidth),
     IsDecimal([u8; 16]),
     IsStr(Box<str>),
@@ -105,6 +107,7 @@ impl<'a> Test<'a> {
                 // llvm does not like switching on 128-bit values
                 !matches!(int_width, IntWidth::U128 | IntWidth::I128)
             }
+            Test::IsIntRange(..) => false,
             Test::IsFloat(_, _) => true,
             Test::IsDecimal(_) => false,
             Test::IsStr(_) => false,
@@ -153,6 +156,12 @@ impl<'a> Hash for Test<'a> {
                 state.write_u8(6);
                 v.hash(state);
             }
+            IsIntRange(lo, hi, width) => {
+                state.write_u8(7);
+                lo.hash(state);
+                hi.hash(state);
+                width.hash(state);
+            }
         }
     }
 }
@@ -328,6 +337,7 @@ fn tests_are_complete_help(last_test: &Test, number_of_tests: usize) -> bool {
         Test::IsByte { num_alts, .. } => number_of_tests == *num_alts,
         Test::IsBit(_) => number_of_tests == 2,
         Test::IsInt(_, _) => false,
+        Test::IsIntRange(..) => false,
         Test::IsFloat(_, _) => false,
         Test::IsDecimal(_) => false,
         Test::IsStr(_) => false,
@@ -436,9 +446,20 @@ fn gather_edges<'a>(
 
     let check = guarded_tests_are_complete(&relevant_tests);
 
+    // the edges are tried in order, so values that passed an earlier int test never reach a later one
+    let mut tested_before = std::vec::Vec::new();
+
     let all_edges = relevant_tests
         .into_iter()
-        .map(|t| edges_for(path, &branches, t))
+        .map(|t| {
+            let edge = edges_for(path, &branches, t, &tested_before);
+
+            if let GuardedTest::TestNotGuarded { test } = &edge.0 {
+                tested_before.extend(int_test_keys(test));
+            }
+
+            edge
+        })
         .collect();
 
     let fallbacks = if check {
@@ -492,6 +513,66 @@ fn tests_at_path<'a>(
     unique
 }
 
+/// An int pattern that matches every value that can pass `test` is consumed by it, and one that
+/// matches none of them is irrelevant. A range that matches just some of them stays, so that it
+/// can be tested again.
+fn int_pattern_relevance<'a>(
+    branch: &Branch<'a>,
+    mut start: Vec<(Vec<PathInstruction>, Pattern<'a>)>,
+    end: Vec<(Vec<PathInstruction>, Pattern<'a>)>,
+    (lo, hi): (u128, u128),
+    test: &Test<'a>,
+    tested_before: &[(u128, u128)],
+) -> Option<Branch<'a>> {
+    let mut reachable: std::vec::Vec<_> = int_test_keys(test).into_iter().collect();
+
+    for (before_lo, before_hi) in tested_before.iter().copied() {
+        reachable = reachable
+            .into_iter()
+            .flat_map(|(a, b)| {
+                if b < before_lo || before_hi < a {
+                    return vec![(a, b)];
+                }
+
+                let below = (a < before_lo).then(|| (a, before_lo - 1));
+                let above = (before_hi < b).then(|| (before_hi + 1, b));
+                below.into_iter().chain(above).collect()
+            })
+            .collect();
+    }
+
+    if reachable.iter().all(|(a, b)| lo <= *a && *b <= hi) {
+        start.extend(end);
+        Some(Branch {
+            goal: branch.goal,
+            guard: branch.guard.clone(),
+            patterns: start,
+        })
+    } else if reachable.iter().all(|(a, b)| *b < lo || hi < *a) {
+        None
+    } else {
+        Some(branch.clone())
+    }
+}
+
+/// The inclusive range of keys that pass an int test.
+fn int_test_keys(test: &Test) -> Option<(u128, u128)> {
+    match test {
+        Test::IsInt(v, width) => Some((int_key(*v, *width), int_key(*v, *width))),
+        Test::IsIntRange(lo, hi, width) => Some((int_key(*lo, *width), int_key(*hi, *width))),
+        _ => None,
+    }
+}
+
+/// Int literals are stored as the bytes of their `i128` value, unless that would not fit: only
+/// `U128` literals may be too large. This maps them to keys that order the same way as the values.
+fn int_key(value: [u8; 16], width: IntWidth) -> u128 {
+    match width {
+        IntWidth::U128 => u128::from_ne_bytes(value),
+        _ => (i128::from_ne_bytes(value) as u128) ^ (1 << 127),
+    }
+}
+
 fn test_at_path<'a>(
     selected_path: &[PathInstruction],
     branch: &Branch<'a>,
@@ -604,6 +685,7 @@ fn test_at_path<'a>(
                     num_alts: union.alternatives.len(),
                 },
                 IntLiteral(v, precision) => IsInt(*v, *precision),
+                IntRange(lo, hi, precision) => IsIntRange(*lo, *hi, *precision),
                 FloatLiteral(v, precision) => IsFloat(*v, *precision),
                 DecimalLiteral(v) => IsDecimal(*v),
                 StrLiteral(v) => IsStr(v.clone()),
@@ -623,6 +705,7 @@ fn edges_for<'a>(
     path: &[PathInstruction],
     branches: &[Branch<'a>],
     test: GuardedTest<'a>,
+    tested_before: &[(u128, u128)],
 ) -> (GuardedTest<'a>, Vec<Branch<'a>>) {
     let mut new_branches = Vec::new();
 
@@ -641,7 +724,7 @@ fn edges_for<'a>(
     };
 
     for branch in it {
-        new_branches.extend(to_relevant_branch(&test, path, branch));
+        new_branches.extend(to_relevant_branch(&test, path, branch, tested_before));
     }
 
     (test, new_branches)
@@ -651,6 +734,7 @@ fn to_relevant_branch<'a>(
     guarded_test: &GuardedTest<'a>,
     path: &[PathInstruction],
     branch: &Branch<'a>,
+    tested_before: &[(u128, u128)],
 ) -> Option<Branch<'a>> {
     // TODO remove clone
     match extract(path, branch.patterns.clone()) {
@@ -671,7 +755,7 @@ fn to_relevant_branch<'a>(
                 Some(branch.clone())
             }
             GuardedTest::TestNotGuarded { test } => {
-                to_relevant_branch_help(test, path, start, end, branch, pattern)
+                to_relevant_branch_help(test, path, start, end, branch, pattern, tested_before)
             }
         },
     }
@@ -684,6 +768,7 @@ fn to_relevant_branch_help<'a>(
     end: Vec<(Vec<PathInstruction>, Pattern<'a>)>,
     branch: &Branch<'a>,
     pattern: Pattern<'a>,
+    tested_before: &[(u128, u128)],
 ) -> Option<Branch<'a>> {
     use Pattern::*;
     use Test::*;
@@ -901,9 +986,18 @@ fn to_relevant_branch_help<'a>(
                     patterns: start,
                 })
             }
+            IsIntRange(..) => {
+                let key = int_key(int, p1);
+                int_pattern_relevance(branch, start, end, (key, key), test, tested_before)
+            }
             _ => None,
         },
 
+        IntRange(lo, hi, width) => {
+            let keys = (int_key(lo, width), int_key(hi, width));
+            int_pattern_relevance(branch, start, end, keys, test, tested_before)
+        }
+
         FloatLiteral(float, p1) => match test {
             IsFloat(test_float, p2) if float == *test_float => {
                 debug_assert_eq!(p1, *p2);
@@ -1019,6 +1113,7 @@ fn needs_tests(pattern: &Pattern) -> bool {
         | BitLiteral { .. }
         | EnumLiteral { .. }
         | IntLiteral(_, _)
+        | IntRange(_, _, _)
         | FloatLiteral(_, _)
         | DecimalLiteral(_)
         | StrLiteral(_) => true,
@@ -1400,6 +1495,42 @@ fn test_to_equality<'a>(
             (stores, lhs_symbol, rhs_symbol, None)
         }
 
+        Test::IsIntRange(lo, hi, precision) => {
+            // check `lo <= value && value <= hi`, and compare the outcome with `true`
+            let int_layout = Layout::int_width(precision);
+            let bool_layout = Layout::Builtin(Builtin::Bool);
+
+            let lo_symbol = env.unique_symbol();
+            let hi_symbol = env.unique_symbol();
+            let above_lo_symbol = env.unique_symbol();
+            let below_hi_symbol = env.unique_symbol();
+            let in_range_symbol = env.unique_symbol();
+            let true_symbol = env.unique_symbol();
+
+            let mut low_level = |op, arguments: [Symbol; 2]| {
+                Expr::Call(crate::ir::Call {
+                    call_type: crate::ir::CallType::LowLevel {
+                        op,
+                        update_mode: env.next_update_mode_id(),
+                    },
+                    arguments: env.arena.alloc(arguments),
+                })
+            };
+
+            let above_lo = low_level(LowLevel::NumGte, [rhs_symbol, lo_symbol]);
+            let below_hi = low_level(LowLevel::NumLte, [rhs_symbol, hi_symbol]);
+            let in_range = low_level(LowLevel::And, [above_lo_symbol, below_hi_symbol]);
+
+            stores.push((lo_symbol, int_layout, Expr::Literal(Literal::Int(lo))));
+            stores.push((hi_symbol, int_layout, Expr::Literal(Literal::Int(hi))));
+            stores.push((above_lo_symbol, bool_layout, above_lo));
+            stores.push((below_hi_symbol, bool_layout, below_hi));
+            stores.push((in_range_symbol, bool_layout, in_range));
+            stores.push((true_symbol, bool_layout, Expr::Literal(Literal::Bool(true))));
+
+            (stores, true_symbol, in_range_symbol, None)
+        }
+
         Test::IsFloat(test_int, precision) => {
             // TODO maybe we can actually use i64 comparison here?
             let test_float = f64::from_bits(test_int as u64);
@@ -1854,6 +1985,7 @@ fn decide_to_branching<'a>(
                     Test::IsBit(v) => v as u64,
                     Test::IsByte { tag_id, .. } => tag_id as u64,
                     Test::IsCtor { tag_id, .. } => tag_id as u64,
+                    Test::IsIntRange(..) => unreachable!("int ranges cannot be switched on"),
                     Test::IsDecimal(_) => unreachable!("decimals cannot be switched on"),
                     Test::IsStr(_) => unreachable!("strings cannot be switched on"),
                 };
@@ -0,0 +1,1335 @@
+//! This module inlines small procs into their callers, and folds operations on values that are
+//! known at compile time. The dev and wasm backends do not have an optimizer of their own, so
+//! without this pass they emit a call for every tiny helper, like the specializations of `Num.add`
+//! or `Bool.not`.
+//!
+//! The pass runs after specialization, before reset/reuse and refcount insertion, and
+//!
+//! - inlines procs that are small, or that are called in only one place
+//! - folds integer, float and boolean arithmetic and comparisons on literals
+//! - folds field and tag id accesses on structs and tags that are constructed in the same proc
+//! - picks the branch of a `switch` on a known value
+//! - removes the definitions that are no longer used afterwards
+//!
+//! Inlined bodies are copied from the procs as they were before this pass, so the result does not
+//! depend on the order in which procs are visited. Procs are never removed, even when all of their
+//! calls have been inlined.
+
+use crate::ir::{
+    BranchInfo, Call, CallSpecIds, CallType, Expr, HigherOrderLowLevel, JoinPointId,
+    ListLiteralElement, Literal, ModifyRc, OptLevel, Param, PassedFunction, Proc, ProcLayout,
+    SelfRecursive, Stmt, UpdateModeIds,
+};
+use crate::layout::{Builtin, Layout, TagIdIntType};
+use crate::low_level::HigherOrder;
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_builtins::bitcode::{FloatWidth, IntWidth};
+use roc_collections::all::{MutMap, MutSet};
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+
+type ProcKey<'a> = (Symbol, ProcLayout<'a>);
+
+/// An inlined body can contain calls that are inlined in turn. This bounds how deep that goes,
+/// and how many calls are inlined into a single proc in total.
+const MAX_INLINE_DEPTH: usize = 8;
+const MAX_INLINED_CALLS: usize = 256;
+
+/// How large a proc body (counted in statements) may be for the proc to be inlined
+struct Budget {
+    /// procs up to this size are inlined at every call site
+    small: usize,
+    /// procs up to this size are inlined if they have exactly one call site
+    single_use: usize,
+}
+
+impl Budget {
+    fn for_opt_level(opt_level: OptLevel) -> Option<Self> {
+        match opt_level {
+            OptLevel::Development => None,
+            // the original proc is kept around, so inlining a single use does not make the
+            // program smaller
+            OptLevel::Size => Some(Budget {
+                small: 3,
+                single_use: 3,
+            }),
+            OptLevel::Normal => Some(Budget {
+                small: 6,
+                single_use: 32,
+            }),
+            OptLevel::Optimize => Some(Budget {
+                small: 12,
+                single_use: 64,
+            }),
+        }
+    }
+}
+
+pub fn inline_and_fold<'a, 'i>(
+    arena: &'a Bump,
+    home: ModuleId,
+    ident_ids: &'i mut IdentIds,
+    update_mode_ids: &'i mut UpdateModeIds,
+    opt_level: OptLevel,
+    procs: &mut MutMap<ProcKey<'a>, Proc<'a>>,
+) {
+    let budget = match Budget::for_opt_level(opt_level) {
+        Some(budget) => budget,
+        None => return,
+    };
+
+    let originals = procs.clone();
+    let inlinable = find_inlinable(&originals, &budget);
+
+    for proc in procs.values_mut() {
+        let mut env = Env {
+            arena,
+            home,
+            ident_ids,
+            originals: &originals,
+            inlinable: &inlinable,
+            substitutions: MutMap::default(),
+            known: MutMap::default(),
+            inline_stack: vec![proc.name.name()],
+            inlined_calls: 0,
+        };
+
+        let body = optimize_stmt(&mut env, &proc.body);
+        let mut body = remove_dead_definitions(arena, body);
+
+        if env.inlined_calls > 0 {
+            // the inlined calls reuse the ids of the proc they were copied from, but the alias
+            // analysis needs them to be unique within the proc they end up in
+            body = renumber_ids(arena, body, &mut CallSpecIds::new(), update_mode_ids);
+        }
+
+        proc.body = body.clone();
+    }
+}
+
+fn find_inlinable<'a>(
+    procs: &MutMap<ProcKey<'a>, Proc<'a>>,
+    budget: &Budget,
+) -> MutSet<ProcKey<'a>> {
+    let mut call_counts: MutMap<ProcKey<'a>, usize> = MutMap::default();
+
+    for proc in procs.values() {
+        visit_stmts(&proc.body, &mut |stmt| {
+            if let Stmt::Let(_, Expr::Call(call), _, _) = stmt {
+                let key = match &call.call_type {
+                    CallType::ByName {
+                        name,
+                        ret_layout,
+                        arg_layouts,
+                        ..
+                    } => proc_key(*name, arg_layouts, **ret_layout),
+                    CallType::HigherOrder(higher_order) => {
+                        let passed_function = &higher_order.passed_function;
+
+                        proc_key(
+                            passed_function.name,
+                            passed_function.argument_layouts,
+                            passed_function.return_layout,
+                        )
+                    }
+                    CallType::Foreign { .. } | CallType::LowLevel { .. } => return,
+                };
+
+                *call_counts.entry(key).or_insert(0) += 1;
+            }
+        });
+    }
+
+    let mut inlinable = MutSet::default();
+
+    for (key, proc) in procs.iter() {
+        if let SelfRecursive::SelfRecursive(_) = proc.is_self_recursive {
+            continue;
+        }
+
+        let mut size = 0;
+        let mut has_expect = false;
+
+        visit_stmts(&proc.body, &mut |stmt| {
+            size += 1;
+            has_expect |= matches!(stmt, Stmt::Expect { .. } | Stmt::ExpectFx { .. });
+        });
+
+        // the region of an expect only makes sense in the module that defines it
+        if has_expect {
+            continue;
+        }
+
+        let single_use = call_counts.get(key) == Some(&1);
+
+        if size <= budget.small || (single_use && size <= budget.single_use) {
+            inlinable.insert(*key);
+        }
+    }
+
+    inlinable
+}
+
+fn proc_key<'a>(
+    name: crate::layout::LambdaName<'a>,
+    arg_layouts: &'a [Layout<'a>],
+    ret_layout: Layout<'a>,
+) -> ProcKey<'a> {
+    let proc_layout = ProcLayout {
+        arguments: arg_layouts,
+        result: ret_layout,
+        captures_niche: name.captures_niche(),
+    };
+
+    (name.name(), proc_layout)
+}
+
+/// A value that is known at compile time
+#[derive(Clone, Copy)]
+enum Known<'a> {
+    Literal(Literal<'a>, Layout<'a>),
+    Struct(&'a [Symbol]),
+    Tag {
+        tag_id: TagIdIntType,
+        arguments: &'a [Symbol],
+    },
+}
+
+struct Env<'a, 'i> {
+    arena: &'a Bump,
+    home: ModuleId,
+    ident_ids: &'i mut IdentIds,
+    originals: &'i MutMap<ProcKey<'a>, Proc<'a>>,
+    inlinable: &'i MutSet<ProcKey<'a>>,
+
+    /// symbols whose definition was removed, and the symbol that holds the same value
+    substitutions: MutMap<Symbol, Symbol>,
+    known: MutMap<Symbol, Known<'a>>,
+
+    /// the proc being optimized, and the procs whose body we are currently inlining into it
+    inline_stack: std::vec::Vec<Symbol>,
+    inlined_calls: usize,
+}
+
+impl<'a, 'i> Env<'a, 'i> {
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    fn substitute(&self, symbol: Symbol) -> Symbol {
+        match self.substitutions.get(&symbol) {
+            Some(substitute) => *substitute,
+            None => symbol,
+        }
+    }
+
+    fn substitute_slice(&self, symbols: &[Symbol]) -> &'a [Symbol] {
+        map_symbols(self.arena, symbols, |symbol| self.substitute(symbol))
+    }
+
+    fn can_inline(&self, key: &ProcKey<'a>, argument_count: usize) -> bool {
+        self.inlinable.contains(key)
+            && self.inline_stack.len() <= MAX_INLINE_DEPTH
+            && self.inlined_calls < MAX_INLINED_CALLS
+            && !self.inline_stack.contains(&key.0)
+            && self.originals[key].args.len() == argument_count
+    }
+
+    /// The branch of a `switch` that a known value selects
+    fn switch_value(&self, symbol: Symbol) -> Option<u64> {
+        match self.known.get(&symbol)? {
+            Known::Literal(Literal::Bool(value), _) => Some(*value as u64),
+            Known::Literal(Literal::Byte(value), _) => Some(*value as u64),
+            Known::Literal(Literal::Int(bytes), _) => {
+                u64::try_from(i128::from_ne_bytes(*bytes)).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+fn optimize_stmt<'a>(env: &mut Env<'a, '_>, stmt: &Stmt<'a>) -> &'a Stmt<'a> {
+    use Stmt::*;
+
+    let arena = env.arena;
+
+    match stmt {
+        Let(symbol, expr, layout, cont) => optimize_let(env, *symbol, expr, *layout, cont),
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+        } => {
+            let cond_symbol = env.substitute(*cond_symbol);
+
+            if let Some(value) = env.switch_value(cond_symbol) {
+                let branch = branches
+                    .iter()
+                    .find(|(label, _, _)| *label == value)
+                    .map(|(_, _, branch)| branch)
+                    .unwrap_or(default_branch.1);
+
+                return optimize_stmt(env, branch);
+            }
+
+            let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
+
+            for (label, info, branch) in branches.iter() {
+                let info = substitute_branch_info(env, info);
+                let branch = optimize_stmt(env, branch);
+
+                new_branches.push((*label, info, branch.clone()));
+            }
+
+            let default_branch = (
+                substitute_branch_info(env, &default_branch.0),
+                optimize_stmt(env, default_branch.1),
+            );
+
+            arena.alloc(Switch {
+                cond_symbol,
+                cond_layout: *cond_layout,
+                branches: new_branches.into_bump_slice(),
+                default_branch,
+                ret_layout: *ret_layout,
+            })
+        }
+        Ret(symbol) => arena.alloc(Ret(env.substitute(*symbol))),
+        Refcounting(modify_rc, cont) => {
+            let modify_rc = map_modify_rc(*modify_rc, |symbol| env.substitute(symbol));
+
+            arena.alloc(Refcounting(modify_rc, optimize_stmt(env, cont)))
+        }
+        Expect {
+            condition,
+            region,
+            lookups,
+            layouts,
+            remainder,
+        } => arena.alloc(Expect {
+            condition: env.substitute(*condition),
+            region: *region,
+            lookups: env.substitute_slice(lookups),
+            layouts,
+            remainder: optimize_stmt(env, remainder),
+        }),
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            layouts,
+            remainder,
+        } => arena.alloc(ExpectFx {
+            condition: env.substitute(*condition),
+            region: *region,
+            lookups: env.substitute_slice(lookups),
+            layouts,
+            remainder: optimize_stmt(env, remainder),
+        }),
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+        } => arena.alloc(Join {
+            id: *id,
+            parameters,
+            body: optimize_stmt(env, body),
+            remainder: optimize_stmt(env, remainder),
+        }),
+        Jump(id, arguments) => arena.alloc(Jump(*id, env.substitute_slice(arguments))),
+        RuntimeError(_) => arena.alloc(stmt.clone()),
+    }
+}
+
+fn optimize_let<'a>(
+    env: &mut Env<'a, '_>,
+    symbol: Symbol,
+    expr: &Expr<'a>,
+    layout: Layout<'a>,
+    cont: &'a Stmt<'a>,
+) -> &'a Stmt<'a> {
+    let arena = env.arena;
+    let mut expr = map_expr_symbols(arena, expr, |symbol| env.substitute(symbol));
+
+    let folded = match &expr {
+        Expr::StructAtIndex {
+            index, structure, ..
+        } => match env.known.get(structure) {
+            Some(Known::Struct(fields)) => {
+                env.substitutions.insert(symbol, fields[*index as usize]);
+
+                return optimize_stmt(env, cont);
+            }
+            _ => None,
+        },
+        Expr::UnionAtIndex {
+            structure,
+            tag_id,
+            index,
+            ..
+        } => match env.known.get(structure) {
+            Some(Known::Tag {
+                tag_id: known_tag_id,
+                arguments,
+            }) if known_tag_id == tag_id && *index < arguments.len() as u64 => {
+                env.substitutions.insert(symbol, arguments[*index as usize]);
+
+                return optimize_stmt(env, cont);
+            }
+            _ => None,
+        },
+        Expr::GetTagId { structure, .. } => match env.known.get(structure) {
+            Some(Known::Tag { tag_id, .. }) => Some(tag_id_literal(*tag_id, layout)),
+            _ => None,
+        },
+        Expr::Call(Call {
+            call_type: CallType::LowLevel { op, .. },
+            arguments,
+        }) => fold_low_level(env, *op, arguments),
+        Expr::Call(Call {
+            call_type:
+                CallType::ByName {
+                    name,
+                    ret_layout,
+                    arg_layouts,
+                    ..
+                },
+            arguments,
+        }) => {
+            let key = proc_key(*name, arg_layouts, **ret_layout);
+
+            if env.can_inline(&key, arguments.len()) {
+                return inline_call(env, symbol, layout, key, arguments, cont);
+            }
+
+            None
+        }
+        _ => None,
+    };
+
+    if let Some(literal) = folded {
+        expr = Expr::Literal(literal);
+    }
+
+    match &expr {
+        Expr::Literal(literal) => {
+            env.known.insert(symbol, Known::Literal(*literal, layout));
+        }
+        Expr::Struct(fields) => {
+            env.known.insert(symbol, Known::Struct(fields));
+        }
+        Expr::Tag {
+            tag_id, arguments, ..
+        } => {
+            let known = Known::Tag {
+                tag_id: *tag_id,
+                arguments,
+            };
+
+            env.known.insert(symbol, known);
+        }
+        _ => {}
+    }
+
+    let cont = optimize_stmt(env, cont);
+
+    arena.alloc(Stmt::Let(symbol, expr, layout, cont))
+}
+
+/// Replaces `let symbol = f arguments in cont` with the body of `f`
+fn inline_call<'a>(
+    env: &mut Env<'a, '_>,
+    symbol: Symbol,
+    layout: Layout<'a>,
+    key: ProcKey<'a>,
+    arguments: &'a [Symbol],
+    cont: &'a Stmt<'a>,
+) -> &'a Stmt<'a> {
+    let arena = env.arena;
+    let callee = &env.originals[&key];
+
+    let mut renames: MutMap<Symbol, Symbol> = callee
+        .args
+        .iter()
+        .map(|(_, parameter)| *parameter)
+        .zip(arguments.iter().copied())
+        .collect();
+
+    let body = copy_with_fresh_symbols(
+        arena,
+        &mut || env.unique_symbol(),
+        &callee.body,
+        &mut renames,
+    );
+
+    env.inlined_calls += 1;
+    env.inline_stack.push(key.0);
+    let body = optimize_stmt(env, body);
+    env.inline_stack.pop();
+
+    match cont {
+        Stmt::Ret(returned) if *returned == symbol => {
+            // a tail call: the returns of the inlined body return from the caller
+            body
+        }
+        _ => match straight_line_result(body) {
+            Some(result) => {
+                env.substitutions.insert(symbol, result);
+                let cont = optimize_stmt(env, cont);
+
+                replace_ret(arena, body, cont)
+            }
+            None => {
+                // the body branches, so each of its returns jumps to the continuation instead
+                let id = JoinPointId(env.unique_symbol());
+                let parameters = arena.alloc([Param {
+                    symbol,
+                    borrow: false,
+                    layout,
+                }]);
+
+                let remainder = map_stmt(arena, body, &mut Expr::clone, &mut |returned| {
+                    Stmt::Jump(id, arena.alloc([returned]))
+                });
+
+                arena.alloc(Stmt::Join {
+                    id,
+                    parameters,
+                    body: optimize_stmt(env, cont),
+                    remainder,
+                })
+            }
+        },
+    }
+}
+
+/// Copies a proc body, giving every symbol it defines a fresh name
+pub(crate) fn copy_with_fresh_symbols<'a>(
+    arena: &'a Bump,
+    fresh_symbol: &mut impl FnMut() -> Symbol,
+    stmt: &Stmt<'a>,
+    renames: &mut MutMap<Symbol, Symbol>,
+) -> &'a Stmt<'a> {
+    use Stmt::*;
+
+    fn rename(renames: &MutMap<Symbol, Symbol>, symbol: Symbol) -> Symbol {
+        match renames.get(&symbol) {
+            Some(renamed) => *renamed,
+            None => symbol,
+        }
+    }
+
+    match stmt {
+        Let(symbol, expr, layout, cont) => {
+            let expr = map_expr_symbols(arena, expr, |symbol| rename(renames, symbol));
+
+            let new_symbol = fresh_symbol();
+            renames.insert(*symbol, new_symbol);
+
+            let cont = copy_with_fresh_symbols(arena, fresh_symbol, cont, renames);
+
+            arena.alloc(Let(new_symbol, expr, *layout, cont))
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+        } => {
+            let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
+
+            for (label, info, branch) in branches.iter() {
+                let info = map_branch_info(info, |symbol| rename(renames, symbol));
+                let branch = copy_with_fresh_symbols(arena, fresh_symbol, branch, renames);
+
+                new_branches.push((*label, info, branch.clone()));
+            }
+
+            let default_info = map_branch_info(&default_branch.0, |symbol| rename(renames, symbol));
+            let default_branch = (
+                default_info,
+                copy_with_fresh_symbols(arena, fresh_symbol, default_branch.1, renames),
+            );
+
+            arena.alloc(Switch {
+                cond_symbol: rename(renames, *cond_symbol),
+                cond_layout: *cond_layout,
+                branches: new_branches.into_bump_slice(),
+                default_branch,
+                ret_layout: *ret_layout,
+            })
+        }
+        Ret(symbol) => arena.alloc(Ret(rename(renames, *symbol))),
+        Refcounting(modify_rc, cont) => {
+            let modify_rc = map_modify_rc(*modify_rc, |symbol| rename(renames, symbol));
+
+            arena.alloc(Refcounting(
+                modify_rc,
+                copy_with_fresh_symbols(arena, fresh_symbol, cont, renames),
+            ))
+        }
+        Expect {
+            condition,
+            region,
+            lookups,
+            layouts,
+            remainder,
+        } => arena.alloc(Expect {
+            condition: rename(renames, *condition),
+            region: *region,
+            lookups: map_symbols(arena, lookups, |symbol| rename(renames, symbol)),
+            layouts,
+            remainder: copy_with_fresh_symbols(arena, fresh_symbol, remainder, renames),
+        }),
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            layouts,
+            remainder,
+        } => arena.alloc(ExpectFx {
+            condition: rename(renames, *condition),
+            region: *region,
+            lookups: map_symbols(arena, lookups, |symbol| rename(renames, symbol)),
+            layouts,
+            remainder: copy_with_fresh_symbols(arena, fresh_symbol, remainder, renames),
+        }),
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+        } => {
+            let new_id = JoinPointId(fresh_symbol());
+            renames.insert(id.0, new_id.0);
+
+            let mut new_parameters = Vec::with_capacity_in(parameters.len(), arena);
+
+            for parameter in parameters.iter() {
+                let new_symbol = fresh_symbol();
+                renames.insert(parameter.symbol, new_symbol);
+
+                new_parameters.push(Param {
+                    symbol: new_symbol,
+                    ..*parameter
+                });
+            }
+
+            arena.alloc(Join {
+                id: new_id,
+                parameters: new_parameters.into_bump_slice(),
+                body: copy_with_fresh_symbols(arena, fresh_symbol, body, renames),
+                remainder: copy_with_fresh_symbols(arena, fresh_symbol, remainder, renames),
+            })
+        }
+        Jump(id, arguments) => arena.alloc(Jump(
+            JoinPointId(rename(renames, id.0)),
+            map_symbols(arena, arguments, |symbol| rename(renames, symbol)),
+        )),
+        RuntimeError(_) => arena.alloc(stmt.clone()),
+    }
+}
+
+/// If the statement is a sequence of `let`s ending in a `ret`, the symbol that it returns
+fn straight_line_result(mut stmt: &Stmt<'_>) -> Option<Symbol> {
+    loop {
+        match stmt {
+            Stmt::Let(_, _, _, cont) => stmt = cont,
+            Stmt::Ret(symbol) => return Some(*symbol),
+            _ => return None,
+        }
+    }
+}
+
+/// Replaces the `ret` at the end of a sequence of `let`s with the given continuation
+fn replace_ret<'a>(arena: &'a Bump, stmt: &Stmt<'a>, cont: &'a Stmt<'a>) -> &'a Stmt<'a> {
+    match stmt {
+        Stmt::Let(symbol, expr, layout, rest) => arena.alloc(Stmt::Let(
+            *symbol,
+            expr.clone(),
+            *layout,
+            replace_ret(arena, rest, cont),
+        )),
+        Stmt::Ret(_) => cont,
+        _ => unreachable!("only straight-line statements are grafted"),
+    }
+}
+
+fn fold_low_level<'a>(
+    env: &Env<'a, '_>,
+    op: LowLevel,
+    arguments: &[Symbol],
+) -> Option<Literal<'a>> {
+    use LowLevel::*;
+
+    let mut operands = std::vec::Vec::with_capacity(arguments.len());
+
+    for argument in arguments {
+        match env.known.get(argument)? {
+            Known::Literal(literal, layout) => operands.push((*literal, *layout)),
+            Known::Struct(_) | Known::Tag { .. } => return None,
+        }
+    }
+
+    match operands.as_slice() {
+        [(Literal::Bool(a), _)] => match op {
+            Not => Some(Literal::Bool(!a)),
+            _ => None,
+        },
+        [(Literal::Bool(a), _), (Literal::Bool(b), _)] => match op {
+            And => Some(Literal::Bool(*a && *b)),
+            Or => Some(Literal::Bool(*a || *b)),
+            Eq => Some(Literal::Bool(a == b)),
+            NotEq => Some(Literal::Bool(a != b)),
+            _ => None,
+        },
+        [(Literal::Byte(a), _), (Literal::Byte(b), _)] => match op {
+            Eq => Some(Literal::Bool(a == b)),
+            NotEq => Some(Literal::Bool(a != b)),
+            _ => None,
+        },
+        [(Literal::Int(a), Layout::Builtin(Builtin::Int(width))), (Literal::Int(b), _)] => {
+            fold_int(op, *width, i128::from_ne_bytes(*a), i128::from_ne_bytes(*b))
+        }
+        [(Literal::Float(a), Layout::Builtin(Builtin::Float(width))), (Literal::Float(b), _)] => {
+            fold_float(op, *width, *a, *b)
+        }
+        _ => None,
+    }
+}
+
+fn fold_int<'a>(op: LowLevel, width: IntWidth, a: i128, b: i128) -> Option<Literal<'a>> {
+    use LowLevel::*;
+
+    let bits = width.stack_size() * 8;
+
+    // the arithmetic below is done in an i128, which cannot represent every result for the
+    // 128-bit widths; those are left to the backends
+    if bits > 64 {
+        return None;
+    }
+
+    let (min, max) = if width.is_signed() {
+        (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+    } else {
+        (0, (1i128 << bits) - 1)
+    };
+
+    let int = |n: i128| Literal::Int(n.to_ne_bytes());
+    let wrap = |n: i128| {
+        let n = n.rem_euclid(1i128 << bits);
+
+        int(if n > max { n - (1i128 << bits) } else { n })
+    };
+    // an overflow crashes at runtime, so we don't fold it
+    let checked = |n: Option<i128>| n.filter(|n| (min..=max).contains(n)).map(int);
+
+    match op {
+        NumAdd => checked(a.checked_add(b)),
+        NumSub => checked(a.checked_sub(b)),
+        NumMul => checked(a.checked_mul(b)),
+        NumAddWrap => Some(wrap(a.wrapping_add(b))),
+        NumSubWrap => Some(wrap(a.wrapping_sub(b))),
+        NumMulWrap => Some(wrap(a.wrapping_mul(b))),
+        NumBitwiseAnd => Some(int(a & b)),
+        NumBitwiseOr => Some(int(a | b)),
+        NumBitwiseXor => Some(int(a ^ b)),
+        Eq => Some(Literal::Bool(a == b)),
+        NotEq => Some(Literal::Bool(a != b)),
+        NumGt => Some(Literal::Bool(a > b)),
+        NumGte => Some(Literal::Bool(a >= b)),
+        NumLt => Some(Literal::Bool(a < b)),
+        NumLte => Some(Literal::Bool(a <= b)),
+        _ => None,
+    }
+}
+
+fn fold_float<'a>(op: LowLevel, width: FloatWidth, a: f64, b: f64) -> Option<Literal<'a>> {
+    use LowLevel::*;
+
+    // an f64 is precise enough that computing the exact result of an f32 operation in it, and
+    // then rounding to f32, gives the same answer as computing in f32 directly
+    let float = match width {
+        FloatWidth::F32 => |n: f64| Literal::Float(n as f32 as f64),
+        FloatWidth::F64 => Literal::Float,
+        FloatWidth::F128 => return None,
+    };
+
+    match op {
+        NumAdd => Some(float(a + b)),
+        NumSub => Some(float(a - b)),
+        NumMul => Some(float(a * b)),
+        Eq => Some(Literal::Bool(a == b)),
+        NotEq => Some(Literal::Bool(a != b)),
+        NumGt => Some(Literal::Bool(a > b)),
+        NumGte => Some(Literal::Bool(a >= b)),
+        NumLt => Some(Literal::Bool(a < b)),
+        NumLte => Some(Literal::Bool(a <= b)),
+        _ => None,
+    }
+}
+
+fn tag_id_literal<'a>(tag_id: TagIdIntType, layout: Layout<'a>) -> Literal<'a> {
+    match layout {
+        Layout::Builtin(Builtin::Bool) => Literal::Bool(tag_id != 0),
+        _ => Literal::Int((tag_id as i128).to_ne_bytes()),
+    }
+}
+
+fn substitute_branch_info<'a>(env: &Env<'a, '_>, info: &BranchInfo<'a>) -> BranchInfo<'a> {
+    map_branch_info(info, |symbol| env.substitute(symbol))
+}
+
+/// Removes the `let`s of pure expressions whose result is never used, and the join points that
+/// are never jumped to
+fn remove_dead_definitions<'a>(arena: &'a Bump, stmt: &'a Stmt<'a>) -> &'a Stmt<'a> {
+    let mut uses: MutMap<Symbol, usize> = MutMap::default();
+
+    visit_stmts(stmt, &mut |stmt| {
+        stmt_uses(stmt, &mut |symbol| *uses.entry(symbol).or_insert(0) += 1)
+    });
+
+    remove_dead_help(arena, stmt, &mut uses)
+}
+
+fn remove_dead_help<'a>(
+    arena: &'a Bump,
+    stmt: &'a Stmt<'a>,
+    uses: &mut MutMap<Symbol, usize>,
+) -> &'a Stmt<'a> {
+    use Stmt::*;
+
+    fn forget_use(uses: &mut MutMap<Symbol, usize>, symbol: Symbol) {
+        if let Some(count) = uses.get_mut(&symbol) {
+            *count -= 1;
+        }
+    }
+
+    fn is_used(uses: &MutMap<Symbol, usize>, symbol: Symbol) -> bool {
+        uses.get(&symbol).map_or(false, |count| *count > 0)
+    }
+
+    match stmt {
+        Let(symbol, expr, layout, cont) => {
+            // visit the continuation first, so the definitions it no longer uses are removed
+            let cont = remove_dead_help(arena, cont, uses);
+
+            if is_pure(expr) && !is_used(uses, *symbol) {
+                expr_uses(expr, &mut |symbol| forget_use(uses, symbol));
+
+                cont
+            } else {
+                arena.alloc(Let(*symbol, expr.clone(), *layout, cont))
+            }
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+        } => {
+            let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
+
+            for (label, info, branch) in branches.iter() {
+                let branch = remove_dead_help(arena, branch, uses);
+
+                new_branches.push((*label, info.clone(), branch.clone()));
+            }
+
+            let default_branch = (
+                default_branch.0.clone(),
+                remove_dead_help(arena, default_branch.1, uses),
+            );
+
+            arena.alloc(Switch {
+                cond_symbol: *cond_symbol,
+                cond_layout: *cond_layout,
+                branches: new_branches.into_bump_slice(),
+                default_branch,
+                ret_layout: *ret_layout,
+            })
+        }
+        Refcounting(modify_rc, cont) => {
+            arena.alloc(Refcounting(*modify_rc, remove_dead_help(arena, cont, uses)))
+        }
+        Expect {
+            condition,
+            region,
+            lookups,
+            layouts,
+            remainder,
+        } => arena.alloc(Expect {
+            condition: *condition,
+            region: *region,
+            lookups,
+            layouts,
+            remainder: remove_dead_help(arena, remainder, uses),
+        }),
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            layouts,
+            remainder,
+        } => arena.alloc(ExpectFx {
+            condition: *condition,
+            region: *region,
+            lookups,
+            layouts,
+            remainder: remove_dead_help(arena, remainder, uses),
+        }),
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+        } => {
+            let remainder = remove_dead_help(arena, remainder, uses);
+
+            if is_used(uses, id.0) {
+                arena.alloc(Join {
+                    id: *id,
+                    parameters,
+                    body: remove_dead_help(arena, body, uses),
+                    remainder,
+                })
+            } else {
+                visit_stmts(body, &mut |stmt| {
+                    stmt_uses(stmt, &mut |symbol| forget_use(uses, symbol))
+                });
+
+                remainder
+            }
+        }
+        Ret(_) | Jump(..) | RuntimeError(_) => stmt,
+    }
+}
+
+fn is_pure(expr: &Expr<'_>) -> bool {
+    !matches!(
+        expr,
+        Expr::Call(_) | Expr::Reuse { .. } | Expr::Reset { .. } | Expr::RuntimeErrorFunction(_)
+    )
+}
+
+/// Gives the calls in a proc body new specialization and update mode ids
+pub(crate) fn renumber_ids<'a>(
+    arena: &'a Bump,
+    stmt: &Stmt<'a>,
+    call_spec_ids: &mut CallSpecIds,
+    update_mode_ids: &mut UpdateModeIds,
+) -> &'a Stmt<'a> {
+    let mut renumber = |expr: &Expr<'a>| match expr {
+        Expr::Call(Call {
+            call_type,
+            arguments,
+        }) => {
+            let call_type = match call_type {
+                CallType::ByName {
+                    name,
+                    ret_layout,
+                    arg_layouts,
+                    ..
+                } => CallType::ByName {
+                    name: *name,
+                    ret_layout,
+                    arg_layouts,
+                    specialization_id: call_spec_ids.next_id(),
+                },
+                CallType::LowLevel { op, .. } => CallType::LowLevel {
+                    op: *op,
+                    update_mode: update_mode_ids.next_id(),
+                },
+                CallType::HigherOrder(higher_order) => {
+                    let passed_function = PassedFunction {
+                        specialization_id: call_spec_ids.next_id(),
+                        ..higher_order.passed_function
+                    };
+
+                    CallType::HigherOrder(arena.alloc(HigherOrderLowLevel {
+                        update_mode: update_mode_ids.next_id(),
+                        passed_function,
+                        ..(*higher_order).clone()
+                    }))
+                }
+                CallType::Foreign { .. } => call_type.clone(),
+            };
+
+            Expr::Call(Call {
+                call_type,
+                arguments,
+            })
+        }
+        _ => expr.clone(),
+    };
+
+    map_stmt(arena, stmt, &mut renumber, &mut Stmt::Ret)
+}
+
+/// Rebuilds a statement with the expressions of its `let`s and its `ret`s replaced
+fn map_stmt<'a>(
+    arena: &'a Bump,
+    stmt: &Stmt<'a>,
+    map_expr: &mut impl FnMut(&Expr<'a>) -> Expr<'a>,
+    map_ret: &mut impl FnMut(Symbol) -> Stmt<'a>,
+) -> &'a Stmt<'a> {
+    use Stmt::*;
+
+    match stmt {
+        Let(symbol, expr, layout, cont) => {
+            let expr = map_expr(expr);
+            let cont = map_stmt(arena, cont, map_expr, map_ret);
+
+            arena.alloc(Let(*symbol, expr, *layout, cont))
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+        } => {
+            let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
+
+            for (label, info, branch) in branches.iter() {
+                let branch = map_stmt(arena, branch, map_expr, map_ret);
+
+                new_branches.push((*label, info.clone(), branch.clone()));
+            }
+
+            let default_branch = (
+                default_branch.0.clone(),
+                map_stmt(arena, default_branch.1, map_expr, map_ret),
+            );
+
+            arena.alloc(Switch {
+                cond_symbol: *cond_symbol,
+                cond_layout: *cond_layout,
+                branches: new_branches.into_bump_slice(),
+                default_branch,
+                ret_layout: *ret_layout,
+            })
+        }
+        Ret(symbol) => arena.alloc(map_ret(*symbol)),
+        Refcounting(modify_rc, cont) => arena.alloc(Refcounting(
+            *modify_rc,
+            map_stmt(arena, cont, map_expr, map_ret),
+        )),
+        Expect {
+            condition,
+            region,
+            lookups,
+            layouts,
+            remainder,
+        } => arena.alloc(Expect {
+            condition: *condition,
+            region: *region,
+            lookups,
+            layouts,
+            remainder: map_stmt(arena, remainder, map_expr, map_ret),
+        }),
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            layouts,
+            remainder,
+        } => arena.alloc(ExpectFx {
+            condition: *condition,
+            region: *region,
+            lookups,
+            layouts,
+            remainder: map_stmt(arena, remainder, map_expr, map_ret),
+        }),
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+        } => arena.alloc(Join {
+            id: *id,
+            parameters,
+            body: map_stmt(arena, body, map_expr, map_ret),
+            remainder: map_stmt(arena, remainder, map_expr, map_ret),
+        }),
+        Jump(..) | RuntimeError(_) => arena.alloc(stmt.clone()),
+    }
+}
+
+pub(crate) fn visit_stmts<'a, 'b>(stmt: &'b Stmt<'a>, f: &mut impl FnMut(&'b Stmt<'a>)) {
+    use Stmt::*;
+
+    let mut stack = vec![stmt];
+
+    while let Some(stmt) = stack.pop() {
+        f(stmt);
+
+        match stmt {
+            Let(_, _, _, cont) | Refcounting(_, cont) => stack.push(cont),
+            Expect { remainder, .. } | ExpectFx { remainder, .. } => stack.push(remainder),
+            Switch {
+                branches,
+                default_branch,
+                ..
+            } => {
+                stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                stack.push(default_branch.1);
+            }
+            Join {
+                body, remainder, ..
+            } => {
+                stack.push(body);
+                stack.push(remainder);
+            }
+            Ret(_) | Jump(..) | RuntimeError(_) => {}
+        }
+    }
+}
+
+/// Calls `f` for every symbol that a statement uses, not counting its child statements.
+/// A jump counts as a use of its join point.
+fn stmt_uses(stmt: &Stmt<'_>, f: &mut impl FnMut(Symbol)) {
+    use Stmt::*;
+
+    match stmt {
+        Let(_, expr, _, _) => expr_uses(expr, f),
+        Switch {
+            cond_symbol,
+            branches,
+            default_branch,
+            ..
+        } => {
+            f(*cond_symbol);
+
+            for (_, info, _) in branches.iter() {
+                map_branch_info(info, |symbol| {
+                    f(symbol);
+                    symbol
+                });
+            }
+
+            map_branch_info(&default_branch.0, |symbol| {
+                f(symbol);
+                symbol
+            });
+        }
+        Ret(symbol) => f(*symbol),
+        Refcounting(modify_rc, _) => f(modify_rc.get_symbol()),
+        Expect {
+            condition, lookups, ..
+        }
+        | ExpectFx {
+            condition, lookups, ..
+        } => {
+            f(*condition);
+            lookups.iter().for_each(|symbol| f(*symbol));
+        }
+        Jump(id, arguments) => {
+            f(id.0);
+            arguments.iter().for_each(|symbol| f(*symbol));
+        }
+        Join { .. } | RuntimeError(_) => {}
+    }
+}
+
+fn expr_uses(expr: &Expr<'_>, f: &mut impl FnMut(Symbol)) {
+    use Expr::*;
+
+    match expr {
+        Call(call) => {
+            call.arguments.iter().for_each(|symbol| f(*symbol));
+
+            if let CallType::HigherOrder(higher_order) = &call.call_type {
+                f(higher_order.passed_function.captured_environment);
+            }
+        }
+        Tag { arguments, .. } | Struct(arguments) => arguments.iter().for_each(|symbol| f(*symbol)),
+        Array { elems, .. } => elems
+            .iter()
+            .filter_map(ListLiteralElement::to_symbol)
+            .for_each(f),
+        StructAtIndex { structure, .. }
+        | GetTagId { structure, .. }
+        | UnionAtIndex { structure, .. } => f(*structure),
+        ExprBox { symbol } | ExprUnbox { symbol } | Reset { symbol, .. } => f(*symbol),
+        Reuse {
+            symbol, arguments, ..
+        } => {
+            f(*symbol);
+            arguments.iter().for_each(|symbol| f(*symbol));
+        }
+        Literal(_) | EmptyArray | RuntimeErrorFunction(_) => {}
+    }
+}
+
+fn map_symbols<'a>(
+    arena: &'a Bump,
+    symbols: &[Symbol],
+    f: impl Fn(Symbol) -> Symbol,
+) -> &'a [Symbol] {
+    Vec::from_iter_in(symbols.iter().map(|symbol| f(*symbol)), arena).into_bump_slice()
+}
+
+fn map_branch_info<'a>(
+    info: &BranchInfo<'a>,
+    mut f: impl FnMut(Symbol) -> Symbol,
+) -> BranchInfo<'a> {
+    match info {
+        BranchInfo::None => BranchInfo::None,
+        BranchInfo::Constructor {
+            scrutinee,
+            layout,
+            tag_id,
+        } => BranchInfo::Constructor {
+            scrutinee: f(*scrutinee),
+            layout: *layout,
+            tag_id: *tag_id,
+        },
+    }
+}
+
+fn map_modify_rc(modify_rc: ModifyRc, f: impl Fn(Symbol) -> Symbol) -> ModifyRc {
+    match modify_rc {
+        ModifyRc::Inc(symbol, n) => ModifyRc::Inc(f(symbol), n),
+        ModifyRc::Dec(symbol) => ModifyRc::Dec(f(symbol)),
+        ModifyRc::DecRef(symbol) => ModifyRc::DecRef(f(symbol)),
+    }
+}
+
+fn map_higher_order_symbols(op: HigherOrder, f: impl Fn(Symbol) -> Symbol) -> HigherOrder {
+    use HigherOrder::*;
+
+    match op {
+        ListMap { xs } => ListMap { xs: f(xs) },
+        ListMap2 { xs, ys } => ListMap2 {
+            xs: f(xs),
+            ys: f(ys),
+        },
+        ListMap3 { xs, ys, zs } => ListMap3 {
+            xs: f(xs),
+            ys: f(ys),
+            zs: f(zs),
+        },
+        ListMap4 { xs, ys, zs, ws } => ListMap4 {
+            xs: f(xs),
+            ys: f(ys),
+            zs: f(zs),
+            ws: f(ws),
+        },
+        ListSortWith { xs } => ListSortWith { xs: f(xs) },
+    }
+}
+
+fn map_expr_symbols<'a>(
+    arena: &'a Bump,
+    expr: &Expr<'a>,
+    f: impl Fn(Symbol) -> Symbol,
+) -> Expr<'a> {
+    use Expr::*;
+
+    match expr {
+        Call(call) => {
+            let call_type = match &call.call_type {
+                CallType::HigherOrder(higher_order) => {
+                    let passed_function = PassedFunction {
+                        captured_environment: f(higher_order.passed_function.captured_environment),
+                        ..higher_order.passed_function
+                    };
+
+                    CallType::HigherOrder(arena.alloc(HigherOrderLowLevel {
+                        op: map_higher_order_symbols(higher_order.op, &f),
+                        passed_function,
+                        ..(*higher_order).clone()
+                    }))
+                }
+                other => other.clone(),
+            };
+
+            Call(crate::ir::Call {
+                call_type,
+                arguments: map_symbols(arena, call.arguments, f),
+            })
+        }
+        Tag {
+            tag_layout,
+            tag_id,
+            arguments,
+        } => Tag {
+            tag_layout: *tag_layout,
+            tag_id: *tag_id,
+            arguments: map_symbols(arena, arguments, f),
+        },
+        Struct(fields) => Struct(map_symbols(arena, fields, f)),
+        StructAtIndex {
+            index,
+            field_layouts,
+            structure,
+        } => StructAtIndex {
+            index: *index,
+            field_layouts,
+            structure: f(*structure),
+        },
+        GetTagId {
+            structure,
+            union_layout,
+        } => GetTagId {
+            structure: f(*structure),
+            union_layout: *union_layout,
+        },
+        UnionAtIndex {
+            structure,
+            tag_id,
+            union_layout,
+            index,
+        } => UnionAtIndex {
+            structure: f(*structure),
+            tag_id: *tag_id,
+            union_layout: *union_layout,
+            index: *index,
+        },
+        Array { elem_layout, elems } => {
+            let elems = elems.iter().map(|elem| match elem {
+                ListLiteralElement::Symbol(symbol) => ListLiteralElement::Symbol(f(*symbol)),
+                ListLiteralElement::Literal(literal) => ListLiteralElement::Literal(*literal),
+            });
+
+            Array {
+                elem_layout: *elem_layout,
+                elems: Vec::from_iter_in(elems, arena).into_bump_slice(),
+            }
+        }
+        ExprBox { symbol } => ExprBox { symbol: f(*symbol) },
+        ExprUnbox { symbol } => ExprUnbox { symbol: f(*symbol) },
+        Reuse {
+            symbol,
+            update_tag_id,
+            update_mode,
+            tag_layout,
+            tag_id,
+            arguments,
+        } => Reuse {
+            symbol: f(*symbol),
+            update_tag_id: *update_tag_id,
+            update_mode: *update_mode,
+            tag_layout: *tag_layout,
+            tag_id: *tag_id,
+            arguments: map_symbols(arena, arguments, f),
+        },
+        Reset {
+            symbol,
+            update_mode,
+        } => Reset {
+            symbol: f(*symbol),
+            update_mode: *update_mode,
+        },
+        Literal(_) | EmptyArray | RuntimeErrorFunction(_) => expr.clone(),
+    }
+}
@@ -16,8 +16,8 @@ use roc_collections::VecMap;
 use roc_debug_flags::dbg_do;
 #[cfg(debug_assertions)]
 use roc_debug_flags::{
-    ROC_PRINT_IR_AFTER_REFCOUNT, ROC_PRINT_IR_AFTER_RESET_REUSE, ROC_PRINT_IR_AFTER_SPECIALIZATION,
-    ROC_PRINT_RUNTIME_ERROR_GEN,
+    ROC_PRINT_IR_AFTER_INLINE, ROC_PRINT_IR_AFTER_REFCOUNT, ROC_PRINT_IR_AFTER_RESET_REUSE,
+    ROC_PRINT_IR_AFTER_SPECIALIZATION, ROC_PRINT_RUNTIME_ERROR_GEN,
 };
 use roc_derive::SharedDerivedModule;
 use roc_error_macros::{internal_error, todo_abilities};
@@ -44,6 +44,9 @@ pub fn pretty_print_ir_symbols() -> bool {
     dbg_do!(ROC_PRINT_IR_AFTER_SPECIALIZATION, {
         return true;
     });
+    dbg_do!(ROC_PRINT_IR_AFTER_INLINE, {
+        return true;
+    });
     dbg_do!(ROC_PRINT_IR_AFTER_RESET_REUSE, {
         return true;
     });
@@ -426,6 +429,33 @@ impl<'a> Proc<'a> {
         );
     }
 
+    pub fn merge_mutually_tail_recursive<'i>(
+        arena: &'a Bump,
+        home: ModuleId,
+        ident_ids: &'i mut IdentIds,
+        update_mode_ids: &'i mut UpdateModeIds,
+        procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    ) {
+        crate::tail_recursion::merge_mutually_tail_recursive(
+            arena,
+            home,
+            ident_ids,
+            update_mode_ids,
+            procs,
+        );
+    }
+
+    pub fn inline_and_fold<'i>(
+        arena: &'a Bump,
+        home: ModuleId,
+        ident_ids: &'i mut IdentIds,
+        update_mode_ids: &'i mut UpdateModeIds,
+        opt_level: OptLevel,
+        procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    ) {
+        crate::inline::inline_and_fold(arena, home, ident_ids, update_mode_ids, opt_level, procs);
+    }
+
     pub fn insert_reset_reuse_operations<'i>(
         arena: &'a Bump,
         home: ModuleId,
@@ -1790,6 +1820,26 @@ impl CallSpecId {
     pub const BACKEND_DUMMY: Self = Self { id: 0 };
 }
 
+/// Hands out fresh call specialization ids for a single proc, for passes that run after
+/// specialization and need to renumber the calls of a proc body.
+#[derive(Debug)]
+pub struct CallSpecIds {
+    next: u32,
+}
+
+impl CallSpecIds {
+    pub const fn new() -> Self {
+        // 0 is reserved for CallSpecId::BACKEND_DUMMY
+        Self { next: 1 }
+    }
+
+    pub fn next_id(&mut self) -> CallSpecId {
+        let id = CallSpecId { id: self.next };
+        self.next += 1;
+        id
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct UpdateModeId {
     id: u32,
@@ -2767,7 +2817,8 @@ fn pattern_to_when<'a>(
         | NumLiteral(..)
         | FloatLiteral(..)
         | StrLiteral(..)
-        | roc_can::pattern::Pattern::SingleQuote(..) => {
+        | roc_can::pattern::Pattern::SingleQuote(..)
+        | NumRange { .. } => {
             // These patters are refutable, and thus should never occur outside a `when` expression
             // They should have been replaced with `UnsupportedPattern` during canonicalization
             unreachable!("refutable pattern {:?} where irrefutable pattern is expected. This should never happen!", pattern.value)
@@ -5464,7 +5515,7 @@ pub fn with_hole<'a>(
                 }
             }
         }
-        TypedHole(_) => Stmt::RuntimeError("Hit a blank"),
+        TypedHole { .. } => Stmt::RuntimeError("Hit a typed hole"),
         RuntimeError(e) => Stmt::RuntimeError(env.arena.alloc(e.runtime_message())),
     }
 }
@@ -7100,6 +7151,7 @@ fn store_pattern_help<'a>(
             return StorePattern::NotProductive(stmt);
         }
         IntLiteral(_, _)
+        | IntRange(_, _, _)
         | FloatLiteral(_, _)
         | DecimalLiteral(_)
         | EnumLiteral { .. }
@@ -7262,6 +7314,7 @@ fn store_tag_pattern<'a>(
                 // ignore
             }
             IntLiteral(_, _)
+            | IntRange(_, _, _)
             | FloatLiteral(_, _)
             | DecimalLiteral(_)
             | EnumLiteral { .. }
@@ -7350,6 +7403,7 @@ fn store_newtype_pattern<'a>(
                 // ignore
             }
             IntLiteral(_, _)
+            | IntRange(_, _, _)
             | FloatLiteral(_, _)
             | DecimalLiteral(_)
             | EnumLiteral { .. }
@@ -7452,6 +7506,7 @@ fn store_record_destruct<'a>(
                 return StorePattern::NotProductive(stmt);
             }
             IntLiteral(_, _)
+            | IntRange(_, _, _)
             | FloatLiteral(_, _)
             | DecimalLiteral(_)
             | EnumLiteral { .. }
@@ -8738,6 +8793,8 @@ pub enum Pattern<'a> {
     Identifier(Symbol),
     Underscore,
     IntLiteral([u8; 16], IntWidth),
+    /// An inclusive range of integers, from the first to the second bound
+    IntRange([u8; 16], [u8; 16], IntWidth),
     FloatLiteral(u64, FloatWidth),
     DecimalLiteral([u8; 16]),
     BitLiteral {
@@ -8784,6 +8841,7 @@ impl<'a> Pattern<'a> {
                 Pattern::Identifier(_)
                 | Pattern::Underscore
                 | Pattern::IntLiteral(_, _)
+                | Pattern::IntRange(_, _, _)
                 | Pattern::FloatLiteral(_, _)
                 | Pattern::DecimalLiteral(_)
                 | Pattern::BitLiteral { .. }
@@ -8900,6 +8958,24 @@ fn from_can_pattern_help<'a>(
             // TODO(opaques) should be `RuntimeError::OpaqueNotDefined`
             Err(RuntimeError::UnsupportedPattern(loc_ident.region))
         }
+        NumRange {
+            num_var,
+            first,
+            last,
+            ..
+        } => {
+            let int_width = match layout_cache.from_var(env.arena, *num_var, env.subs) {
+                Ok(Layout::Builtin(Builtin::Int(int_width))) => int_width,
+                Ok(layout) => internal_error!("range pattern of non-integer layout {:?}", layout),
+                Err(_) => return Err(RuntimeError::UnresolvedTypeVar),
+            };
+
+            let bytes = |value: &IntValue| match value {
+                IntValue::I128(bytes) | IntValue::U128(bytes) => *bytes,
+            };
+
+            Ok(Pattern::IntRange(bytes(first), bytes(last), int_width))
+        }
         NumLiteral(var, num_str, num, _bound) => Ok(make_num_literal_pattern(
             env,
             layout_cache,
@@ -1,10 +1,22 @@
 #![allow(clippy::manual_map)]
 
-use crate::ir::{CallType, Expr, JoinPointId, Param, Stmt};
-use crate::layout::{LambdaName, Layout};
+use crate::inline::{copy_with_fresh_symbols, renumber_ids, visit_stmts};
+use crate::ir::{
+    BranchInfo, Call, CallSpecIds, CallType, Expr, HostExposedLayouts, JoinPointId, Literal, Param,
+    Proc, ProcLayout, SelfRecursive, Stmt, UpdateModeIds,
+};
+use crate::layout::{Builtin, CapturesNiche, LambdaName, Layout};
 use bumpalo::collections::Vec;
 use bumpalo::Bump;
-use roc_module::symbol::Symbol;
+use roc_builtins::bitcode::IntWidth;
+use roc_collections::all::MutMap;
+use roc_collections::ReferenceMatrix;
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+
+type ProcKey<'a> = (Symbol, ProcLayout<'a>);
+
+/// The member of a merged group is selected with a `U8`
+const MAX_GROUP_SIZE: usize = 256;
 
 /// Make tail calls into loops (using join points)
 ///
@@ -38,7 +50,21 @@ pub fn make_tail_recursive<'a>(
 ) -> Option<Stmt<'a>> {
     let allocated = arena.alloc(stmt);
 
-    let new_stmt = insert_jumps(arena, allocated, id, needle, args, ret_layout)?;
+    // to insert a tail-call, it must not just be a call to the function itself, but it must also
+    // have the same layout. In particular when lambda sets get involved, a self-recursive call may
+    // have a different type and should not be converted to a jump!
+    let is_equal_function = |function_name: LambdaName, arguments: &[_], result| {
+        let it = args.iter().map(|t| &t.0);
+        needle == function_name && it.eq(arguments.iter()) && ret_layout == result
+    };
+
+    let new_stmt = insert_jumps(arena, allocated, &|name, arg_layouts, result, arguments| {
+        if is_equal_function(name, arg_layouts, result) {
+            Some(Stmt::Jump(id, arguments))
+        } else {
+            None
+        }
+    })?;
 
     // if we did not early-return, jumps were inserted, we must now add a join point
 
@@ -67,24 +93,298 @@ pub fn make_tail_recursive<'a>(
     Some(join)
 }
 
+/// Make tail calls between mutually recursive functions into loops
+///
+/// e.g.
+///
+/// > isEven n = if n == 0 then Bool.true else isOdd (n - 1)
+/// > isOdd n = if n == 0 then Bool.false else isEven (n - 1)
+///
+/// becomes
+///
+/// ```elm
+/// isEvenOrOdd which1 n1 =
+///     let joinpoint j which n =
+///             when which is
+///                 0 -> if n == 0 then Bool.true else jump j 1 (n - 1)
+///                 _ -> if n == 0 then Bool.false else jump j 0 (n - 1)
+///
+///     in
+///         jump j which1 n1
+///
+/// isEven n = isEvenOrOdd 0 n
+/// isOdd n = isEvenOrOdd 1 n
+/// ```
+///
+/// Only functions with the same argument and return layouts are merged, so that the join point
+/// can take the arguments of every member of the group.
+pub fn merge_mutually_tail_recursive<'a, 'i>(
+    arena: &'a Bump,
+    home: ModuleId,
+    ident_ids: &'i mut IdentIds,
+    update_mode_ids: &'i mut UpdateModeIds,
+    procs: &mut MutMap<ProcKey<'a>, Proc<'a>>,
+) {
+    // sort the procs, so the members of a group (and hence the generated code) have a stable order
+    let mut keys: std::vec::Vec<ProcKey<'a>> = procs.keys().copied().collect();
+    keys.sort_by_key(|(symbol, layout)| (*symbol, layout.arguments, layout.result));
+
+    let index_of: MutMap<ProcKey<'a>, usize> = keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| (*key, index))
+        .collect();
+
+    let mut matrix = ReferenceMatrix::new(keys.len());
+
+    for (row, key) in keys.iter().enumerate() {
+        visit_stmts(&procs[key].body, &mut |stmt| {
+            if let Some(callee) = tail_call_target(stmt) {
+                match index_of.get(&callee) {
+                    Some(&col) if col != row && have_same_layout(&key.1, &callee.1) => {
+                        matrix.set_row_col(row, col, true);
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    let groups: std::vec::Vec<std::vec::Vec<ProcKey<'a>>> = matrix
+        .strongly_connected_components_all()
+        .groups()
+        .filter(|group| (2..=MAX_GROUP_SIZE).contains(&group.count_ones()))
+        .map(|group| group.iter_ones().map(|index| keys[index]).collect())
+        .collect();
+
+    for members in groups {
+        merge_group(arena, home, ident_ids, update_mode_ids, procs, &members);
+    }
+}
+
+fn tail_call_target<'a>(stmt: &Stmt<'a>) -> Option<ProcKey<'a>> {
+    match stmt {
+        Stmt::Let(
+            symbol,
+            Expr::Call(Call {
+                call_type:
+                    CallType::ByName {
+                        name,
+                        ret_layout,
+                        arg_layouts,
+                        ..
+                    },
+                ..
+            }),
+            _,
+            Stmt::Ret(rsym),
+        ) if symbol == rsym => {
+            let proc_layout = ProcLayout {
+                arguments: arg_layouts,
+                result: **ret_layout,
+                captures_niche: name.captures_niche(),
+            };
+
+            Some((name.name(), proc_layout))
+        }
+        _ => None,
+    }
+}
+
+fn have_same_layout(a: &ProcLayout, b: &ProcLayout) -> bool {
+    a.arguments == b.arguments && a.result == b.result
+}
+
+/// Moves the bodies of the members into one proc, and turns the members into wrappers that call
+/// that proc with their index in the group.
+fn merge_group<'a>(
+    arena: &'a Bump,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    update_mode_ids: &mut UpdateModeIds,
+    procs: &mut MutMap<ProcKey<'a>, Proc<'a>>,
+    members: &[ProcKey<'a>],
+) {
+    let mut fresh_symbol = || Symbol::new(home, ident_ids.gen_unique());
+
+    let which_layout = Layout::Builtin(Builtin::Int(IntWidth::U8));
+    let which_literal = |index: usize| Expr::Literal(Literal::Int((index as i128).to_ne_bytes()));
+
+    let ProcLayout {
+        arguments: arg_layouts,
+        result: ret_layout,
+        ..
+    } = members[0].1;
+
+    let merged_name = LambdaName::no_niche(fresh_symbol());
+    let merged_arg_layouts = Vec::from_iter_in(
+        std::iter::once(which_layout).chain(arg_layouts.iter().copied()),
+        arena,
+    )
+    .into_bump_slice();
+
+    let loop_id = JoinPointId(fresh_symbol());
+    let loop_which = fresh_symbol();
+    let loop_args = Vec::from_iter_in(arg_layouts.iter().map(|_| fresh_symbol()), arena);
+
+    // the index of every member, to pass to the join point
+    let whiches: std::vec::Vec<Symbol> = members.iter().map(|_| fresh_symbol()).collect();
+
+    let replace_tail_call =
+        |name: LambdaName<'a>, layouts: &[Layout<'a>], result: Layout<'a>, arguments: &[Symbol]| {
+            let index = members.iter().position(|(symbol, proc_layout)| {
+                *symbol == name.name()
+                    && proc_layout.captures_niche == name.captures_niche()
+                    && proc_layout.arguments == layouts
+                    && proc_layout.result == result
+            })?;
+
+            let jump_arguments = Vec::from_iter_in(
+                std::iter::once(whiches[index]).chain(arguments.iter().copied()),
+                arena,
+            );
+
+            Some(Stmt::Jump(loop_id, jump_arguments.into_bump_slice()))
+        };
+
+    let mut bodies = std::vec::Vec::with_capacity(members.len());
+
+    for key in members {
+        let proc = &procs[key];
+
+        let body = arena.alloc(proc.body.clone());
+        let body = insert_jumps(arena, body, &replace_tail_call).unwrap_or(body);
+
+        // the bodies end up in the same proc, so every member gets its own copy of the symbols
+        let mut renames: MutMap<Symbol, Symbol> = proc
+            .args
+            .iter()
+            .map(|(_, symbol)| *symbol)
+            .zip(loop_args.iter().copied())
+            .collect();
+
+        bodies.push(copy_with_fresh_symbols(
+            arena,
+            &mut fresh_symbol,
+            body,
+            &mut renames,
+        ));
+    }
+
+    let (last_body, other_bodies) = bodies.split_last().unwrap();
+
+    let branches = Vec::from_iter_in(
+        other_bodies
+            .iter()
+            .enumerate()
+            .map(|(index, body)| (index as u64, BranchInfo::None, (*body).clone())),
+        arena,
+    );
+
+    let switch = arena.alloc(Stmt::Switch {
+        cond_symbol: loop_which,
+        cond_layout: which_layout,
+        branches: branches.into_bump_slice(),
+        default_branch: (BranchInfo::None, last_body),
+        ret_layout,
+    });
+
+    let parameters = Vec::from_iter_in(
+        std::iter::once((which_layout, loop_which))
+            .chain(arg_layouts.iter().copied().zip(loop_args.iter().copied()))
+            .map(|(layout, symbol)| Param {
+                symbol,
+                layout,
+                borrow: true,
+            }),
+        arena,
+    )
+    .into_bump_slice();
+
+    let merged_args = Vec::from_iter_in(
+        merged_arg_layouts
+            .iter()
+            .map(|layout| (*layout, fresh_symbol())),
+        arena,
+    )
+    .into_bump_slice();
+
+    let initial_jump = Vec::from_iter_in(merged_args.iter().map(|(_, symbol)| *symbol), arena);
+
+    let mut body = arena.alloc(Stmt::Join {
+        id: loop_id,
+        parameters,
+        body: switch,
+        remainder: arena.alloc(Stmt::Jump(loop_id, initial_jump.into_bump_slice())),
+    });
+
+    for (index, which) in whiches.iter().enumerate().rev() {
+        body = arena.alloc(Stmt::Let(*which, which_literal(index), which_layout, body));
+    }
+
+    let body = renumber_ids(arena, body, &mut CallSpecIds::new(), update_mode_ids);
+
+    let merged_proc = Proc {
+        name: merged_name,
+        args: merged_args,
+        body: body.clone(),
+        closure_data_layout: None,
+        ret_layout,
+        is_self_recursive: SelfRecursive::NotSelfRecursive,
+        must_own_arguments: false,
+        host_exposed_layouts: HostExposedLayouts::NotHostExposed,
+    };
+
+    let merged_layout = ProcLayout {
+        arguments: merged_arg_layouts,
+        result: ret_layout,
+        captures_niche: CapturesNiche::no_niche(),
+    };
+
+    // the members now just call the merged proc
+    for (index, key) in members.iter().enumerate() {
+        let which = fresh_symbol();
+        let result = fresh_symbol();
+
+        let proc = procs.get_mut(key).unwrap();
+
+        let arguments = Vec::from_iter_in(
+            std::iter::once(which).chain(proc.args.iter().map(|(_, symbol)| *symbol)),
+            arena,
+        );
+
+        let call = Expr::Call(Call {
+            call_type: CallType::ByName {
+                name: merged_name,
+                ret_layout: arena.alloc(ret_layout),
+                arg_layouts: merged_arg_layouts,
+                specialization_id: CallSpecIds::new().next_id(),
+            },
+            arguments: arguments.into_bump_slice(),
+        });
+
+        let ret = arena.alloc(Stmt::Ret(result));
+        let call = arena.alloc(Stmt::Let(result, call, ret_layout, ret));
+
+        proc.body = Stmt::Let(which, which_literal(index), which_layout, call);
+        proc.is_self_recursive = SelfRecursive::NotSelfRecursive;
+    }
+
+    procs.insert((merged_name.name(), merged_layout), merged_proc);
+}
+
 fn insert_jumps<'a>(
     arena: &'a Bump,
     stmt: &'a Stmt<'a>,
-    goal_id: JoinPointId,
-    needle: LambdaName,
-    needle_arguments: &'a [(Layout<'a>, Symbol, Symbol)],
-    needle_result: Layout,
+    replace_tail_call: &impl Fn(
+        LambdaName<'a>,
+        &[Layout<'a>],
+        Layout<'a>,
+        &'a [Symbol],
+    ) -> Option<Stmt<'a>>,
 ) -> Option<&'a Stmt<'a>> {
     use Stmt::*;
 
-    // to insert a tail-call, it must not just be a call to the function itself, but it must also
-    // have the same layout. In particular when lambda sets get involved, a self-recursive call may
-    // have a different type and should not be converted to a jump!
-    let is_equal_function = |function_name: LambdaName, arguments: &[_], result| {
-        let it = needle_arguments.iter().map(|t| &t.0);
-        needle == function_name && it.eq(arguments.iter()) && needle_result == result
-    };
-
     match stmt {
         Let(
             symbol,
@@ -100,23 +400,15 @@ fn insert_jumps<'a>(
             }),
             _,
             Stmt::Ret(rsym),
-        ) if symbol == rsym && is_equal_function(*fsym, arg_layouts, **ret_layout) => {
+        ) if symbol == rsym => {
             // replace the call and return with a jump
-
-            let jump = Stmt::Jump(goal_id, arguments);
+            let jump = replace_tail_call(*fsym, arg_layouts, **ret_layout, arguments)?;
 
             Some(arena.alloc(jump))
         }
 
         Let(symbol, expr, layout, cont) => {
-            let opt_cont = insert_jumps(
-                arena,
-                cont,
-                goal_id,
-                needle,
-                needle_arguments,
-                needle_result,
-            );
+            let opt_cont = insert_jumps(arena, cont, replace_tail_call);
 
             if opt_cont.is_some() {
                 let cont = opt_cont.unwrap_or(cont);
@@ -133,22 +425,8 @@ fn insert_jumps<'a>(
             remainder,
             body: continuation,
         } => {
-            let opt_remainder = insert_jumps(
-                arena,
-                remainder,
-                goal_id,
-                needle,
-                needle_arguments,
-                needle_result,
-            );
-            let opt_continuation = insert_jumps(
-                arena,
-                continuation,
-                goal_id,
-                needle,
-                needle_arguments,
-                needle_result,
-            );
+            let opt_remainder = insert_jumps(arena, remainder, replace_tail_call);
+            let opt_continuation = insert_jumps(arena, continuation, replace_tail_call);
 
             if opt_remainder.is_some() || opt_continuation.is_some() {
                 let remainder = opt_remainder.unwrap_or(remainder);
@@ -171,27 +449,13 @@ fn insert_jumps<'a>(
             default_branch,
             ret_layout,
         } => {
-            let opt_default = insert_jumps(
-                arena,
-                default_branch.1,
-                goal_id,
-                needle,
-                needle_arguments,
-                needle_result,
-            );
+            let opt_default = insert_jumps(arena, default_branch.1, replace_tail_call);
 
             let mut did_change = false;
 
             let opt_branches = Vec::from_iter_in(
                 branches.iter().map(|(label, info, branch)| {
-                    match insert_jumps(
-                        arena,
-                        branch,
-                        goal_id,
-                        needle,
-                        needle_arguments,
-                        needle_result,
-                    ) {
+                    match insert_jumps(arena, branch, replace_tail_call) {
                         None => None,
                         Some(branch) => {
                             did_change = true;
@@ -235,19 +499,10 @@ fn insert_jumps<'a>(
                 None
             }
         }
-        Refcounting(modify, cont) => {
-            match insert_jumps(
-                arena,
-                cont,
-                goal_id,
-                needle,
-                needle_arguments,
-                needle_result,
-            ) {
-                Some(cont) => Some(arena.alloc(Refcounting(*modify, cont))),
-                None => None,
-            }
-        }
+        Refcounting(modify, cont) => match insert_jumps(arena, cont, replace_tail_call) {
+            Some(cont) => Some(arena.alloc(Refcounting(*modify, cont))),
+            None => None,
+        },
 
         Expect {
             condition,
@@ -255,14 +510,7 @@ fn insert_jumps<'a>(
             lookups,
             layouts,
             remainder,
-        } => match insert_jumps(
-            arena,
-            remainder,
-            goal_id,
-            needle,
-            needle_arguments,
-            needle_result,
-        ) {
+        } => match insert_jumps(arena, remainder, replace_tail_call) {
             Some(cont) => Some(arena.alloc(Expect {
                 condition: *condition,
                 region: *region,
@@ -279,14 +527,7 @@ fn insert_jumps<'a>(
             lookups,
             layouts,
             remainder,
-        } => match insert_jumps(
-            arena,
-            remainder,
-            goal_id,
-            needle,
-            needle_arguments,
-            needle_result,
-        ) {
+        } => match insert_jumps(arena, remainder, replace_tail_call) {
             Some(cont) => Some(arena.alloc(ExpectFx {
                 condition: *condition,
                 region: *region,
@@ -275,11 +275,19 @@ pub enum Has<'a> {
 pub struct AbilityMember<'a> {
     pub name: Loc<Spaced<'a, &'a str>>,
     pub typ: Loc<TypeAnnotation<'a>>,
+    /// The implementation used by types that don't implement this member themselves, given on the
+    /// line after the demand, e.g. `isNotEq = \a, b -> !(isEq a b)`.
+    pub default: Option<&'a Loc<Expr<'a>>>,
 }
 
 impl AbilityMember<'_> {
     pub fn region(&self) -> Region {
-        Region::across_all([self.name.region, self.typ.region].iter())
+        let end = match self.default {
+            Some(default) => default.region,
+            None => self.typ.region,
+        };
+
+        Region::span_across(&self.name.region, &end)
     }
 }
 
@@ -304,9 +312,13 @@ pub enum TypeDef<'a> {
     /// An ability definition. E.g.
     ///   Hash has
     ///     hash : a -> U64 | a has Hash
+    ///
+    /// The abilities following `has` are the ones every type with this ability must also have,
+    /// e.g. the `Eq` in `Ord has Eq`.
     Ability {
         header: TypeHeader<'a>,
         loc_has: Loc<Has<'a>>,
+        superabilities: &'a [Loc<TypeAnnotation<'a>>],
         members: &'a [AbilityMember<'a>],
     },
 }
@@ -340,6 +352,10 @@ pub enum ValueDef<'a> {
         condition: &'a Loc<Expr<'a>>,
         preceding_comment: Region,
     },
+
+    /// A top-level def that failed to parse. Holds its source text, so the rest of the module
+    /// can still be canonicalized and formatted.
+    Malformed(&'a str),
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -653,6 +669,10 @@ pub enum Pattern<'a> {
     Underscore(&'a str),
     SingleQuote(&'a str),
 
+    /// A range of numbers or characters, e.g. `0..10` or `'a'..='z'`.
+    /// Both ends are always number or single quote literals.
+    Range(&'a Loc<Pattern<'a>>, RangeEnd, &'a Loc<Pattern<'a>>),
+
     // Space
     SpaceBefore(&'a Pattern<'a>, &'a [CommentOrNewline<'a>]),
     SpaceAfter(&'a Pattern<'a>, &'a [CommentOrNewline<'a>]),
@@ -666,6 +686,13 @@ pub enum Pattern<'a> {
     },
 }
 
+/// Whether the end of a range pattern is part of the range, i.e. `0..10` or `0..=10`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RangeEnd {
+    Exclusive,
+    Inclusive,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Base {
     Octal,
@@ -767,6 +794,12 @@ impl<'a> Pattern<'a> {
             (FloatLiteral(x), FloatLiteral(y)) => x == y,
             (StrLiteral(x), StrLiteral(y)) => x == y,
             (Underscore(x), Underscore(y)) => x == y,
+            (SingleQuote(x), SingleQuote(y)) => x == y,
+            (Range(start_x, end_kind_x, end_x), Range(start_y, end_kind_y, end_y)) => {
+                end_kind_x == end_kind_y
+                    && start_x.value.equivalent(&start_y.value)
+                    && end_x.value.equivalent(&end_y.value)
+            }
 
             // Space
             (SpaceBefore(x, _), SpaceBefore(y, _)) => x.equivalent(y),
@@ -1209,11 +1209,101 @@ mod ability {
                 AbilityMember {
                     name: name.map_owned(Spaced::Item),
                     typ,
+                    default: None,
                 }
             }
         )
     }
 
+    /// Parses the abilities following the `has` of an ability definition, like the `Eq, Hash` in
+    /// `Ord has Eq, Hash`. These must be on the same line as the `has`.
+    pub fn parse_superabilities<'a>(
+        arena: &'a Bump,
+        mut state: State<'a>,
+    ) -> ParseResult<'a, &'a [Loc<TypeAnnotation<'a>>], EAbility<'a>> {
+        let mut superabilities = Vec::new_in(arena);
+
+        loop {
+            let initial = state.clone();
+
+            // Anything after a newline is a demand.
+            let (_, spaces, next_state) = space0_e(0, EAbility::DemandName).parse(arena, state)?;
+            if !spaces.is_empty() {
+                return Ok((MadeProgress, superabilities.into_bump_slice(), initial));
+            }
+
+            match specialize(EAbility::Type, type_annotation::ability_name())
+                .parse(arena, next_state)
+            {
+                Ok((_, ability, next_state)) => {
+                    superabilities.push(ability);
+                    state = next_state;
+                }
+                Err((NoProgress, _, _)) if superabilities.is_empty() => {
+                    // This is a demand on the same line, e.g. `Hash has hash : a -> U64 | a has Hash`
+                    return Ok((NoProgress, &[], initial));
+                }
+                Err((_, fail, state)) => return Err((MadeProgress, fail, state)),
+            }
+
+            let before_comma = state.clone();
+            let comma = skip_first!(
+                space0_e(0, EAbility::DemandName),
+                word1(b',', EAbility::DemandName)
+            );
+
+            match comma.parse(arena, state) {
+                Ok((_, _, next_state)) => state = next_state,
+                Err(_) => {
+                    return Ok((MadeProgress, superabilities.into_bump_slice(), before_comma))
+                }
+            }
+        }
+    }
+
+    /// Parses the default implementation of a demand named `name`, like the
+    /// `isNotEq = \a, b -> !(isEq a b)` after `isNotEq : a, a -> Bool | a has Eq`. It must be on
+    /// the line right after the demand, at the same indentation.
+    pub fn parse_default<'a>(
+        indent_column: u32,
+        name: &'a str,
+        arena: &'a Bump,
+        state: State<'a>,
+    ) -> ParseResult<'a, &'a Loc<Expr<'a>>, EExpr<'a>> {
+        let initial = state.clone();
+        let no_default = |state: State<'a>| Err((NoProgress, EExpr::Start(state.pos()), state));
+
+        let state = match space0_e(0, EExpr::IndentStart).parse(arena, state) {
+            Ok((_, spaces, state))
+                if state.column() == indent_column
+                    && spaces.iter().all(|space| space.is_newline()) =>
+            {
+                state
+            }
+            _ => return no_default(initial),
+        };
+
+        let state = match lowercase_ident().parse(arena, state) {
+            Ok((_, ident, state)) if ident == name => state,
+            _ => return no_default(initial),
+        };
+
+        let equals = skip_first!(space0_e(0, EExpr::IndentEquals), operator());
+        let state = match equals.parse(arena, state) {
+            Ok((_, BinOp::Assignment, state)) => state,
+            _ => return no_default(initial),
+        };
+
+        let (_, default, state) = space0_before_e(
+            move |a, s| parse_loc_expr(indent_column + 1, a, s),
+            indent_column + 1,
+            EExpr::IndentEnd,
+        )
+        .parse(arena, state)?;
+
+        Ok((MadeProgress, &*arena.alloc(default), state))
+    }
+
     pub enum IndentLevel {
         PendingMin(u32),
         Exact(u32),
@@ -1309,6 +1399,9 @@ fn finish_parsing_ability_def_help<'a>(
 
     let min_indent_for_demand = start_column + 1;
 
+    let (_, superabilities, state) = ability::parse_superabilities(arena, state)
+        .map_err(|(progress, err, state)| (progress, EExpr::Ability(err, state.pos()), state))?;
+
     // Parse the first demand. This will determine the indentation level all the
     // other demands must observe.
     let (_, (demand_indent_level, first_demand), mut state) =
@@ -1323,6 +1416,19 @@ fn finish_parsing_ability_def_help<'a>(
     let demand_parser = ability::parse_demand(demand_indent);
 
     loop {
+        // A demand may be followed by its default implementation.
+        let last_demand = demands.last_mut().unwrap();
+        let name = last_demand.name.value.extract_spaces().item;
+
+        match ability::parse_default(demand_indent_level, name, arena, state.clone()) {
+            Ok((_, default, next_state)) => {
+                state = next_state;
+                last_demand.default = Some(default);
+            }
+            Err((MadeProgress, fail, state)) => return Err((MadeProgress, fail, state)),
+            Err((NoProgress, _, _)) => {}
+        }
+
         match demand_parser.parse(arena, state.clone()) {
             Ok((_, (_indent, demand), next_state)) => {
                 state = next_state;
@@ -1342,10 +1448,11 @@ fn finish_parsing_ability_def_help<'a>(
         }
     }
 
-    let def_region = Region::span_across(&name.region, &demands.last().unwrap().typ.region);
+    let def_region = Region::span_across(&name.region, &demands.last().unwrap().region());
     let type_def = TypeDef::Ability {
         header: TypeHeader { name, vars: args },
         loc_has,
+        superabilities,
         members: demands.into_bump_slice(),
     };
 
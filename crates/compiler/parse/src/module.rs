@@ -1,4 +1,4 @@
-use crate::ast::{Collection, CommentOrNewline, Defs, Module, Spaced};
+use crate::ast::{Collection, CommentOrNewline, Defs, Module, Spaced, ValueDef};
 use crate::blankspace::{space0_around_ee, space0_before_e, space0_e};
 use crate::header::{
     package_entry, package_name, AppHeader, ExposedName, HostedHeader, ImportsEntry,
@@ -14,7 +14,7 @@ use crate::parser::{
 use crate::state::State;
 use crate::string_literal;
 use crate::type_annotation;
-use roc_region::all::{Loc, Position};
+use roc_region::all::{Loc, Position, Region};
 
 fn end_of_file<'a>() -> impl Parser<'a, (), SyntaxError<'a>> {
     |_arena, state: State<'a>| {
@@ -38,6 +38,245 @@ pub fn module_defs<'a>() -> impl Parser<'a, Defs<'a>, SyntaxError<'a>> {
     )
 }
 
+/// Parses the defs of a module like [module_defs], but doesn't stop at the first syntax error.
+///
+/// If the defs don't parse, every top-level def (one that starts at the beginning of a line) is
+/// parsed on its own instead. Each def that still fails to parse becomes a
+/// [ValueDef::Malformed] holding its source, and its error is recorded, so that the rest of the
+/// module can still be canonicalized and type checked.
+pub fn module_defs_with_recovery<'a>(
+    arena: &'a bumpalo::Bump,
+    state: State<'a>,
+) -> (Defs<'a>, Vec<SourceError<'a, SyntaxError<'a>>>) {
+    let first_fail = match module_defs().parse(arena, state.clone()) {
+        Ok((_, defs, _)) => return (defs, Vec::new()),
+        Err((_, fail, _)) => fail,
+    };
+
+    let boundaries = top_level_def_starts(state.original_bytes(), state.pos().offset as usize);
+    let parse_between = |start: usize, end: usize| {
+        let chunk_state = if start == 0 {
+            state.truncate(boundaries[end])
+        } else {
+            state
+                .at_line_start(boundaries[start])
+                .truncate(boundaries[end])
+        };
+
+        match module_defs().parse(arena, chunk_state) {
+            Ok((_, defs, _)) => Ok(defs),
+            Err((_, fail, _)) => Err(fail),
+        }
+    };
+
+    let mut defs = Defs::default();
+    let mut errors = Vec::new();
+    let mut start = 0;
+
+    while start + 1 < boundaries.len() {
+        match parse_between(start, start + 1) {
+            Ok(chunk) => {
+                // An annotation is followed by its body on the next line; they must be parsed
+                // together to become one def.
+                let ends_in_annotation =
+                    matches!(chunk.last(), Some(Err(ValueDef::Annotation(..))));
+                let joined = if ends_in_annotation && start + 2 < boundaries.len() {
+                    parse_between(start, start + 2).ok()
+                } else {
+                    None
+                };
+
+                match joined {
+                    Some(joined) => {
+                        append_defs(&mut defs, &joined);
+                        start += 2;
+                    }
+                    None => {
+                        append_defs(&mut defs, &chunk);
+                        start += 1;
+                    }
+                }
+            }
+            Err(fail) => {
+                errors.push(fail.into_source_error(&state));
+                push_malformed(
+                    &mut defs,
+                    state.original_bytes(),
+                    boundaries[start],
+                    boundaries[start + 1],
+                );
+                start += 1;
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        // Every def parses on its own, so the problem is in how they fit together.
+        errors.push(first_fail.into_source_error(&state));
+    }
+
+    (defs, errors)
+}
+
+/// The offsets at which top-level defs may start: `start`, the end of the input, and every line
+/// in between that begins with something that can't start a def, like whitespace, a comment, a
+/// closing bracket or quote, or the `|` of a `has` clause. Lines that begin inside of a string
+/// literal are skipped, as are the contents of comments.
+fn top_level_def_starts(bytes: &[u8], start: usize) -> Vec<usize> {
+    let mut starts = vec![start];
+    let mut nesting: Vec<Nesting> = Vec::new();
+    let mut offset = start;
+
+    while offset < bytes.len() {
+        let byte = bytes[offset];
+        let rest = &bytes[offset..];
+
+        if byte == b'\n' {
+            // A single-line string ends with its line, even when it's missing its closing quote.
+            let single_line = nesting.iter().position(|n| {
+                matches!(
+                    n,
+                    Nesting::Str {
+                        triple_quoted: false
+                    }
+                )
+            });
+
+            if let Some(index) = single_line {
+                nesting.truncate(index);
+            }
+
+            let next = offset + 1;
+
+            if nesting.is_empty() && next < bytes.len() && !b" \t\r\n#)]}|\"".contains(&bytes[next])
+            {
+                starts.push(next);
+            }
+
+            offset = next;
+            continue;
+        }
+
+        match nesting.last() {
+            None | Some(Nesting::Interpolation { .. }) => match byte {
+                b'#' => {
+                    // Skip to the end of the comment
+                    offset += rest.iter().position(|b| *b == b'\n').unwrap_or(rest.len());
+                    continue;
+                }
+                b'"' => {
+                    let triple_quoted = rest.starts_with(b"\"\"\"");
+
+                    nesting.push(Nesting::Str { triple_quoted });
+                    offset += if triple_quoted { 3 } else { 1 };
+                    continue;
+                }
+                b'\'' => {
+                    // Skip a character literal, which may be a quote, like '"'
+                    let mut end = 1;
+
+                    while let Some(byte) = rest.get(end) {
+                        match byte {
+                            b'\n' => break,
+                            b'\'' => {
+                                end += 1;
+                                break;
+                            }
+                            b'\\' => end += 2,
+                            _ => end += 1,
+                        }
+                    }
+
+                    offset += end;
+                    continue;
+                }
+                b'(' => {
+                    if let Some(Nesting::Interpolation { open_parens }) = nesting.last_mut() {
+                        *open_parens += 1;
+                    }
+                }
+                b')' => {
+                    if let Some(Nesting::Interpolation { open_parens }) = nesting.last_mut() {
+                        match open_parens.checked_sub(1) {
+                            Some(remaining) => *open_parens = remaining,
+                            None => {
+                                nesting.pop();
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Some(&Nesting::Str { triple_quoted }) => {
+                if rest.starts_with(b"\\(") {
+                    nesting.push(Nesting::Interpolation { open_parens: 0 });
+                    offset += 2;
+                    continue;
+                } else if byte == b'\\' {
+                    // Skip the escaped character, unless it's a newline
+                    offset += if rest.get(1) == Some(&b'\n') { 1 } else { 2 };
+                    continue;
+                } else if triple_quoted && rest.starts_with(b"\"\"\"") {
+                    nesting.pop();
+                    offset += 3;
+                    continue;
+                } else if !triple_quoted && byte == b'"' {
+                    nesting.pop();
+                }
+            }
+        }
+
+        offset += 1;
+    }
+
+    starts.push(bytes.len());
+    starts
+}
+
+/// A string literal, or an interpolation inside of one, that [top_level_def_starts] is in
+enum Nesting {
+    Str { triple_quoted: bool },
+    Interpolation { open_parens: usize },
+}
+
+fn append_defs<'a>(defs: &mut Defs<'a>, other: &Defs<'a>) {
+    for (index, def) in other.defs().enumerate() {
+        let region = other.regions[index];
+        let spaces_before = &other.spaces[other.space_before[index].indices()];
+        let spaces_after = &other.spaces[other.space_after[index].indices()];
+
+        match def {
+            Ok(type_def) => defs.push_type_def(*type_def, region, spaces_before, spaces_after),
+            Err(value_def) => defs.push_value_def(*value_def, region, spaces_before, spaces_after),
+        }
+    }
+}
+
+fn push_malformed<'a>(defs: &mut Defs<'a>, bytes: &'a [u8], start: usize, end: usize) {
+    let source = std::str::from_utf8(&bytes[start..end]).unwrap_or_default();
+    let text = source.trim();
+
+    let text_start = start + (source.len() - source.trim_start().len());
+    let region = Region::new(
+        Position::new(text_start as u32),
+        Position::new((text_start + text.len()) as u32),
+    );
+
+    let newlines = |whitespace: &str| {
+        let count = whitespace.matches('\n').count();
+        vec![CommentOrNewline::Newline; count]
+    };
+    let spaces_before = newlines(&source[..text_start - start]);
+    let spaces_after = newlines(&source[text_start - start + text.len()..]);
+
+    defs.push_value_def(
+        ValueDef::Malformed(text),
+        region,
+        &spaces_before,
+        &spaces_after,
+    );
+}
+
 pub fn parse_header<'a>(
     arena: &'a bumpalo::Bump,
     state: State<'a>,
@@ -119,6 +119,10 @@ fn chomp_number(mut bytes: &[u8]) -> (bool, usize) {
 
     while let Some(byte) = bytes.first() {
         match byte {
+            b'.' if bytes.get(1) == Some(&b'.') => {
+                // the start of a range, e.g. `0..10`
+                return (is_float, start_bytes_len - bytes.len());
+            }
             b'.' => {
                 // skip, fix multiple `.`s in canonicalization
                 is_float = true;
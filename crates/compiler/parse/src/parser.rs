@@ -525,6 +525,7 @@ pub enum EPattern<'a> {
 
     PInParens(PInParens<'a>, Position),
     NumLiteral(ENumber, Position),
+    RangeEnd(Position),
 
     IndentStart(Position),
     IndentEnd(Position),
@@ -1,4 +1,4 @@
-use crate::ast::{Has, Pattern};
+use crate::ast::{Has, Pattern, RangeEnd};
 use crate::blankspace::{space0_around_ee, space0_before_e, space0_e};
 use crate::ident::{lowercase_ident, parse_ident, Ident};
 use crate::parser::Progress::{self, *};
@@ -59,9 +59,8 @@ pub fn loc_pattern_help<'a>(min_indent: u32) -> impl Parser<'a, Loc<Pattern<'a>>
             EPattern::Record,
             crate::pattern::record_pattern_help(min_indent)
         )),
-        loc!(number_pattern_help()),
+        loc_literal_or_range_pattern_help(),
         loc!(string_pattern_help()),
-        loc!(single_quote_pattern_help()),
     )
 }
 
@@ -140,8 +139,7 @@ fn loc_parse_tag_pattern_arg<'a>(
             crate::pattern::record_pattern_help(min_indent)
         )),
         loc!(string_pattern_help()),
-        loc!(single_quote_pattern_help()),
-        loc!(number_pattern_help())
+        loc_literal_or_range_pattern_help()
     )
     .parse(arena, state)
 }
@@ -162,6 +160,41 @@ fn loc_pattern_in_parens_help<'a>(
     )
 }
 
+/// A number or single quote literal, optionally followed by `..` or `..=` and the literal that
+/// ends the range, e.g. `0..10` or `'a'..='z'`.
+fn loc_literal_or_range_pattern_help<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
+    move |arena: &'a Bump, state: State<'a>| {
+        let (_, loc_start, state) = range_literal_help().parse(arena, state)?;
+
+        let (end_kind, width) = if state.bytes().starts_with(b"..=") {
+            (RangeEnd::Inclusive, 3)
+        } else if state.bytes().starts_with(b"..") {
+            (RangeEnd::Exclusive, 2)
+        } else {
+            return Ok((MadeProgress, loc_start, state));
+        };
+
+        let state = state.advance(width);
+        let end_pos = state.pos();
+
+        let (_, loc_end, state) = range_literal_help()
+            .parse(arena, state)
+            .map_err(|(_, _, state)| (MadeProgress, EPattern::RangeEnd(end_pos), state))?;
+
+        let region = Region::span_across(&loc_start.region, &loc_end.region);
+        let value = Pattern::Range(arena.alloc(loc_start), end_kind, arena.alloc(loc_end));
+
+        Ok((MadeProgress, Loc::at(region, value), state))
+    }
+}
+
+fn range_literal_help<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
+    one_of!(
+        loc!(single_quote_pattern_help()),
+        loc!(number_pattern_help())
+    )
+}
+
 fn number_pattern_help<'a>() -> impl Parser<'a, Pattern<'a>, EPattern<'a>> {
     specialize(
         EPattern::NumLiteral,
@@ -29,6 +29,39 @@ impl<'a> State<'a> {
         self.original_bytes
     }
 
+    /// The same position, but in other bytes of the same length, e.g. a rewritten copy of the
+    /// file whose regions should still point into the original.
+    pub fn with_original_bytes(self, bytes: &'a [u8]) -> State<'a> {
+        debug_assert_eq!(self.original_bytes.len(), bytes.len());
+
+        State {
+            original_bytes: bytes,
+            ..self
+        }
+    }
+
+    /// The same position, but as if the input ended at `end`. Positions are still counted from
+    /// the start of the original input.
+    pub(crate) fn truncate(&self, end: usize) -> State<'a> {
+        debug_assert!(self.offset <= end);
+
+        State {
+            original_bytes: &self.original_bytes[..end],
+            ..self.clone()
+        }
+    }
+
+    /// The state at `offset`, which must be the start of a line.
+    pub(crate) fn at_line_start(&self, offset: usize) -> State<'a> {
+        debug_assert!(offset == 0 || self.original_bytes[offset - 1] == b'\n');
+
+        State {
+            original_bytes: self.original_bytes,
+            offset,
+            line_start: Position::new(offset as u32),
+        }
+    }
+
     pub(crate) fn bytes(&self) -> &'a [u8] {
         &self.original_bytes[self.offset..]
     }
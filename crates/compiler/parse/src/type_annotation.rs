@@ -505,10 +505,16 @@ pub fn has_abilities<'a>(min_indent: u32) -> impl Parser<'a, Loc<HasAbilities<'a
     )
 }
 
+/// Parse the name of an ability, e.g. `Hash`; this may be qualified from another module like
+/// `Hash.Hash`.
+pub fn ability_name<'a>() -> impl Parser<'a, Loc<TypeAnnotation<'a>>, EType<'a>> {
+    loc!(specialize(EType::TApply, parse_concrete_type))
+}
+
 fn parse_has_ability<'a>(min_indent: u32) -> impl Parser<'a, HasAbility<'a>, EType<'a>> {
     map!(
         and!(
-            loc!(specialize(EType::TApply, parse_concrete_type)),
+            ability_name(),
             optional(space0_before_e(
                 loc!(map!(
                     specialize(
@@ -0,0 +1,6 @@
+Eq has
+  isEq : a, a -> Bool | a has Eq
+  isNotEq : a, a -> Bool | a has Eq
+  isNotEq = \x, y -> !(isEq x y)
+
+1
@@ -0,0 +1,4 @@
+Ord has Eq, Hash
+  compare : a, a -> [LT, EQ, GT] | a has Ord
+
+1
@@ -0,0 +1,5 @@
+when x is
+ 0..10 -> 1
+ 10..=19 -> 2
+ 'a'..='z' -> 3
+ _ -> 4
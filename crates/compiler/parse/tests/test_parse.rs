@@ -123,8 +123,10 @@ mod test_parse {
         fail/type_argument_no_arrow.expr,
         fail/type_double_comma.expr,
         pass/ability_demand_signature_is_multiline.expr,
+        pass/ability_member_default.expr,
         pass/ability_multi_line.expr,
         pass/ability_single_line.expr,
+        pass/ability_superabilities.expr,
         pass/ability_two_in_a_row.expr,
         pass/add_var_with_spaces.expr,
         pass/add_with_spaces.expr,
@@ -286,6 +288,7 @@ mod test_parse {
         pass/when_with_function_application.expr,
         pass/when_with_negative_numbers.expr,
         pass/when_with_numbers.expr,
+        pass/when_with_ranges.expr,
         pass/when_with_records.expr,
         pass/where_clause_function.expr,
         pass/where_clause_multiple_has_across_newlines.expr,
@@ -833,6 +836,73 @@ mod test_parse {
         }
     }
 
+    #[test]
+    fn module_defs_recover_at_top_level_defs() {
+        use roc_parse::ast::ValueDef;
+        use roc_parse::module::module_defs_with_recovery;
+
+        let arena = &Bump::new();
+
+        let src = indoc!(
+            r#"
+            broken = [
+
+            name : Str
+            name = "World"
+
+            alsoBroken = )
+
+            greeting = Str.concat "Hello, " name
+            "#
+        );
+
+        let (defs, errors) = module_defs_with_recovery(arena, State::new(src.as_bytes()));
+
+        assert_eq!(errors.len(), 2);
+
+        let value_defs: std::vec::Vec<_> = defs.defs().map(|def| def.unwrap_err()).collect();
+
+        match value_defs.as_slice() {
+            [ValueDef::Malformed("broken = ["), ValueDef::AnnotatedBody { .. }, ValueDef::Malformed("alsoBroken = )"), ValueDef::Body(..)] =>
+                {}
+            other => panic!("unexpected defs: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn module_defs_recover_outside_strings_and_comments() {
+        use roc_parse::ast::ValueDef;
+        use roc_parse::module::module_defs_with_recovery;
+
+        let arena = &Bump::new();
+
+        let src = indoc!(
+            r#"
+            message =
+                """
+            greeting = "Hi \(name)"
+                """
+
+            broken = [ # a """ quote
+            comma = ',
+
+            quote = '"'
+            "#
+        );
+
+        let (defs, errors) = module_defs_with_recovery(arena, State::new(src.as_bytes()));
+
+        assert_eq!(errors.len(), 3);
+
+        let value_defs: std::vec::Vec<_> = defs.defs().map(|def| def.unwrap_err()).collect();
+
+        match value_defs.as_slice() {
+            [ValueDef::Malformed(message), ValueDef::Malformed("broken = [ # a \"\"\" quote"), ValueDef::Malformed("comma = ',"), ValueDef::Body(..)]
+                if message.ends_with("    \"\"\"") => {}
+            other => panic!("unexpected defs: {:?}", other),
+        }
+    }
+
     #[test]
     fn parse_expr_size() {
         assert_eq!(std::mem::size_of::<roc_parse::ast::Expr>(), 40);
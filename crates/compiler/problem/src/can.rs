@@ -7,6 +7,8 @@ use roc_parse::pattern::PatternType;
 use roc_region::all::{Loc, Region};
 use roc_types::types::AliasKind;
 
+use crate::warning::WarningKind;
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct CycleEntry {
     pub symbol: Symbol,
@@ -179,6 +181,148 @@ pub enum Problem {
     },
 }
 
+impl Problem {
+    /// The kind of warning this problem is, or `None` if it is an error
+    pub fn warning_kind(&self) -> Option<WarningKind> {
+        match self {
+            Problem::UnusedDef(..) | Problem::UnusedBranchDef(..) => {
+                Some(WarningKind::UnusedDefinition)
+            }
+            Problem::UnusedImport(..) => Some(WarningKind::UnusedImport),
+            Problem::UnusedArgument(..) => Some(WarningKind::UnusedArgument),
+            Problem::DuplicateRecordFieldValue { .. }
+            | Problem::DuplicateRecordFieldType { .. } => Some(WarningKind::DuplicateFieldName),
+            Problem::DuplicateTag { .. } => Some(WarningKind::DuplicateTagName),
+            Problem::NestedSpecialization(..) => Some(WarningKind::SpecializationNotOnTopLevel),
+            Problem::IllegalDerivedAbility(..) => Some(WarningKind::IllegalDerive),
+            Problem::NotAnAbility(..) => Some(WarningKind::NotAnAbility),
+            Problem::ImplementsNonRequired { .. } => Some(WarningKind::UnnecessaryImplementations),
+            Problem::NoIdentifiersIntroduced(..) => Some(WarningKind::UnnecessaryDefinition),
+            Problem::OverloadedSpecialization { .. } => Some(WarningKind::OverloadedSpecialization),
+            Problem::ExposedButNotDefined(..)
+            | Problem::UnknownGeneratesWith(..)
+            | Problem::PrecedenceProblem(..)
+            | Problem::UnsupportedPattern(..)
+            | Problem::Shadowing { .. }
+            | Problem::CyclicAlias(..)
+            | Problem::BadRecursion(..)
+            | Problem::PhantomTypeArgument { .. }
+            | Problem::UnboundTypeVariable { .. }
+            | Problem::InvalidOptionalValue { .. }
+            | Problem::RuntimeError(..)
+            | Problem::SignatureDefMismatch { .. }
+            | Problem::InvalidAliasRigid { .. }
+            | Problem::InvalidInterpolation(..)
+            | Problem::InvalidHexadecimal(..)
+            | Problem::InvalidUnicodeCodePt(..)
+            | Problem::NestedDatatype { .. }
+            | Problem::InvalidExtensionType { .. }
+            | Problem::AbilityHasTypeVariables { .. }
+            | Problem::HasClauseIsNotAbility { .. }
+            | Problem::IllegalHasClause { .. }
+            | Problem::AbilityMemberMissingHasClause { .. }
+            | Problem::AbilityMemberMultipleBoundVars { .. }
+            | Problem::AbilityNotOnToplevel { .. }
+            | Problem::AbilityUsedAsType(..)
+            | Problem::ImplementationNotFound { .. }
+            | Problem::NotAnAbilityMember { .. }
+            | Problem::OptionalAbilityImpl { .. }
+            | Problem::QualifiedAbilityImpl { .. }
+            | Problem::AbilityImplNotIdent { .. }
+            | Problem::DuplicateImpl { .. }
+            | Problem::DoesNotImplementAbility { .. }
+            | Problem::NotBoundInAllPatterns { .. } => None,
+        }
+    }
+
+    /// The region of the code that the problem is about, if there is one
+    pub fn region(&self) -> Option<Region> {
+        match self {
+            Problem::UnusedDef(_, region)
+            | Problem::UnusedImport(_, region)
+            | Problem::UnusedArgument(_, _, _, region)
+            | Problem::UnusedBranchDef(_, region)
+            | Problem::PrecedenceProblem(PrecedenceProblem::BothNonAssociative(region, _, _))
+            | Problem::UnsupportedPattern(_, region)
+            | Problem::CyclicAlias(_, region, _, _)
+            | Problem::PhantomTypeArgument {
+                variable_region: region,
+                ..
+            }
+            | Problem::UnboundTypeVariable {
+                one_occurrence: region,
+                ..
+            }
+            | Problem::DuplicateRecordFieldValue {
+                field_region: region,
+                ..
+            }
+            | Problem::DuplicateRecordFieldType {
+                field_region: region,
+                ..
+            }
+            | Problem::InvalidOptionalValue {
+                field_region: region,
+                ..
+            }
+            | Problem::DuplicateTag {
+                tag_region: region, ..
+            }
+            | Problem::SignatureDefMismatch {
+                def_pattern: region,
+                ..
+            }
+            | Problem::InvalidAliasRigid { region, .. }
+            | Problem::InvalidInterpolation(region)
+            | Problem::InvalidHexadecimal(region)
+            | Problem::InvalidUnicodeCodePt(region)
+            | Problem::NestedDatatype {
+                def_region: region, ..
+            }
+            | Problem::InvalidExtensionType { region, .. }
+            | Problem::AbilityHasTypeVariables {
+                variables_region: region,
+                ..
+            }
+            | Problem::HasClauseIsNotAbility { region }
+            | Problem::IllegalHasClause { region }
+            | Problem::AbilityMemberMissingHasClause { region, .. }
+            | Problem::AbilityMemberMultipleBoundVars {
+                span_has_clauses: region,
+                ..
+            }
+            | Problem::AbilityNotOnToplevel { region }
+            | Problem::AbilityUsedAsType(_, _, region)
+            | Problem::NestedSpecialization(_, region)
+            | Problem::IllegalDerivedAbility(region)
+            | Problem::ImplementationNotFound { region, .. }
+            | Problem::NotAnAbilityMember { region, .. }
+            | Problem::OptionalAbilityImpl { region, .. }
+            | Problem::QualifiedAbilityImpl { region }
+            | Problem::AbilityImplNotIdent { region }
+            | Problem::DuplicateImpl {
+                duplicate: region, ..
+            }
+            | Problem::NotAnAbility(region)
+            | Problem::ImplementsNonRequired { region, .. }
+            | Problem::DoesNotImplementAbility { region, .. }
+            | Problem::NotBoundInAllPatterns { region, .. }
+            | Problem::NoIdentifiersIntroduced(region)
+            | Problem::OverloadedSpecialization {
+                overload: region, ..
+            } => Some(*region),
+            Problem::UnknownGeneratesWith(Loc { region, .. })
+            | Problem::Shadowing {
+                shadow: Loc { region, .. },
+                ..
+            } => Some(*region),
+            Problem::BadRecursion(cycle) => cycle.first().map(|entry| entry.symbol_region),
+            Problem::RuntimeError(runtime_error) => runtime_error.region(),
+            Problem::ExposedButNotDefined(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum ExtensionTypeKind {
     Record,
@@ -342,6 +486,58 @@ pub enum RuntimeError {
 }
 
 impl RuntimeError {
+    /// The region of the code that the error is about, if there is one
+    pub fn region(&self) -> Option<Region> {
+        use RuntimeError::*;
+
+        match self {
+            Shadowing {
+                shadow: Loc { region, .. },
+                ..
+            }
+            | InvalidOptionalValue {
+                field_region: region,
+                ..
+            }
+            | UnsupportedPattern(region)
+            | MalformedPattern(_, region)
+            | LookupNotInScope(Loc { region, .. }, _)
+            | OpaqueNotDefined {
+                usage: Loc { region, .. },
+                ..
+            }
+            | OpaqueOutsideScope {
+                referenced_region: region,
+                ..
+            }
+            | OpaqueNotApplied(Loc { region, .. })
+            | OpaqueAppliedToMultipleArgs(region)
+            | ValueNotExposed { region, .. }
+            | ModuleNotImported { region, .. }
+            | InvalidPrecedence(_, region)
+            | MalformedIdentifier(_, _, region)
+            | MalformedTypeName(_, region)
+            | MalformedClosure(region)
+            | InvalidRecordUpdate { region }
+            | InvalidFloat(_, region, _)
+            | InvalidInt(_, _, region, _)
+            | InvalidInterpolation(region)
+            | InvalidHexadecimal(region)
+            | InvalidUnicodeCodePt(region)
+            | EmptySingleQuote(region)
+            | MultipleCharsInSingleQuote(region)
+            | DegenerateBranch(region) => Some(*region),
+            CircularDef(cycle) => cycle.first().map(|entry| entry.symbol_region),
+            UnresolvedTypeVar
+            | ErroneousType
+            | NonExhaustivePattern
+            | NoImplementationNamed { .. }
+            | NoImplementation
+            | VoidValue
+            | ExposedButNotDefined(_) => None,
+        }
+    }
+
     pub fn runtime_message(self) -> String {
         use RuntimeError::*;
 
@@ -367,4 +563,6 @@ pub enum MalformedPatternProblem {
     BadIdent(roc_parse::ident::BadIdent),
     EmptySingleQuote,
     MultipleCharsInSingleQuote,
+    FloatRangeEnd,
+    EmptyRange,
 }
@@ -0,0 +1,69 @@
+use std::fmt;
+
+/// The kinds of warnings the compiler reports. A kind can be allowed with `--allow <kind>`, or
+/// with a `# roc-allow: <kind>` comment in the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningKind {
+    UnusedDefinition,
+    UnusedImport,
+    UnusedArgument,
+    DuplicateFieldName,
+    DuplicateTagName,
+    SpecializationNotOnTopLevel,
+    IllegalDerive,
+    NotAnAbility,
+    UnnecessaryImplementations,
+    UnnecessaryDefinition,
+    OverloadedSpecialization,
+    RedundantPattern,
+    UnmatchablePattern,
+}
+
+impl WarningKind {
+    pub const ALL: &'static [WarningKind] = &[
+        WarningKind::UnusedDefinition,
+        WarningKind::UnusedImport,
+        WarningKind::UnusedArgument,
+        WarningKind::DuplicateFieldName,
+        WarningKind::DuplicateTagName,
+        WarningKind::SpecializationNotOnTopLevel,
+        WarningKind::IllegalDerive,
+        WarningKind::NotAnAbility,
+        WarningKind::UnnecessaryImplementations,
+        WarningKind::UnnecessaryDefinition,
+        WarningKind::OverloadedSpecialization,
+        WarningKind::RedundantPattern,
+        WarningKind::UnmatchablePattern,
+    ];
+
+    /// The name of the kind, as written in `--allow` and `# roc-allow:`
+    pub const fn name(self) -> &'static str {
+        use WarningKind::*;
+
+        match self {
+            UnusedDefinition => "unused-definition",
+            UnusedImport => "unused-import",
+            UnusedArgument => "unused-argument",
+            DuplicateFieldName => "duplicate-field-name",
+            DuplicateTagName => "duplicate-tag-name",
+            SpecializationNotOnTopLevel => "specialization-not-on-top-level",
+            IllegalDerive => "illegal-derive",
+            NotAnAbility => "not-an-ability",
+            UnnecessaryImplementations => "unnecessary-implementations",
+            UnnecessaryDefinition => "unnecessary-definition",
+            OverloadedSpecialization => "overloaded-specialization",
+            RedundantPattern => "redundant-pattern",
+            UnmatchablePattern => "unmatchable-pattern",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|kind| kind.name() == name)
+    }
+}
+
+impl fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -2,7 +2,7 @@ use roc_can::abilities::AbilitiesStore;
 use roc_can::expr::PendingDerives;
 use roc_collections::{VecMap, VecSet};
 use roc_error_macros::{internal_error, todo_abilities};
-use roc_module::symbol::Symbol;
+use roc_module::symbol::{ModuleId, Symbol};
 use roc_region::all::{Loc, Region};
 use roc_solve_problem::{
     NotDerivableContext, NotDerivableDecode, TypeError, UnderivableReason, Unfulfilled,
@@ -414,6 +414,46 @@ impl ObligationCache {
     }
 }
 
+/// Checks that every opaque type defined in `home` which implements an ability also implements
+/// that ability's superabilities. For example, given `Ord has Eq`, an opaque with `has [Ord]` must
+/// have `Eq` too.
+#[must_use]
+pub fn check_superabilities(home: ModuleId, abilities_store: &AbilitiesStore) -> Vec<TypeError> {
+    let mut implemented = Vec::new();
+
+    for (impl_key, _) in abilities_store.iter_declared_implementations() {
+        if impl_key.opaque.module_id() != home {
+            continue;
+        }
+
+        if let Some(member_def) = abilities_store.member_def(impl_key.ability_member) {
+            implemented.push((impl_key.opaque, member_def.parent_ability));
+        }
+    }
+
+    // Each ability has many members, and the declared implementations are not in any order.
+    implemented.sort();
+    implemented.dedup();
+
+    let mut problems = vec![];
+
+    for (opaque, ability) in implemented {
+        for &superability in abilities_store.superabilities_of(ability) {
+            if !abilities_store.has_declared_implementation(opaque, superability) {
+                problems.push(TypeError::UnfulfilledAbility(
+                    Unfulfilled::OpaqueMissingSuperability {
+                        typ: opaque,
+                        ability,
+                        superability,
+                    },
+                ));
+            }
+        }
+    }
+
+    problems
+}
+
 #[inline(always)]
 #[rustfmt::skip]
 fn is_builtin_number_alias(symbol: Symbol) -> bool {
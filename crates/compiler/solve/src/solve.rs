@@ -1,6 +1,6 @@
 use crate::ability::{
-    resolve_ability_specialization, type_implementing_specialization, AbilityImplError,
-    CheckedDerives, ObligationCache, PendingDerivesTable, Resolved,
+    check_superabilities, resolve_ability_specialization, type_implementing_specialization,
+    AbilityImplError, CheckedDerives, ObligationCache, PendingDerivesTable, Resolved,
 };
 use crate::module::Solved;
 use crate::specialize::{
@@ -22,7 +22,7 @@ use roc_error_macros::internal_error;
 use roc_module::ident::TagName;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_problem::can::CycleEntry;
-use roc_region::all::Loc;
+use roc_region::all::{Loc, Region};
 use roc_solve_problem::TypeError;
 use roc_types::subs::{
     self, AliasVariables, Content, Descriptor, FlatType, GetSubsSlice, LambdaSet, Mark,
@@ -31,8 +31,8 @@ use roc_types::subs::{
 };
 use roc_types::types::Type::{self, *};
 use roc_types::types::{
-    gather_fields_unsorted_iter, AliasCommon, AliasKind, Category, OptAbleType, OptAbleVar, Reason,
-    RecordField, TypeExtension, Uls,
+    gather_fields_unsorted_iter, AliasCommon, AliasKind, Category, MemberImpl, OptAbleType,
+    OptAbleVar, Reason, RecordField, TypeExtension, Uls,
 };
 use roc_unify::unify::{
     unify, unify_introduced_ability_specialization, Env as UEnv, Mode, Obligated,
@@ -507,6 +507,14 @@ struct State {
     mark: Mark,
 }
 
+/// A typed hole, reported once solving is done so that its type is as precise as possible.
+struct PendingTypedHole {
+    var: Variable,
+    region: Region,
+    /// The values in scope at the hole, with their types.
+    candidates: Vec<(Symbol, Variable)>,
+}
+
 #[allow(clippy::too_many_arguments)] // TODO: put params in a context/env var
 pub fn run(
     home: ModuleId,
@@ -539,7 +547,7 @@ pub fn run(
 /// Modify an existing subs in-place instead
 #[allow(clippy::too_many_arguments)] // TODO: put params in a context/env var
 fn run_in_place(
-    _home: ModuleId, // TODO: remove me?
+    home: ModuleId,
     constraints: &Constraints,
     problems: &mut Vec<TypeError>,
     subs: &mut Subs,
@@ -569,12 +577,17 @@ fn run_in_place(
     } = obligation_cache.check_derives(subs, abilities_store, pending_derives);
     problems.extend(derives_problems);
 
+    problems.extend(check_superabilities(home, abilities_store));
+
     let derived_env = DerivedEnv {
         derived_module: &derived_module,
         exposed_types: exposed_by_module,
     };
 
+    let mut typed_holes = Vec::new();
+
     let state = solve(
+        home,
         &arena,
         constraints,
         state,
@@ -588,8 +601,13 @@ fn run_in_place(
         &mut obligation_cache,
         &mut awaiting_specializations,
         &derived_env,
+        &mut typed_holes,
     );
 
+    for typed_hole in typed_holes {
+        problems.push(check_typed_hole(&arena, subs, typed_hole));
+    }
+
     state.env
 }
 
@@ -631,6 +649,7 @@ enum Work<'a> {
 
 #[allow(clippy::too_many_arguments)]
 fn solve(
+    home: ModuleId,
     arena: &Bump,
     constraints: &Constraints,
     mut state: State,
@@ -644,6 +663,7 @@ fn solve(
     obligation_cache: &mut ObligationCache,
     awaiting_specializations: &mut AwaitingSpecializations,
     derived_env: &DerivedEnv,
+    typed_holes: &mut Vec<PendingTypedHole>,
 ) -> State {
     let initial = Work::Constraint {
         env: &Env::default(),
@@ -710,6 +730,20 @@ fn solve(
                         *loc_var,
                     );
 
+                    check_ability_member_default(
+                        home,
+                        arena,
+                        subs,
+                        derived_env,
+                        pools,
+                        abilities_store,
+                        obligation_cache,
+                        awaiting_specializations,
+                        problems,
+                        *symbol,
+                        *loc_var,
+                    );
+
                     new_env.insert_symbol_var_if_vacant(*symbol, loc_var.value);
                 }
 
@@ -820,6 +854,20 @@ fn solve(
                         *loc_var,
                     );
 
+                    check_ability_member_default(
+                        home,
+                        arena,
+                        subs,
+                        derived_env,
+                        pools,
+                        abilities_store,
+                        obligation_cache,
+                        awaiting_specializations,
+                        problems,
+                        *symbol,
+                        *loc_var,
+                    );
+
                     new_env.insert_symbol_var_if_vacant(*symbol, loc_var.value);
                 }
 
@@ -1547,6 +1595,28 @@ fn solve(
                     cycle_mark.set_illegal(subs);
                 }
 
+                state
+            }
+            TypedHole(hole_index) => {
+                let roc_can::constraint::TypedHole {
+                    var,
+                    region,
+                    candidates,
+                } = &constraints.typed_holes[hole_index.index()];
+
+                // Values that are in scope but not solved yet, like defs further down the same
+                // block, have no type to check against the hole.
+                let candidates = candidates
+                    .iter()
+                    .filter_map(|symbol| env.get_var_by_symbol(symbol).map(|var| (*symbol, var)))
+                    .collect();
+
+                typed_holes.push(PendingTypedHole {
+                    var: *var,
+                    region: *region,
+                    candidates,
+                });
+
                 state
             }
         };
@@ -1555,6 +1625,48 @@ fn solve(
     state
 }
 
+/// Reports the type of a typed hole, along with the values in scope that would fit in it.
+fn check_typed_hole(arena: &Bump, subs: &mut Subs, typed_hole: PendingTypedHole) -> TypeError {
+    let PendingTypedHole {
+        var,
+        region,
+        candidates,
+    } = typed_hole;
+
+    // Any value fits in a hole whose type is unconstrained, so suggesting some would not help.
+    let is_unconstrained = matches!(
+        subs.get_content_without_compacting(var),
+        Content::FlexVar(_)
+    );
+
+    let mut fits = Vec::new();
+
+    if !is_unconstrained {
+        let mut pools = Pools::default();
+
+        for (symbol, candidate_var) in candidates {
+            if fits.contains(&symbol) {
+                continue;
+            }
+
+            // Instantiate the candidate like a lookup of it would, and see if it fits.
+            let snapshot = subs.snapshot();
+            let candidate_var =
+                deep_copy_var_in(subs, Rank::toplevel(), &mut pools, candidate_var, arena);
+            let unified = unify(&mut UEnv::new(subs), candidate_var, var, Mode::EQ);
+            subs.rollback_to(snapshot);
+
+            if matches!(unified, Success { .. }) {
+                fits.push(symbol);
+            }
+        }
+    }
+
+    let (typ, _) = subs.var_to_error_type(var);
+
+    TypeError::TypedHole { region, typ, fits }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn compact_lambdas_and_check_obligations(
     arena: &Bump,
@@ -1841,6 +1953,174 @@ fn check_ability_specialization(
     }
 }
 
+/// If a symbol is the default implementation of an ability member, check that its solved type
+/// is as general as the member's signature, and record what lambda sets it specializes to.
+#[allow(clippy::too_many_arguments)]
+fn check_ability_member_default(
+    home: ModuleId,
+    arena: &Bump,
+    subs: &mut Subs,
+    derived_env: &DerivedEnv,
+    pools: &mut Pools,
+    abilities_store: &mut AbilitiesStore,
+    obligation_cache: &mut ObligationCache,
+    awaiting_specializations: &mut AwaitingSpecializations,
+    problems: &mut Vec<TypeError>,
+    symbol: Symbol,
+    symbol_loc_var: Loc<Variable>,
+) {
+    let ability_member = match abilities_store.member_of_default(symbol) {
+        Some(member) => member,
+        None => return,
+    };
+    let (root_signature_var, parent_ability, member_region) =
+        match abilities_store.member_def(ability_member) {
+            Some(root_data) => (
+                root_data.signature_var(),
+                root_data.parent_ability,
+                root_data.region,
+            ),
+            // The member was dropped from its ability; an error was reported for that already.
+            None => return,
+        };
+
+    // Defaults from other modules were checked in their own module; we only need to know their
+    // specialization lambda sets here.
+    let is_local = symbol.module_id() == home;
+
+    let root_signature_var =
+        deep_copy_var_in(subs, Rank::toplevel(), pools, root_signature_var, arena);
+    let snapshot = subs.snapshot();
+    let unified = unify_introduced_ability_specialization(
+        &mut UEnv::new(subs),
+        root_signature_var,
+        symbol_loc_var.value,
+        Mode::EQ,
+    );
+
+    let resolved_mark = match unified {
+        Success {
+            vars: _,
+            must_implement_ability,
+            lambda_sets_to_specialize,
+            extra_metadata: SpecializationLsetCollector(specialization_lambda_sets),
+        } => match type_implementing_specialization(&must_implement_ability, parent_ability) {
+            None => {
+                // The default is as general as the member - all is good. Note that we don't
+                // introduce the unified variables at this rank: the default must stay
+                // generalized, since every type using it instantiates it.
+                subs.commit_snapshot(snapshot);
+
+                let specialization_lambda_sets = specialization_lambda_sets
+                    .into_iter()
+                    .map(|((symbol, region), var)| {
+                        debug_assert_eq!(symbol, ability_member);
+                        (region, var)
+                    })
+                    .collect();
+
+                compact_lambdas_and_check_obligations(
+                    arena,
+                    pools,
+                    problems,
+                    subs,
+                    abilities_store,
+                    obligation_cache,
+                    awaiting_specializations,
+                    derived_env,
+                    lambda_sets_to_specialize,
+                );
+
+                Ok(MemberSpecializationInfo::new(
+                    symbol,
+                    specialization_lambda_sets,
+                ))
+            }
+            Some(_) => {
+                // The default only works for one type, so it can't be the default for all of
+                // them.
+                subs.rollback_to(snapshot);
+
+                if is_local {
+                    let (expected_type, _problems) = subs.var_to_error_type(root_signature_var);
+                    let (actual_type, _problems) = subs.var_to_error_type(symbol_loc_var.value);
+
+                    let reason = Reason::SpecializedAbilityMemberDefault {
+                        member_name: ability_member,
+                        def_region: member_region,
+                    };
+
+                    problems.push(TypeError::BadExpr(
+                        symbol_loc_var.region,
+                        Category::AbilityMemberDefault(ability_member),
+                        actual_type,
+                        Expected::ForReason(reason, expected_type, symbol_loc_var.region),
+                    ));
+                }
+
+                Err(())
+            }
+        },
+        Failure(_, expected_type, actual_type, unimplemented_abilities) => {
+            subs.rollback_to(snapshot);
+
+            if is_local {
+                let reason = Reason::InvalidAbilityMemberDefault {
+                    member_name: ability_member,
+                    def_region: member_region,
+                    unimplemented_abilities,
+                };
+
+                problems.push(TypeError::BadExpr(
+                    symbol_loc_var.region,
+                    Category::AbilityMemberDefault(ability_member),
+                    actual_type,
+                    Expected::ForReason(reason, expected_type, symbol_loc_var.region),
+                ));
+            }
+
+            Err(())
+        }
+        BadType(_, problem) => {
+            subs.rollback_to(snapshot);
+
+            if is_local {
+                problems.push(TypeError::BadType(problem));
+            }
+
+            Err(())
+        }
+    };
+
+    abilities_store.mark_member_default(symbol, resolved_mark);
+
+    // Get the lambda sets waiting on any implementation that uses this default, and compact them
+    // now that it's resolved.
+    let impl_keys: Vec<_> = abilities_store
+        .iter_declared_implementations()
+        .filter(|(impl_key, member_impl)| {
+            impl_key.ability_member == ability_member && **member_impl == MemberImpl::Impl(symbol)
+        })
+        .map(|(impl_key, _)| impl_key)
+        .collect();
+
+    for impl_key in impl_keys {
+        let new_lambda_sets_to_specialize =
+            awaiting_specializations.remove_for_specialized(subs, impl_key);
+        compact_lambdas_and_check_obligations(
+            arena,
+            pools,
+            problems,
+            subs,
+            abilities_store,
+            obligation_cache,
+            awaiting_specializations,
+            derived_env,
+            new_lambda_sets_to_specialize,
+        );
+    }
+}
+
 #[derive(Debug)]
 enum LocalDefVarsVec<T> {
     Stack(arrayvec::ArrayVec<T, 32>),
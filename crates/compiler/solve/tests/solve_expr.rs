@@ -195,7 +195,9 @@ mod solve_expr {
 
         let subs = solved.inner_mut();
 
-        exposed_to_host.retain(|s, _| !abilities_store.is_specialization_name(*s));
+        exposed_to_host.retain(|s, _| {
+            !abilities_store.is_specialization_name(*s) && !abilities_store.is_member_default(*s)
+        });
 
         debug_assert!(exposed_to_host.len() == 1, "{:?}", exposed_to_host);
         let (_symbol, variable) = exposed_to_host.into_iter().next().unwrap();
@@ -6035,6 +6037,98 @@ mod solve_expr {
         )
     }
 
+    #[test]
+    fn ability_member_default_specializations() {
+        check_inferred_abilities(
+            indoc!(
+                r#"
+                app "test" provides [similar] to "./platform"
+
+                Similar has
+                    similar : a, a -> Bool | a has Similar
+                    different : a, a -> Bool | a has Similar
+                    different = \x, y -> !(similar x y)
+
+                Id := U64 has [Similar {similar}]
+
+                similar = \@Id m, @Id n -> m == n
+                "#
+            ),
+            [("Similar:similar", "Id"), ("Similar:different", "Id")],
+        )
+    }
+
+    #[test]
+    fn ability_member_default_called() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Similar has
+                    similar : a, a -> Bool | a has Similar
+                    different : a, a -> Bool | a has Similar
+                    different = \x, y -> !(similar x y)
+
+                Id := U64 has [Similar {similar}]
+
+                similar = \@Id m, @Id n -> m == n
+
+                main = different (@Id 0) (@Id 1)
+                "#
+            ),
+            "Bool",
+        )
+    }
+
+    #[test]
+    fn ability_member_default_overridden() {
+        check_inferred_abilities(
+            indoc!(
+                r#"
+                app "test" provides [similar, different] to "./platform"
+
+                Similar has
+                    similar : a, a -> Bool | a has Similar
+                    different : a, a -> Bool | a has Similar
+                    different = \x, y -> !(similar x y)
+
+                Id := U64 has [Similar {similar, different}]
+
+                similar = \@Id m, @Id n -> m == n
+                different = \@Id m, @Id n -> m != n
+                "#
+            ),
+            [("Similar:similar", "Id"), ("Similar:different", "Id")],
+        )
+    }
+
+    #[test]
+    fn resolve_lambda_set_of_ability_member_default() {
+        infer_queries!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Similar has
+                    similar : a, a -> Bool | a has Similar
+                    different : a, a -> Bool | a has Similar
+                    different = \x, y -> !(similar x y)
+
+                Id := U64 has [Similar {similar}]
+
+                similar = \@Id m, @Id n -> m == n
+
+                main = different (@Id 0) (@Id 1)
+                #      ^^^^^^^^^
+                "#
+            ),
+            @r#"
+            Id#different(4) : Id, Id -[[different(4)]]-> Bool
+            "#
+        )
+    }
+
     #[test]
     fn ability_checked_specialization_with_typed_body() {
         check_inferred_abilities(
@@ -1,6 +1,7 @@
 use roc_can::expected::{Expected, PExpected};
 use roc_module::{ident::Lowercase, symbol::Symbol};
 use roc_problem::can::CycleEntry;
+use roc_problem::warning::WarningKind;
 use roc_region::all::Region;
 
 use roc_types::types::{Category, ErrorType, PatternCategory};
@@ -29,12 +30,91 @@ pub enum TypeError {
         expected_opaque: Symbol,
         found_opaque: Symbol,
     },
+    TypedHole {
+        region: Region,
+        typ: ErrorType,
+        /// Values in scope whose type fits the hole
+        fits: Vec<Symbol>,
+    },
+}
+
+impl TypeError {
+    /// The kind of warning this error is, or `None` if it is an error
+    pub fn warning_kind(&self) -> Option<WarningKind> {
+        match self {
+            TypeError::Exhaustive(roc_exhaustive::Error::Redundant { .. }) => {
+                Some(WarningKind::RedundantPattern)
+            }
+            TypeError::Exhaustive(roc_exhaustive::Error::Unmatchable { .. }) => {
+                Some(WarningKind::UnmatchablePattern)
+            }
+            TypeError::Exhaustive(roc_exhaustive::Error::Incomplete(..))
+            | TypeError::BadExpr(..)
+            | TypeError::BadPattern(..)
+            | TypeError::CircularType(..)
+            | TypeError::CircularDef(..)
+            | TypeError::BadType(..)
+            | TypeError::UnexposedLookup(..)
+            | TypeError::UnfulfilledAbility(..)
+            | TypeError::BadExprMissingAbility(..)
+            | TypeError::BadPatternMissingAbility(..)
+            | TypeError::StructuralSpecialization { .. }
+            | TypeError::WrongSpecialization { .. }
+            | TypeError::TypedHole { .. } => None,
+        }
+    }
+
+    /// The region of the code that the error is about, if there is one
+    pub fn region(&self) -> Option<Region> {
+        use roc_types::types::Problem;
+
+        match self {
+            TypeError::BadExpr(region, ..)
+            | TypeError::BadPattern(region, ..)
+            | TypeError::CircularType(region, ..)
+            | TypeError::BadExprMissingAbility(region, ..)
+            | TypeError::BadPatternMissingAbility(region, ..)
+            | TypeError::StructuralSpecialization { region, .. }
+            | TypeError::WrongSpecialization { region, .. }
+            | TypeError::TypedHole { region, .. } => Some(*region),
+            TypeError::CircularDef(cycle) => cycle.first().map(|entry| entry.symbol_region),
+            TypeError::BadType(problem) => match problem {
+                Problem::CircularType(_, _, region)
+                | Problem::CyclicAlias(_, region, _)
+                | Problem::Shadowed(region, _)
+                | Problem::BadTypeArguments { region, .. }
+                | Problem::HasClauseIsNotAbility(region) => Some(*region),
+                Problem::CanonicalizationProblem
+                | Problem::UnrecognizedIdent(_)
+                | Problem::InvalidModule
+                | Problem::SolvedTypeError => None,
+            },
+            TypeError::UnexposedLookup(_) => None,
+            TypeError::UnfulfilledAbility(unfulfilled) => match unfulfilled {
+                Unfulfilled::OpaqueUnderivable { derive_region, .. } => Some(*derive_region),
+                Unfulfilled::OpaqueDoesNotImplement { .. }
+                | Unfulfilled::OpaqueMissingSuperability { .. }
+                | Unfulfilled::AdhocUnderivable { .. } => None,
+            },
+            TypeError::Exhaustive(error) => match error {
+                roc_exhaustive::Error::Incomplete(region, ..) => Some(*region),
+                roc_exhaustive::Error::Redundant { branch_region, .. }
+                | roc_exhaustive::Error::Unmatchable { branch_region, .. } => Some(*branch_region),
+            },
+        }
+    }
 }
 
 #[derive(PartialEq, Debug, Clone)]
 pub enum Unfulfilled {
     /// No claimed implementation of an ability for an opaque type.
     OpaqueDoesNotImplement { typ: Symbol, ability: Symbol },
+    /// An opaque type implements an ability, but not one of that ability's superabilities.
+    OpaqueMissingSuperability {
+        typ: Symbol,
+        ability: Symbol,
+        superability: Symbol,
+    },
     /// Cannot derive implementation of an ability for a structural type.
     AdhocUnderivable {
         typ: ErrorType,
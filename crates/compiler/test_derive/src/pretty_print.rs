@@ -258,7 +258,7 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
         OpaqueRef { .. } => todo!(),
         Expect { .. } => todo!(),
         ExpectFx { .. } => todo!(),
-        TypedHole(_) => todo!(),
+        TypedHole { .. } => todo!(),
         RuntimeError(_) => todo!(),
     }
 }
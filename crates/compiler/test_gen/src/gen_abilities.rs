@@ -62,6 +62,61 @@ fn hash_specialization_multiple_add() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ability_member_default() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Similar has
+                similar : a, a -> Bool | a has Similar
+                different : a, a -> Bool | a has Similar
+                different = \x, y -> !(similar x y)
+
+            Id := U64 has [Similar {similar}]
+
+            similar = \@Id m, @Id n -> m == n
+
+            main = { same: different (@Id 1) (@Id 1), notSame: different (@Id 0) (@Id 1) }
+            "#
+        ),
+        (true, false),
+        (bool, bool)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ability_member_default_overridden() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Similar has
+                similar : a, a -> Bool | a has Similar
+                different : a, a -> Bool | a has Similar
+                different = \x, y -> !(similar x y)
+
+            Id := U64 has [Similar {similar}]
+
+            similar = \@Id m, @Id n -> m == n
+
+            Always := {} has [Similar {similar: alwaysSimilar, different: neverDifferent}]
+
+            alwaysSimilar = \@Always _, @Always _ -> Bool.true
+            neverDifferent = \@Always _, @Always _ -> Bool.false
+
+            main = { default: different (@Id 0) (@Id 1), overridden: different (@Always {}) (@Always {}) }
+            "#
+        ),
+        (true, false),
+        (bool, bool)
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn alias_member_specialization() {
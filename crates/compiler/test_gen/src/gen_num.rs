@@ -841,6 +841,125 @@ fn gen_div_checked_by_zero_dec() {
     );
 }
 
+/// Dec operands for `dec_arithmetic_matches_roc_std`: a few hand-picked ones, and a fixed
+/// pseudo-random sequence of numbers of every magnitude.
+fn dec_operands() -> Vec<RocDec> {
+    let mut operands: Vec<RocDec> = [
+        "1.5",
+        "2.25",
+        "-10",
+        "3",
+        "0.000000000000000001",
+        "0.5",
+        "0.333333333333333333",
+        "-987654321.987654321",
+        "17014118346046923173.168730371588410572",
+        "-9.999999999999999999",
+    ]
+    .iter()
+    .map(|str| RocDec::from_str(str).unwrap())
+    .collect();
+
+    operands.push(RocDec::MAX);
+
+    // xorshift64, so the numbers are the same on every run
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        state
+    };
+
+    for _ in 0..40 {
+        let bits = ((next() as u128) << 64 | next() as u128) as i128;
+
+        operands.push(RocDec::new(bits >> (next() % 127)));
+    }
+
+    operands
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn dec_arithmetic_matches_roc_std() {
+    // Hosts do Dec arithmetic with roc_std, so it has to agree with the builtins to the last digit
+    let operands = dec_operands();
+    let mut pairs = Vec::new();
+    let mut expected = Vec::new();
+
+    for (i, x) in operands.iter().copied().enumerate() {
+        // pair every number with a few others, rather than all of them, to keep the program small
+        for y in operands
+            .iter()
+            .copied()
+            .cycle()
+            .skip(i + 1)
+            .step_by(7)
+            .take(6)
+        {
+            // Products that are too big for a Dec wrap around in Roc, like with `*`. Products with
+            // MIN and the other overflows would crash the program; they are tested separately
+            let product = (x != RocDec::MIN && y != RocDec::MIN).then(|| x * y);
+
+            let answers = (
+                x.checked_sub(y),
+                product,
+                x.checked_div(y),
+                x.checked_add(y),
+            );
+
+            if let (Some(difference), Some(product), Some(quotient), Some(sum)) = answers {
+                if y != RocDec::ZERO {
+                    pairs.push(format!("{{ x: {}, y: {} }}", x, y));
+                    expected.push((difference, product, quotient, sum));
+                }
+            }
+        }
+    }
+
+    let source = format!(
+        indoc!(
+            r#"
+                pairs : List {{ x : Dec, y : Dec }}
+                pairs = [
+                    {}
+                ]
+
+                List.map pairs \{{ x, y }} -> {{ sum: x + y, difference: x - y, product: x * y, quotient: x / y }}
+            "#
+        ),
+        pairs.join(",\n    ")
+    );
+
+    // Record fields are laid out alphabetically
+    assert_evals_to!(
+        &source,
+        RocList::from_slice(&expected),
+        RocList<(RocDec, RocDec, RocDec, RocDec)>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[should_panic(expected = "Decimal multiplication overflowed!")]
+fn dec_mul_min_overflows() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                # the smallest Dec, which has no absolute value
+                x : Dec
+                x = -170141183460469231731.687303715884105727 - 0.000000000000000001
+
+                x * 2
+            "#
+        ),
+        RocDec::MIN,
+        RocDec
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn gen_int_eq() {
@@ -1641,7 +1760,7 @@ fn num_to_frac() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn num_to_frac_f64_to_f32() {
     assert_evals_to!(
         indoc!(
@@ -1660,7 +1779,7 @@ fn num_to_frac_f64_to_f32() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn num_to_frac_f32_to_f32() {
     assert_evals_to!(
         indoc!(
@@ -2488,12 +2607,12 @@ num_conversion_tests! {
     "Num.toI64", i64, (
         to_i64_same_width, "15u64", 15, ["gen-wasm"]
         to_i64_extend, "15i8", 15, ["gen-wasm"]
-        to_i64_truncate, "115i128", 115
-        to_i64_truncate_wraps, "10_000_000_000_000_000_000i128", -8446744073709551616
+        to_i64_truncate, "115i128", 115, ["gen-wasm"]
+        to_i64_truncate_wraps, "10_000_000_000_000_000_000i128", -8446744073709551616, ["gen-wasm"]
     )
     "Num.toI128", i128, (
-        to_i128_same_width, "15u128", 15
-        to_i128_extend, "15i8", 15
+        to_i128_same_width, "15u128", 15, ["gen-wasm"]
+        to_i128_extend, "15i8", 15, ["gen-wasm"]
     )
     "Num.toU8", u8, (
         to_u8_same_width, "15i8", 15, ["gen-wasm"]
@@ -2515,47 +2634,47 @@ num_conversion_tests! {
     "Num.toU64", u64, (
         to_u64_same_width, "15i64", 15, ["gen-wasm"]
         to_u64_extend, "15i8", 15, ["gen-wasm"]
-        to_u64_truncate, "115i128", 115
-        to_u64_truncate_wraps, "10_000_000_000_000_000_000_000i128", 1864712049423024128
+        to_u64_truncate, "115i128", 115, ["gen-wasm"]
+        to_u64_truncate_wraps, "10_000_000_000_000_000_000_000i128", 1864712049423024128, ["gen-wasm"]
     )
     "Num.toU128", u128, (
-        to_u128_same_width, "15i128", 15
-        to_u128_extend, "15i8", 15
+        to_u128_same_width, "15i128", 15, ["gen-wasm"]
+        to_u128_extend, "15i8", 15, ["gen-wasm"]
     )
     "Num.toNat", usize, (
         to_nat_same_width, "15i64", 15, ["gen-wasm"]
         to_nat_extend, "15i8", 15, ["gen-wasm"]
-        to_nat_truncate, "115i128", 115
+        to_nat_truncate, "115i128", 115, ["gen-wasm"]
     )
     "Num.toF32", f32, (
-        to_f32_from_i8, "15i8", 15.0
-        to_f32_from_i16, "15i16", 15.0
-        to_f32_from_i32, "15i32", 15.0
-        to_f32_from_i64, "15i64", 15.0
-        to_f32_from_i128, "15i128", 15.0
-        to_f32_from_u8, "15u8", 15.0
-        to_f32_from_u16, "15u16", 15.0
-        to_f32_from_u32, "15u32", 15.0
-        to_f32_from_u64, "15u64", 15.0
-        to_f32_from_u128, "15u128", 15.0
-        to_f32_from_nat, "15nat", 15.0
-        to_f32_from_f32, "1.5f32", 1.5
-        to_f32_from_f64, "1.5f64", 1.5
+        to_f32_from_i8, "15i8", 15.0, ["gen-wasm"]
+        to_f32_from_i16, "15i16", 15.0, ["gen-wasm"]
+        to_f32_from_i32, "15i32", 15.0, ["gen-wasm"]
+        to_f32_from_i64, "15i64", 15.0, ["gen-wasm"]
+        to_f32_from_i128, "15i128", 15.0, ["gen-wasm"]
+        to_f32_from_u8, "15u8", 15.0, ["gen-wasm"]
+        to_f32_from_u16, "15u16", 15.0, ["gen-wasm"]
+        to_f32_from_u32, "15u32", 15.0, ["gen-wasm"]
+        to_f32_from_u64, "15u64", 15.0, ["gen-wasm"]
+        to_f32_from_u128, "15u128", 15.0, ["gen-wasm"]
+        to_f32_from_nat, "15nat", 15.0, ["gen-wasm"]
+        to_f32_from_f32, "1.5f32", 1.5, ["gen-wasm"]
+        to_f32_from_f64, "1.5f64", 1.5, ["gen-wasm"]
     )
     "Num.toF64", f64, (
-        to_f64_from_i8, "15i8", 15.0
-        to_f64_from_i16, "15i16", 15.0
-        to_f64_from_i32, "15i32", 15.0
-        to_f64_from_i64, "15i64", 15.0
-        to_f64_from_i128, "15i128", 15.0
-        to_f64_from_u8, "15u8", 15.0
-        to_f64_from_u16, "15u16", 15.0
-        to_f64_from_u32, "15u32", 15.0
-        to_f64_from_u64, "15u64", 15.0
-        to_f64_from_u128, "15u128", 15.0
-        to_f64_from_nat, "15nat", 15.0
-        to_f64_from_f32, "1.5f32", 1.5
-        to_f64_from_f64, "1.5f64", 1.5
+        to_f64_from_i8, "15i8", 15.0, ["gen-wasm"]
+        to_f64_from_i16, "15i16", 15.0, ["gen-wasm"]
+        to_f64_from_i32, "15i32", 15.0, ["gen-wasm"]
+        to_f64_from_i64, "15i64", 15.0, ["gen-wasm"]
+        to_f64_from_i128, "15i128", 15.0, ["gen-wasm"]
+        to_f64_from_u8, "15u8", 15.0, ["gen-wasm"]
+        to_f64_from_u16, "15u16", 15.0, ["gen-wasm"]
+        to_f64_from_u32, "15u32", 15.0, ["gen-wasm"]
+        to_f64_from_u64, "15u64", 15.0, ["gen-wasm"]
+        to_f64_from_u128, "15u128", 15.0, ["gen-wasm"]
+        to_f64_from_nat, "15nat", 15.0, ["gen-wasm"]
+        to_f64_from_f32, "1.5f32", 1.5, ["gen-wasm"]
+        to_f64_from_f64, "1.5f64", 1.5, ["gen-wasm"]
     )
 }
 
@@ -2903,6 +3022,220 @@ fn bytes_to_u32_random_u8s() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn count_leading_zero_bits() {
+    assert_evals_to!("Num.countLeadingZeroBits 0b0010_1000u8", 2, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 0u8", 8, u8);
+    assert_evals_to!("Num.countLeadingZeroBits -1i8", 0, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 0b0010_1000u16", 10, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 0u32", 32, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 1u64", 63, u8);
+    assert_evals_to!("Num.countLeadingZeroBits -1i64", 0, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn count_trailing_zero_bits() {
+    assert_evals_to!("Num.countTrailingZeroBits 0b0010_1000u8", 3, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0u8", 8, u8);
+    assert_evals_to!("Num.countTrailingZeroBits -128i8", 7, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0u16", 16, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0b0010_0000_0000u32", 9, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0u64", 64, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn count_one_bits() {
+    assert_evals_to!("Num.countOneBits 0b0010_1000u8", 2, u8);
+    assert_evals_to!("Num.countOneBits -1i8", 8, u8);
+    assert_evals_to!("Num.countOneBits -1i16", 16, u8);
+    assert_evals_to!("Num.countOneBits 0b0010_1011u32", 4, u8);
+    assert_evals_to!("Num.countOneBits 0u64", 0, u8);
+    assert_evals_to!("Num.countOneBits -1i64", 64, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn count_bits_128() {
+    assert_evals_to!("Num.countLeadingZeroBits 1u128", 127, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0u128", 128, u8);
+    assert_evals_to!("Num.countOneBits -1i128", 128, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn to_bytes_le() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        "Num.toBytesLe 0x12u8",
+        RocList::from_slice(&[0x12u8]),
+        RocList<u8>
+    );
+    assert_evals_to!(
+        "Num.toBytesLe 0x0102u16",
+        RocList::from_slice(&[2u8, 1]),
+        RocList<u8>
+    );
+    assert_evals_to!(
+        "Num.toBytesLe -2i32",
+        RocList::from_slice(&[0xFEu8, 0xFF, 0xFF, 0xFF]),
+        RocList<u8>
+    );
+    assert_evals_to!(
+        "Num.toBytesLe 0x0102_0304_0506_0708u64",
+        RocList::from_slice(&[8u8, 7, 6, 5, 4, 3, 2, 1]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn to_bytes_be() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        "Num.toBytesBe 0x12u8",
+        RocList::from_slice(&[0x12u8]),
+        RocList<u8>
+    );
+    assert_evals_to!(
+        "Num.toBytesBe 0x0102u16",
+        RocList::from_slice(&[1u8, 2]),
+        RocList<u8>
+    );
+    assert_evals_to!(
+        "Num.toBytesBe 0x0102_0304u32",
+        RocList::from_slice(&[1u8, 2, 3, 4]),
+        RocList<u8>
+    );
+    assert_evals_to!(
+        "Num.toBytesBe 1u128",
+        RocList::from_slice(&[0u8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn from_bytes_le() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                result : Result U16 [OutOfBounds]
+                result = Num.fromBytesLe [1, 2, 3] 1
+
+                when result is
+                    Ok v -> v
+                    Err OutOfBounds -> 0
+                "#
+        ),
+        0x0302,
+        u16
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+                result : Result I32 [OutOfBounds]
+                result = Num.fromBytesLe [0xFE, 0xFF, 0xFF, 0xFF] 0
+
+                when result is
+                    Ok v -> v
+                    Err OutOfBounds -> 0
+                "#
+        ),
+        -2,
+        i32
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn from_bytes_be() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                result : Result U32 [OutOfBounds]
+                result = Num.fromBytesBe [0, 1, 2, 3, 4] 1
+
+                when result is
+                    Ok v -> v
+                    Err OutOfBounds -> 0
+                "#
+        ),
+        0x0102_0304,
+        u32
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn from_bytes_out_of_bounds() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                result : Result U16 [OutOfBounds]
+                result = Num.fromBytesLe [1, 2, 3] 2
+
+                when result is
+                    Ok _ -> Bool.false
+                    Err OutOfBounds -> Bool.true
+                "#
+        ),
+        true,
+        bool
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+                result : Result U64 [OutOfBounds]
+                result = Num.fromBytesBe [1, 2, 3] 234
+
+                when result is
+                    Ok _ -> Bool.false
+                    Err OutOfBounds -> Bool.true
+                "#
+        ),
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn to_bytes_from_bytes_roundtrip() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                x : U64
+                x = 0x1234_5678_9ABC_DEF0
+
+                when Num.fromBytesBe (Num.toBytesBe x) 0 is
+                    Ok v -> v == x
+                    Err OutOfBounds -> Bool.false
+                "#
+        ),
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn f64_to_bits() {
+    assert_evals_to!("Num.f64ToBits 1.0", 0x3FF0_0000_0000_0000, u64);
+    assert_evals_to!("Num.f64ToBits -2.5", 0xC004_0000_0000_0000, u64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn f64_from_bits() {
+    assert_evals_to!("Num.f64FromBits 0x4000_0000_0000_0000", 2.0, f64);
+    assert_evals_to!("Num.f64FromBits 0xBFF8_0000_0000_0000", -1.5, f64);
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn when_on_i32() {
@@ -3808,3 +4141,364 @@ fn condition_polymorphic_num_becomes_float() {
         f32
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn compare_i128() {
+    assert_evals_to!("-1i128 < 1i128", true, bool);
+    assert_evals_to!(
+        "18446744073709551616i128 > 18446744073709551615i128",
+        true,
+        bool
+    );
+    assert_evals_to!(
+        "-18446744073709551616i128 > -18446744073709551615i128",
+        false,
+        bool
+    );
+    assert_evals_to!("5i128 <= 5i128", true, bool);
+    assert_evals_to!("4i128 >= 5i128", false, bool);
+    assert_evals_to!("Num.compare -1i128 1i128", RocOrder::Lt, RocOrder);
+    assert_evals_to!("Num.compare 7i128 7i128", RocOrder::Eq, RocOrder);
+    assert_evals_to!(
+        "Num.compare 18446744073709551616i128 1i128",
+        RocOrder::Gt,
+        RocOrder
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn compare_u128() {
+    assert_evals_to!(
+        "340282366920938463463374607431768211455u128 > 1u128",
+        true,
+        bool
+    );
+    assert_evals_to!(
+        "18446744073709551615u128 < 18446744073709551616u128",
+        true,
+        bool
+    );
+    assert_evals_to!(
+        "Num.compare 1u128 340282366920938463463374607431768211455u128",
+        RocOrder::Lt,
+        RocOrder
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+fn compare_dec() {
+    assert_evals_to!("-1.5dec < 0.5dec", true, bool);
+    assert_evals_to!("2.25dec >= 2.5dec", false, bool);
+    assert_evals_to!("Num.compare 2.5dec 2.5dec", RocOrder::Eq, RocOrder);
+    assert_evals_to!("Num.compare 0.1dec -0.1dec", RocOrder::Gt, RocOrder);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn bitwise_i128() {
+    assert_evals_to!(
+        "Num.bitwiseAnd 340282366920938463463374607431768211455u128 18446744073709551616u128",
+        1 << 64,
+        u128
+    );
+    assert_evals_to!(
+        "Num.bitwiseOr 18446744073709551616i128 1i128",
+        (1 << 64) | 1,
+        i128
+    );
+    assert_evals_to!(
+        "Num.bitwiseXor -1i128 18446744073709551616i128",
+        !(1 << 64),
+        i128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn shift_i128() {
+    assert_evals_to!("Num.shiftLeftBy 1i128 100", 1 << 100, i128);
+    assert_evals_to!("Num.shiftRightBy -256i128 4", -16, i128);
+    assert_evals_to!(
+        "Num.shiftRightZfBy (Num.shiftLeftBy 1u128 127) 127",
+        1,
+        u128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn div_rem_i128() {
+    assert_evals_to!("-7i128 // 2", -3, i128);
+    assert_evals_to!("-7i128 % 2", -1, i128);
+    assert_evals_to!("7i128 % -2", 1, i128);
+    assert_evals_to!(
+        "340282366920938463463374607431768211455u128 // 2",
+        u128::MAX / 2,
+        u128
+    );
+    assert_evals_to!("Num.isMultipleOf 36893488147419103232i128 -4", true, bool);
+    assert_evals_to!("Num.isMultipleOf 36893488147419103233i128 2", false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn abs_neg_i128() {
+    assert_evals_to!("Num.abs -18446744073709551616i128", 1 << 64, i128);
+    assert_evals_to!("Num.neg 18446744073709551616i128", -(1 << 64), i128);
+    assert_evals_to!("Num.neg Num.maxI128", -i128::MAX, i128);
+}
+
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+fn abs_neg_dec() {
+    assert_evals_to!(
+        "Num.abs -1.5dec",
+        RocDec::from_str_to_i128_unsafe("1.5"),
+        i128
+    );
+    assert_evals_to!(
+        "Num.neg 1.5dec",
+        RocDec::from_str_to_i128_unsafe("-1.5"),
+        i128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn wrap_i128() {
+    assert_evals_to!("Num.addWrap Num.maxI128 1", i128::MIN, i128);
+    assert_evals_to!("Num.subWrap Num.minI128 1", i128::MAX, i128);
+    assert_evals_to!("Num.mulWrap Num.maxI128 2", -2, i128);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn round_to_i128() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            x : I128
+            x = Num.round -3.7f64
+            x
+            "#
+        ),
+        -4,
+        i128
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+            x : I128
+            x = Num.ceiling 3.2f64
+            x
+            "#
+        ),
+        4,
+        i128
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+            x : I128
+            x = Num.floor 3.7f64
+            x
+            "#
+        ),
+        3,
+        i128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn pow_int_i128() {
+    assert_evals_to!("Num.powInt 3i128 40", 12157665459056928801, i128);
+}
+
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+fn dec_to_float() {
+    assert_evals_to!("Num.toF64 -1.5dec", -1.5, f64);
+    assert_evals_to!("Num.toF32 2.25dec", 2.25, f32);
+}
+
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+fn to_float_checked() {
+    assert_evals_to!("Result.withDefault (Num.toF32Checked 1.5f64) 23", 1.5, f32);
+    assert_evals_to!(
+        "Result.withDefault (Num.toF32Checked Num.maxF64) 23",
+        23.0,
+        f32
+    );
+    assert_evals_to!(
+        "Result.withDefault (Num.toF64Checked 18446744073709551616i128) 23",
+        18446744073709551616.0,
+        f64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn when_inclusive_range() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f : I64 -> I64
+                f = \n ->
+                    when n is
+                        0..=9 -> 1
+                        10..=19 -> 2
+                        _ -> 3
+
+                [f -1, f 0, f 9, f 10, f 19, f 20]
+                "#
+        ),
+        RocList::from_slice(&[3, 1, 1, 2, 2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn when_exclusive_range() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f : I64 -> I64
+                f = \n ->
+                    when n is
+                        0..10 -> 1
+                        10..20 -> 2
+                        _ -> 3
+
+                [f 0, f 9, f 10, f 19, f 20]
+                "#
+        ),
+        RocList::from_slice(&[1, 1, 2, 2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn when_overlapping_ranges() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f : I64 -> I64
+                f = \n ->
+                    when n is
+                        0..=10 -> 1
+                        5..=15 -> 2
+                        _ -> 3
+
+                [f 4, f 5, f 10, f 11, f 15, f 16]
+                "#
+        ),
+        RocList::from_slice(&[1, 1, 1, 2, 2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn when_char_range() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f : U32 -> I64
+                f = \c ->
+                    when c is
+                        'a'..='z' -> 1
+                        'A'..='Z' -> 2
+                        _ -> 3
+
+                [f 'a', f 'q', f 'z', f 'Q', f '1']
+                "#
+        ),
+        RocList::from_slice(&[1, 1, 1, 2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn when_range_with_guard() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f : I64, I64 -> I64
+                f = \n, m ->
+                    when n is
+                        0..=9 if m > 0 -> 1
+                        0..=9 -> 2
+                        _ -> 3
+
+                [f 5 1, f 5 0, f 10 1]
+                "#
+        ),
+        RocList::from_slice(&[1, 2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn when_u128_range() {
+    use roc_std::RocList;
+
+    // covers every U128, so no catch-all branch is needed
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f : U128 -> I64
+                f = \n ->
+                    when n is
+                        0..=18446744073709551615 -> 1
+                        18446744073709551616..=340282366920938463463374607431768211455 -> 2
+
+                [f 0, f 18446744073709551615, f 18446744073709551616, f 340282366920938463463374607431768211455]
+                "#
+        ),
+        RocList::from_slice(&[1, 1, 2, 2]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn when_i128_range() {
+    use roc_std::RocList;
+
+    // covers every I128, so no catch-all branch is needed
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f : I128 -> I64
+                f = \n ->
+                    when n is
+                        -170141183460469231731687303715884105728..0 -> 1
+                        0 -> 2
+                        1..=170141183460469231731687303715884105727 -> 3
+
+                [f -170141183460469231731687303715884105728, f -1, f 0, f 1, f 170141183460469231731687303715884105727]
+                "#
+        ),
+        RocList::from_slice(&[1, 1, 2, 3, 3]),
+        RocList<i64>
+    );
+}
@@ -1,11 +1,11 @@
 #[cfg(feature = "gen-llvm")]
-use crate::helpers::llvm::assert_evals_to;
+use crate::helpers::llvm::{assert_evals_to, expect_runtime_error_panic};
 
 #[cfg(feature = "gen-dev")]
 use crate::helpers::dev::assert_evals_to;
 
 #[cfg(feature = "gen-wasm")]
-use crate::helpers::wasm::assert_evals_to;
+use crate::helpers::wasm::{assert_evals_to, expect_runtime_error_panic};
 
 use indoc::indoc;
 #[allow(unused_imports)]
@@ -1012,6 +1012,24 @@ fn annotation_without_body() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[should_panic(expected = r#"Roc failed with message: "Hit a typed hole""#)]
+fn typed_hole() {
+    expect_runtime_error_panic!(indoc!(
+        r#"
+            app "test" provides [main] to "./platform"
+
+            main : Bool
+            main =
+                if Bool.true then
+                    _
+                else
+                    Bool.false
+            "#
+    ))
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn simple_closure() {
@@ -3476,6 +3494,64 @@ fn mutual_recursion_top_level_defs() {
     )
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn mutual_tail_recursion_uses_constant_stack() {
+    // without merging isEven and isOdd into a loop, this needs ten million stack frames
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [ main ] to "./platform"
+
+            isEven : U64 -> Bool
+            isEven = \n ->
+                if n == 0 then Bool.true else isOdd (n - 1)
+
+            isOdd : U64 -> Bool
+            isOdd = \n ->
+                if n == 0 then Bool.false else isEven (n - 1)
+
+            main = isEven 10_000_000
+            "#
+        ),
+        true,
+        bool
+    )
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn mutual_tail_recursion_state_machine() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [ main ] to "./platform"
+
+            inWord : List U8, Nat, Nat -> Nat
+            inWord = \bytes, index, count ->
+                when List.get bytes index is
+                    Ok 32 -> inSpace bytes (index + 1) count
+                    Ok _ -> inWord bytes (index + 1) count
+                    Err _ -> count
+
+            inSpace : List U8, Nat, Nat -> Nat
+            inSpace = \bytes, index, count ->
+                when List.get bytes index is
+                    Ok 32 -> inSpace bytes (index + 1) count
+                    Ok _ -> inWord bytes (index + 1) (count + 1)
+                    Err _ -> count
+
+            main =
+                bytes = List.join (List.repeat [119, 111, 114, 100, 32] 1_000_000)
+
+                inSpace bytes 0 0
+            "#
+        ),
+        1_000_000,
+        usize
+    )
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn polymorphic_lambda_captures_polymorphic_value() {
@@ -1,7 +1,7 @@
 use libloading::Library;
 use roc_build::link::{link, LinkType};
 use roc_builtins::bitcode;
-use roc_load::{EntryPoint, ExecutionMode, LoadConfig, Threading};
+use roc_load::{EntryPoint, ExecutionMode, LoadConfig, OptLevel, Threading};
 use roc_region::all::LineInfo;
 use tempfile::tempdir;
 
@@ -55,6 +55,8 @@ pub fn helper(
         render: roc_reporting::report::RenderTarget::ColorTerminal,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
+        opt_level: OptLevel::Development,
+        warning_config: Default::default(),
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -124,15 +126,18 @@ pub fn helper(
     for (home, (module_path, src)) in loaded.sources {
         use roc_reporting::report::{can_problem, type_problem, RocDocAllocator, DEFAULT_PALETTE};
 
+        let syntax_problems = loaded.syntax_problems.remove(&home).unwrap_or_default();
         let can_problems = loaded.can_problems.remove(&home).unwrap_or_default();
         let type_problems = loaded.type_problems.remove(&home).unwrap_or_default();
 
-        let error_count = can_problems.len() + type_problems.len();
+        let error_count = syntax_problems.len() + can_problems.len() + type_problems.len();
 
         if error_count == 0 {
             continue;
         }
 
+        lines.extend(syntax_problems);
+
         let line_info = LineInfo::new(&src);
         let src_lines: Vec<&str> = src.split('\n').collect();
         let palette = DEFAULT_PALETTE;
@@ -72,6 +72,8 @@ fn create_llvm_module<'a>(
         render: RenderTarget::ColorTerminal,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
+        opt_level: OptLevel::Development,
+        warning_config: Default::default(),
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -107,15 +109,18 @@ fn create_llvm_module<'a>(
     for (home, (module_path, src)) in loaded.sources {
         use roc_reporting::report::{can_problem, type_problem, RocDocAllocator, DEFAULT_PALETTE};
 
+        let syntax_problems = loaded.syntax_problems.remove(&home).unwrap_or_default();
         let can_problems = loaded.can_problems.remove(&home).unwrap_or_default();
         let type_problems = loaded.type_problems.remove(&home).unwrap_or_default();
 
-        let error_count = can_problems.len() + type_problems.len();
+        let error_count = syntax_problems.len() + can_problems.len() + type_problems.len();
 
         if error_count == 0 {
             continue;
         }
 
+        lines.extend(syntax_problems);
+
         let line_info = LineInfo::new(&src);
         let src_lines: Vec<&str> = src.split('\n').collect();
         let palette = DEFAULT_PALETTE;
@@ -15,6 +15,7 @@ const EXPANDED_STACK_SIZE: usize = 8 * 1024 * 1024;
 
 use roc_load::ExecutionMode;
 use roc_load::LoadConfig;
+use roc_load::OptLevel;
 use test_mono_macros::*;
 
 use roc_collections::all::MutMap;
@@ -74,7 +75,7 @@ fn promote_expr_to_module(src: &str) -> String {
     buffer
 }
 
-fn compiles_to_ir(test_name: &str, src: &str) {
+fn compiles_to_ir(test_name: &str, src: &str, opt_level: OptLevel) {
     use bumpalo::Bump;
     use std::path::PathBuf;
 
@@ -99,6 +100,8 @@ fn compiles_to_ir(test_name: &str, src: &str) {
         threading: Threading::Single,
         render: roc_reporting::report::RenderTarget::Generic,
         exec_mode: ExecutionMode::Executable,
+        opt_level,
+        warning_config: Default::default(),
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -127,9 +130,16 @@ fn compiles_to_ir(test_name: &str, src: &str) {
         ..
     } = loaded;
 
+    let syntax_problems = loaded.syntax_problems.remove(&home).unwrap_or_default();
     let can_problems = loaded.can_problems.remove(&home).unwrap_or_default();
     let type_problems = loaded.type_problems.remove(&home).unwrap_or_default();
 
+    for report in syntax_problems.iter() {
+        println!("{}", report);
+    }
+
+    assert!(syntax_problems.is_empty());
+
     if !can_problems.is_empty() {
         println!("Ignoring {} canonicalization problems", can_problems.len());
     }
@@ -354,6 +364,20 @@ fn when_nested_maybe() {
     "#
 }
 
+#[mono_test]
+fn when_on_overlapping_ranges() {
+    r#"
+    x : U8
+    x = 12
+
+    when x is
+        5..=15 if x > 10 -> 1
+        0..10 -> 2
+        20 -> 3
+        _ -> 4
+    "#
+}
+
 #[mono_test]
 fn when_on_two_values() {
     r#"
@@ -2000,3 +2024,117 @@ fn unreachable_branch_is_eliminated_but_produces_lambda_specializations() {
         "#
     )
 }
+
+#[mono_test(optimize)]
+fn inline_small_proc_and_fold_add() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        add = \x, y -> x + y
+
+        main = add 1 2
+        "#
+    )
+}
+
+#[mono_test(optimize)]
+fn inline_bool_not() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = Bool.not (1 == 2)
+        "#
+    )
+}
+
+#[mono_test(optimize)]
+fn fold_when_on_known_tag() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        x : [Just I64, Nothing]
+        x = Just 3
+
+        main =
+            when x is
+                Just n -> n + 1
+                Nothing -> 0
+        "#
+    )
+}
+
+#[mono_test(optimize)]
+fn inline_branching_proc_with_join_point() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main =
+            when List.get [1, 2, 3] 0 is
+                Ok n -> n
+                Err _ -> 0
+        "#
+    )
+}
+
+#[mono_test(optimize)]
+fn fold_int_overflow() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main =
+            x : U8
+            x = 255
+
+            { wrapped: Num.addWrap x 1, checked: x + 1 }
+        "#
+    )
+}
+
+#[mono_test]
+fn mutually_tail_recursive_is_even_is_odd() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        isEven : U64 -> Bool
+        isEven = \n ->
+            if n == 0 then Bool.true else isOdd (n - 1)
+
+        isOdd : U64 -> Bool
+        isOdd = \n ->
+            if n == 0 then Bool.false else isEven (n - 1)
+
+        main = isEven 1_000_000
+        "#
+    )
+}
+
+#[mono_test]
+fn mutually_tail_recursive_state_machine() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        inWord : List U8, Nat, Nat -> Nat
+        inWord = \bytes, index, count ->
+            when List.get bytes index is
+                Ok 32 -> inSpace bytes (index + 1) count
+                Ok _ -> inWord bytes (index + 1) count
+                Err _ -> count
+
+        inSpace : List U8, Nat, Nat -> Nat
+        inSpace = \bytes, index, count ->
+            when List.get bytes index is
+                Ok 32 -> inSpace bytes (index + 1) count
+                Ok _ -> inWord bytes (index + 1) (count + 1)
+                Err _ -> count
+
+        main = inSpace [104, 105, 32, 32, 116, 104, 101, 114, 101] 0 0
+        "#
+    )
+}
@@ -4,7 +4,14 @@ use proc_macro::TokenStream;
 use quote::quote;
 
 #[proc_macro_attribute]
-pub fn mono_test(_args: TokenStream, item: TokenStream) -> TokenStream {
+pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
+    // `#[mono_test(optimize)]` runs the optimizations that are disabled in development builds
+    let opt_level = match args.to_string().as_str() {
+        "" => quote!(roc_mono::ir::OptLevel::Development),
+        "optimize" => quote!(roc_mono::ir::OptLevel::Optimize),
+        other => panic!("unknown mono_test argument `{}`", other),
+    };
+
     let task_fn = syn::parse_macro_input!(item as syn::ItemFn);
 
     let args = task_fn.sig.inputs.clone();
@@ -20,7 +27,7 @@ pub fn mono_test(_args: TokenStream, item: TokenStream) -> TokenStream {
         #[test]
         #(#attributes)*
         #visibility fn #name(#args) {
-            compiles_to_ir(#name_str, #body);
+            compiles_to_ir(#name_str, #body, #opt_level);
 
         }
     };
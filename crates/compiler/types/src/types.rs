@@ -1978,6 +1978,15 @@ pub enum Reason {
         member_name: Symbol,
         def_region: Region,
     },
+    InvalidAbilityMemberDefault {
+        member_name: Symbol,
+        def_region: Region,
+        unimplemented_abilities: DoesNotImplementAbility,
+    },
+    SpecializedAbilityMemberDefault {
+        member_name: Symbol,
+        def_region: Region,
+    },
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -2019,6 +2028,7 @@ pub enum Category {
     DefaultValue(Lowercase), // for setting optional fields
 
     AbilityMemberSpecialization(Symbol),
+    AbilityMemberDefault(Symbol),
 
     Expect,
     Unknown,
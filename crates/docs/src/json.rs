@@ -0,0 +1,216 @@
+//! Documentation as JSON, for tools other than the HTML renderer (search indexes, doc portals...)
+//!
+//! The shape of the output is part of our public API: bump `SCHEMA_VERSION` whenever a change
+//! could break an existing consumer, i.e. anything other than adding new fields.
+use roc_load::docs::{
+    AbilityMember, DocDef, DocDefKind, DocEntry, Documentation, ModuleDocumentation, RecordField,
+    Tag, TypeAnnotation,
+};
+use roc_load::LoadedModule;
+use roc_region::all::LineInfo;
+use serde_json::{json, Value};
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+pub fn package_to_json(package: &Documentation) -> Value {
+    let modules: Vec<Value> = package
+        .modules
+        .iter()
+        .filter_map(|loaded_module| {
+            loaded_module
+                .documentation
+                .get(&loaded_module.module_id)
+                .map(|module| module_to_json(module, loaded_module))
+        })
+        .collect();
+
+    json!({
+        "schema_version": SCHEMA_VERSION,
+        "package": {
+            "name": package.name,
+            "version": package.version,
+            "docs": package.docs,
+        },
+        "modules": modules,
+    })
+}
+
+/// What the HTML docs know about a def that the search index needs
+pub struct SearchEntry {
+    pub url: String,
+    /// The type signature as plain text, on one line
+    pub signature: String,
+}
+
+/// The index `search.js` uses to search by type signature: every exposed def, with its type
+pub fn search_index<F>(package: &Documentation, mut to_search_entry: F) -> Value
+where
+    F: FnMut(&ModuleDocumentation, &DocDef) -> SearchEntry,
+{
+    let mut entries = Vec::new();
+
+    for loaded_module in package.modules.iter() {
+        if let Some(module) = loaded_module.documentation.get(&loaded_module.module_id) {
+            for entry in &module.entries {
+                if let DocEntry::DocDef(def) = entry {
+                    if module.exposed.contains(&def.name) {
+                        let SearchEntry { url, signature } = to_search_entry(module, def);
+
+                        entries.push(json!({
+                            "module": module.name,
+                            "name": def.name,
+                            "kind": kind_to_str(def.kind),
+                            "url": url,
+                            "signature": signature,
+                            "type": type_to_json(&def.type_annotation),
+                        }));
+                    }
+                }
+            }
+        }
+    }
+
+    json!({
+        "schema_version": SCHEMA_VERSION,
+        "entries": entries,
+    })
+}
+
+fn module_to_json(module: &ModuleDocumentation, loaded_module: &LoadedModule) -> Value {
+    let (path, line_info) = match loaded_module.sources.get(&loaded_module.module_id) {
+        Some((path, src)) => (Some(path.display().to_string()), Some(LineInfo::new(src))),
+        None => (None, None),
+    };
+
+    let mut docs = Vec::new();
+    let mut defs = Vec::new();
+
+    for entry in &module.entries {
+        match entry {
+            DocEntry::DocDef(def) => {
+                // Only what the module exposes is part of its API
+                if module.exposed.contains(&def.name) {
+                    defs.push(def_to_json(def, line_info.as_ref()));
+                }
+            }
+            DocEntry::DetachedDoc(detached, _) => docs.push(detached.as_str()),
+        }
+    }
+
+    json!({
+        "name": module.name,
+        "path": path,
+        "docs": docs,
+        "defs": defs,
+    })
+}
+
+fn def_to_json(def: &DocDef, line_info: Option<&LineInfo>) -> Value {
+    let mut value = json!({
+        "name": def.name,
+        "kind": kind_to_str(def.kind),
+        "type_vars": def.type_vars,
+        "docs": def.docs,
+        "location": line_info.map(|line_info| {
+            let start = line_info.convert_pos(def.region.start());
+
+            // 1-based, like editors and compiler error messages show them
+            json!({ "line": start.line + 1, "column": start.column + 1 })
+        }),
+    });
+
+    match &def.type_annotation {
+        TypeAnnotation::Ability { members } => {
+            value["type"] = Value::Null;
+            value["members"] = members.iter().map(ability_member_to_json).collect();
+        }
+        type_ann => {
+            value["type"] = type_to_json(type_ann);
+        }
+    }
+
+    value
+}
+
+fn kind_to_str(kind: DocDefKind) -> &'static str {
+    match kind {
+        DocDefKind::Value => "value",
+        DocDefKind::Alias => "alias",
+        DocDefKind::Opaque => "opaque",
+        DocDefKind::Ability => "ability",
+    }
+}
+
+fn ability_member_to_json(member: &AbilityMember) -> Value {
+    let able_variables: Vec<Value> = member
+        .able_variables
+        .iter()
+        .map(|(name, ability)| json!({ "name": name, "ability": type_to_json(ability) }))
+        .collect();
+
+    json!({
+        "name": member.name,
+        "type": type_to_json(&member.type_annotation),
+        "able_variables": able_variables,
+        "docs": member.docs,
+    })
+}
+
+/// Every type is an object with a "kind" saying which of the other fields it has.
+/// A missing annotation (e.g. on an opaque type, or an absent extension variable) is `null`.
+fn type_to_json(type_ann: &TypeAnnotation) -> Value {
+    match type_ann {
+        TypeAnnotation::NoTypeAnn => Value::Null,
+        TypeAnnotation::Wildcard => json!({ "kind": "wildcard" }),
+        TypeAnnotation::BoundVariable(name) => json!({ "kind": "variable", "name": name }),
+        TypeAnnotation::Apply { name, parts } => json!({
+            "kind": "apply",
+            "name": name,
+            "args": parts.iter().map(type_to_json).collect::<Vec<_>>(),
+        }),
+        TypeAnnotation::Function { args, output } => json!({
+            "kind": "function",
+            "args": args.iter().map(type_to_json).collect::<Vec<_>>(),
+            "output": type_to_json(output),
+        }),
+        TypeAnnotation::Record { fields, extension } => json!({
+            "kind": "record",
+            "fields": fields.iter().map(record_field_to_json).collect::<Vec<_>>(),
+            "extension": type_to_json(extension),
+        }),
+        TypeAnnotation::TagUnion { tags, extension } => json!({
+            "kind": "tag_union",
+            "tags": tags.iter().map(tag_to_json).collect::<Vec<_>>(),
+            "extension": type_to_json(extension),
+        }),
+        TypeAnnotation::ObscuredRecord => json!({ "kind": "obscured_record" }),
+        TypeAnnotation::ObscuredTagUnion => json!({ "kind": "obscured_tag_union" }),
+        TypeAnnotation::Ability { members } => json!({
+            "kind": "ability",
+            "members": members.iter().map(ability_member_to_json).collect::<Vec<_>>(),
+        }),
+    }
+}
+
+fn record_field_to_json(field: &RecordField) -> Value {
+    match field {
+        RecordField::RecordField {
+            name,
+            type_annotation,
+        } => json!({ "name": name, "kind": "required", "type": type_to_json(type_annotation) }),
+        RecordField::OptionalField {
+            name,
+            type_annotation,
+        } => json!({ "name": name, "kind": "optional", "type": type_to_json(type_annotation) }),
+        RecordField::LabelOnly { name } => {
+            json!({ "name": name, "kind": "label_only", "type": Value::Null })
+        }
+    }
+}
+
+fn tag_to_json(tag: &Tag) -> Value {
+    json!({
+        "name": tag.name,
+        "args": tag.values.iter().map(type_to_json).collect::<Vec<_>>(),
+    })
+}
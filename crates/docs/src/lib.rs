@@ -3,16 +3,20 @@ extern crate roc_load;
 use bumpalo::Bump;
 use docs_error::{DocsError, DocsResult};
 use html::mark_node_to_html;
+use json::SearchEntry;
 use roc_can::scope::Scope;
 use roc_code_markup::markup::nodes::MarkupNode;
 use roc_code_markup::slow_pool::SlowPool;
+use roc_collections::MutMap;
 use roc_highlight::highlight_parser::{highlight_defs, highlight_expr};
 use roc_load::docs::DocEntry::DocDef;
 use roc_load::docs::{DocEntry, TypeAnnotation};
 use roc_load::docs::{ModuleDocumentation, RecordField};
-use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, OptLevel, Threading};
 use roc_module::symbol::{IdentIdsByModule, Interns, ModuleId};
+use roc_parse::ast::{ExtractSpaces, Module};
 use roc_parse::ident::{parse_ident, Ident};
+use roc_parse::module::parse_header;
 use roc_parse::state::State;
 use roc_region::all::Region;
 use std::fs;
@@ -20,20 +24,48 @@ use std::path::{Path, PathBuf};
 
 mod docs_error;
 mod html;
+mod json;
+
+const ROC_FILE_EXTENSION: &str = "roc";
+const README: &str = "README.md";
+const DOCS_JSON: &str = "docs.json";
+const SEARCH_INDEX: &str = "search-index.json";
+
+/// Where the docs of other packages link to for the builtins
+pub const BUILTINS_DOCS_URL: &str = "https://www.roc-lang.org/builtins/";
+
+/// Parses a `--package-url` argument, e.g. `roc/cli=https://example.com/cli/`, into the name of
+/// the package and the URL of its docs
+pub fn parse_package_url(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, url)) if !name.is_empty() && !url.is_empty() => {
+            Ok((name.to_string(), url.to_string()))
+        }
+        _ => Err(format!(
+            "`{}` should be the name of a package and the URL of its docs, like roc/cli=https://example.com/cli/",
+            arg
+        )),
+    }
+}
 
-const BUILD_DIR: &str = "./generated-docs";
-
-pub fn generate_docs_html(filenames: Vec<PathBuf>) {
-    let build_dir = Path::new(BUILD_DIR);
-    let loaded_modules = load_modules_for_files(filenames);
+/// The modules of a package, as described by the header of its `platform` module
+struct PackageHeader {
+    name: String,
+    /// The directory the `platform` module is in; exposed module names are relative to this
+    root_dir: PathBuf,
+    exposes: Vec<String>,
+}
 
-    // TODO: get info from a package module; this is all hardcoded for now.
-    let mut package = roc_load::docs::Documentation {
-        name: "documentation".to_string(),
-        version: "".to_string(),
-        docs: "Package introduction or README.".to_string(),
-        modules: loaded_modules,
-    };
+pub fn generate_docs_html(
+    filenames: Vec<PathBuf>,
+    build_dir: &Path,
+    base_url: &str,
+    builtins_url: &str,
+    package_urls: &[(String, String)],
+) -> Result<(), String> {
+    let base_url = normalize_base_url(base_url);
+    let mut package = load_package(filenames)?;
+    let urls = DocsUrls::new(&base_url, builtins_url, package_urls, &package);
 
     if !build_dir.exists() {
         fs::create_dir_all(build_dir).expect("TODO gracefully handle unable to create build dir");
@@ -69,13 +101,7 @@ pub fn generate_docs_html(filenames: Vec<PathBuf>) {
                 // being a hash map in the first place if only one of its entries
                 // actually has interesting information in it?
                 if *module_id == loaded_module.module_id {
-                    let exposed_values = loaded_module
-                        .exposed_values
-                        .iter()
-                        .map(|symbol| symbol.as_str(&loaded_module.interns).to_string())
-                        .collect::<Vec<String>>();
-
-                    Some((module, exposed_values))
+                    Some((module, module.exposed.clone()))
                 } else {
                     None
                 }
@@ -83,15 +109,15 @@ pub fn generate_docs_html(filenames: Vec<PathBuf>) {
     });
 
     let template_html = include_str!("./static/index.html")
-        .replace("<!-- search.js -->", "/search.js")
-        .replace("<!-- styles.css -->", "/styles.css")
-        .replace("<!-- favicon.svg -->", "/favicon.svg")
+        .replace("<!-- search.js -->", &format!("{base_url}search.js"))
+        .replace("<!-- styles.css -->", &format!("{base_url}styles.css"))
+        .replace("<!-- favicon.svg -->", &format!("{base_url}favicon.svg"))
         .replace(
             "<!-- Prefetch links -->",
             &module_pairs
                 .clone()
                 .map(|(module, _)| {
-                    let href = sidebar_link_url(module);
+                    let href = sidebar_link_url(&base_url, module);
 
                     format!(r#"<link rel="prefetch" href="{href}"/>"#)
                 })
@@ -100,9 +126,40 @@ pub fn generate_docs_html(filenames: Vec<PathBuf>) {
         )
         .replace(
             "<!-- Module links -->",
-            render_sidebar(module_pairs).as_str(),
+            render_sidebar(&base_url, module_pairs.clone()).as_str(),
+        )
+        .replace(
+            "<!-- Package Name and Version -->",
+            render_name_and_version(&base_url, package.name.as_str(), package.version.as_str())
+                .as_str(),
         );
 
+    // Write the landing page of the package
+    let rendered_package = template_html.replace(
+        "<!-- Module Docs -->",
+        render_package_index(&base_url, &package.docs, module_pairs).as_str(),
+    );
+
+    fs::write(build_dir.join("index.html"), rendered_package)
+        .expect("TODO gracefully handle failing to write the package's index.html");
+
+    // search.js loads this for searches by type signature
+    fs::write(
+        build_dir.join(SEARCH_INDEX),
+        serde_json::to_string(&json::search_index(&package, |module, def| {
+            let mut signature = String::new();
+
+            type_annotation_to_html(0, &mut signature, &def.type_annotation, false, None);
+
+            SearchEntry {
+                url: format!("{}{}#{}", base_url, module.name, def.name),
+                signature: signature.split_whitespace().collect::<Vec<_>>().join(" "),
+            }
+        }))
+        .expect("TODO gracefully handle failing to serialize the search index"),
+    )
+    .expect("TODO gracefully handle failing to write the search index");
+
     // Write each package's module docs html file
     for loaded_module in package.modules.iter_mut() {
         for (module_id, module_docs) in loaded_module.documentation.iter() {
@@ -112,16 +169,10 @@ pub fn generate_docs_html(filenames: Vec<PathBuf>) {
                 fs::create_dir_all(&module_dir)
                     .expect("TODO gracefully handle not being able to create the module dir");
 
-                let rendered_module = template_html
-                    .replace(
-                        "<!-- Package Name and Version -->",
-                        render_name_and_version(package.name.as_str(), package.version.as_str())
-                            .as_str(),
-                    )
-                    .replace(
-                        "<!-- Module Docs -->",
-                        render_module_documentation(module_docs, loaded_module).as_str(),
-                    );
+                let rendered_module = template_html.replace(
+                    "<!-- Module Docs -->",
+                    render_module_documentation(&urls, module_docs, loaded_module).as_str(),
+                );
 
                 fs::write(module_dir.join("index.html"), rendered_module).expect(
                     "TODO gracefully handle failing to write index.html inside module's dir",
@@ -131,10 +182,312 @@ pub fn generate_docs_html(filenames: Vec<PathBuf>) {
     }
 
     println!("🎉 Docs generated in {}", build_dir.display());
+
+    Ok(())
+}
+
+/// Loads the modules of the package the given files belong to, along with the package's docs
+fn load_package(filenames: Vec<PathBuf>) -> Result<roc_load::docs::Documentation, String> {
+    let (name, filenames, root_dir) = match find_package_header(&filenames)? {
+        Some(header) => {
+            // Only the modules the package exposes are part of its public API, so those are the
+            // only ones which get documented.
+            let filenames = header
+                .exposes
+                .iter()
+                .map(|module_name| {
+                    let mut filename = header.root_dir.join(module_name.replace('.', "/"));
+                    filename.set_extension(ROC_FILE_EXTENSION);
+
+                    if filename.is_file() {
+                        Ok(filename)
+                    } else {
+                        Err(format!(
+                            "The {} package exposes the {} module, but I could not find its file at {}",
+                            header.name,
+                            module_name,
+                            filename.display()
+                        ))
+                    }
+                })
+                .collect::<Result<_, _>>()?;
+
+            (header.name, filenames, Some(header.root_dir))
+        }
+        None => {
+            let root_dir = filenames
+                .first()
+                .and_then(|filename| filename.parent())
+                .map(Path::to_path_buf);
+
+            (String::new(), filenames, root_dir)
+        }
+    };
+
+    let readme = root_dir.and_then(|root_dir| fs::read_to_string(root_dir.join(README)).ok());
+
+    Ok(roc_load::docs::Documentation {
+        name,
+        // Package headers don't have a version yet.
+        version: String::new(),
+        docs: readme.unwrap_or_default(),
+        modules: load_modules_for_files(filenames),
+    })
+}
+
+pub fn generate_docs_json(filenames: Vec<PathBuf>, build_dir: &Path) -> Result<(), String> {
+    let package = load_package(filenames)?;
+
+    if !build_dir.exists() {
+        fs::create_dir_all(build_dir).expect("TODO gracefully handle unable to create build dir");
+    }
+
+    let path = build_dir.join(DOCS_JSON);
+    let json = serde_json::to_string_pretty(&json::package_to_json(&package))
+        .expect("TODO gracefully handle failing to serialize the docs");
+
+    fs::write(&path, json).expect("TODO gracefully handle failing to write docs.json");
+
+    println!("🎉 Docs generated in {}", path.display());
+
+    Ok(())
+}
+
+/// Finds the `platform` module among the given files, if there is one. Its header says what the
+/// package is called and which of its modules are public.
+fn find_package_header(filenames: &[PathBuf]) -> Result<Option<PackageHeader>, String> {
+    let mut found: Option<PackageHeader> = None;
+
+    for filename in filenames {
+        if let Some(header) = read_package_header(filename) {
+            if let Some(other) = &found {
+                return Err(format!(
+                    "I can only generate docs for one package at a time, but I found the {} and {} platform modules.",
+                    other.name, header.name
+                ));
+            }
+
+            found = Some(header);
+        }
+    }
+
+    Ok(found)
 }
 
-fn sidebar_link_url(module: &ModuleDocumentation) -> String {
-    let mut href_buf = base_url();
+/// The package header of a file, if it is a `platform` module
+fn read_package_header(filename: &Path) -> Option<PackageHeader> {
+    let arena = Bump::new();
+
+    // loading the module will report it if it can't be read
+    let src = fs::read(filename).ok()?;
+
+    match parse_header(&arena, State::new(src.as_slice())) {
+        Ok((Module::Platform { header }, _)) => Some(PackageHeader {
+            name: header.name.value.to_str().to_string(),
+            root_dir: filename.parent().map(Path::to_path_buf).unwrap_or_default(),
+            exposes: header
+                .exposes
+                .iter()
+                .map(|module_name| module_name.value.extract_spaces().item.as_str().to_string())
+                .collect(),
+        }),
+        _ => None,
+    }
+}
+
+/// The name of the package a module file belongs to: the package whose `platform` module is in
+/// the same directory as the file, or in the closest directory above it.
+fn find_owning_package(
+    module_path: &Path,
+    package_dirs: &mut MutMap<PathBuf, Option<String>>,
+) -> Option<String> {
+    for dir in module_path.ancestors().skip(1) {
+        let package = package_dirs
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| {
+                let entries = fs::read_dir(dir).ok()?;
+
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| {
+                        path.extension().and_then(|ext| ext.to_str()) == Some(ROC_FILE_EXTENSION)
+                    })
+                    .find_map(|path| read_package_header(&path))
+                    .map(|header| header.name)
+            })
+            .clone();
+
+        if package.is_some() {
+            return package;
+        }
+    }
+
+    None
+}
+
+/// The URL all the generated docs live under, always starting and ending with a slash,
+/// e.g. "/builtins/" in "https://roc-lang.org/builtins/Str"
+fn normalize_base_url(base_url: &str) -> String {
+    let mut url_str = String::with_capacity(base_url.len() + 2);
+
+    if !base_url.starts_with('/') {
+        url_str.push('/');
+    }
+
+    url_str.push_str(base_url);
+
+    if !url_str.ends_with('/') {
+        url_str.push('/');
+    }
+
+    url_str
+}
+
+/// Where the docs for each module live, so references to them can link there
+struct DocsUrls {
+    /// The URL of the docs being generated
+    base_url: String,
+    /// The URL of the builtins' docs, for when they aren't the docs being generated
+    builtins_url: String,
+    /// The modules documented at `base_url`, and what they expose
+    documented_modules: Vec<(String, Vec<String>)>,
+    /// The modules of other packages whose docs URL we were given, and that URL
+    package_modules: Vec<(String, String)>,
+}
+
+impl DocsUrls {
+    fn new(
+        base_url: &str,
+        builtins_url: &str,
+        package_urls: &[(String, String)],
+        package: &roc_load::docs::Documentation,
+    ) -> Self {
+        let documented_modules: Vec<(String, Vec<String>)> = package
+            .modules
+            .iter()
+            .filter_map(|loaded_module| loaded_module.documentation.get(&loaded_module.module_id))
+            .map(|module| (module.name.clone(), module.exposed.clone()))
+            .collect();
+
+        let mut package_dirs = MutMap::default();
+        let mut package_modules: Vec<(String, String)> = Vec::new();
+
+        for loaded_module in package.modules.iter() {
+            for (module_id, (path, _)) in loaded_module.sources.iter() {
+                let module_name = loaded_module.interns.module_name(*module_id).as_str();
+
+                if module_id.is_builtin()
+                    || documented_modules
+                        .iter()
+                        .any(|(name, _)| name == module_name)
+                    || package_modules.iter().any(|(name, _)| name == module_name)
+                {
+                    continue;
+                }
+
+                let package_url = find_owning_package(path, &mut package_dirs).and_then(|name| {
+                    package_urls
+                        .iter()
+                        .find(|(package_name, _)| *package_name == name)
+                });
+
+                if let Some((_, url)) = package_url {
+                    package_modules.push((module_name.to_string(), with_trailing_slash(url)));
+                }
+            }
+        }
+
+        DocsUrls {
+            base_url: base_url.to_string(),
+            builtins_url: with_trailing_slash(builtins_url),
+            documented_modules,
+            package_modules,
+        }
+    }
+
+    /// The URL of the docs for something a module exposes, if we know where they are
+    fn def_url(&self, module_name: &str, module_id: ModuleId, ident: &str) -> Option<String> {
+        match self
+            .documented_modules
+            .iter()
+            .find(|(name, _)| name == module_name)
+        {
+            Some((_, exposed)) => {
+                if exposed.iter().any(|exposed_ident| exposed_ident == ident) {
+                    Some(format!("{}{}#{}", self.base_url, module_name, ident))
+                } else if ident == module_name {
+                    // Builtin types like `Str.Str` aren't exposed from their module's header,
+                    // the docs for the whole module are about them.
+                    Some(format!("{}{}", self.base_url, module_name))
+                } else {
+                    None
+                }
+            }
+            None if module_id.is_builtin() => {
+                Some(external_def_url(&self.builtins_url, module_name, ident))
+            }
+            None => self
+                .package_modules
+                .iter()
+                .find(|(name, _)| name == module_name)
+                .map(|(_, package_url)| external_def_url(package_url, module_name, ident)),
+        }
+    }
+}
+
+/// The URL of something a module exposes, in the docs of another package
+fn external_def_url(package_url: &str, module_name: &str, ident: &str) -> String {
+    if ident == module_name {
+        format!("{}{}", package_url, module_name)
+    } else {
+        format!("{}{}#{}", package_url, module_name, ident)
+    }
+}
+
+fn with_trailing_slash(url: &str) -> String {
+    let mut url = url.to_string();
+
+    if !url.ends_with('/') {
+        url.push('/');
+    }
+
+    url
+}
+
+/// Resolves the type names in the signatures of one module to the docs of those types
+struct TypeLinks<'a> {
+    urls: &'a DocsUrls,
+    scope: &'a Scope,
+    interns: &'a Interns,
+}
+
+impl<'a> TypeLinks<'a> {
+    /// The URL for a type name as it's written in a signature, e.g. `Str` or `Task.Task`
+    fn url(&self, name: &str) -> Option<String> {
+        let (module_id, module_name, ident) = match name.rsplit_once('.') {
+            Some((module_name, ident)) => {
+                let module_id = self.interns.module_ids.get_id(&module_name.into())?;
+
+                (module_id, module_name, ident)
+            }
+            None => {
+                let symbol = self.scope.lookup_str(name, Region::zero()).ok()?;
+
+                (
+                    symbol.module_id(),
+                    symbol.module_string(self.interns).as_str(),
+                    name,
+                )
+            }
+        };
+
+        self.urls.def_url(module_name, module_id, ident)
+    }
+}
+
+fn sidebar_link_url(base_url: &str, module: &ModuleDocumentation) -> String {
+    let mut href_buf = base_url.to_string();
     href_buf.push_str(module.name.as_str());
 
     href_buf
@@ -184,6 +537,7 @@ pub fn syntax_highlight_top_level_defs(code_str: &str) -> DocsResult<String> {
 }
 
 fn render_module_documentation(
+    urls: &DocsUrls,
     module: &ModuleDocumentation,
     loaded_module: &LoadedModule,
 ) -> String {
@@ -193,12 +547,17 @@ fn render_module_documentation(
         html_to_string(
             "h2",
             vec![("class", "module-name")],
-            html_to_string("a", vec![("href", "/#")], module.name.as_str()).as_str(),
+            html_to_string("a", vec![("href", "#")], module.name.as_str()).as_str(),
         )
         .as_str(),
     );
 
-    let exposed_values = loaded_module.exposed_values_str();
+    let exposed_values: Vec<&str> = module.exposed.iter().map(String::as_str).collect();
+    let type_links = TypeLinks {
+        urls,
+        scope: &module.scope,
+        interns: &loaded_module.interns,
+    };
 
     for entry in &module.entries {
         let mut should_render_entry = true;
@@ -239,7 +598,7 @@ fn render_module_documentation(
                         }
                     }
 
-                    type_annotation_to_html(0, &mut content, type_ann, false);
+                    type_annotation_to_html(0, &mut content, type_ann, false, Some(&type_links));
 
                     buf.push_str(
                         html_to_string(
@@ -253,6 +612,7 @@ fn render_module_documentation(
                     if let Some(docs) = &doc_def.docs {
                         buf.push_str(
                             markdown_to_html(
+                                urls,
                                 &exposed_values,
                                 &module.scope,
                                 docs.to_string(),
@@ -264,8 +624,9 @@ fn render_module_documentation(
 
                     buf.push_str("</section>");
                 }
-                DocEntry::DetachedDoc(docs) => {
+                DocEntry::DetachedDoc(docs, _) => {
                     let markdown = markdown_to_html(
+                        urls,
                         &exposed_values,
                         &module.scope,
                         docs.to_string(),
@@ -309,77 +670,81 @@ fn html_to_string(tag_name: &str, attrs: Vec<(&str, &str)>, content: &str) -> St
     buf
 }
 
-fn base_url() -> String {
-    // e.g. "builtins/" in "https://roc-lang.org/builtins/Str"
-    //
-    // TODO make this a CLI flag to the `docs` subcommand instead of an env var
-    match std::env::var("ROC_DOCS_URL_ROOT") {
-        Ok(root_builtins_path) => {
-            let mut url_str = String::with_capacity(root_builtins_path.len() + 64);
-
-            if !root_builtins_path.starts_with('/') {
-                url_str.push('/');
-            }
-
-            url_str.push_str(&root_builtins_path);
-
-            if !root_builtins_path.ends_with('/') {
-                url_str.push('/');
-            }
-
-            url_str
-        }
-        _ => {
-            let mut url_str = String::with_capacity(64);
-
-            url_str.push('/');
-
-            url_str
-        }
-    }
-}
-
-fn render_name_and_version(name: &str, version: &str) -> String {
+fn render_name_and_version(base_url: &str, name: &str, version: &str) -> String {
     let mut buf = String::new();
-    let mut url_str = base_url();
 
-    url_str.push_str(name);
+    if name.is_empty() {
+        return buf;
+    }
 
     buf.push_str(
         html_to_string(
             "h1",
             vec![("class", "pkg-full-name")],
-            html_to_string("a", vec![("href", url_str.as_str())], name).as_str(),
+            html_to_string("a", vec![("href", base_url)], name).as_str(),
         )
         .as_str(),
     );
 
-    let mut versions_url_str = base_url();
+    if !version.is_empty() {
+        let mut versions_url_str = base_url.to_string();
 
-    versions_url_str.push('/');
-    versions_url_str.push_str(name);
-    versions_url_str.push('/');
-    versions_url_str.push_str(version);
+        versions_url_str.push_str(name);
+        versions_url_str.push('/');
+        versions_url_str.push_str(version);
 
-    buf.push_str(
-        html_to_string(
-            "a",
-            vec![("class", "version"), ("href", versions_url_str.as_str())],
-            version,
-        )
-        .as_str(),
-    );
+        buf.push_str(
+            html_to_string(
+                "a",
+                vec![("class", "version"), ("href", versions_url_str.as_str())],
+                version,
+            )
+            .as_str(),
+        );
+    }
+
+    buf
+}
+
+/// The landing page of the package: its README, or a list of its modules if it has none
+fn render_package_index<'a, I: Iterator<Item = (&'a ModuleDocumentation, Vec<String>)>>(
+    base_url: &str,
+    readme: &str,
+    modules: I,
+) -> String {
+    let mut buf = String::new();
+
+    if readme.is_empty() {
+        for (module, _) in modules {
+            let href = sidebar_link_url(base_url, module);
+
+            buf.push_str(
+                html_to_string(
+                    "h2",
+                    vec![("class", "module-name")],
+                    html_to_string("a", vec![("href", href.as_str())], module.name.as_str())
+                        .as_str(),
+                )
+                .as_str(),
+            );
+        }
+    } else {
+        let parser = pulldown_cmark::Parser::new_ext(readme, pulldown_cmark::Options::empty());
+
+        pulldown_cmark::html::push_html(&mut buf, parser);
+    }
 
     buf
 }
 
 fn render_sidebar<'a, I: Iterator<Item = (&'a ModuleDocumentation, Vec<String>)>>(
+    base_url: &str,
     modules: I,
 ) -> String {
     let mut buf = String::new();
 
     for (module, exposed_values) in modules {
-        let href = sidebar_link_url(module);
+        let href = sidebar_link_url(base_url, module);
         let mut sidebar_entry_content = String::new();
 
         sidebar_entry_content.push_str(
@@ -450,6 +815,8 @@ pub fn load_modules_for_files(filenames: Vec<PathBuf>) -> Vec<LoadedModule> {
             render: roc_reporting::report::RenderTarget::ColorTerminal,
             threading: Threading::AllAvailable,
             exec_mode: ExecutionMode::Check,
+            opt_level: OptLevel::Development,
+            warning_config: Default::default(),
         };
         match roc_load::load_and_typecheck(&arena, filename, Default::default(), load_config) {
             Ok(loaded) => modules.push(loaded),
@@ -476,12 +843,13 @@ fn new_line(buf: &mut String) {
     buf.push('\n');
 }
 
-// html is written to buf
+// html is written to buf; type names link to their docs if there are `links`
 fn type_annotation_to_html(
     indent_level: usize,
     buf: &mut String,
     type_ann: &TypeAnnotation,
     needs_parens: bool,
+    links: Option<&TypeLinks>,
 ) {
     let is_multiline = should_be_multiline(type_ann);
     match type_ann {
@@ -516,7 +884,7 @@ fn type_annotation_to_html(
 
                     for type_value in &tag.values {
                         buf.push(' ');
-                        type_annotation_to_html(next_indent_level, buf, type_value, true);
+                        type_annotation_to_html(next_indent_level, buf, type_value, true, links);
                     }
 
                     if is_multiline {
@@ -535,23 +903,23 @@ fn type_annotation_to_html(
                 buf.push(']');
             }
 
-            type_annotation_to_html(indent_level, buf, extension, true);
+            type_annotation_to_html(indent_level, buf, extension, true, links);
         }
         TypeAnnotation::BoundVariable(var_name) => {
             buf.push_str(var_name);
         }
         TypeAnnotation::Apply { name, parts } => {
             if parts.is_empty() {
-                buf.push_str(name);
+                push_type_name(buf, name, links);
             } else {
                 if needs_parens {
                     buf.push('(');
                 }
 
-                buf.push_str(name);
+                push_type_name(buf, name, links);
                 for part in parts {
                     buf.push(' ');
-                    type_annotation_to_html(indent_level, buf, part, true);
+                    type_annotation_to_html(indent_level, buf, part, true, links);
                 }
 
                 if needs_parens {
@@ -599,13 +967,25 @@ fn type_annotation_to_html(
                             type_annotation, ..
                         } => {
                             buf.push_str(" : ");
-                            type_annotation_to_html(next_indent_level, buf, type_annotation, false);
+                            type_annotation_to_html(
+                                next_indent_level,
+                                buf,
+                                type_annotation,
+                                false,
+                                links,
+                            );
                         }
                         RecordField::OptionalField {
                             type_annotation, ..
                         } => {
                             buf.push_str(" ? ");
-                            type_annotation_to_html(next_indent_level, buf, type_annotation, false);
+                            type_annotation_to_html(
+                                next_indent_level,
+                                buf,
+                                type_annotation,
+                                false,
+                                links,
+                            );
                         }
                         RecordField::LabelOnly { .. } => {}
                     }
@@ -628,7 +1008,7 @@ fn type_annotation_to_html(
                 buf.push('}');
             }
 
-            type_annotation_to_html(indent_level, buf, extension, true);
+            type_annotation_to_html(indent_level, buf, extension, true, links);
         }
         TypeAnnotation::Function { args, output } => {
             let mut peekable_args = args.iter().peekable();
@@ -640,7 +1020,7 @@ fn type_annotation_to_html(
                     indent(buf, indent_level + 1);
                 }
 
-                type_annotation_to_html(indent_level, buf, arg, false);
+                type_annotation_to_html(indent_level, buf, arg, false, links);
 
                 if peekable_args.peek().is_some() {
                     buf.push_str(", ");
@@ -660,7 +1040,7 @@ fn type_annotation_to_html(
                 next_indent_level += 1;
             }
 
-            type_annotation_to_html(next_indent_level, buf, output, false);
+            type_annotation_to_html(next_indent_level, buf, output, false, links);
         }
         TypeAnnotation::Ability { members: _ } => {
             // TODO(abilities): fill me in
@@ -676,6 +1056,13 @@ fn type_annotation_to_html(
     }
 }
 
+fn push_type_name(buf: &mut String, name: &str, links: Option<&TypeLinks>) {
+    match links.and_then(|links| links.url(name)) {
+        Some(url) => buf.push_str(html_to_string("a", vec![("href", url.as_str())], name).as_str()),
+        None => buf.push_str(name),
+    }
+}
+
 fn should_be_multiline(type_ann: &TypeAnnotation) -> bool {
     match type_ann {
         TypeAnnotation::TagUnion { tags, extension } => {
@@ -752,7 +1139,9 @@ struct DocUrl {
     title: String,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn doc_url<'a>(
+    urls: &DocsUrls,
     home: ModuleId,
     exposed_values: &[&str],
     dep_idents: &IdentIdsByModule,
@@ -760,8 +1149,8 @@ fn doc_url<'a>(
     interns: &'a Interns,
     mut module_name: &'a str,
     ident: &str,
-) -> DocUrl {
-    if module_name.is_empty() {
+) -> Result<DocUrl, String> {
+    let module_id = if module_name.is_empty() {
         // This is an unqualified lookup, so look for the ident
         // in scope!
         match scope.lookup_str(ident, Region::zero()) {
@@ -771,13 +1160,14 @@ fn doc_url<'a>(
                 // module - for example, if this is in scope from an
                 // unqualified import.
                 module_name = symbol.module_string(interns);
+
+                symbol.module_id()
             }
             Err(_) => {
-                // TODO return Err here
-                panic!(
-                "Tried to generate an automatic link in docs for symbol `{}`, but that symbol was not in scope in this module.",
-                ident
-            );
+                return Err(format!(
+                    "Tried to generate an automatic link in docs for symbol `{}`, but that symbol was not in scope in this module.",
+                    ident
+                ));
             }
         }
     } else {
@@ -789,10 +1179,10 @@ fn doc_url<'a>(
                     // Check to see if the value is exposed in this module.
                     // If it's not exposed, then we can't link to it!
                     if !exposed_values.contains(&ident) {
-                        // TODO return Err here
-                        panic!(
+                        return Err(format!(
                             "Tried to generate an automatic link in docs for `{}.{}`, but `{}` does not expose `{}`.",
-                            module_name, ident, module_name, ident);
+                            module_name, ident, module_name, ident
+                        ));
                     }
                 } else {
                     // This is not the home module
@@ -803,42 +1193,42 @@ fn doc_url<'a>(
                         Some(_) => {
                             // This is a valid symbol for this dependency,
                             // so proceed using the current module's name.
-                            //
-                            // TODO: In the future, this is where we'll
-                            // incorporate the package name into the link.
                         }
                         _ => {
-                            // TODO return Err here
-                            panic!(
+                            return Err(format!(
                                 "Tried to generate an automatic link in docs for `{}.{}`, but `{}` is not exposed in `{}`.",
-                                module_name, ident, ident, module_name);
+                                module_name, ident, ident, module_name
+                            ));
                         }
                     }
                 }
+
+                module_id
             }
             None => {
-                // TODO return Err here
-                panic!("Tried to generate a doc link for `{}.{}` but the `{}` module was not imported!", module_name, ident, module_name);
+                return Err(format!(
+                    "Tried to generate a doc link for `{}.{}` but the `{}` module was not imported!",
+                    module_name, ident, module_name
+                ));
             }
         }
-    }
-
-    let mut url = base_url();
+    };
 
     // Example:
     //
     // module_name: "Str", ident: "join" => "/Str#join"
-    url.push_str(module_name);
-    url.push('#');
-    url.push_str(ident);
+    let url = urls
+        .def_url(module_name, module_id, ident)
+        .unwrap_or_else(|| format!("{}{}#{}", urls.base_url, module_name, ident));
 
-    DocUrl {
+    Ok(DocUrl {
         url,
         title: format!("Docs for {}.{}", module_name, ident),
-    }
+    })
 }
 
 fn markdown_to_html(
+    urls: &DocsUrls,
     exposed_values: &[&str],
     scope: &Scope,
     markdown: String,
@@ -868,7 +1258,8 @@ fn markdown_to_html(
 
                         match iter.next() {
                             Some(symbol_name) if iter.next().is_none() => {
-                                let DocUrl { url, title } = doc_url(
+                                match doc_url(
+                                    urls,
                                     loaded_module.module_id,
                                     exposed_values,
                                     &loaded_module.dep_idents,
@@ -876,9 +1267,15 @@ fn markdown_to_html(
                                     &loaded_module.interns,
                                     module_name,
                                     symbol_name,
-                                );
-
-                                Some((url.into(), title.into()))
+                                ) {
+                                    Ok(DocUrl { url, title }) => Some((url.into(), title.into())),
+                                    Err(problem) => {
+                                        // Show it as text, so a broken link doesn't stop the docs
+                                        eprintln!("Warning: {}", problem);
+
+                                        None
+                                    }
+                                }
                             }
                             _ => {
                                 // This had record field access,
@@ -891,7 +1288,8 @@ fn markdown_to_html(
                     Ok((_, Ident::Tag(type_name), _)) => {
                         // This looks like a tag name, but it could
                         // be a type alias that's in scope, e.g. [I64]
-                        let DocUrl { url, title } = doc_url(
+                        match doc_url(
+                            urls,
                             loaded_module.module_id,
                             exposed_values,
                             &loaded_module.dep_idents,
@@ -899,9 +1297,15 @@ fn markdown_to_html(
                             &loaded_module.interns,
                             "",
                             type_name,
-                        );
+                        ) {
+                            Ok(DocUrl { url, title }) => Some((url.into(), title.into())),
+                            Err(problem) => {
+                                // Show it as text, so a broken link doesn't stop the docs
+                                eprintln!("Warning: {}", problem);
 
-                        Some((url.into(), title.into()))
+                                None
+                            }
+                        }
                     }
                     _ => None,
                 }
@@ -921,7 +1325,6 @@ fn markdown_to_html(
         Some(&mut broken_link_callback),
     )
     .fold((0, 0), |(start_quote_count, end_quote_count), event| {
-
         match &event {
             // Replace this sequence (`>>>` syntax):
             //     Start(BlockQuote)
@@ -998,16 +1401,14 @@ fn markdown_to_html(
                 (0, 0)
             }
             Event::Text(CowStr::Borrowed(code_str)) if expecting_code_block => {
-
-                match syntax_highlight_expr(
-                    code_str
-                )
-                {
+                match syntax_highlight_expr(code_str) {
                     Ok(highlighted_code_str) => {
                         docs_parser.push(Event::Html(CowStr::from(highlighted_code_str)));
                     }
-                    Err(syntax_error) => {
-                        panic!("Unexpected parse failure when parsing this for rendering in docs:\n\n{}\n\nParse error was:\n\n{:?}\n\n", code_str, syntax_error)
+                    Err(_) => {
+                        // The highlighter only knows expressions, so e.g. a type signature
+                        // is shown as it is.
+                        docs_parser.push(Event::Text(CowStr::Borrowed(code_str)));
                     }
                 };
 
@@ -1026,3 +1427,103 @@ fn markdown_to_html(
 
     docs_html
 }
+
+#[cfg(test)]
+mod test_docs_urls {
+    use super::*;
+
+    fn urls() -> DocsUrls {
+        DocsUrls {
+            base_url: "/shapes/".to_string(),
+            builtins_url: "https://example.com/builtins/".to_string(),
+            documented_modules: vec![(
+                "Shape".to_string(),
+                vec!["Shape".to_string(), "area".to_string()],
+            )],
+            package_modules: vec![(
+                "Color".to_string(),
+                "https://example.com/colors/".to_string(),
+            )],
+        }
+    }
+
+    /// The id of a module that isn't a builtin
+    fn user_module_id(name: &str) -> ModuleId {
+        Interns::default().module_id(&name.into())
+    }
+
+    #[test]
+    fn documented_module() {
+        let urls = urls();
+        let module_id = user_module_id("Shape");
+
+        assert_eq!(
+            urls.def_url("Shape", module_id, "area"),
+            Some("/shapes/Shape#area".to_string())
+        );
+        assert_eq!(urls.def_url("Shape", module_id, "helper"), None);
+    }
+
+    #[test]
+    fn builtin_module() {
+        let urls = urls();
+
+        assert_eq!(
+            urls.def_url("Str", ModuleId::STR, "Str"),
+            Some("https://example.com/builtins/Str".to_string())
+        );
+        assert_eq!(
+            urls.def_url("Num", ModuleId::NUM, "F64"),
+            Some("https://example.com/builtins/Num#F64".to_string())
+        );
+    }
+
+    #[test]
+    fn other_package() {
+        let urls = urls();
+        let module_id = user_module_id("Color");
+
+        assert_eq!(
+            urls.def_url("Color", module_id, "Color"),
+            Some("https://example.com/colors/Color".to_string())
+        );
+        assert_eq!(
+            urls.def_url("Color", module_id, "red"),
+            Some("https://example.com/colors/Color#red".to_string())
+        );
+        // a module of a package we don't know the docs of
+        assert_eq!(urls.def_url("Texture", module_id, "Texture"), None);
+    }
+
+    #[test]
+    fn owning_package() {
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("shapes");
+        let mut package_dirs = MutMap::default();
+
+        assert_eq!(
+            find_owning_package(&fixture.join("Shape.roc"), &mut package_dirs),
+            Some("test/shapes".to_string())
+        );
+        // modules in subdirectories belong to the package above them
+        assert_eq!(
+            find_owning_package(&fixture.join("Shape").join("Side.roc"), &mut package_dirs),
+            Some("test/shapes".to_string())
+        );
+    }
+
+    #[test]
+    fn package_url_arg() {
+        assert_eq!(
+            parse_package_url("roc/cli=https://example.com/cli/"),
+            Ok((
+                "roc/cli".to_string(),
+                "https://example.com/cli/".to_string()
+            ))
+        );
+        assert!(parse_package_url("roc/cli").is_err());
+        assert!(parse_package_url("=https://example.com/cli/").is_err());
+    }
+}
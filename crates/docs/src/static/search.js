@@ -1,10 +1,24 @@
 (() => {
   let sidebar = document.getElementById("sidebar-nav");
   let searchBox = document.getElementById("module-search");
+  let typeResults = document.getElementById("type-search-results");
+
+  // The index of every exposed def and its type, for searching by type signature.
+  // It's next to this script, and only fetched once someone searches for a type.
+  let searchIndexUrl = new URL("search-index.json", document.currentScript.src);
+  let searchIndex = null;
 
   function search() {
     let text = searchBox.value.toLowerCase(); // Search is case-insensitive.
 
+    if (looksLikeType(searchBox.value)) {
+      searchByType(searchBox.value);
+      return;
+    }
+
+    typeResults.classList.add("hidden");
+    sidebar.querySelector(".module-links").classList.remove("hidden");
+
     if (text === "") {
       // Un-hide everything
       sidebar.querySelectorAll(".sidebar-entry a").forEach((entry) => entry.classList.remove("hidden"));
@@ -41,6 +55,204 @@
     }
   }
 
+  // Searches like `List a -> Nat` or `: Str` are for type signatures
+  function looksLikeType(query) {
+    return query.includes("->") || query.trim().startsWith(":");
+  }
+
+  function searchByType(query) {
+    sidebar.querySelector(".module-links").classList.add("hidden");
+    typeResults.classList.remove("hidden");
+
+    if (searchIndex === null) {
+      searchIndex = fetch(searchIndexUrl).then((response) => response.json());
+    }
+
+    searchIndex.then((index) => {
+      // The user may have kept typing while the index was loading
+      if (searchBox.value !== query) {
+        return;
+      }
+
+      let queryType = parseType(query.trim().replace(/^:/, ""));
+      let results = [];
+
+      if (queryType !== null) {
+        index.entries.forEach((entry) => {
+          let score = typeMatchScore(queryType, entry.type);
+
+          if (score > 0) {
+            results.push({ entry, score });
+          }
+        });
+      }
+
+      // Exact matches first, then the ones which only matched the return type
+      results.sort((a, b) => b.score - a.score);
+
+      typeResults.replaceChildren(...results.map(({ entry }) => {
+        let item = document.createElement("li");
+        let link = document.createElement("a");
+        let signature = document.createElement("code");
+
+        link.href = entry.url;
+        link.textContent = `${entry.module}.${entry.name}`;
+        signature.textContent = entry.signature;
+
+        item.append(link, signature);
+
+        return item;
+      }));
+    });
+  }
+
+  // Parses a type signature into the same shape as the "type" of the entries in the index.
+  // Records and tag unions aren't supported; for those (and for syntax errors) this returns null.
+  function parseType(source) {
+    let tokens = source.match(/->|[A-Za-z][A-Za-z0-9_.]*|[(),*]|\S/g) || [];
+    let pos = 0;
+
+    function peek() {
+      return tokens[pos];
+    }
+
+    function next() {
+      return tokens[pos++];
+    }
+
+    // a, b -> c
+    function parseFunction() {
+      let args = [parseApply()];
+
+      while (peek() === ",") {
+        next();
+        args.push(parseApply());
+      }
+
+      if (peek() === "->") {
+        next();
+
+        return { kind: "function", args, output: parseFunction() };
+      } else if (args.length === 1) {
+        return args[0];
+      } else {
+        throw "a list of arguments has to be followed by ->";
+      }
+    }
+
+    // List a
+    function parseApply() {
+      let token = peek();
+
+      if (token !== undefined && /^[A-Z]/.test(token)) {
+        next();
+
+        let args = [];
+
+        while (peek() !== undefined && (/^[A-Za-z]/.test(peek()) || peek() === "(" || peek() === "*")) {
+          args.push(parseAtom());
+        }
+
+        return { kind: "apply", name: token, args };
+      }
+
+      return parseAtom();
+    }
+
+    // a, *, Str or (...)
+    function parseAtom() {
+      let token = next();
+
+      if (token === "(") {
+        let inner = parseFunction();
+
+        if (next() !== ")") {
+          throw "unclosed (";
+        }
+
+        return inner;
+      } else if (token === "*") {
+        return { kind: "wildcard" };
+      } else if (token !== undefined && /^[a-z]/.test(token)) {
+        return { kind: "variable", name: token };
+      } else if (token !== undefined && /^[A-Z]/.test(token)) {
+        return { kind: "apply", name: token, args: [] };
+      }
+
+      throw `unexpected ${token}`;
+    }
+
+    try {
+      let parsed = parseFunction();
+
+      return pos === tokens.length ? parsed : null;
+    } catch (_) {
+      return null;
+    }
+  }
+
+  // 2 if the whole type matches, 1 if only what a function returns does, 0 if there's no match
+  function typeMatchScore(query, type) {
+    if (type === null) {
+      return 0;
+    }
+
+    if (unify(query, type, new Map(), new Map())) {
+      return 2;
+    }
+
+    if (type.kind === "function" && query.kind !== "function" && unify(query, type.output, new Map(), new Map())) {
+      return 1;
+    }
+
+    return 0;
+  }
+
+  // Whether two types are the same up to the names of their type variables.
+  // The variables map one-to-one, so `a -> a` doesn't match `a -> b`.
+  function unify(query, type, queryVars, typeVars) {
+    if (type === null) {
+      return false;
+    }
+
+    if (query.kind === "wildcard" || type.kind === "wildcard") {
+      return true;
+    }
+
+    if (query.kind === "variable" && type.kind === "variable") {
+      let boundToQuery = queryVars.get(query.name);
+      let boundToType = typeVars.get(type.name);
+
+      if (boundToQuery === undefined && boundToType === undefined) {
+        queryVars.set(query.name, type.name);
+        typeVars.set(type.name, query.name);
+
+        return true;
+      }
+
+      return boundToQuery === type.name && boundToType === query.name;
+    }
+
+    if (query.kind === "apply" && type.kind === "apply") {
+      // Qualified or not, `Str.Str` and `Str` are the same type
+      return unqualified(query.name) === unqualified(type.name)
+        && query.args.length === type.args.length
+        && query.args.every((arg, i) => unify(arg, type.args[i], queryVars, typeVars));
+    }
+
+    if (query.kind === "function" && type.kind === "function") {
+      return query.args.length === type.args.length
+        && query.args.every((arg, i) => unify(arg, type.args[i], queryVars, typeVars))
+        && unify(query.output, type.output, queryVars, typeVars);
+    }
+
+    return false;
+  }
+
+  function unqualified(name) {
+    return name.substring(name.lastIndexOf(".") + 1);
+  }
+
   searchBox.addEventListener("input", search);
 
   search();
@@ -0,0 +1,153 @@
+#[macro_use]
+extern crate pretty_assertions;
+
+#[cfg(test)]
+mod docs_html {
+    use roc_docs::generate_docs_html;
+    use serde_json::{json, Value};
+    use std::path::{Path, PathBuf};
+
+    const BUILTINS_URL: &str = "https://example.com/builtins";
+
+    /// Generates the docs for the fixture package into a directory of its own, since tests run in
+    /// parallel
+    fn generate(test_name: &str) -> PathBuf {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("shapes");
+        let build_dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+            .join("docs_html")
+            .join(test_name);
+
+        generate_docs_html(
+            vec![dir.join("main.roc"), dir.join("Shape.roc")],
+            &build_dir,
+            "/shapes",
+            BUILTINS_URL,
+            &[],
+        )
+        .unwrap();
+
+        build_dir
+    }
+
+    /// The `<h3>` that shows the name and type of a def
+    fn signature_html(module_html: &str, name: &str) -> String {
+        let start = module_html
+            .find(&format!(r#"<h3 id="{}""#, name))
+            .unwrap_or_else(|| panic!("{} was not documented", name));
+        let end = start + module_html[start..].find("</h3>").unwrap();
+
+        module_html[start..end].to_string()
+    }
+
+    /// The targets and texts of the links in some HTML
+    fn links(html: &str) -> Vec<(&str, &str)> {
+        html.split("<a href=\"")
+            .skip(1)
+            .map(|link| {
+                let (url, rest) = link.split_once('"').unwrap();
+                let text = &rest[rest.find('>').unwrap() + 1..rest.find("</a>").unwrap()];
+
+                (url, text)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn search_index() {
+        let build_dir = generate("search_index");
+        let json = std::fs::read_to_string(build_dir.join("search-index.json")).unwrap();
+        let index: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(index["schema_version"], 1);
+
+        let entries: Vec<_> = index["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| {
+                (
+                    entry["module"].as_str().unwrap(),
+                    entry["name"].as_str().unwrap(),
+                    entry["kind"].as_str().unwrap(),
+                    entry["url"].as_str().unwrap(),
+                    entry["signature"].as_str().unwrap(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            entries,
+            [
+                ("Shape", "Shape", "opaque", "/shapes/Shape#Shape", ""),
+                (
+                    "Shape",
+                    "Size",
+                    "alias",
+                    "/shapes/Shape#Size",
+                    "[ Small, Large Str ]"
+                ),
+                ("Shape", "HasArea", "ability", "/shapes/Shape#HasArea", ""),
+                (
+                    "Shape",
+                    "area",
+                    "value",
+                    "/shapes/Shape#area",
+                    "Shape -> F64"
+                ),
+                (
+                    "Shape",
+                    "square",
+                    "value",
+                    "/shapes/Shape#square",
+                    "F64 -> Shape"
+                ),
+                (
+                    "Shape",
+                    "describe",
+                    "value",
+                    "/shapes/Shape#describe",
+                    "{ shape : Shape, name ? Str }* -> Size"
+                ),
+            ]
+        );
+
+        // the structured type, which search.js matches queries like `F64 -> Shape` against
+        assert_eq!(
+            index["entries"][4]["type"],
+            json!({
+                "kind": "function",
+                "args": [{ "kind": "apply", "name": "F64", "args": [] }],
+                "output": { "kind": "apply", "name": "Shape", "args": [] },
+            })
+        );
+    }
+
+    #[test]
+    fn type_links() {
+        let build_dir = generate("type_links");
+        let module_html =
+            std::fs::read_to_string(build_dir.join("Shape").join("index.html")).unwrap();
+
+        assert_eq!(
+            links(&signature_html(&module_html, "area")),
+            [
+                ("#area", "area"),
+                ("/shapes/Shape#Shape", "Shape"),
+                ("https://example.com/builtins/Num#F64", "F64"),
+            ]
+        );
+
+        assert_eq!(
+            links(&signature_html(&module_html, "describe")),
+            [
+                ("#describe", "describe"),
+                ("/shapes/Shape#Shape", "Shape"),
+                ("https://example.com/builtins/Str", "Str"),
+                ("/shapes/Shape#Size", "Size"),
+            ]
+        );
+    }
+}
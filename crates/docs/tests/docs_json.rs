@@ -0,0 +1,200 @@
+#[macro_use]
+extern crate pretty_assertions;
+
+#[cfg(test)]
+mod docs_json {
+    use roc_docs::generate_docs_json;
+    use serde_json::{json, Value};
+    use std::path::{Path, PathBuf};
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join(name)
+    }
+
+    /// Generates the docs for a fixture package into a directory of its own, since tests run in
+    /// parallel
+    fn generate(name: &str, test_name: &str) -> Value {
+        let dir = fixture(name);
+        let build_dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+            .join("docs_json")
+            .join(test_name);
+
+        generate_docs_json(
+            vec![dir.join("main.roc"), dir.join("Shape.roc")],
+            &build_dir,
+        )
+        .unwrap();
+
+        let json = std::fs::read_to_string(build_dir.join("docs.json")).unwrap();
+
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn def<'a>(module: &'a Value, name: &str) -> &'a Value {
+        module["defs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|def| def["name"] == name)
+            .unwrap_or_else(|| panic!("{} was not documented", name))
+    }
+
+    fn apply(name: &str, args: Vec<Value>) -> Value {
+        json!({ "kind": "apply", "name": name, "args": args })
+    }
+
+    #[test]
+    fn package() {
+        let json = generate("shapes", "package");
+
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(
+            json["package"],
+            json!({ "name": "test/shapes", "version": "", "docs": "" })
+        );
+
+        let modules = json["modules"].as_array().unwrap();
+
+        // only the modules the platform exposes are documented
+        assert_eq!(modules.len(), 1);
+
+        let module = &modules[0];
+        let names: Vec<_> = module["defs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|def| def["name"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(module["name"], "Shape");
+        assert!(module["path"].as_str().unwrap().ends_with("Shape.roc"));
+        assert_eq!(
+            names,
+            ["Shape", "Size", "HasArea", "area", "square", "describe"]
+        );
+    }
+
+    #[test]
+    fn opaque_and_alias() {
+        let json = generate("shapes", "opaque_and_alias");
+        let module = &json["modules"][0];
+
+        assert_eq!(
+            def(module, "Shape"),
+            &json!({
+                "name": "Shape",
+                "kind": "opaque",
+                "type_vars": [],
+                "docs": "A shape, whose dimensions are kept private\n",
+                "location": { "line": 6, "column": 1 },
+                "type": null,
+            })
+        );
+
+        assert_eq!(
+            def(module, "Size")["type"],
+            json!({
+                "kind": "tag_union",
+                "tags": [
+                    { "name": "Small", "args": [] },
+                    { "name": "Large", "args": [apply("Str", vec![])] },
+                ],
+                "extension": null,
+            })
+        );
+    }
+
+    #[test]
+    fn ability() {
+        let json = generate("shapes", "ability");
+        let has_area = def(&json["modules"][0], "HasArea");
+
+        assert_eq!(has_area["kind"], "ability");
+        assert_eq!(has_area["type"], Value::Null);
+        assert_eq!(
+            has_area["members"],
+            json!([{
+                "name": "measure",
+                "type": {
+                    "kind": "function",
+                    "args": [{ "kind": "variable", "name": "a" }],
+                    "output": apply("F64", vec![]),
+                },
+                "able_variables": [{ "name": "a", "ability": apply("HasArea", vec![]) }],
+                "docs": "The space it takes up\n",
+            }])
+        );
+    }
+
+    #[test]
+    fn functions() {
+        let json = generate("shapes", "functions");
+        let module = &json["modules"][0];
+
+        let area = def(module, "area");
+
+        assert_eq!(area["kind"], "value");
+        assert_eq!(area["docs"], "The area of a shape\n");
+        assert_eq!(area["location"], json!({ "line": 17, "column": 1 }));
+        assert_eq!(
+            area["type"],
+            json!({
+                "kind": "function",
+                "args": [apply("Shape", vec![])],
+                "output": apply("F64", vec![]),
+            })
+        );
+
+        let describe = def(module, "describe");
+
+        assert_eq!(describe["docs"], Value::Null);
+        assert_eq!(
+            describe["type"]["args"][0],
+            json!({
+                "kind": "record",
+                "fields": [
+                    { "name": "shape", "kind": "required", "type": apply("Shape", vec![]) },
+                    { "name": "name", "kind": "optional", "type": apply("Str", vec![]) },
+                ],
+                "extension": { "kind": "wildcard" },
+            })
+        );
+    }
+
+    #[test]
+    fn missing_exposed_module() {
+        let dir = fixture("missing_module");
+        let build_dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+            .join("docs_json")
+            .join("missing_exposed_module");
+
+        assert_eq!(
+            generate_docs_json(vec![dir.join("main.roc")], &build_dir),
+            Err(format!(
+                "The test/missing package exposes the Missing module, but I could not find its file at {}",
+                dir.join("Missing.roc").display()
+            ))
+        );
+    }
+
+    #[test]
+    fn two_platforms() {
+        let build_dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+            .join("docs_json")
+            .join("two_platforms");
+
+        assert_eq!(
+            generate_docs_json(
+                vec![
+                    fixture("shapes").join("main.roc"),
+                    fixture("missing_module").join("main.roc"),
+                ],
+                &build_dir,
+            ),
+            Err("I can only generate docs for one package at a time, but I found the test/shapes and test/missing platform modules.".to_string())
+        );
+    }
+}
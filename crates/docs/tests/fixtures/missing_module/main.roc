@@ -0,0 +1,9 @@
+platform "test/missing"
+    requires {} { main : Str }
+    exposes [Missing]
+    packages {}
+    imports []
+    provides [mainForHost]
+
+mainForHost : Str
+mainForHost = main
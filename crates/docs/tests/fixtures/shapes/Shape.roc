@@ -0,0 +1,32 @@
+interface Shape
+    exposes [Shape, Size, HasArea, area, square, describe]
+    imports []
+
+## A shape, whose dimensions are kept private
+Shape := { width : F64, height : F64 }
+
+## How big a shape is
+Size : [Small, Large Str]
+
+## Anything that takes up space
+HasArea has
+    ## The space it takes up
+    measure : a -> F64 | a has HasArea
+
+## The area of a shape
+area : Shape -> F64
+area = \@Shape { width, height } -> width * height
+
+## A square with sides of the given length
+square : F64 -> Shape
+square = \side -> @Shape { width: side, height: side }
+
+describe : { shape : Shape, name ? Str }* -> Size
+describe = \{ shape } ->
+    if area shape > 100 then
+        Large "big"
+    else
+        Small
+
+# not exposed, so not documented
+helper = 1
@@ -0,0 +1,9 @@
+platform "test/shapes"
+    requires {} { main : Str }
+    exposes [Shape]
+    packages {}
+    imports []
+    provides [mainForHost]
+
+mainForHost : Str
+mainForHost = main
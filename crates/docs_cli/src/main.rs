@@ -1,14 +1,19 @@
 use clap::{Arg, Command};
-use roc_docs::generate_docs_html;
+use roc_docs::{generate_docs_html, generate_docs_json};
 use std::fs::{self, FileType};
 use std::io;
 use std::path::{Path, PathBuf};
 
 pub const DIRECTORY_OR_FILES: &str = "DIRECTORY_OR_FILES";
+pub const FLAG_OUTPUT: &str = "output";
+pub const FLAG_BASE_URL: &str = "base-url";
+pub const FLAG_FORMAT: &str = "format";
+pub const FLAG_BUILTINS_URL: &str = "builtins-url";
+pub const FLAG_PACKAGE_URL: &str = "package-url";
 
 fn main() -> io::Result<()> {
     let matches = Command::new("roc-docs")
-        .about("Build HTML documentation files from the given .roc files")
+        .about("Build HTML (or JSON) documentation files from the given .roc files")
         .arg(
             Arg::new(DIRECTORY_OR_FILES)
                 .multiple_values(true)
@@ -16,6 +21,50 @@ fn main() -> io::Result<()> {
                 .help("The directory or files to build documentation for")
                 .allow_invalid_utf8(true),
         )
+        .arg(
+            Arg::new(FLAG_OUTPUT)
+                .long(FLAG_OUTPUT)
+                .help("The directory to write the generated docs to")
+                .value_name("DIR")
+                .takes_value(true)
+                .allow_invalid_utf8(true)
+                .default_value("generated-docs")
+                .required(false),
+        )
+        .arg(
+            Arg::new(FLAG_BASE_URL)
+                .long(FLAG_BASE_URL)
+                .help("The URL path the generated docs will be served from, e.g. /packages/cli")
+                .takes_value(true)
+                .default_value("/")
+                .required(false),
+        )
+        .arg(
+            Arg::new(FLAG_BUILTINS_URL)
+                .long(FLAG_BUILTINS_URL)
+                .help("The URL of the builtins' docs, which types like Str and List link to")
+                .takes_value(true)
+                .default_value(roc_docs::BUILTINS_DOCS_URL)
+                .required(false),
+        )
+        .arg(
+            Arg::new(FLAG_PACKAGE_URL)
+                .long(FLAG_PACKAGE_URL)
+                .help("The URL of the docs of another package, which types from its modules link to\n(For example: --package-url roc/cli=https://example.com/cli/ for the package whose platform module is called \"roc/cli\")")
+                .value_name("NAME=URL")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .validator(roc_docs::parse_package_url)
+                .required(false),
+        )
+        .arg(
+            Arg::new(FLAG_FORMAT)
+                .long(FLAG_FORMAT)
+                .help("What to generate\n(`json` writes a single docs.json, for search indexes and other tools.)")
+                .default_value("html")
+                .possible_values(["html", "json"])
+                .required(false),
+        )
         .get_matches();
 
     let mut roc_files = Vec::new();
@@ -26,7 +75,31 @@ fn main() -> io::Result<()> {
         roc_files_recursive(os_str, metadata.file_type(), &mut roc_files)?;
     }
 
-    generate_docs_html(roc_files);
+    let output_dir = Path::new(matches.value_of_os(FLAG_OUTPUT).unwrap());
+
+    // clap has already validated these
+    let package_urls: Vec<(String, String)> = matches
+        .values_of(FLAG_PACKAGE_URL)
+        .into_iter()
+        .flatten()
+        .filter_map(|arg| roc_docs::parse_package_url(arg).ok())
+        .collect();
+
+    let docs_result = match matches.value_of(FLAG_FORMAT) {
+        Some("json") => generate_docs_json(roc_files, output_dir),
+        _ => generate_docs_html(
+            roc_files,
+            output_dir,
+            matches.value_of(FLAG_BASE_URL).unwrap(),
+            matches.value_of(FLAG_BUILTINS_URL).unwrap(),
+            &package_urls,
+        ),
+    };
+
+    if let Err(message) = docs_result {
+        eprintln!("{}", message);
+        std::process::exit(1);
+    }
 
     Ok(())
 }
@@ -0,0 +1,136 @@
+use roc_ast::lang::core::expr::expr2::{ClosureExtra, Expr2};
+use roc_ast::lang::core::pattern::Pattern2;
+use roc_ast::mem_pool::pool::NodeId;
+use roc_ast::mem_pool::pool_vec::PoolVec;
+use roc_can::expr::Recursive;
+use roc_code_markup::markup::nodes;
+use roc_code_markup::slow_pool::MarkNodeId;
+use roc_module::symbol::Symbol;
+use roc_types::subs::Variable;
+
+use crate::editor::ed_error::EdResult;
+use crate::editor::mvc::app_update::InputOutcome;
+use crate::editor::mvc::ed_model::EdModel;
+use crate::editor::mvc::ed_update::get_node_context;
+use crate::editor::mvc::ed_update::NodeContext;
+
+pub fn start_new_closure(ed_model: &mut EdModel) -> EdResult<InputOutcome> {
+    let NodeContext {
+        old_caret_pos: _,
+        curr_mark_node_id: _,
+        curr_mark_node,
+        parent_id_opt: _,
+        ast_node_id,
+    } = get_node_context(ed_model)?;
+
+    let is_blank_node = curr_mark_node.is_blank();
+
+    let env = &mut ed_model.module.env;
+
+    let body_id = env.pool.add(Expr2::Blank);
+
+    let extra = ClosureExtra {
+        return_type: env.var_store.fresh(),
+        captured_symbols: PoolVec::empty(env.pool),
+        closure_type: env.var_store.fresh(),
+        closure_ext_var: env.var_store.fresh(),
+    };
+
+    let expr2_node = Expr2::Closure {
+        args: PoolVec::empty(env.pool),
+        uniq_symbol: env.gen_unique_symbol(),
+        body_id,
+        function_type: env.var_store.fresh(),
+        recursive: Recursive::NotRecursive,
+        extra: env.pool.add(extra),
+    };
+
+    env.pool.set(ast_node_id.to_expr_id()?, expr2_node);
+
+    if is_blank_node {
+        ed_model.simple_move_carets_right(nodes::BACKSLASH.len());
+
+        Ok(InputOutcome::Accepted)
+    } else {
+        Ok(InputOutcome::Ignored)
+    }
+}
+
+// Adds new_char to the end of the argument name that is right in front of the caret,
+// or creates the first argument if the caret is right behind the backslash.
+pub fn update_closure_arg(
+    prev_mark_node_id: MarkNodeId,
+    args: &PoolVec<(Variable, NodeId<Pattern2>)>,
+    new_char: &char,
+    ed_model: &mut EdModel,
+) -> EdResult<InputOutcome> {
+    if !new_char.is_ascii_alphanumeric() {
+        return Ok(InputOutcome::Ignored);
+    }
+
+    let prev_mark_node = ed_model.mark_node_pool.get(prev_mark_node_id);
+
+    if prev_mark_node.get_content() == nodes::BACKSLASH {
+        if !args.is_empty() || !new_char.is_ascii_lowercase() {
+            return Ok(InputOutcome::Ignored);
+        }
+
+        let env = &mut ed_model.module.env;
+
+        let ident_id = env.ident_ids.add_str(&new_char.to_string());
+        let arg_pattern = Pattern2::Identifier(Symbol::new(env.home, ident_id));
+        let arg_pattern_id = env.pool.add(arg_pattern);
+
+        let new_args = PoolVec::new(
+            std::iter::once((env.var_store.fresh(), arg_pattern_id)),
+            env.pool,
+        );
+
+        let closure_expr_id = ed_model
+            .mark_id_ast_id_map
+            .get(prev_mark_node_id)?
+            .to_expr_id()?;
+
+        if let Expr2::Closure { args, .. } = ed_model.module.env.pool.get_mut(closure_expr_id) {
+            *args = new_args;
+        }
+    } else if prev_mark_node.is_all_alphanumeric() {
+        // args are separated by commas, so the commas in front of the arg tell us which one it is
+        let arg_index = prev_mark_node
+            .get_sibling_ids(&ed_model.mark_node_pool)
+            .into_iter()
+            .take_while(|sibling_id| *sibling_id != prev_mark_node_id)
+            .filter(|sibling_id| {
+                ed_model.mark_node_pool.get(*sibling_id).get_content() == nodes::COMMA
+            })
+            .count();
+
+        let env = &mut ed_model.module.env;
+
+        let arg_pattern_id = match args.iter(env.pool).nth(arg_index) {
+            Some((_, arg_pattern_id)) => *arg_pattern_id,
+            None => return Ok(InputOutcome::Ignored),
+        };
+
+        let mut new_name = match env.pool.get(arg_pattern_id) {
+            Pattern2::Identifier(symbol) => env
+                .ident_ids
+                .get_name_str_res(symbol.ident_id())?
+                .to_owned(),
+            Pattern2::Shadowed { shadowed_ident } => shadowed_ident.as_str(env.pool).to_owned(),
+            _ => return Ok(InputOutcome::Ignored),
+        };
+        new_name.push(*new_char);
+
+        let ident_id = env.ident_ids.add_str(&new_name);
+        let new_arg_pattern = Pattern2::Identifier(Symbol::new(env.home, ident_id));
+
+        env.pool.set(arg_pattern_id, new_arg_pattern);
+    } else {
+        return Ok(InputOutcome::Ignored);
+    }
+
+    ed_model.simple_move_carets_right(1);
+
+    Ok(InputOutcome::Accepted)
+}
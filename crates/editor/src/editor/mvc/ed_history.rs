@@ -0,0 +1,89 @@
+use crate::ui::text::caret_w_select::CaretWSelect;
+use nonempty::NonEmpty;
+use roc_ast::lang::core::def::def2::DefId;
+use roc_ast::mem_pool::pool::PoolChanges;
+use roc_code_markup::slow_pool::MarkNodeId;
+use std::collections::VecDeque;
+use std::fmt;
+
+// Older edits are dropped once the undo stack reaches this length.
+const MAX_HISTORY_LEN: usize = 1000;
+
+/// An edit of the AST that can be undone and redone.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EdAction {
+    /// A typed char, this includes newlines.
+    Insert(char),
+    /// The selected def was deleted, e.g. by pressing backspace.
+    Delete,
+    /// The selected expression was replaced by a blank, e.g. by pressing backspace.
+    ReplaceSelectedExpr,
+}
+
+/// An action along with its inverse: the parts of the EdModel that the action changed, the way they are
+/// on the other side of the action. Swapping them with the EdModel undoes the action, swapping them again redoes it.
+/// The markup, code lines and grid are not in here because they are regenerated from the AST.
+#[derive(Debug)]
+pub struct EdCommand {
+    pub action: EdAction,
+    pub pool_changes: PoolChanges,
+    // only kept if the action added or removed top-level defs
+    pub def_ids_opt: Option<Vec<DefId>>,
+    pub caret_w_select_vec: NonEmpty<(CaretWSelect, Option<MarkNodeId>)>,
+}
+
+/// The commands that can be undone, oldest first, and the commands that were undone and can be redone.
+#[derive(Debug, Default)]
+pub struct EdHistory {
+    undo_stack: VecDeque<EdCommand>,
+    redo_stack: Vec<EdCommand>,
+}
+
+impl EdHistory {
+    /// Record the command of an action that was just performed.
+    /// A new action makes the actions that were undone impossible to redo.
+    pub fn record(&mut self, command: EdCommand) {
+        if self.undo_stack.len() == MAX_HISTORY_LEN {
+            self.undo_stack.pop_front();
+        }
+
+        self.undo_stack.push_back(command);
+        self.redo_stack.clear();
+    }
+
+    pub fn pop_undo(&mut self) -> Option<EdCommand> {
+        self.undo_stack.pop_back()
+    }
+
+    pub fn push_undo(&mut self, command: EdCommand) {
+        self.undo_stack.push_back(command);
+    }
+
+    pub fn pop_redo(&mut self) -> Option<EdCommand> {
+        self.redo_stack.pop()
+    }
+
+    pub fn push_redo(&mut self, command: EdCommand) {
+        self.redo_stack.push(command);
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+}
+
+impl fmt::Display for EdHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let undo_actions: Vec<EdAction> = self.undo_stack.iter().map(|cmd| cmd.action).collect();
+        let redo_actions: Vec<EdAction> = self.redo_stack.iter().map(|cmd| cmd.action).collect();
+
+        writeln!(f, "undo: {:?}", undo_actions)?;
+        writeln!(f, "redo: {:?}", redo_actions)?;
+
+        Ok(())
+    }
+}
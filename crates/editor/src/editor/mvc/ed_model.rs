@@ -1,5 +1,6 @@
 use crate::editor::code_lines::CodeLines;
 use crate::editor::grid_node_map::GridNodeMap;
+use crate::editor::mvc::ed_history::EdHistory;
 use crate::editor::{
     ed_error::SrcParseSnafu,
     ed_error::{EdResult, EmptyCodeStringSnafu, MissingParentSnafu, NoNodeAtCaretPositionSnafu},
@@ -40,6 +41,7 @@ pub struct EdModel<'a> {
     pub loaded_module: LoadedModule, // contains all roc symbols, exposed values, exposed aliases, solved types... in the file(=module)
     pub show_debug_view: bool,       // see render_debug.rs for the debug view
     pub dirty: bool, // EdModel is dirty if it has changed since the previous render.
+    pub history: EdHistory, // previous edits of the AST, used for undo and redo
 }
 
 // a selected AST node, the roc type of this node is shown in the editor on ctrl+shift+"up arrow"
@@ -117,6 +119,7 @@ pub fn init_model<'a>(
         loaded_module: owned_loaded_module,
         show_debug_view: false,
         dirty: true,
+        history: EdHistory::default(),
     })
 }
 
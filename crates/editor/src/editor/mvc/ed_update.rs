@@ -8,6 +8,8 @@ use crate::editor::ed_error::EdResult;
 use crate::editor::ed_error::{MissingSelectionSnafu, RocCheckFailedSnafu};
 use crate::editor::grid_node_map::GridNodeMap;
 use crate::editor::mvc::app_update::InputOutcome;
+use crate::editor::mvc::closure_update::{start_new_closure, update_closure_arg};
+use crate::editor::mvc::ed_history::{EdAction, EdCommand};
 use crate::editor::mvc::ed_model::EdModel;
 use crate::editor::mvc::ed_model::SelectedBlock;
 use crate::editor::mvc::int_update::start_new_int;
@@ -22,6 +24,7 @@ use crate::editor::mvc::string_update::start_new_string;
 use crate::editor::mvc::string_update::update_small_string;
 use crate::editor::mvc::string_update::update_string;
 use crate::editor::mvc::tld_value_update::{start_new_tld_value, update_tld_val_name};
+use crate::editor::mvc::when_update::update_when_branch_pattern;
 #[cfg(feature = "with_sound")]
 use crate::editor::sound::play_sound;
 use crate::ui::text::caret_w_select::CaretWSelect;
@@ -70,7 +73,7 @@ use VirtualKeyCode::*;
 
 use super::break_line::break_line;
 use super::break_line::insert_new_blank;
-use super::let_update::start_new_let_value;
+use super::let_update::{start_new_let_value, update_let_keyword};
 
 /// ed_update.rs contains all functions that change the ed_model.
 /// Additions and deletions of new characters to the editor are handled here.
@@ -538,6 +541,20 @@ impl<'a> EdModel<'a> {
                     self.run_file()?
                 }
             }
+            Z => {
+                if modifiers.cmd_or_ctrl() {
+                    if modifiers.shift {
+                        self.redo()?
+                    } else {
+                        self.undo()?
+                    }
+                }
+            }
+            Y => {
+                if modifiers.cmd_or_ctrl() {
+                    self.redo()?
+                }
+            }
 
             Home => self.move_caret_home(modifiers)?,
             End => self.move_caret_end(modifiers)?,
@@ -688,6 +705,46 @@ impl<'a> EdModel<'a> {
 
         Ok(())
     }
+
+    // Swaps the AST and carets with the way they are on the other side of the command's action, this undoes or redoes it.
+    // Everything else is regenerated from the AST.
+    fn swap_command(&mut self, command: &mut EdCommand) -> EdResult<()> {
+        self.module.env.pool.swap_changes(&mut command.pool_changes);
+
+        if let Some(def_ids) = &mut command.def_ids_opt {
+            std::mem::swap(&mut self.module.ast.def_ids, def_ids);
+        }
+
+        self.post_process_ast_update()?;
+
+        std::mem::swap(
+            &mut self.caret_w_select_vec,
+            &mut command.caret_w_select_vec,
+        );
+        // the selected block is not kept in the command, so the selection can not be restored either
+        self.set_sel_none();
+        self.dirty = true;
+
+        Ok(())
+    }
+
+    fn undo(&mut self) -> EdResult<()> {
+        if let Some(mut command) = self.history.pop_undo() {
+            self.swap_command(&mut command)?;
+            self.history.push_redo(command);
+        }
+
+        Ok(())
+    }
+
+    fn redo(&mut self) -> EdResult<()> {
+        if let Some(mut command) = self.history.pop_redo() {
+            self.swap_command(&mut command)?;
+            self.history.push_undo(command);
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> SelectableLines for EdModel<'a> {
@@ -947,6 +1004,7 @@ pub fn handle_new_char_expr(
             '"' => start_new_string(ed_model)?,
             '{' => start_new_record(ed_model)?,
             '0'..='9' => start_new_int(ed_model, ch)?,
+            '\\' => start_new_closure(ed_model)?,
             '[' => {
                 // this can also be a tag union or become a set, assuming list for now
                 start_new_list(ed_model)?
@@ -1082,7 +1140,7 @@ pub fn handle_new_char_expr(
                 // insert a Blank first, this results in cleaner code
                 add_blank_child(new_child_index, new_ast_child_index, ed_model)?;
                 ed_model.post_process_ast_update()?;
-                handle_new_char(received_char, ed_model)?
+                apply_new_char(received_char, ed_model)?
             } else {
                 InputOutcome::Ignored
             }
@@ -1165,11 +1223,27 @@ pub fn handle_new_char_diff_mark_nodes_prev_is_expr(
                 // insert a Blank first, this results in cleaner code
                 add_blank_child(new_child_index, new_ast_child_index, ed_model)?;
                 ed_model.post_process_ast_update()?;
-                handle_new_char(received_char, ed_model)?
+                apply_new_char(received_char, ed_model)?
             } else {
                 InputOutcome::Ignored
             }
         }
+        Expr2::LetValue {
+            def_id,
+            body_id,
+            body_var: _,
+        } if curr_mark_node.get_content() == nodes::EQUALS => {
+            update_let_keyword(prev_expr_id, *def_id, *body_id, ch, ed_model)?
+        }
+        Expr2::When { branches, .. } if curr_mark_node.get_content() == nodes::ARROW => {
+            update_when_branch_pattern(prev_mark_node_id, branches, ch, ed_model)?
+        }
+        Expr2::Closure { args, .. }
+            if curr_mark_node.get_content() == nodes::ARROW
+                || curr_mark_node.get_content() == nodes::COMMA =>
+        {
+            update_closure_arg(prev_mark_node_id, args, ch, ed_model)?
+        }
         _ => match curr_expr_ref {
             Expr2::EmptyRecord => {
                 let sibling_ids = curr_mark_node.get_sibling_ids(&ed_model.mark_node_pool);
@@ -1184,7 +1258,48 @@ pub fn handle_new_char_diff_mark_nodes_prev_is_expr(
 }
 
 // updates the ed_model based on the char the user just typed if the result would be syntactically correct.
+// Every accepted change is recorded in the history of the ed_model so it can be undone.
 pub fn handle_new_char(received_char: &char, ed_model: &mut EdModel) -> EdResult<InputOutcome> {
+    let action = match received_char {
+        '\u{8}' | '\u{7f}' => match &ed_model.selected_block_opt {
+            Some(sel_block) => match sel_block.ast_node_id {
+                ASTNodeId::ADefId(_) => EdAction::Delete,
+                ASTNodeId::AExprId(_) => EdAction::ReplaceSelectedExpr,
+            },
+            // without a selected expression, backspace only selects one
+            None => return apply_new_char(received_char, ed_model),
+        },
+        ch => EdAction::Insert(*ch),
+    };
+
+    let def_ids_before = ed_model.module.ast.def_ids.clone();
+    let caret_w_select_vec_before = ed_model.caret_w_select_vec.clone();
+
+    ed_model.module.env.pool.start_journal();
+    let input_outcome_res = apply_new_char(received_char, ed_model);
+    let pool_changes = ed_model.module.env.pool.end_journal();
+
+    let input_outcome = input_outcome_res?;
+
+    if let InputOutcome::Accepted = input_outcome {
+        let def_ids_opt = if ed_model.module.ast.def_ids != def_ids_before {
+            Some(def_ids_before)
+        } else {
+            None
+        };
+
+        ed_model.history.record(EdCommand {
+            action,
+            pool_changes,
+            def_ids_opt,
+            caret_w_select_vec: caret_w_select_vec_before,
+        });
+    }
+
+    Ok(input_outcome)
+}
+
+fn apply_new_char(received_char: &char, ed_model: &mut EdModel) -> EdResult<InputOutcome> {
     //dbg!("{}", ed_model.module.ast.ast_to_string(ed_model.module.env.pool));
 
     let input_outcome = match received_char {
@@ -1245,7 +1360,7 @@ pub fn handle_new_char(received_char: &char, ed_model: &mut EdModel) -> EdResult
                                                     ed_model.post_process_ast_update()?;
                                                 }
                                             }
-                                            handle_new_char(received_char, ed_model)?
+                                            apply_new_char(received_char, ed_model)?
                                         }
                                         _ => {
                                             InputOutcome::Ignored
@@ -1287,6 +1402,7 @@ pub mod test_ed_update {
     use crate::ui::text::lines::SelectableLines;
     use crate::ui::ui_error::UIResult;
     use crate::window::keyboard_input::no_mods;
+    use crate::window::keyboard_input::test_modifiers::ctrl_cmd;
     use crate::window::keyboard_input::test_modifiers::ctrl_cmd_shift;
     use crate::window::keyboard_input::Modifiers;
     use bumpalo::Bump;
@@ -2576,6 +2692,145 @@ pub mod test_ed_update {
         Ok(())
     }
 
+    #[test]
+    fn test_if() -> Result<(), String> {
+        assert_insert_seq_in_def(add_nls(ovec!["if ┃  then   else  "]), "if")?;
+        assert_insert_seq_in_def(add_nls(ovec!["if 1┃ then   else  "]), "if1")?;
+        assert_insert_seq_in_def(add_nls(ovec!["if \"a┃\" then   else  "]), "if\"a")?;
+        // move to the then and else expressions
+        assert_insert_seq_in_def(add_nls(ovec!["if 1 then 2┃ else  "]), "if1🡲🡲🡲🡲🡲🡲2")?;
+        assert_insert_seq_in_def(
+            add_nls(ovec!["if 1 then 2 else { ┃ }"]),
+            "if1🡲🡲🡲🡲🡲🡲2🡲🡲🡲🡲🡲🡲{",
+        )?;
+
+        assert_insert_nls(
+            ovec!["val = if 1┃ then 2 else 3"],
+            ovec!["val = if 12┃ then 2 else 3"],
+            '2',
+        )?;
+        assert_insert_nls(
+            ovec!["val = if 1 then 2 else ┃3"],
+            ovec!["val = if 1 then 2 else 4┃3"],
+            '4',
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ignore_if() -> Result<(), String> {
+        assert_insert_seq_ignore_nls(ovec!["val = i┃f 1 then 2 else 3"], IGNORE_CHARS)?;
+        assert_insert_seq_ignore_nls(ovec!["val = if 1 th┃en 2 else 3"], IGNORE_CHARS)?;
+        assert_insert_seq_ignore_nls(ovec!["val = if 1 then 2 el┃se 3"], IGNORE_CHARS)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_when() -> Result<(), String> {
+        assert_insert_seq_in_def(add_nls(ovec!["when ┃  is", "    _ ->  "]), "when")?;
+        assert_insert_seq_in_def(add_nls(ovec!["when 1┃ is", "    _ ->  "]), "when1")?;
+
+        // patterns of branches
+        assert_insert_nls(
+            ovec!["val = when 1 is", "    _┃ -> 2"],
+            ovec!["val = when 1 is", "    a┃ -> 2"],
+            'a',
+        )?;
+        assert_insert_nls(
+            ovec!["val = when 1 is", "    _┃ -> 2"],
+            ovec!["val = when 1 is", "    A┃ -> 2"],
+            'A',
+        )?;
+        assert_insert_nls(
+            ovec!["val = when 1 is", "    x┃ -> 2"],
+            ovec!["val = when 1 is", "    x1┃ -> 2"],
+            '1',
+        )?;
+        assert_insert_nls(
+            ovec!["val = when 1 is", "    Ok┃ -> 2", "    Err -> 3"],
+            ovec!["val = when 1 is", "    Okk┃ -> 2", "    Err -> 3"],
+            'k',
+        )?;
+        assert_insert_nls(
+            ovec!["val = when 1 is", "    Ok -> 2", "    Err┃ -> 3"],
+            ovec!["val = when 1 is", "    Ok -> 2", "    Erro┃ -> 3"],
+            'o',
+        )?;
+
+        // bodies of branches
+        assert_insert_nls(
+            ovec!["val = when 1 is", "    x -> 2┃"],
+            ovec!["val = when 1 is", "    x -> 23┃"],
+            '3',
+        )?;
+        assert_insert_nls(
+            ovec!["val = when 1.5 is", "    1.5 -> 2┃", "    _ -> 3"],
+            ovec!["val = when 1.5 is", "    1.5 -> 23┃", "    _ -> 3"],
+            '3',
+        )?;
+        assert_insert_nls(
+            ovec![
+                "val = when { height: 2 } is",
+                "    { height: 2 } -> 3┃",
+                "    _ -> 4"
+            ],
+            ovec![
+                "val = when { height: 2 } is",
+                "    { height: 2 } -> 35┃",
+                "    _ -> 4"
+            ],
+            '5',
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ignore_when() -> Result<(), String> {
+        assert_insert_seq_ignore_nls(ovec!["val = wh┃en 1 is", "    x -> 2"], IGNORE_CHARS)?;
+        assert_insert_seq_ignore_nls(ovec!["val = when 1 i┃s", "    x -> 2"], IGNORE_CHARS)?;
+        assert_insert_seq_ignore_nls(ovec!["val = when 1 is", "    x -┃> 2"], IGNORE_CHARS)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_closure() -> Result<(), String> {
+        assert_insert_in_def(add_nls(ovec!["\\┃ -> ", " "]), '\\')?;
+        assert_insert_seq_in_def(add_nls(ovec!["\\a┃ -> ", " "]), "\\a")?;
+        assert_insert_seq_in_def(add_nls(ovec!["\\ab1┃ -> ", " "]), "\\ab1")?;
+        // an argument name has to start with a lowercase letter
+        assert_insert_seq_in_def(add_nls(ovec!["\\┃ -> ", " "]), "\\1A")?;
+
+        assert_insert_nls(
+            ovec!["val = \\a┃ -> ", "    a"],
+            ovec!["val = \\ab┃ -> ", "    a"],
+            'b',
+        )?;
+        assert_insert_nls(
+            ovec!["val = \\a, b┃ -> ", "    a"],
+            ovec!["val = \\a, bc┃ -> ", "    a"],
+            'c',
+        )?;
+        assert_insert_nls(
+            ovec!["val = \\a┃, b -> ", "    a"],
+            ovec!["val = \\a2┃, b -> ", "    a"],
+            '2',
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ignore_closure() -> Result<(), String> {
+        assert_insert_seq_ignore_nls(ovec!["val = \\a -┃> ", "    a"], IGNORE_CHARS)?;
+        assert_insert_seq_ignore_nls(ovec!["val = \\a,┃ b -> ", "    a"], IGNORE_CHARS)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_enter() -> Result<(), String> {
         assert_insert_seq(
@@ -3472,4 +3727,210 @@ pub mod test_ed_update {
 
         Ok(())
     }
+
+    fn undo(ed_model: &mut EdModel) -> Result<(), String> {
+        ed_res_to_res(ed_model.ed_handle_key_down(&ctrl_cmd(), Z, &mut ThreadPool::new(1)))
+    }
+
+    fn redo(ed_model: &mut EdModel) -> Result<(), String> {
+        ed_res_to_res(ed_model.ed_handle_key_down(&ctrl_cmd_shift(), Z, &mut ThreadPool::new(1)))
+    }
+
+    fn assert_ed_model_lines(ed_model: &EdModel, expected_lines: &[String]) -> Result<(), String> {
+        let mut lines = ui_res_to_res(ed_model_to_dsl(ed_model))?;
+        strip_header(&mut lines);
+
+        assert_eq!(lines, expected_lines);
+
+        Ok(())
+    }
+
+    // Create ed_model from pre_lines DSL, do handle_new_char() for every char in new_char_seq and check
+    // if the ed_model has expected_post_lines. Then undo once for every entry of expected_undo_lines and
+    // compare with that entry, the code and caret position should be restored. Finally redo everything
+    // that was undone, which should bring us back to expected_post_lines.
+    fn assert_undo_redo(
+        pre_lines: Vec<String>,
+        new_char_seq: &str,
+        expected_post_lines: Vec<String>,
+        expected_undo_lines: Vec<Vec<String>>,
+    ) -> Result<(), String> {
+        let mut code_str = pre_lines.join("\n").replace('┃', "");
+
+        let mut model_refs = init_model_refs();
+        let code_arena = Bump::new();
+        let module_ids = ModuleIds::default();
+
+        let mut ed_model = ed_model_from_dsl(
+            &mut code_str,
+            pre_lines,
+            &mut model_refs,
+            &module_ids,
+            &code_arena,
+        )?;
+
+        for input_char in new_char_seq.chars() {
+            if input_char == '🡲' {
+                ed_model.simple_move_carets_right(1);
+            } else {
+                ed_res_to_res(handle_new_char(&input_char, &mut ed_model))?;
+            }
+        }
+
+        assert_ed_model_lines(&ed_model, &expected_post_lines)?;
+
+        for expected_lines in expected_undo_lines.iter() {
+            undo(&mut ed_model)?;
+            assert_ed_model_lines(&ed_model, expected_lines)?;
+        }
+
+        for expected_lines in expected_undo_lines.iter().rev().skip(1) {
+            redo(&mut ed_model)?;
+            assert_ed_model_lines(&ed_model, expected_lines)?;
+        }
+
+        redo(&mut ed_model)?;
+        assert_ed_model_lines(&ed_model, &expected_post_lines)?;
+
+        // nothing left to redo
+        redo(&mut ed_model)?;
+        assert_ed_model_lines(&ed_model, &expected_post_lines)
+    }
+
+    #[test]
+    fn test_undo_redo_int() -> Result<(), String> {
+        assert_undo_redo(
+            ovec!["val = 1┃"],
+            "23",
+            add_nls(ovec!["val = 123┃"]),
+            vec![add_nls(ovec!["val = 12┃"]), add_nls(ovec!["val = 1┃"])],
+        )?;
+        assert_undo_redo(
+            ovec!["val = 1┃0"],
+            "5",
+            add_nls(ovec!["val = 15┃0"]),
+            vec![add_nls(ovec!["val = 1┃0"])],
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo_redo_nested() -> Result<(), String> {
+        assert_undo_redo(
+            ovec!["┃"],
+            "val🡲🡲🡲{a:\"",
+            add_nls(ovec!["val = { a: \"┃\" }"]),
+            vec![
+                add_nls(ovec!["val = { a: ┃  }"]),
+                add_nls(ovec!["val = { a┃ }"]),
+                add_nls(ovec!["val = { ┃ }"]),
+                add_nls(ovec!["val = ┃ "]),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo_redo_ignored_chars() -> Result<(), String> {
+        // ignored input is not recorded, so undo goes straight back to before the 2
+        assert_undo_redo(
+            ovec!["val = 1┃"],
+            "2;-",
+            add_nls(ovec!["val = 12┃"]),
+            vec![add_nls(ovec!["val = 1┃"])],
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo_redo_backspace() -> Result<(), String> {
+        let pre_lines = ovec!["val = { a: ┃12 }"];
+        let mut code_str = pre_lines.join("").replace('┃', "");
+
+        let mut model_refs = init_model_refs();
+        let code_arena = Bump::new();
+        let module_ids = ModuleIds::default();
+
+        let mut ed_model = ed_model_from_dsl(
+            &mut code_str,
+            pre_lines,
+            &mut model_refs,
+            &module_ids,
+            &code_arena,
+        )?;
+
+        // selecting an expression with backspace does not change the AST, so there is nothing to undo
+        handle_new_char(&'\u{8}', &mut ed_model)?;
+        assert!(!ed_model.history.can_undo());
+
+        handle_new_char(&'\u{8}', &mut ed_model)?;
+        assert_ed_model_lines(&ed_model, &add_nls(ovec!["val = { a: ┃  }"]))?;
+
+        undo(&mut ed_model)?;
+        assert_ed_model_lines(&ed_model, &add_nls(ovec!["val = { a: ┃12 }"]))?;
+        assert!(ed_model.history.can_redo());
+
+        ed_res_to_res(ed_model.ed_handle_key_down(&ctrl_cmd(), Y, &mut ThreadPool::new(1)))?;
+        assert_ed_model_lines(&ed_model, &add_nls(ovec!["val = { a: ┃  }"]))?;
+
+        // a new edit after an undo can not be followed by a redo
+        undo(&mut ed_model)?;
+        handle_new_char(&'3', &mut ed_model)?;
+        assert_ed_model_lines(&ed_model, &add_nls(ovec!["val = { a: 3┃12 }"]))?;
+        assert!(!ed_model.history.can_redo());
+
+        redo(&mut ed_model)?;
+        assert_ed_model_lines(&ed_model, &add_nls(ovec!["val = { a: 3┃12 }"]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo_redo_new_edit_after_undo() -> Result<(), String> {
+        let pre_lines = ovec!["┃"];
+        let mut code_str = pre_lines.join("").replace('┃', "");
+
+        let mut model_refs = init_model_refs();
+        let code_arena = Bump::new();
+        let module_ids = ModuleIds::default();
+
+        let mut ed_model = ed_model_from_dsl(
+            &mut code_str,
+            pre_lines,
+            &mut model_refs,
+            &module_ids,
+            &code_arena,
+        )?;
+
+        for input_char in "val🡲🡲🡲{a".chars() {
+            if input_char == '🡲' {
+                ed_model.simple_move_carets_right(1);
+            } else {
+                ed_res_to_res(handle_new_char(&input_char, &mut ed_model))?;
+            }
+        }
+
+        undo(&mut ed_model)?;
+        undo(&mut ed_model)?;
+        assert_ed_model_lines(&ed_model, &add_nls(ovec!["val = ┃ "]))?;
+
+        // the nodes that were added by the undone edits are not reused by the new ones
+        ed_res_to_res(handle_new_char(&'[', &mut ed_model))?;
+        ed_res_to_res(handle_new_char(&'1', &mut ed_model))?;
+        assert_ed_model_lines(&ed_model, &add_nls(ovec!["val = [ 1┃ ]"]))?;
+
+        undo(&mut ed_model)?;
+        assert_ed_model_lines(&ed_model, &add_nls(ovec!["val = [ ┃ ]"]))?;
+        undo(&mut ed_model)?;
+        assert_ed_model_lines(&ed_model, &add_nls(ovec!["val = ┃ "]))?;
+
+        redo(&mut ed_model)?;
+        redo(&mut ed_model)?;
+        assert_ed_model_lines(&ed_model, &add_nls(ovec!["val = [ 1┃ ]"]))?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,35 @@
+use roc_ast::lang::core::expr::expr2::{Expr2, ExprId};
+use roc_ast::mem_pool::pool_vec::PoolVec;
+use roc_code_markup::markup::nodes;
+
+use crate::editor::ed_error::EdResult;
+use crate::editor::mvc::app_update::InputOutcome;
+use crate::editor::mvc::ed_model::EdModel;
+
+// Replaces the expression with `if  then  else `, all three parts start out as Blank.
+// typed_keyword_len is the length of the part of `if` that is in front of the caret.
+pub fn start_new_if(
+    expr_id: ExprId,
+    typed_keyword_len: usize,
+    ed_model: &mut EdModel,
+) -> EdResult<InputOutcome> {
+    let env = &mut ed_model.module.env;
+
+    let cond_id = env.pool.add(Expr2::Blank);
+    let then_id = env.pool.add(Expr2::Blank);
+    let final_else = env.pool.add(Expr2::Blank);
+
+    let expr2_node = Expr2::If {
+        cond_var: env.var_store.fresh(),
+        expr_var: env.var_store.fresh(),
+        branches: PoolVec::new(std::iter::once((cond_id, then_id)), env.pool),
+        final_else,
+    };
+
+    env.pool.set(expr_id, expr2_node);
+
+    // caret goes to the condition
+    ed_model.simple_move_carets_right(nodes::IF.len() - typed_keyword_len);
+
+    Ok(InputOutcome::Accepted)
+}
@@ -1,6 +1,8 @@
-use roc_ast::lang::core::expr::expr2::Expr2;
+use roc_ast::lang::core::expr::expr2::{Expr2, ExprId};
 use roc_ast::lang::core::pattern::Pattern2;
 use roc_ast::lang::core::val_def::ValueDef;
+use roc_ast::mem_pool::pool::NodeId;
+use roc_code_markup::markup::nodes;
 use roc_module::symbol::Symbol;
 
 use crate::editor::ed_error::EdResult;
@@ -8,6 +10,8 @@ use crate::editor::mvc::app_update::InputOutcome;
 use crate::editor::mvc::ed_model::EdModel;
 use crate::editor::mvc::ed_update::get_node_context;
 use crate::editor::mvc::ed_update::NodeContext;
+use crate::editor::mvc::if_update::start_new_if;
+use crate::editor::mvc::when_update::start_new_when;
 
 pub fn start_new_let_value(ed_model: &mut EdModel, new_char: &char) -> EdResult<InputOutcome> {
     let NodeContext {
@@ -62,6 +66,49 @@ pub fn start_new_let_value(ed_model: &mut EdModel, new_char: &char) -> EdResult<
     }
 }
 
+// Keywords are typed on top of a let value: `i` starts a let value and typing `f` behind it
+// turns that let value into an `if`. Only names that are the start of a keyword can be extended for now.
+pub fn update_let_keyword(
+    let_expr_id: ExprId,
+    def_id: NodeId<ValueDef>,
+    body_id: ExprId,
+    new_char: &char,
+    ed_model: &mut EdModel,
+) -> EdResult<InputOutcome> {
+    let env = &mut ed_model.module.env;
+
+    let pattern_id = env.pool.get(def_id).get_pattern_id();
+
+    let old_name = match env.pool.get(pattern_id) {
+        Pattern2::Identifier(symbol) => env
+            .ident_ids
+            .get_name_str_res(symbol.ident_id())?
+            .to_owned(),
+        _ => return Ok(InputOutcome::Ignored),
+    };
+
+    let mut new_name = old_name.clone();
+    new_name.push(*new_char);
+
+    if new_name == nodes::IF.trim_end() {
+        start_new_if(let_expr_id, old_name.len(), ed_model)
+    } else if new_name == nodes::WHEN.trim_end() {
+        start_new_when(let_expr_id, old_name.len(), ed_model)
+    } else if nodes::WHEN.starts_with(&new_name) {
+        let ident_id = env.ident_ids.add_str(&new_name);
+        let var_symbol = Symbol::new(env.home, ident_id);
+
+        env.pool.set(pattern_id, Pattern2::Identifier(var_symbol));
+        env.pool.set(body_id, Expr2::Var(var_symbol));
+
+        ed_model.simple_move_carets_right(1);
+
+        Ok(InputOutcome::Accepted)
+    } else {
+        Ok(InputOutcome::Ignored)
+    }
+}
+
 // TODO reenable this for updating non-top level value defs
 /*
 pub fn update_let_value(
@@ -1,9 +1,12 @@
 pub mod app_model;
 pub mod app_update;
 mod break_line;
+mod closure_update;
+pub mod ed_history;
 pub mod ed_model;
 pub mod ed_update;
 pub mod ed_view;
+mod if_update;
 mod int_update;
 mod let_update;
 mod list_update;
@@ -11,3 +14,4 @@ mod lookup_update;
 mod record_update;
 mod string_update;
 pub mod tld_value_update;
+mod when_update;
@@ -0,0 +1,148 @@
+use roc_ast::lang::core::expr::expr2::{Expr2, ExprId, WhenBranch};
+use roc_ast::lang::core::pattern::Pattern2;
+use roc_ast::mem_pool::pool_str::PoolStr;
+use roc_ast::mem_pool::pool_vec::PoolVec;
+use roc_code_markup::markup::nodes;
+use roc_code_markup::slow_pool::MarkNodeId;
+use roc_module::symbol::Symbol;
+
+use crate::editor::ed_error::EdResult;
+use crate::editor::mvc::app_update::InputOutcome;
+use crate::editor::mvc::ed_model::EdModel;
+
+// Replaces the expression with a `when` that has a Blank condition and a single `_ -> ` branch.
+// typed_keyword_len is the length of the part of `when` that is in front of the caret.
+pub fn start_new_when(
+    expr_id: ExprId,
+    typed_keyword_len: usize,
+    ed_model: &mut EdModel,
+) -> EdResult<InputOutcome> {
+    let env = &mut ed_model.module.env;
+
+    let cond = env.pool.add(Expr2::Blank);
+    let body = env.pool.add(Expr2::Blank);
+
+    let branch = WhenBranch {
+        patterns: PoolVec::new(std::iter::once(Pattern2::Underscore), env.pool),
+        body,
+        guard: None,
+    };
+
+    let expr2_node = Expr2::When {
+        cond_var: env.var_store.fresh(),
+        expr_var: env.var_store.fresh(),
+        branches: PoolVec::new(std::iter::once(branch), env.pool),
+        cond,
+    };
+
+    env.pool.set(expr_id, expr2_node);
+
+    // caret goes to the condition
+    ed_model.simple_move_carets_right(nodes::WHEN.len() - typed_keyword_len);
+
+    Ok(InputOutcome::Accepted)
+}
+
+// Adds new_char to the end of the pattern of a branch, the caret is right behind that pattern.
+// `_` is replaced by an identifier or a tag, depending on the case of new_char.
+pub fn update_when_branch_pattern(
+    pattern_mn_id: MarkNodeId,
+    branches: &PoolVec<WhenBranch>,
+    new_char: &char,
+    ed_model: &mut EdModel,
+) -> EdResult<InputOutcome> {
+    if !new_char.is_ascii_alphanumeric() {
+        return Ok(InputOutcome::Ignored);
+    }
+
+    // every branch has one arrow, so the arrows in front of the pattern tell us which branch it is
+    let pattern_mn = ed_model.mark_node_pool.get(pattern_mn_id);
+    let branch_index = pattern_mn
+        .get_sibling_ids(&ed_model.mark_node_pool)
+        .into_iter()
+        .take_while(|sibling_id| *sibling_id != pattern_mn_id)
+        .filter(|sibling_id| ed_model.mark_node_pool.get(*sibling_id).get_content() == nodes::ARROW)
+        .count();
+
+    let branch_node_id = match branches.iter_node_ids().nth(branch_index) {
+        Some(branch_node_id) => branch_node_id,
+        None => return Ok(InputOutcome::Ignored),
+    };
+
+    let env = &mut ed_model.module.env;
+    let old_patterns = &env.pool.get(branch_node_id).patterns;
+
+    // alternatives like `A | B` can not be edited yet
+    if old_patterns.len() != 1 {
+        return Ok(InputOutcome::Ignored);
+    }
+
+    let old_pattern = old_patterns
+        .iter_node_ids()
+        .next()
+        .map(|pattern_node_id| env.pool.get(pattern_node_id));
+
+    let (new_pattern, caret_move) = match old_pattern {
+        Some(Pattern2::Underscore) if new_char.is_ascii_lowercase() => {
+            let ident_id = env.ident_ids.add_str(&new_char.to_string());
+
+            // the new char takes the place of `_`
+            (Pattern2::Identifier(Symbol::new(env.home, ident_id)), 0)
+        }
+        Some(Pattern2::Underscore) if new_char.is_ascii_uppercase() => (
+            Pattern2::Tag {
+                whole_var: env.var_store.fresh(),
+                ext_var: env.var_store.fresh(),
+                tag_name: PoolStr::new(&new_char.to_string(), env.pool),
+                arguments: PoolVec::empty(env.pool),
+            },
+            0,
+        ),
+        Some(Pattern2::Identifier(symbol)) => {
+            let mut new_name = env
+                .ident_ids
+                .get_name_str_res(symbol.ident_id())?
+                .to_owned();
+            new_name.push(*new_char);
+
+            let ident_id = env.ident_ids.add_str(&new_name);
+
+            (Pattern2::Identifier(Symbol::new(env.home, ident_id)), 1)
+        }
+        Some(Pattern2::Shadowed { shadowed_ident }) => {
+            let mut new_name = shadowed_ident.as_str(env.pool).to_owned();
+            new_name.push(*new_char);
+
+            let ident_id = env.ident_ids.add_str(&new_name);
+
+            (Pattern2::Identifier(Symbol::new(env.home, ident_id)), 1)
+        }
+        Some(Pattern2::Tag {
+            whole_var,
+            ext_var,
+            tag_name,
+            arguments,
+        }) if arguments.is_empty() => {
+            let mut new_tag_name = tag_name.as_str(env.pool).to_owned();
+            new_tag_name.push(*new_char);
+
+            (
+                Pattern2::Tag {
+                    whole_var: *whole_var,
+                    ext_var: *ext_var,
+                    tag_name: PoolStr::new(&new_tag_name, env.pool),
+                    arguments: PoolVec::empty(env.pool),
+                },
+                1,
+            )
+        }
+        _ => return Ok(InputOutcome::Ignored),
+    };
+
+    let new_patterns = PoolVec::new(std::iter::once(new_pattern), env.pool);
+    env.pool.get_mut(branch_node_id).patterns = new_patterns;
+
+    ed_model.simple_move_carets_right(caret_move);
+
+    Ok(InputOutcome::Accepted)
+}
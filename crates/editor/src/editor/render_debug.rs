@@ -26,6 +26,10 @@ pub fn build_debug_graphics(
             .with_color(colors::to_slice(from_hsb(0, 0, 100)))
             .with_scale(config.debug_font_size);
 
+    let history_text = glyph_brush::OwnedText::new(format!("{}\n", ed_model.history))
+        .with_color(colors::to_slice(from_hsb(50, 41, 100)))
+        .with_scale(config.debug_font_size);
+
     let grid_node_map_text = glyph_brush::OwnedText::new(format!("{}", ed_model.grid_node_map))
         .with_color(colors::to_slice(from_hsb(20, 41, 100)))
         .with_scale(config.debug_font_size);
@@ -66,6 +70,7 @@ pub fn build_debug_graphics(
     let section = gr_text::owned_section_from_glyph_texts(
         vec![
             carets_text,
+            history_text,
             grid_node_map_text,
             code_lines_text,
             mark_node_tree_text,
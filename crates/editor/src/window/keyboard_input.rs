@@ -52,6 +52,13 @@ pub fn from_winit(winit_mods: &winit::event::ModifiersState) -> Modifiers {
 pub mod test_modifiers {
     use crate::window::keyboard_input::Modifiers;
 
+    pub fn ctrl_cmd() -> Modifiers {
+        Modifiers {
+            shift: false,
+            ..ctrl_cmd_shift()
+        }
+    }
+
     pub fn ctrl_cmd_shift() -> Modifiers {
         #[cfg(target_os = "macos")]
         let mods = Modifiers {
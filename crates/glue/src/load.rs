@@ -2,7 +2,7 @@ use crate::rust_glue;
 use crate::types::{Env, Types};
 use bumpalo::Bump;
 use roc_intern::GlobalInterner;
-use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, OptLevel, Threading};
 use roc_reporting::report::RenderTarget;
 use roc_target::{Architecture, OperatingSystem, TargetInfo};
 use std::fs::File;
@@ -89,6 +89,8 @@ pub fn load_types(
             render: RenderTarget::Generic,
             threading,
             exec_mode: ExecutionMode::Check,
+            opt_level: OptLevel::Development,
+            warning_config: Default::default(),
         },
     )
     .unwrap_or_else(|problem| match problem {
@@ -0,0 +1,207 @@
+//! Lints for Roc code that compiles, but could be written more clearly.
+//!
+//! Every lint is a [Rule] in the registry returned by [all_rules]. The rules run over the
+//! canonical [Declarations] of a module, after type checking, so they can use the solved types.
+//! Their findings are turned into [Report]s just like compiler problems are.
+#![warn(clippy::dbg_macro)]
+// See github.com/roc-lang/roc/issues/800 for discussion of the large_enum_variant check.
+#![allow(clippy::large_enum_variant)]
+
+mod rules;
+
+use std::path::PathBuf;
+
+use roc_can::expr::{Declarations, Expr};
+use roc_can::traverse::{walk_expr, Visitor};
+use roc_collections::MutMap;
+use roc_load::LoadedModule;
+use roc_region::all::{LineInfo, Region};
+use roc_reporting::report::{Report, RocDocAllocator, Severity};
+use roc_types::subs::{Subs, Variable};
+use ven_pretty::DocAllocator;
+
+pub use rules::all_rules;
+
+/// How a lint that fires should be reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Level {
+    /// Don't report the lint at all
+    Allow,
+    /// Report the lint as a warning
+    Warn,
+    /// Report the lint as an error
+    Deny,
+}
+
+/// A single lint rule.
+///
+/// The rule is called for every expression in a module, and reports a [Finding] when the
+/// expression is something it lints against.
+pub trait Rule: Sync {
+    /// The name used to configure the rule, e.g. `roc lint --allow when-on-bool`
+    fn name(&self) -> &'static str;
+
+    /// The title of the report, e.g. `WHEN ON BOOL`
+    fn title(&self) -> &'static str;
+
+    /// A one-line description of what the rule lints against
+    fn description(&self) -> &'static str;
+
+    fn default_level(&self) -> Level {
+        Level::Warn
+    }
+
+    fn check_expr(
+        &self,
+        ctx: &LintContext,
+        expr: &Expr,
+        region: Region,
+        var: Variable,
+    ) -> Option<Finding>;
+}
+
+/// What the rules can look at besides the expression itself
+pub struct LintContext<'a> {
+    pub subs: &'a Subs,
+}
+
+/// What a rule found, and how to fix it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub region: Region,
+    pub message: &'static str,
+    pub hint: &'static str,
+}
+
+/// A finding of a rule that is not allowed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub rule: &'static str,
+    pub title: &'static str,
+    pub level: Level,
+    pub finding: Finding,
+}
+
+/// The level of every rule; rules that are not configured use their default level.
+#[derive(Debug, Clone, Default)]
+pub struct LintConfig {
+    levels: MutMap<&'static str, Level>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownRule(pub String);
+
+impl LintConfig {
+    pub fn set_level(&mut self, rule_name: &str, level: Level) -> Result<(), UnknownRule> {
+        match all_rules().iter().find(|rule| rule.name() == rule_name) {
+            Some(rule) => {
+                self.levels.insert(rule.name(), level);
+
+                Ok(())
+            }
+            None => Err(UnknownRule(rule_name.to_string())),
+        }
+    }
+
+    pub fn level(&self, rule: &dyn Rule) -> Level {
+        self.levels
+            .get(rule.name())
+            .copied()
+            .unwrap_or_else(|| rule.default_level())
+    }
+}
+
+struct LintVisitor<'a> {
+    ctx: LintContext<'a>,
+    rules: Vec<(&'static dyn Rule, Level)>,
+    lints: Vec<Lint>,
+}
+
+impl Visitor for LintVisitor<'_> {
+    fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+        for (rule, level) in self.rules.iter() {
+            if let Some(finding) = rule.check_expr(&self.ctx, expr, region, var) {
+                self.lints.push(Lint {
+                    rule: rule.name(),
+                    title: rule.title(),
+                    level: *level,
+                    finding,
+                });
+            }
+        }
+
+        walk_expr(self, expr, var);
+    }
+}
+
+/// Runs the rules that are not allowed over the declarations of a module, and returns their lints
+/// in source order.
+pub fn lint_declarations(decls: &Declarations, subs: &Subs, config: &LintConfig) -> Vec<Lint> {
+    let rules = all_rules()
+        .iter()
+        .map(|rule| (*rule, config.level(*rule)))
+        .filter(|(_, level)| *level != Level::Allow)
+        .collect();
+
+    let mut visitor = LintVisitor {
+        ctx: LintContext { subs },
+        rules,
+        lints: Vec::new(),
+    };
+
+    visitor.visit_decls(decls);
+
+    let mut lints = visitor.lints;
+    lints.sort_by_key(|lint| lint.finding.region.start());
+
+    lints
+}
+
+/// Lints the root module of a program that was loaded with `roc_load::load_and_typecheck`.
+pub fn lint_loaded_module(loaded: &LoadedModule, config: &LintConfig) -> Vec<Lint> {
+    match loaded.declarations_by_id.get(&loaded.module_id) {
+        Some(decls) => lint_declarations(decls, loaded.solved.inner(), config),
+        None => Vec::new(),
+    }
+}
+
+pub fn lint_report<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    lint: Lint,
+) -> Report<'b> {
+    let Lint {
+        rule,
+        title,
+        level,
+        finding,
+    } = lint;
+
+    let severity = match level {
+        Level::Deny => Severity::RuntimeError,
+        Level::Warn | Level::Allow => Severity::Warning,
+    };
+
+    let doc = alloc.stack([
+        alloc.reflow(finding.message),
+        alloc.region(lines.convert_region(finding.region)),
+        alloc.reflow(finding.hint),
+        alloc.concat([
+            alloc.reflow("This is reported by the "),
+            alloc.keyword(rule),
+            alloc.reflow(" lint, which can be turned off with "),
+            alloc.parser_suggestion("--allow"),
+            alloc.space(),
+            alloc.parser_suggestion(rule),
+            alloc.text("."),
+        ]),
+    ]);
+
+    Report {
+        title: title.to_string(),
+        filename,
+        doc,
+        severity,
+    }
+}
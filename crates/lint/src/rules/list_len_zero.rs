@@ -0,0 +1,86 @@
+use roc_can::expr::{Expr, IntValue};
+use roc_module::called_via::{BinOp, CalledVia};
+use roc_module::symbol::Symbol;
+use roc_region::all::Region;
+use roc_types::subs::Variable;
+
+use super::lookup_symbol;
+use crate::{Finding, LintContext, Rule};
+
+/// `List.len list == 0` computes a length just to check for emptiness.
+pub struct ListLenZero;
+
+impl Rule for ListLenZero {
+    fn name(&self) -> &'static str {
+        "list-len-zero"
+    }
+
+    fn title(&self) -> &'static str {
+        "LIST LENGTH COMPARED TO ZERO"
+    }
+
+    fn description(&self) -> &'static str {
+        "comparing `List.len` to zero instead of using `List.isEmpty`"
+    }
+
+    fn check_expr(
+        &self,
+        _ctx: &LintContext,
+        expr: &Expr,
+        region: Region,
+        _var: Variable,
+    ) -> Option<Finding> {
+        let (function, args, op) = match expr {
+            Expr::Call(
+                function,
+                args,
+                CalledVia::BinOp(op @ (BinOp::Equals | BinOp::NotEquals)),
+            ) => (&function.1.value, args, *op),
+            _ => return None,
+        };
+
+        if !matches!(
+            lookup_symbol(function),
+            Some(Symbol::BOOL_EQ | Symbol::BOOL_NEQ)
+        ) {
+            return None;
+        }
+
+        match args.as_slice() {
+            [(_, left), (_, right)]
+                if (is_list_len(&left.value) && is_zero(&right.value))
+                    || (is_zero(&left.value) && is_list_len(&right.value)) =>
+            {
+                let hint = match op {
+                    BinOp::Equals => "Use `List.isEmpty` to check whether a list is empty.",
+                    _ => "Use `!(List.isEmpty list)` to check whether a list has elements.",
+                };
+
+                Some(Finding {
+                    region,
+                    message: "This compares the length of a list to zero:",
+                    hint,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+fn is_list_len(expr: &Expr) -> bool {
+    match expr {
+        Expr::Call(function, _, _) => lookup_symbol(&function.1.value) == Some(Symbol::LIST_LEN),
+        _ => false,
+    }
+}
+
+fn is_zero(expr: &Expr) -> bool {
+    let value = match expr {
+        Expr::Num(_, _, value, _) | Expr::Int(_, _, _, value, _) => value,
+        _ => return false,
+    };
+
+    match value {
+        IntValue::I128(bytes) | IntValue::U128(bytes) => bytes.iter().all(|byte| *byte == 0),
+    }
+}
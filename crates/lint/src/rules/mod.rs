@@ -0,0 +1,28 @@
+mod list_len_zero;
+mod pipe_into_lambda;
+mod unnecessary_backpassing;
+mod when_on_bool;
+
+use crate::Rule;
+use roc_can::expr::Expr;
+use roc_module::symbol::Symbol;
+
+static RULES: &[&dyn Rule] = &[
+    &when_on_bool::WhenOnBool,
+    &list_len_zero::ListLenZero,
+    &unnecessary_backpassing::UnnecessaryBackpassing,
+    &pipe_into_lambda::PipeIntoLambda,
+];
+
+/// Every lint rule, in the order they are documented in `roc lint --help`
+pub fn all_rules() -> &'static [&'static dyn Rule] {
+    RULES
+}
+
+/// The symbol a function is looked up by, if the function is a plain lookup
+fn lookup_symbol(expr: &Expr) -> Option<Symbol> {
+    match expr {
+        Expr::Var(symbol) | Expr::AbilityMember(symbol, _, _) => Some(*symbol),
+        _ => None,
+    }
+}
@@ -0,0 +1,44 @@
+use roc_can::expr::Expr;
+use roc_module::called_via::{BinOp, CalledVia};
+use roc_region::all::Region;
+use roc_types::subs::Variable;
+
+use crate::{Finding, LintContext, Rule};
+
+/// `x |> (\y -> ...)` is a roundabout way to give `x` a name.
+pub struct PipeIntoLambda;
+
+impl Rule for PipeIntoLambda {
+    fn name(&self) -> &'static str {
+        "pipe-into-lambda"
+    }
+
+    fn title(&self) -> &'static str {
+        "PIPE INTO LAMBDA"
+    }
+
+    fn description(&self) -> &'static str {
+        "piping a value into an anonymous function"
+    }
+
+    fn check_expr(
+        &self,
+        _ctx: &LintContext,
+        expr: &Expr,
+        region: Region,
+        _var: Variable,
+    ) -> Option<Finding> {
+        match expr {
+            Expr::Call(function, _, CalledVia::BinOp(BinOp::Pizza))
+                if matches!(function.1.value, Expr::Closure(_)) =>
+            {
+                Some(Finding {
+                    region,
+                    message: "This pipes a value into an anonymous function:",
+                    hint: "Give the value a name with a def instead, or define the function separately and call it.",
+                })
+            }
+            _ => None,
+        }
+    }
+}
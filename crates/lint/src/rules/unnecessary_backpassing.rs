@@ -0,0 +1,89 @@
+use roc_can::expr::{ClosureData, Expr};
+use roc_can::pattern::Pattern;
+use roc_region::all::Region;
+use roc_types::subs::Variable;
+
+use super::lookup_symbol;
+use crate::{Finding, LintContext, Rule};
+
+/// `x <- Task.await task` followed by just `Stdout.line x` is `Task.await task Stdout.line`.
+pub struct UnnecessaryBackpassing;
+
+impl Rule for UnnecessaryBackpassing {
+    fn name(&self) -> &'static str {
+        "unnecessary-backpassing"
+    }
+
+    fn title(&self) -> &'static str {
+        "UNNECESSARY BACKPASSING"
+    }
+
+    fn description(&self) -> &'static str {
+        "backpassing into a function that only passes its arguments on to another function"
+    }
+
+    fn check_expr(
+        &self,
+        _ctx: &LintContext,
+        expr: &Expr,
+        region: Region,
+        _var: Variable,
+    ) -> Option<Finding> {
+        let args = match expr {
+            Expr::Call(_, args, _) => args,
+            _ => return None,
+        };
+
+        // backpassing desugars to a call whose last argument is a closure that spans the whole
+        // call, because the closure is made from everything after the `<-`
+        let closure = match args.last() {
+            Some((_, loc_closure)) if loc_closure.region == region => match &loc_closure.value {
+                Expr::Closure(closure) => closure,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        if only_forwards_arguments(closure) {
+            Some(Finding {
+                region,
+                message: "This backpassing only passes its arguments on to another function:",
+                hint: "Pass that function as the last argument of the call instead.",
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether the closure is `\a, b -> f a b` for some function `f` that is not one of the arguments
+fn only_forwards_arguments(closure: &ClosureData) -> bool {
+    let (function, call_args) = match &closure.loc_body.value {
+        Expr::Call(call, call_args, _) => (&call.1.value, call_args),
+        _ => return false,
+    };
+
+    let params: Vec<_> = closure
+        .arguments
+        .iter()
+        .map(|(_, _, loc_pattern)| match loc_pattern.value {
+            Pattern::Identifier(symbol) => Some(symbol),
+            _ => None,
+        })
+        .collect();
+
+    let function = match lookup_symbol(function) {
+        Some(symbol) => symbol,
+        None => return false,
+    };
+
+    params.len() == call_args.len()
+        && !params.contains(&Some(function))
+        && params
+            .iter()
+            .zip(call_args.iter())
+            .all(|(param, (_, arg))| match (param, &arg.value) {
+                (Some(param), Expr::Var(symbol)) => param == symbol,
+                _ => false,
+            })
+}
@@ -0,0 +1,48 @@
+use roc_can::expr::Expr;
+use roc_module::symbol::Symbol;
+use roc_region::all::Region;
+use roc_types::subs::{Content, Variable};
+
+use crate::{Finding, LintContext, Rule};
+
+/// `when` on a `Bool` can only branch with guards or catch-alls; `if` says the same thing
+/// more directly.
+pub struct WhenOnBool;
+
+impl Rule for WhenOnBool {
+    fn name(&self) -> &'static str {
+        "when-on-bool"
+    }
+
+    fn title(&self) -> &'static str {
+        "WHEN ON BOOL"
+    }
+
+    fn description(&self) -> &'static str {
+        "a `when` on a `Bool`, which is clearer as an `if`"
+    }
+
+    fn check_expr(
+        &self,
+        ctx: &LintContext,
+        expr: &Expr,
+        _region: Region,
+        _var: Variable,
+    ) -> Option<Finding> {
+        let (cond_var, loc_cond) = match expr {
+            Expr::When {
+                cond_var, loc_cond, ..
+            } => (*cond_var, loc_cond),
+            _ => return None,
+        };
+
+        match ctx.subs.get_content_without_compacting(cond_var) {
+            Content::Alias(Symbol::BOOL_BOOL, ..) => Some(Finding {
+                region: loc_cond.region,
+                message: "This `when` branches on a `Bool`:",
+                hint: "An `if` expression is the idiomatic way to branch on a `Bool`.",
+            }),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,203 @@
+#[macro_use]
+extern crate pretty_assertions;
+
+#[cfg(test)]
+mod test_lint {
+    use bumpalo::Bump;
+    use roc_lint::{all_rules, lint_loaded_module, Level, LintConfig, UnknownRule};
+
+    /// Lints `src` (the body of an interface module), and returns the rules that fired together
+    /// with the source text they fired on.
+    fn lints_with(src: &str, config: &LintConfig) -> Vec<(&'static str, Level, String)> {
+        let arena = Bump::new();
+
+        let module_src = format!("interface Test exposes [main] imports []\n\n{}", src.trim());
+
+        let dir = tempfile::tempdir().unwrap();
+        let filename = dir.path().join("Test.roc");
+
+        let loaded = roc_load::load_and_typecheck_str(
+            &arena,
+            filename,
+            arena.alloc_str(&module_src),
+            dir.path().to_path_buf(),
+            Default::default(),
+            roc_target::TargetInfo::default_x86_64(),
+            roc_reporting::report::RenderTarget::Generic,
+        )
+        .expect("failed to load module");
+
+        let type_problems = &loaded.type_problems[&loaded.module_id];
+        assert!(type_problems.is_empty(), "{:?}", type_problems);
+
+        lint_loaded_module(&loaded, config)
+            .into_iter()
+            .map(|lint| {
+                let region = lint.finding.region;
+                let start = region.start().offset as usize;
+                let end = region.end().offset as usize;
+
+                (lint.rule, lint.level, module_src[start..end].to_string())
+            })
+            .collect()
+    }
+
+    fn lints(src: &str) -> Vec<(&'static str, Level, String)> {
+        lints_with(src, &LintConfig::default())
+    }
+
+    #[test]
+    fn when_on_bool() {
+        assert_eq!(
+            lints(
+                r#"
+                main = \n ->
+                    when n > 10 is
+                        _ -> "big"
+                "#
+            ),
+            [("when-on-bool", Level::Warn, "n > 10".to_string())]
+        );
+    }
+
+    #[test]
+    fn when_on_tag_is_fine() {
+        assert_eq!(
+            lints(
+                r#"
+                main = \result ->
+                    when result is
+                        Ok n -> n
+                        Err _ -> 0
+                "#
+            ),
+            []
+        );
+    }
+
+    #[test]
+    fn list_len_zero() {
+        assert_eq!(
+            lints(
+                r#"
+                main = \list, other ->
+                    List.len list == 0 || 0 != List.len other
+                "#
+            ),
+            [
+                (
+                    "list-len-zero",
+                    Level::Warn,
+                    "List.len list == 0".to_string()
+                ),
+                (
+                    "list-len-zero",
+                    Level::Warn,
+                    "0 != List.len other".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn list_len_nonzero_is_fine() {
+        assert_eq!(
+            lints(
+                r#"
+                main = \list ->
+                    List.len list == 1
+                "#
+            ),
+            []
+        );
+    }
+
+    #[test]
+    fn unnecessary_backpassing() {
+        assert_eq!(
+            lints(
+                r#"
+                main = \list ->
+                    x <- List.map list
+                    Num.toStr x
+                "#
+            ),
+            [(
+                "unnecessary-backpassing",
+                Level::Warn,
+                "x <- List.map list\n                    Num.toStr x".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn necessary_backpassing() {
+        assert_eq!(
+            lints(
+                r#"
+                main = \list ->
+                    x <- List.map list
+                    Num.toStr (x + 1)
+                "#
+            ),
+            []
+        );
+    }
+
+    #[test]
+    fn pipe_into_lambda() {
+        assert_eq!(
+            lints(
+                r#"
+                main = \n ->
+                    n |> \x -> x + 1
+                "#
+            ),
+            [(
+                "pipe-into-lambda",
+                Level::Warn,
+                "n |> \\x -> x + 1".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn configured_levels() {
+        let mut config = LintConfig::default();
+        config.set_level("when-on-bool", Level::Deny).unwrap();
+        config.set_level("list-len-zero", Level::Allow).unwrap();
+
+        assert_eq!(
+            lints_with(
+                r#"
+                main = \list ->
+                    when List.len list == 0 is
+                        _ -> "empty"
+                "#,
+                &config
+            ),
+            [(
+                "when-on-bool",
+                Level::Deny,
+                "List.len list == 0".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn unknown_rule() {
+        assert_eq!(
+            LintConfig::default().set_level("no-such-rule", Level::Deny),
+            Err(UnknownRule("no-such-rule".to_string()))
+        );
+    }
+
+    #[test]
+    fn rule_names_are_unique() {
+        let mut names: Vec<_> = all_rules().iter().map(|rule| rule.name()).collect();
+        names.sort_unstable();
+        names.dedup();
+
+        assert_eq!(names.len(), all_rules().len());
+    }
+}
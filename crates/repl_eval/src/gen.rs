@@ -1,5 +1,5 @@
 use bumpalo::Bump;
-use roc_load::{ExecutionMode, LoadConfig, Threading};
+use roc_load::{ExecutionMode, LoadConfig, OptLevel, Threading};
 use roc_reporting::report::Palette;
 use std::path::PathBuf;
 
@@ -65,6 +65,8 @@ pub fn compile_to_mono<'a>(
             render: roc_reporting::report::RenderTarget::ColorTerminal,
             threading: Threading::Single,
             exec_mode: ExecutionMode::Executable,
+            opt_level: OptLevel::Development,
+            warning_config: Default::default(),
         },
     );
 
@@ -81,6 +83,7 @@ pub fn compile_to_mono<'a>(
     let MonomorphizedModule {
         interns,
         sources,
+        syntax_problems,
         can_problems,
         type_problems,
         ..
@@ -89,6 +92,14 @@ pub fn compile_to_mono<'a>(
     let mut lines = Vec::new();
 
     for (home, (module_path, src)) in sources.iter() {
+        let syntax_probs = syntax_problems.remove(home).unwrap_or_default();
+
+        if !syntax_probs.is_empty() {
+            // The input is a single def, so once it fails to parse every other problem is noise.
+            lines.extend(syntax_probs);
+            continue;
+        }
+
         let can_probs = can_problems.remove(home).unwrap_or_default();
         let type_probs = type_problems.remove(home).unwrap_or_default();
 
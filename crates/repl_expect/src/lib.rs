@@ -100,6 +100,14 @@ mod test {
     use super::*;
 
     fn run_expect_test(source: &str, expected: &str) {
+        run_expect_test_help(source, expected, ExecutionMode::Test)
+    }
+
+    fn run_doc_test(source: &str, expected: &str) {
+        run_expect_test_help(source, expected, ExecutionMode::DocTest)
+    }
+
+    fn run_expect_test_help(source: &str, expected: &str, exec_mode: ExecutionMode) {
         let arena = bumpalo::Bump::new();
         let arena = &arena;
 
@@ -119,7 +127,9 @@ mod test {
             target_info,
             render: RenderTarget::ColorTerminal,
             threading: Threading::Single,
-            exec_mode: ExecutionMode::Test,
+            exec_mode,
+            opt_level,
+            warning_config: Default::default(),
         };
         let loaded = roc_load::load_and_monomorphize_from_str(
             arena,
@@ -934,4 +944,58 @@ mod test {
             ),
         );
     }
+
+    #[test]
+    fn doc_test_pass() {
+        run_doc_test(
+            indoc!(
+                r#"
+                interface A exposes [double] imports []
+
+                ## Doubles a number.
+                ##
+                ## ```roc
+                ## two = double 1
+                ## expect two == 2
+                ## expect double two == 4
+                ## ```
+                double : Num a -> Num a
+                double = \n -> n * 2
+                "#
+            ),
+            "",
+        );
+    }
+
+    #[test]
+    fn doc_test_fail() {
+        run_doc_test(
+            indoc!(
+                r#"
+                interface A exposes [double] imports []
+
+                ## ```roc
+                ## x = 1 + 1
+                ## expect x == 3
+                ## ```
+                double : Num a -> Num a
+                double = \n -> n * 2
+                "#
+            ),
+            indoc!(
+                r#"
+                This expectation failed:
+
+                3│>  ## ```roc
+                4│>  ## x = 1 + 1
+                5│>  ## expect x == 3
+
+                When it failed, these variables had these values:
+
+                x : Num a
+                x = 2
+                "#
+            ),
+        );
+    }
 }
@@ -1453,6 +1453,7 @@ fn pretty_runtime_error<'b>(
                 QualifiedIdentifier => " qualified ",
                 EmptySingleQuote => " empty character literal ",
                 MultipleCharsInSingleQuote => " overfull literal ",
+                FloatRangeEnd | EmptyRange => " range ",
             };
 
             let tip = match problem {
@@ -1465,6 +1466,14 @@ fn pretty_runtime_error<'b>(
                 QualifiedIdentifier => alloc
                     .tip()
                     .append(alloc.reflow("In patterns, only tags can be qualified")),
+                FloatRangeEnd => alloc
+                    .tip()
+                    .append(alloc.reflow(
+                        "Ranges can only be made of integers or characters, not fractions",
+                    )),
+                EmptyRange => alloc.tip().append(alloc.reflow(
+                    "This range contains no values. The start of a range must come before its end",
+                )),
             };
 
             doc = alloc.stack([
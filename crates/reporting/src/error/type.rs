@@ -255,6 +255,41 @@ pub fn type_problem<'b>(
                 severity: Severity::RuntimeError,
             })
         }
+        TypedHole { region, typ, fits } => {
+            let is_unconstrained = matches!(typ, ErrorType::FlexVar(_));
+            let typ = error_type_to_doc(alloc, typ);
+
+            let suggestions = if is_unconstrained {
+                alloc.reflow("Nothing here says what its type should be yet, so any value fits.")
+            } else if fits.is_empty() {
+                alloc.reflow("None of the values in scope have that type.")
+            } else {
+                let fits = fits
+                    .into_iter()
+                    .map(|symbol| alloc.symbol_unqualified(symbol));
+
+                alloc.stack([
+                    alloc.reflow("These values in scope have that type:"),
+                    alloc.vcat(fits).indent(4),
+                ])
+            };
+
+            let stack = [
+                alloc.reflow("This hole still needs to be filled in:"),
+                alloc.region(lines.convert_region(region)),
+                alloc.reflow("It needs a value of type:"),
+                alloc.type_block(typ),
+                suggestions,
+                alloc.reflow("If this code is reached, the program will crash."),
+            ];
+
+            Some(Report {
+                title: "TYPED HOLE".to_string(),
+                filename,
+                doc: alloc.stack(stack),
+                severity: Severity::RuntimeError,
+            })
+        }
     }
 }
 
@@ -275,6 +310,38 @@ fn report_unfulfilled_ability<'a>(
 
             alloc.stack(stack)
         }
+        Unfulfilled::OpaqueMissingSuperability {
+            typ,
+            ability,
+            superability,
+        } => {
+            let stack = vec![
+                alloc.concat([
+                    alloc.reflow("The type "),
+                    alloc.symbol_unqualified(typ),
+                    alloc.reflow(" has the "),
+                    alloc.symbol_unqualified(ability),
+                    alloc.reflow(" ability, but not "),
+                    alloc.symbol_unqualified(superability),
+                    alloc.reflow(". Every type that has "),
+                    alloc.symbol_unqualified(ability),
+                    alloc.reflow(" must also have "),
+                    alloc.symbol_unqualified(superability),
+                    alloc.reflow("."),
+                ]),
+                alloc.tip().append(alloc.concat([
+                    alloc.reflow("Add "),
+                    alloc.symbol_unqualified(superability),
+                    alloc.reflow(" to the "),
+                    alloc.keyword("has"),
+                    alloc.reflow(" clause of "),
+                    alloc.symbol_unqualified(typ),
+                    alloc.reflow("."),
+                ])),
+            ];
+
+            alloc.stack(stack)
+        }
         Unfulfilled::AdhocUnderivable {
             typ,
             ability,
@@ -1355,6 +1422,98 @@ fn to_expr_report<'b>(
                 )
             }
 
+            Reason::InvalidAbilityMemberDefault {
+                member_name,
+                def_region: _,
+                unimplemented_abilities,
+            } => {
+                let problem = alloc.concat([
+                    alloc.reflow("Something is off with the default implementation of "),
+                    alloc.symbol_unqualified(member_name),
+                    alloc.reflow(":"),
+                ]);
+                let this_is = alloc.reflow("This value is");
+                let instead_of = alloc.concat([
+                    alloc.reflow("But the type annotation on "),
+                    alloc.symbol_unqualified(member_name),
+                    alloc.reflow(" says it must match:"),
+                ]);
+
+                let hint = if unimplemented_abilities.is_empty() {
+                    None
+                } else {
+                    let mut stack = Vec::with_capacity(unimplemented_abilities.len());
+                    for (err_type, ability) in unimplemented_abilities.into_iter() {
+                        stack.push(does_not_implement(alloc, err_type, ability));
+                    }
+
+                    let hint = alloc.stack([
+                        alloc.concat([
+                            alloc.note(""),
+                            alloc.reflow("Some types in this default implementation don't implement the abilities they are expected to. I found the following missing implementations:"),
+                        ]),
+                        alloc.type_block(alloc.stack(stack)),
+                    ]);
+
+                    Some(hint)
+                };
+
+                report_mismatch(
+                    alloc,
+                    lines,
+                    filename,
+                    &category,
+                    found,
+                    expected_type,
+                    region,
+                    Some(expr_region),
+                    problem,
+                    this_is,
+                    instead_of,
+                    hint,
+                )
+            }
+
+            Reason::SpecializedAbilityMemberDefault {
+                member_name,
+                def_region: _,
+            } => {
+                let problem = alloc.concat([
+                    alloc.reflow("This default implementation of "),
+                    alloc.symbol_unqualified(member_name),
+                    alloc.reflow(" only works for one type:"),
+                ]);
+                let this_is = alloc.reflow("This value is");
+                let instead_of = alloc.concat([
+                    alloc.reflow("But the type annotation on "),
+                    alloc.symbol_unqualified(member_name),
+                    alloc.reflow(" says it must match:"),
+                ]);
+
+                let note = alloc.stack([
+                    alloc.concat([
+                        alloc.note(""),
+                        alloc.reflow("A default implementation is used by every type that doesn't implement the member itself, so it must work for all of them."),
+                    ]),
+                    alloc.reflow("If this implementation is only meant for one type, make it that type's own implementation instead."),
+                ]);
+
+                report_mismatch(
+                    alloc,
+                    lines,
+                    filename,
+                    &category,
+                    found,
+                    expected_type,
+                    region,
+                    Some(expr_region),
+                    problem,
+                    this_is,
+                    instead_of,
+                    Some(note),
+                )
+            }
+
             Reason::WhenBranches => {
                 let snippet = alloc.region_with_subregion(
                     lines.convert_region(region),
@@ -1754,6 +1913,10 @@ fn format_category<'b>(
             alloc.concat([this_is, alloc.text(" a declared specialization")]),
             alloc.text(" of type:"),
         ),
+        AbilityMemberDefault(_ability_member) => (
+            alloc.concat([this_is, alloc.text(" a default implementation")]),
+            alloc.text(" of type:"),
+        ),
         Expect => (
             alloc.concat([this_is, alloc.text(" an expectation")]),
             alloc.text(" of type:"),
@@ -2403,6 +2566,20 @@ fn type_with_able_vars<'b>(
     let mut doc = Vec::with_capacity(1 + 6 * able.len());
     doc.push(typ);
 
+    // A variable that appears more than once, like in `a, a -> Bool`, is only bound once.
+    let mut seen = Vec::with_capacity(able.len());
+    let able = able
+        .into_iter()
+        .filter(|var_and_ability| {
+            if seen.contains(var_and_ability) {
+                false
+            } else {
+                seen.push(var_and_ability.clone());
+                true
+            }
+        })
+        .collect::<Vec<_>>();
+
     for (i, (var, ability)) in able.into_iter().enumerate() {
         doc.push(alloc.string(if i == 0 { " | " } else { ", " }.to_string()));
         doc.push(alloc.type_variable(var));
@@ -3983,10 +4160,21 @@ fn pattern_to_doc_help<'b>(
             Bit(true) => alloc.text("Bool.true"),
             Bit(false) => alloc.text("Bool.false"),
             Byte(b) => alloc.text(b.to_string()),
+            Char(c) => alloc.text(format!("'{}'", c.escape_default())),
             Float(f) => alloc.text(f.to_string()),
             Decimal(d) => alloc.text(RocDec::from_ne_bytes(d).to_string()),
             Str(s) => alloc.string(s.into()),
         },
+        IntRange(range) => {
+            let lo = int_bound_to_doc(alloc, range.domain.render_as, range.lo());
+
+            if range.is_singleton() {
+                lo
+            } else {
+                let hi = int_bound_to_doc(alloc, range.domain.render_as, range.hi());
+                alloc.concat([lo, alloc.text("..="), hi])
+            }
+        }
         Ctor(union, tag_id, args) => {
             match union.render_as {
                 RenderAs::Guard => {
@@ -4012,7 +4200,7 @@ fn pattern_to_doc_help<'b>(
                             Anything => {
                                 arg_docs.push(alloc.text(label.to_string()));
                             }
-                            Literal(_) | Ctor(_, _, _) => {
+                            Literal(_) | Ctor(_, _, _) | IntRange(_) => {
                                 arg_docs.push(
                                     alloc
                                         .text(label.to_string())
@@ -4072,3 +4260,22 @@ fn pattern_to_doc_help<'b>(
         }
     }
 }
+
+fn int_bound_to_doc<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    render_as: roc_exhaustive::IntRender,
+    bound: roc_exhaustive::IntBound,
+) -> RocDocBuilder<'b> {
+    use roc_exhaustive::{IntBound, IntRender};
+
+    let as_char = match (render_as, bound) {
+        (IntRender::Char, IntBound::Unsigned(n)) => u32::try_from(n).ok().and_then(char::from_u32),
+        _ => None,
+    };
+
+    match (as_char, bound) {
+        (Some(c), _) => alloc.text(format!("'{}'", c.escape_default())),
+        (None, IntBound::Signed(n)) => alloc.text(n.to_string()),
+        (None, IntBound::Unsigned(n)) => alloc.text(n.to_string()),
+    }
+}
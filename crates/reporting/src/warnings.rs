@@ -0,0 +1,356 @@
+//! Controls over which warnings are reported.
+//!
+//! Every warning has a [`WarningKind`], e.g. an `UNUSED DEFINITION` report is of kind
+//! `unused-definition`. A kind can be allowed for a whole program with `--allow <kind>`, or in
+//! the source with a comment:
+//!
+//! ```roc
+//! # roc-allow: unused-definition
+//! helper = \x -> x
+//! ```
+//!
+//! A `# roc-allow:` comment allows the kinds on the next line of code. A `# roc-allow-module:`
+//! comment after the module header allows them in the whole module, which is useful for
+//! generated code.
+use roc_collections::all::{MutMap, MutSet};
+use roc_parse::ast::{
+    AssignedField, Collection, CommentOrNewline, Defs, Expr, Pattern, StrLiteral, StrSegment,
+    ValueDef,
+};
+use roc_region::all::{LineInfo, Loc};
+
+pub use roc_problem::warning::WarningKind;
+
+const ALLOW_PREFIX: &str = "roc-allow:";
+const ALLOW_MODULE_PREFIX: &str = "roc-allow-module:";
+
+/// Which warnings are reported, and whether they count as errors
+#[derive(Debug, Clone, Default)]
+pub struct WarningConfig {
+    /// Report warnings as errors, so they fail the build
+    pub deny_warnings: bool,
+    /// The kinds of warnings that are not reported
+    pub allowed: MutSet<WarningKind>,
+}
+
+impl WarningConfig {
+    pub fn allows(&self, kind: WarningKind) -> bool {
+        self.allowed.contains(&kind)
+    }
+
+    /// Whether a problem is not reported, because it is a warning whose kind was allowed with
+    /// `--allow`, or by a `# roc-allow:` comment for the 0-based line the problem starts on
+    pub fn allows_problem(
+        &self,
+        kind: Option<WarningKind>,
+        suppressions: Option<&Suppressions>,
+        line: Option<u32>,
+    ) -> bool {
+        match kind {
+            Some(kind) => {
+                self.allows(kind)
+                    || suppressions
+                        .map_or(false, |suppressions| suppressions.allows(kind.name(), line))
+            }
+            None => false,
+        }
+    }
+}
+
+/// The warnings that are allowed by `# roc-allow:` comments in the source of a module
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Suppressions {
+    module: Vec<String>,
+    /// The kinds that are allowed on a line, by 0-based line number
+    lines: MutMap<u32, Vec<String>>,
+}
+
+impl Suppressions {
+    /// Find the `# roc-allow:` comments among the comments of the parsed definitions of a module.
+    /// The definitions must have been parsed from `src`.
+    pub fn from_defs<'a>(src: &'a str, defs: &Defs<'a>) -> Self {
+        let mut comments = Vec::new();
+        defs_comments(defs, &mut comments);
+
+        // the lines that start with a comment, so a `#` in a multiline string is not mistaken for one
+        let line_info = LineInfo::new(src);
+        let src_range = src.as_ptr() as usize..src.as_ptr() as usize + src.len();
+        let comment_lines: MutSet<u32> = comments
+            .into_iter()
+            .filter(|comment| src_range.contains(&(comment.as_ptr() as usize)))
+            .map(|comment| {
+                let offset = comment.as_ptr() as usize - src_range.start;
+
+                line_info.convert_offset(offset as u32).line
+            })
+            .collect();
+
+        if comment_lines.is_empty() {
+            return Suppressions::default();
+        }
+
+        let mut suppressions = Suppressions::default();
+        let mut pending: Vec<String> = Vec::new();
+
+        for (line_number, line) in src.lines().enumerate() {
+            let line_number = line_number as u32;
+            let line = line.trim_start();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let comment = match line.strip_prefix('#') {
+                Some(comment) if comment_lines.contains(&line_number) => comment.trim_start(),
+                _ => {
+                    // the first line of code after `# roc-allow:` comments is the one they allow
+                    if !pending.is_empty() {
+                        suppressions
+                            .lines
+                            .insert(line_number, std::mem::take(&mut pending));
+                    }
+
+                    continue;
+                }
+            };
+
+            if let Some(kinds) = comment.strip_prefix(ALLOW_MODULE_PREFIX) {
+                suppressions.module.extend(parse_kinds(kinds));
+            } else if let Some(kinds) = comment.strip_prefix(ALLOW_PREFIX) {
+                pending.extend(parse_kinds(kinds));
+            }
+        }
+
+        suppressions
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.module.is_empty() && self.lines.is_empty()
+    }
+
+    /// Whether a warning of this kind, starting on the given 0-based line, is allowed
+    pub fn allows(&self, kind: &str, line: Option<u32>) -> bool {
+        let allowed = |kinds: &Vec<String>| kinds.iter().any(|allowed| allowed == kind);
+
+        allowed(&self.module)
+            || line
+                .and_then(|line| self.lines.get(&line))
+                .map_or(false, allowed)
+    }
+}
+
+fn parse_kinds(kinds: &str) -> impl Iterator<Item = String> + '_ {
+    kinds
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|kind| !kind.is_empty())
+        .map(|kind| kind.to_string())
+}
+
+fn push_comments<'a>(spaces: &[CommentOrNewline<'a>], comments: &mut Vec<&'a str>) {
+    for space in spaces {
+        match space {
+            CommentOrNewline::LineComment(comment) | CommentOrNewline::DocComment(comment) => {
+                comments.push(comment)
+            }
+            CommentOrNewline::Newline => {}
+        }
+    }
+}
+
+fn defs_comments<'a>(defs: &Defs<'a>, comments: &mut Vec<&'a str>) {
+    push_comments(&defs.spaces, comments);
+
+    for value_def in defs.value_defs.iter() {
+        match value_def {
+            ValueDef::Annotation(..) | ValueDef::Malformed(_) => {}
+            ValueDef::Body(pattern, body) => {
+                pattern_comments(&pattern.value, comments);
+                expr_comments(&body.value, comments);
+            }
+            ValueDef::AnnotatedBody {
+                comment,
+                body_pattern,
+                body_expr,
+                ..
+            } => {
+                comments.extend(comment);
+                pattern_comments(&body_pattern.value, comments);
+                expr_comments(&body_expr.value, comments);
+            }
+            ValueDef::Expect { condition, .. } | ValueDef::ExpectFx { condition, .. } => {
+                expr_comments(&condition.value, comments);
+            }
+        }
+    }
+}
+
+fn collection_comments<'a, T>(
+    collection: &Collection<'a, T>,
+    comments: &mut Vec<&'a str>,
+    mut item_comments: impl FnMut(&T, &mut Vec<&'a str>),
+) {
+    for item in collection.items {
+        item_comments(item, comments);
+    }
+
+    push_comments(collection.final_comments(), comments);
+}
+
+fn field_comments<'a>(field: &AssignedField<'a, Expr<'a>>, comments: &mut Vec<&'a str>) {
+    match field {
+        AssignedField::RequiredValue(_, spaces, value)
+        | AssignedField::OptionalValue(_, spaces, value) => {
+            push_comments(spaces, comments);
+            expr_comments(&value.value, comments);
+        }
+        AssignedField::SpaceBefore(field, spaces) | AssignedField::SpaceAfter(field, spaces) => {
+            push_comments(spaces, comments);
+            field_comments(field, comments);
+        }
+        AssignedField::LabelOnly(_) | AssignedField::Malformed(_) => {}
+    }
+}
+
+fn str_comments<'a>(literal: &StrLiteral<'a>, comments: &mut Vec<&'a str>) {
+    let segments = match literal {
+        StrLiteral::PlainLine(_) => return,
+        StrLiteral::Line(segments) => std::slice::from_ref(segments),
+        StrLiteral::Block(lines) => lines,
+    };
+
+    for segment in segments.iter().flat_map(|segments| segments.iter()) {
+        if let StrSegment::Interpolated(expr) = segment {
+            expr_comments(expr.value, comments);
+        }
+    }
+}
+
+fn expr_comments<'a>(expr: &Expr<'a>, comments: &mut Vec<&'a str>) {
+    match expr {
+        Expr::SpaceBefore(expr, spaces) | Expr::SpaceAfter(expr, spaces) => {
+            push_comments(spaces, comments);
+            expr_comments(expr, comments);
+        }
+        Expr::Str(literal) => str_comments(literal, comments),
+        Expr::Access(expr, _) | Expr::ParensAround(expr) => expr_comments(expr, comments),
+        Expr::List(items) => {
+            collection_comments(items, comments, |item, comments| {
+                expr_comments(&item.value, comments)
+            });
+        }
+        Expr::RecordUpdate { update, fields } => {
+            expr_comments(&update.value, comments);
+            collection_comments(fields, comments, |field, comments| {
+                field_comments(&field.value, comments)
+            });
+        }
+        Expr::Record(fields) => {
+            collection_comments(fields, comments, |field, comments| {
+                field_comments(&field.value, comments)
+            });
+        }
+        Expr::Closure(patterns, body) => {
+            for pattern in patterns.iter() {
+                pattern_comments(&pattern.value, comments);
+            }
+            expr_comments(&body.value, comments);
+        }
+        Expr::Defs(defs, final_expr) => {
+            defs_comments(defs, comments);
+            expr_comments(&final_expr.value, comments);
+        }
+        Expr::Backpassing(patterns, call, continuation) => {
+            for pattern in patterns.iter() {
+                pattern_comments(&pattern.value, comments);
+            }
+            expr_comments(&call.value, comments);
+            expr_comments(&continuation.value, comments);
+        }
+        Expr::Expect(condition, continuation) => {
+            expr_comments(&condition.value, comments);
+            expr_comments(&continuation.value, comments);
+        }
+        Expr::Apply(function, arguments, _) => {
+            expr_comments(&function.value, comments);
+            for argument in arguments.iter() {
+                expr_comments(&argument.value, comments);
+            }
+        }
+        Expr::BinOps(operands, last) => {
+            for (operand, _) in operands.iter() {
+                expr_comments(&operand.value, comments);
+            }
+            expr_comments(&last.value, comments);
+        }
+        Expr::UnaryOp(expr, _) => expr_comments(&expr.value, comments),
+        Expr::If(branches, final_else) => {
+            for (condition, then) in branches.iter() {
+                expr_comments(&condition.value, comments);
+                expr_comments(&then.value, comments);
+            }
+            expr_comments(&final_else.value, comments);
+        }
+        Expr::When(condition, branches) => {
+            expr_comments(&condition.value, comments);
+            for branch in branches.iter() {
+                for pattern in branch.patterns.iter() {
+                    pattern_comments(&pattern.value, comments);
+                }
+                if let Some(Loc { value: guard, .. }) = &branch.guard {
+                    expr_comments(guard, comments);
+                }
+                expr_comments(&branch.value.value, comments);
+            }
+        }
+        Expr::Float(_)
+        | Expr::Num(_)
+        | Expr::NonBase10Int { .. }
+        | Expr::AccessorFunction(_)
+        | Expr::SingleQuote(_)
+        | Expr::Var { .. }
+        | Expr::Underscore(_)
+        | Expr::Tag(_)
+        | Expr::OpaqueRef(_)
+        | Expr::MalformedIdent(..)
+        | Expr::MalformedClosure
+        | Expr::PrecedenceConflict(_) => {}
+    }
+}
+
+fn pattern_comments<'a>(pattern: &Pattern<'a>, comments: &mut Vec<&'a str>) {
+    match pattern {
+        Pattern::SpaceBefore(pattern, spaces) | Pattern::SpaceAfter(pattern, spaces) => {
+            push_comments(spaces, comments);
+            pattern_comments(pattern, comments);
+        }
+        Pattern::Apply(tag, arguments) => {
+            pattern_comments(&tag.value, comments);
+            for argument in arguments.iter() {
+                pattern_comments(&argument.value, comments);
+            }
+        }
+        Pattern::RecordDestructure(fields) => {
+            collection_comments(fields, comments, |field, comments| {
+                pattern_comments(&field.value, comments)
+            });
+        }
+        Pattern::RequiredField(_, pattern) => pattern_comments(&pattern.value, comments),
+        Pattern::OptionalField(_, default) => expr_comments(&default.value, comments),
+        Pattern::Range(start, _, end) => {
+            pattern_comments(&start.value, comments);
+            pattern_comments(&end.value, comments);
+        }
+        Pattern::Identifier(_)
+        | Pattern::Tag(_)
+        | Pattern::OpaqueRef(_)
+        | Pattern::NumLiteral(_)
+        | Pattern::NonBase10Literal { .. }
+        | Pattern::FloatLiteral(_)
+        | Pattern::StrLiteral(_)
+        | Pattern::Underscore(_)
+        | Pattern::SingleQuote(_)
+        | Pattern::Malformed(_)
+        | Pattern::MalformedIdent(..)
+        | Pattern::QualifiedIdentifier { .. } => {}
+    }
+}
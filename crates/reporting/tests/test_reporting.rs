@@ -13,7 +13,9 @@ mod test_reporting {
     use indoc::indoc;
     use roc_can::abilities::AbilitiesStore;
     use roc_can::expr::PendingDerives;
-    use roc_load::{self, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+    use roc_load::{
+        self, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, OptLevel, Threading,
+    };
     use roc_module::symbol::{Interns, ModuleId};
     use roc_region::all::LineInfo;
     use roc_reporting::report::{
@@ -88,6 +90,8 @@ mod test_reporting {
                 render: RenderTarget::Generic,
                 threading: Threading::Single,
                 exec_mode: ExecutionMode::Check,
+                opt_level: OptLevel::Development,
+                warning_config: Default::default(),
             };
             let result =
                 roc_load::load_and_typecheck(arena, full_file_path, exposed_types, load_config);
@@ -117,12 +121,17 @@ mod test_reporting {
         let (module_src, result) = run_load_and_infer(subdir, arena, expr_src);
         let LoadedModule {
             module_id: home,
+            mut syntax_problems,
             mut can_problems,
             mut type_problems,
             interns,
             ..
         } = result?;
 
+        if let Some(reports) = syntax_problems.remove(&home) {
+            return Err(LoadingProblem::FormattedReport(reports.concat()));
+        }
+
         let can_problems = can_problems.remove(&home).unwrap_or_default();
         let type_problems = type_problems.remove(&home).unwrap_or_default();
 
@@ -2745,6 +2754,308 @@ mod test_reporting {
     "###
     );
 
+    test_report!(
+        patterns_u8_every_value_exhaustive,
+        &format!(
+            "x : U8\nx = 0\n\nwhen x is\n{}",
+            (0..=255)
+                .map(|n| format!("    {} -> 1\n", n))
+                .collect::<String>()
+        ),
+        @""
+    );
+
+    test_report!(
+        patterns_u8_missing_ranges,
+        &format!(
+            "x : U8\nx = 0\n\nwhen x is\n{}",
+            (0..200)
+                .filter(|n| *n != 7)
+                .map(|n| format!("    {} -> 1\n", n))
+                .collect::<String>()
+        ),
+        |golden| {
+            assert!(golden.contains("── UNSAFE PATTERN"), "{}", golden);
+            assert!(
+                golden.contains("Other possibilities include:\n\n    7\n    200..=255\n"),
+                "{}",
+                golden
+            );
+        }
+    );
+
+    test_report!(
+        patterns_u8_catch_all_redundant,
+        &format!(
+            "x : U8\nx = 0\n\nwhen x is\n{}    _ -> 2\n",
+            (0..=255)
+                .map(|n| format!("    {} -> 1\n", n))
+                .collect::<String>()
+        ),
+        |golden| {
+            assert!(golden.contains("── REDUNDANT PATTERN"), "{}", golden);
+            assert!(
+                golden.contains("The 257th pattern is redundant"),
+                "{}",
+                golden
+            );
+        }
+    );
+
+    test_report!(
+        patterns_u8_ranges_exhaustive,
+        indoc!(
+            r#"
+            x : U8
+            x = 0
+
+            when x is
+                0..100 -> 1
+                100 -> 2
+                101..=255 -> 3
+            "#
+        ),
+        @""
+    );
+
+    test_report!(
+        patterns_u8_ranges_missing,
+        indoc!(
+            r#"
+            x : U8
+            x = 0
+
+            when x is
+                0..10 -> 1
+                20..=255 -> 2
+            "#
+        ),
+        @r###"
+    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+
+    This `when` does not cover all the possibilities:
+
+    7│>      when x is
+    8│>          0..10 -> 1
+    9│>          20..=255 -> 2
+
+    Other possibilities include:
+
+        10..=19
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "###
+    );
+
+    test_report!(
+        patterns_range_redundant,
+        indoc!(
+            r#"
+            x : U8
+            x = 0
+
+            when x is
+                0..=10 -> 1
+                5 -> 2
+                _ -> 3
+            "#
+        ),
+        @r###"
+    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+
+    The 2nd pattern is redundant:
+
+     7│       when x is
+     8│           0..=10 -> 1
+     9│>          5 -> 2
+    10│           _ -> 3
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "###
+    );
+
+    test_report!(
+        patterns_char_range_redundant,
+        indoc!(
+            r#"
+            c : U32
+            c = 'x'
+
+            when c is
+                'a'..='z' -> 1
+                'q' -> 2
+                _ -> 3
+            "#
+        ),
+        @r###"
+    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+
+    The 2nd pattern is redundant:
+
+     7│       when c is
+     8│           'a'..='z' -> 1
+     9│>          'q' -> 2
+    10│           _ -> 3
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "###
+    );
+
+    test_report!(
+        patterns_generic_int_ranges_not_exhaustive,
+        indoc!(
+            r#"
+            f : Int a -> U8
+            f = \n ->
+                when n is
+                    0..=255 -> 1
+
+            f 0u8
+            "#
+        ),
+        @r###"
+    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+
+    This `when` does not cover all the possibilities:
+
+    6│>          when n is
+    7│>              0..=255 -> 1
+
+    Other possibilities include:
+
+        _
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "###
+    );
+
+    test_report!(
+        patterns_nat_ranges_not_exhaustive,
+        indoc!(
+            r#"
+            x : Nat
+            x = 0
+
+            when x is
+                0..=18446744073709551615 -> 1
+            "#
+        ),
+        @r###"
+    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+
+    This `when` does not cover all the possibilities:
+
+    7│>      when x is
+    8│>          0..=18446744073709551615 -> 1
+
+    Other possibilities include:
+
+        _
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "###
+    );
+
+    test_report!(
+        patterns_range_with_literal_out_of_bounds,
+        indoc!(
+            r#"
+            x : U8
+            x = 0
+
+            when x is
+                0..=255 -> 1
+                300 -> 2
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    The branches of this `when` expression don't match the condition:
+
+    7│>      when x is
+    8│           0..=255 -> 1
+    9│           300 -> 2
+
+    This `x` value is a:
+
+        U8
+
+    But the branch patterns have type:
+
+        I16, U16, F32, I32, U32, F64, I64, Nat, U64, I128, Dec, or U128
+
+    The branches must be cases of the `when` condition's type!
+    "###
+    );
+
+    test_report!(
+        patterns_empty_range,
+        indoc!(
+            r#"
+            when 3 is
+                10..5 -> 1
+                _ -> 2
+            "#
+        ),
+        @r###"
+    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+
+    This range pattern is malformed:
+
+    5│          10..5 -> 1
+                ^^^^^
+
+    Tip: This range contains no values. The start of a range must come
+    before its end
+    "###
+    );
+
+    test_report!(
+        patterns_fraction_range,
+        indoc!(
+            r#"
+            when 0.5 is
+                0.5..1 -> 1
+                _ -> 2
+            "#
+        ),
+        @r###"
+    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+
+    This range pattern is malformed:
+
+    5│          0.5..1 -> 1
+                ^^^^^^
+
+    Tip: Ranges can only be made of integers or characters, not fractions
+    "###
+    );
+
+    test_report!(
+        patterns_unfinished_range,
+        indoc!(
+            r#"
+            when 3 is
+                0.. -> 1
+                _ -> 2
+            "#
+        ),
+        @r###"
+    ── UNFINISHED RANGE PATTERN ─────────── tmp/patterns_unfinished_range/Test.roc ─
+
+    I am partway through parsing a range pattern, but I got stuck here:
+
+    5│          0.. -> 1
+                   ^
+
+    I was expecting a number or a character to end the range, like 0..10
+    or 'a'..='z'.
+    "###
+    );
+
     test_report!(
         unify_alias_other,
         indoc!(
@@ -8438,6 +8749,134 @@ All branches in an `if` must have the same type!
     "###
     );
 
+    test_report!(
+        ability_missing_superability,
+        indoc!(
+            r#"
+            app "test" provides [compare] to "./platform"
+
+            Similar has
+                similar : a, a -> Bool | a has Similar
+
+            Ord has Similar
+                compare : a, a -> [LT, EQ, GT] | a has Ord
+
+            Id := U64 has [Ord {compare}]
+
+            compare = \@Id m, @Id n -> Num.compare m n
+            "#
+        ),
+        @r###"
+    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+
+    The type `Id` has the `Ord` ability, but not `Similar`. Every type that has
+    `Ord` must also have `Similar`.
+
+    Tip: Add `Similar` to the `has` clause of `Id`.
+    "###
+    );
+
+    test_report!(
+        ability_superability_is_not_an_ability,
+        indoc!(
+            r#"
+            app "test" provides [compare] to "./platform"
+
+            Ord has Str
+                compare : a, a -> [LT, EQ, GT] | a has Ord
+            "#
+        ),
+        @r###"
+    ── NOT AN ABILITY ──────────────────────────────────────── /code/proj/Main.roc ─
+
+    This identifier is not an ability in scope:
+
+    3│  Ord has Str
+                ^^^
+
+    Only abilities can be implemented.
+    "###
+    );
+
+    test_report!(
+        ability_member_default_is_specialized,
+        indoc!(
+            r#"
+            app "test" provides [similar] to "./platform"
+
+            Similar has
+                similar : a, a -> Bool | a has Similar
+                different : a, a -> Bool | a has Similar
+                different = \@Id m, @Id n -> m != n
+
+            Id := U64 has [Similar {similar}]
+
+            similar = \@Id m, @Id n -> m == n
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    This default implementation of `different` only works for one type:
+
+    6│      different = \@Id m, @Id n -> m != n
+                        ^^^^^^^^^^^^^^^^^^^^^^^
+
+    This value is a default implementation of type:
+
+        Id, Id -> Bool
+
+    But the type annotation on `different` says it must match:
+
+        a, a -> Bool | a has Similar
+
+    Note: A default implementation is used by every type that doesn't
+    implement the member itself, so it must work for all of them.
+
+    If this implementation is only meant for one type, make it that type's
+    own implementation instead.
+
+    Tip: Type comparisons between an opaque type are only ever equal if
+    both types are the same opaque type. Did you mean to create an opaque
+    type by wrapping it? If I have an opaque type Age := U32 I can create
+    an instance of this opaque type by doing @Age 23.
+    "###
+    );
+
+    test_report!(
+        ability_member_default_does_not_match_type,
+        indoc!(
+            r#"
+            app "test" provides [similar] to "./platform"
+
+            Similar has
+                similar : a, a -> Bool | a has Similar
+                different : a, a -> Bool | a has Similar
+                different = \_, _ -> "yes"
+
+            Id := U64 has [Similar {similar}]
+
+            similar = \@Id m, @Id n -> m == n
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    Something is off with the default implementation of `different`:
+
+    6│      different = \_, _ -> "yes"
+                        ^^^^^^^^^^^^^^
+
+    This value is a default implementation of type:
+
+        a, a -> Str | a has Similar
+
+    But the type annotation on `different` says it must match:
+
+        a, a -> Bool | a has Similar
+    "###
+    );
+
     test_report!(
         ability_specialization_is_unused,
         indoc!(
@@ -9933,14 +10372,124 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPED HOLE ──────────────────────────────────────────── /code/proj/Main.roc ─
 
-    Underscores are not allowed in identifier names:
+    This hole still needs to be filled in:
 
     6│      f 1 _ 1
-      
+                ^
+
+    It needs a value of type:
+
+        Num a
+
+    None of the values in scope have that type.
+
+    If this code is reached, the program will crash.
+    "###
+    );
+
+    test_report!(
+        typed_hole_suggests_values_that_fit,
+        indoc!(
+            r#"
+            greeting = "Hello"
+            count = List.len []
+            punctuation = "!"
+
+            Str.concat _ punctuation
+            "#
+        ),
+        @r###"
+    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+
+    `greeting` is not used anywhere in your code.
+
+    4│      greeting = "Hello"
+            ^^^^^^^^
+
+    If you didn't intend on using `greeting` then remove it so future
+    readers of your code don't wonder why it is there.
+
+    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+
+    `count` is not used anywhere in your code.
+
+    5│      count = List.len []
+            ^^^^^
+
+    If you didn't intend on using `count` then remove it so future readers
+    of your code don't wonder why it is there.
+
+    ── TYPED HOLE ──────────────────────────────────────────── /code/proj/Main.roc ─
+
+    This hole still needs to be filled in:
+
+    8│      Str.concat _ punctuation
+                       ^
+
+    It needs a value of type:
+
+        Str
+
+    These values in scope have that type:
+
+        `punctuation`
+        `greeting`
+
+    If this code is reached, the program will crash.
+    "###
+    );
+
+    test_report!(
+        typed_hole_named,
+        indoc!(
+            r#"
+            Str.concat _greeting "!"
+            "#
+        ),
+        @r###"
+    ── TYPED HOLE ──────────────────────────────────────────── /code/proj/Main.roc ─
+
+    This hole still needs to be filled in:
+
+    4│      Str.concat _greeting "!"
+                       ^^^^^^^^^
+
+    It needs a value of type:
+
+        Str
+
+    None of the values in scope have that type.
+
+    If this code is reached, the program will crash.
+    "###
+    );
+
+    test_report!(
+        typed_hole_unconstrained,
+        indoc!(
+            r#"
+            x = _
+
+            x
+            "#
+        ),
+        @r###"
+    ── TYPED HOLE ──────────────────────────────────────────── /code/proj/Main.roc ─
+
+    This hole still needs to be filled in:
+
+    4│      x = _
+                ^
+
+    It needs a value of type:
+
+        a
+
+    Nothing here says what its type should be yet, so any value fits.
 
-    I recommend using camelCase, it is the standard in the Roc ecosystem.
+    If this code is reached, the program will crash.
     "###
     );
 
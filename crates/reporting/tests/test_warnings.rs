@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod test_warnings {
+    use bumpalo::Bump;
+    use roc_parse::test_helpers::parse_defs_with;
+    use roc_reporting::warnings::{Suppressions, WarningConfig, WarningKind};
+
+    fn suppressions(src: &str) -> Suppressions {
+        let arena = Bump::new();
+        let defs = parse_defs_with(&arena, src).unwrap();
+
+        Suppressions::from_defs(src, &defs)
+    }
+
+    #[test]
+    fn allow_next_line() {
+        let suppressions = suppressions(
+            "x = 1\n# roc-allow: unused-definition, unused-argument\n# some docs\n\ny = 2\nz = 3\n",
+        );
+
+        assert!(suppressions.allows("unused-definition", Some(4)));
+        assert!(suppressions.allows("unused-argument", Some(4)));
+        assert!(!suppressions.allows("unused-import", Some(4)));
+        assert!(!suppressions.allows("unused-definition", Some(0)));
+        assert!(!suppressions.allows("unused-definition", Some(5)));
+    }
+
+    #[test]
+    fn allow_nested_def() {
+        let suppressions =
+            suppressions("f = \\x ->\n    # roc-allow: unused-definition\n    y = 1\n\n    x\n");
+
+        assert!(suppressions.allows("unused-definition", Some(2)));
+        assert!(!suppressions.allows("unused-definition", Some(4)));
+    }
+
+    #[test]
+    fn allow_module() {
+        let suppressions = suppressions("# roc-allow-module: unused-definition\nx = 1\ny = 2\n");
+
+        assert!(suppressions.allows("unused-definition", Some(1)));
+        assert!(suppressions.allows("unused-definition", Some(2)));
+        assert!(!suppressions.allows("unused-import", Some(2)));
+        assert!(suppressions.allows("unused-definition", None));
+    }
+
+    #[test]
+    fn no_comments() {
+        assert!(suppressions("x = 1 # roc-allow: unused-definition\n").is_empty());
+    }
+
+    #[test]
+    fn comment_in_string() {
+        let suppressions = suppressions(
+            "x =\n    \"\"\"\n    # roc-allow: unused-definition\n    \"\"\"\n\ny = 2\n",
+        );
+
+        assert!(suppressions.is_empty());
+    }
+
+    #[test]
+    fn kind_names() {
+        for kind in WarningKind::ALL {
+            assert_eq!(WarningKind::from_name(kind.name()), Some(*kind));
+        }
+
+        assert_eq!(WarningKind::from_name("unused-definitions"), None);
+    }
+
+    #[test]
+    fn allow_problem() {
+        let config = WarningConfig {
+            deny_warnings: false,
+            allowed: [WarningKind::UnusedImport].into_iter().collect(),
+        };
+        let suppressions = suppressions("# roc-allow: unused-definition\nx = 1\n");
+
+        assert!(config.allows_problem(Some(WarningKind::UnusedImport), None, None));
+        assert!(config.allows_problem(
+            Some(WarningKind::UnusedDefinition),
+            Some(&suppressions),
+            Some(1)
+        ));
+        assert!(!config.allows_problem(
+            Some(WarningKind::UnusedDefinition),
+            Some(&suppressions),
+            Some(0)
+        ));
+        assert!(!config.allows_problem(None, Some(&suppressions), Some(1)));
+    }
+}
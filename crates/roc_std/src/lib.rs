@@ -1,16 +1,15 @@
 #![no_std]
 #![crate_type = "lib"]
 
-use arrayvec::ArrayString;
 use core::cmp::Ordering;
 use core::ffi::c_void;
 use core::fmt::{self, Debug};
 use core::hash::{Hash, Hasher};
 use core::mem::{ManuallyDrop, MaybeUninit};
-use core::ops::Drop;
-use core::str;
+use core::ops::{Add, Div, Drop, Mul, Neg, Rem, Sub};
 
 mod roc_box;
+mod roc_dec;
 mod roc_dict;
 mod roc_list;
 mod roc_set;
@@ -18,7 +17,8 @@ mod roc_str;
 mod storage;
 
 pub use roc_box::RocBox;
-pub use roc_dict::RocDict;
+pub use roc_dec::{DecConversionError, RocDec};
+pub use roc_dict::{Entry, OccupiedEntry, RocDict, VacantEntry};
 pub use roc_list::RocList;
 pub use roc_set::RocSet;
 pub use roc_str::{InteriorNulError, RocStr};
@@ -227,162 +227,6 @@ impl<T, E> Drop for RocResult<T, E> {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-#[repr(C)]
-pub struct RocDec([u8; 16]);
-
-impl RocDec {
-    pub const MIN: Self = Self(i128::MIN.to_ne_bytes());
-    pub const MAX: Self = Self(i128::MAX.to_ne_bytes());
-
-    const DECIMAL_PLACES: usize = 18;
-    const ONE_POINT_ZERO: i128 = 10i128.pow(Self::DECIMAL_PLACES as u32);
-    const MAX_DIGITS: usize = 39;
-    const MAX_STR_LENGTH: usize = Self::MAX_DIGITS + 2; // + 2 here to account for the sign & decimal dot
-
-    pub fn new(num: i128) -> Self {
-        Self(num.to_ne_bytes())
-    }
-
-    pub fn as_bits(&self) -> (i64, u64) {
-        let lower_bits = self.as_i128() as u64;
-        let upper_bits = (self.as_i128() >> 64) as i64;
-        (upper_bits, lower_bits)
-    }
-
-    #[allow(clippy::should_implement_trait)]
-    pub fn from_str(value: &str) -> Option<Self> {
-        // Split the string into the parts before and after the "."
-        let mut parts = value.split('.');
-
-        let before_point = match parts.next() {
-            Some(answer) => answer,
-            None => {
-                return None;
-            }
-        };
-
-        let opt_after_point = match parts.next() {
-            Some(answer) if answer.len() <= Self::DECIMAL_PLACES => Some(answer),
-            _ => None,
-        };
-
-        // There should have only been one "." in the string!
-        if parts.next().is_some() {
-            return None;
-        }
-
-        // Calculate the low digits - the ones after the decimal point.
-        let lo = match opt_after_point {
-            Some(after_point) => {
-                match after_point.parse::<i128>() {
-                    Ok(answer) => {
-                        // Translate e.g. the 1 from 0.1 into 10000000000000000000
-                        // by "restoring" the elided trailing zeroes to the number!
-                        let trailing_zeroes = Self::DECIMAL_PLACES - after_point.len();
-                        let lo = answer * 10i128.pow(trailing_zeroes as u32);
-
-                        if !before_point.starts_with('-') {
-                            lo
-                        } else {
-                            -lo
-                        }
-                    }
-                    Err(_) => {
-                        return None;
-                    }
-                }
-            }
-            None => 0,
-        };
-
-        // Calculate the high digits - the ones before the decimal point.
-        match before_point.parse::<i128>() {
-            Ok(answer) => match answer.checked_mul(Self::ONE_POINT_ZERO) {
-                Some(hi) => hi.checked_add(lo).map(|num| Self(num.to_ne_bytes())),
-                None => None,
-            },
-            Err(_) => None,
-        }
-    }
-
-    pub fn from_str_to_i128_unsafe(val: &str) -> i128 {
-        Self::from_str(val).unwrap().as_i128()
-    }
-
-    /// This is private because RocDec being an i128 is an implementation detail
-    #[inline(always)]
-    fn as_i128(&self) -> i128 {
-        i128::from_ne_bytes(self.0)
-    }
-
-    pub fn from_ne_bytes(bytes: [u8; 16]) -> Self {
-        Self(bytes)
-    }
-
-    pub fn to_ne_bytes(&self) -> [u8; 16] {
-        self.0
-    }
-
-    fn to_str_helper(self, string: &mut ArrayString<{ Self::MAX_STR_LENGTH }>) -> &str {
-        use core::fmt::Write;
-
-        if self.as_i128() == 0 {
-            return "0";
-        }
-
-        // The :019 in the following write! is computed as Self::DECIMAL_PLACES + 1. If you change
-        // Self::DECIMAL_PLACES, this assert should remind you to change that format string as well.
-        static_assertions::const_assert!(RocDec::DECIMAL_PLACES + 1 == 19);
-
-        // By using the :019 format, we're guaranteeing that numbers less than 1, say 0.01234
-        // get their leading zeros placed in bytes for us. i.e. `string = b"0012340000000000000"`
-        write!(string, "{:019}", self.as_i128()).unwrap();
-
-        let is_negative = self.as_i128() < 0;
-        let decimal_location = string.len() - Self::DECIMAL_PLACES + (is_negative as usize);
-
-        // skip trailing zeros
-        let last_nonzero_byte = string.trim_end_matches('0').len();
-
-        if last_nonzero_byte < decimal_location {
-            // This means that we've removed trailing zeros and are left with an integer. Our
-            // convention is to print these without a decimal point or trailing zeros, so we're done.
-            string.truncate(decimal_location);
-            return string.as_str();
-        }
-
-        // otherwise, we're dealing with a fraction, and need to insert the decimal dot
-
-        // truncate all extra zeros off
-        string.truncate(last_nonzero_byte);
-
-        // push a dummy character so we have space for the decimal dot
-        string.push('$');
-
-        // Safety: at any time, the string only contains ascii characters, so it is always valid utf8
-        let bytes = unsafe { string.as_bytes_mut() };
-
-        // shift the fractional part by one
-        bytes.copy_within(decimal_location..last_nonzero_byte, decimal_location + 1);
-
-        // and put in the decimal dot in the right place
-        bytes[decimal_location] = b'.';
-
-        string.as_str()
-    }
-
-    pub fn to_str(&self) -> RocStr {
-        RocStr::from(self.to_str_helper(&mut ArrayString::new()))
-    }
-}
-
-impl fmt::Display for RocDec {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.to_str_helper(&mut ArrayString::new()))
-    }
-}
-
 #[repr(C, align(16))]
 #[derive(Clone, Copy, Eq, Default)]
 pub struct I128([u8; 16]);
@@ -486,3 +330,118 @@ impl Hash for U128 {
         u128::from(*self).hash(state);
     }
 }
+
+impl Neg for I128 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match i128::from(self).checked_neg() {
+            Some(answer) => Self::from(answer),
+            None => panic!("integer negation overflowed because its argument is the minimum value"),
+        }
+    }
+}
+
+/// Arithmetic that panics where Roc's would: on overflow, and when dividing by zero.
+/// The checked_* methods return None instead.
+macro_rules! int_arithmetic {
+    ($name:ident, $int:ty) => {
+        impl $name {
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                <$int>::from(self)
+                    .checked_add(<$int>::from(other))
+                    .map(Self::from)
+            }
+
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                <$int>::from(self)
+                    .checked_sub(<$int>::from(other))
+                    .map(Self::from)
+            }
+
+            pub fn checked_mul(self, other: Self) -> Option<Self> {
+                <$int>::from(self)
+                    .checked_mul(<$int>::from(other))
+                    .map(Self::from)
+            }
+
+            pub fn checked_div(self, other: Self) -> Option<Self> {
+                <$int>::from(self)
+                    .checked_div(<$int>::from(other))
+                    .map(Self::from)
+            }
+
+            pub fn checked_rem(self, other: Self) -> Option<Self> {
+                <$int>::from(self)
+                    .checked_rem(<$int>::from(other))
+                    .map(Self::from)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                self.checked_add(other)
+                    .unwrap_or_else(|| panic!("integer addition overflowed!"))
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                self.checked_sub(other)
+                    .unwrap_or_else(|| panic!("integer subtraction overflowed!"))
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                self.checked_mul(other)
+                    .unwrap_or_else(|| panic!("integer multiplication overflowed!"))
+            }
+        }
+
+        impl Div for $name {
+            type Output = Self;
+
+            fn div(self, other: Self) -> Self {
+                Self::from(<$int>::from(self) / <$int>::from(other))
+            }
+        }
+
+        impl Rem for $name {
+            type Output = Self;
+
+            fn rem(self, other: Self) -> Self {
+                Self::from(<$int>::from(self) % <$int>::from(other))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                <$int>::from(*self).serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                <$int>::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+int_arithmetic!(I128, i128);
+int_arithmetic!(U128, u128);
@@ -0,0 +1,684 @@
+#[cfg(feature = "serde")]
+use serde::{
+    de::{self, Deserializer, Visitor},
+    ser::Serializer,
+    Deserialize, Serialize,
+};
+
+use arrayvec::ArrayString;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::RocStr;
+
+/// A Roc `Dec`: a fixed-point decimal with 18 digits after the decimal point, stored as an
+/// `i128` count of 10^-18ths.
+///
+/// The arithmetic here matches `dec.zig` in the builtins, so a host gets the same answers
+/// (and the same overflows) as the Roc code it calls.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct RocDec([u8; 16]);
+
+impl RocDec {
+    pub const MIN: Self = Self(i128::MIN.to_ne_bytes());
+    pub const MAX: Self = Self(i128::MAX.to_ne_bytes());
+    pub const ZERO: Self = Self(0i128.to_ne_bytes());
+    pub const ONE: Self = Self(Self::ONE_POINT_ZERO.to_ne_bytes());
+
+    const DECIMAL_PLACES: usize = 18;
+    const ONE_POINT_ZERO: i128 = 10i128.pow(Self::DECIMAL_PLACES as u32);
+    const MAX_DIGITS: usize = 39;
+    const MAX_STR_LENGTH: usize = Self::MAX_DIGITS + 2; // + 2 here to account for the sign & decimal dot
+
+    pub fn new(num: i128) -> Self {
+        Self(num.to_ne_bytes())
+    }
+
+    pub fn as_bits(&self) -> (i64, u64) {
+        let lower_bits = self.as_i128() as u64;
+        let upper_bits = (self.as_i128() >> 64) as i64;
+        (upper_bits, lower_bits)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(value: &str) -> Option<Self> {
+        // Split the string into the parts before and after the "."
+        let mut parts = value.split('.');
+
+        let before_point = match parts.next() {
+            Some(answer) => answer,
+            None => {
+                return None;
+            }
+        };
+
+        let opt_after_point = match parts.next() {
+            Some(answer) if answer.len() <= Self::DECIMAL_PLACES => Some(answer),
+            _ => None,
+        };
+
+        // There should have only been one "." in the string!
+        if parts.next().is_some() {
+            return None;
+        }
+
+        // Calculate the low digits - the ones after the decimal point.
+        let lo = match opt_after_point {
+            Some(after_point) => {
+                match after_point.parse::<i128>() {
+                    Ok(answer) => {
+                        // Translate e.g. the 1 from 0.1 into 10000000000000000000
+                        // by "restoring" the elided trailing zeroes to the number!
+                        let trailing_zeroes = Self::DECIMAL_PLACES - after_point.len();
+                        let lo = answer * 10i128.pow(trailing_zeroes as u32);
+
+                        if !before_point.starts_with('-') {
+                            lo
+                        } else {
+                            -lo
+                        }
+                    }
+                    Err(_) => {
+                        return None;
+                    }
+                }
+            }
+            None => 0,
+        };
+
+        // Calculate the high digits - the ones before the decimal point.
+        match before_point.parse::<i128>() {
+            Ok(answer) => match answer.checked_mul(Self::ONE_POINT_ZERO) {
+                Some(hi) => hi.checked_add(lo).map(|num| Self(num.to_ne_bytes())),
+                None => None,
+            },
+            Err(_) => None,
+        }
+    }
+
+    pub fn from_str_to_i128_unsafe(val: &str) -> i128 {
+        Self::from_str(val).unwrap().as_i128()
+    }
+
+    /// This is private because RocDec being an i128 is an implementation detail
+    #[inline(always)]
+    fn as_i128(&self) -> i128 {
+        i128::from_ne_bytes(self.0)
+    }
+
+    pub fn from_ne_bytes(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn to_ne_bytes(&self) -> [u8; 16] {
+        self.0
+    }
+
+    fn to_str_helper(self, string: &mut ArrayString<{ Self::MAX_STR_LENGTH }>) -> &str {
+        use core::fmt::Write;
+
+        if self.as_i128() == 0 {
+            return "0";
+        }
+
+        // The :019 in the following write! is computed as Self::DECIMAL_PLACES + 1. If you change
+        // Self::DECIMAL_PLACES, this assert should remind you to change that format string as well.
+        static_assertions::const_assert!(RocDec::DECIMAL_PLACES + 1 == 19);
+
+        // The sign goes in front of the digits, rather than counting towards the width of the
+        // :019 format below (which would push the decimal dot one digit too far right).
+        if self.as_i128() < 0 {
+            string.push('-');
+        }
+
+        // By using the :019 format, we're guaranteeing that numbers less than 1, say 0.01234
+        // get their leading zeros placed in bytes for us. i.e. `string = b"0012340000000000000"`
+        write!(string, "{:019}", self.as_i128().unsigned_abs()).unwrap();
+
+        let decimal_location = string.len() - Self::DECIMAL_PLACES;
+
+        // skip trailing zeros
+        let last_nonzero_byte = string.trim_end_matches('0').len();
+
+        if last_nonzero_byte < decimal_location {
+            // This means that we've removed trailing zeros and are left with an integer. Our
+            // convention is to print these without a decimal point or trailing zeros, so we're done.
+            string.truncate(decimal_location);
+            return string.as_str();
+        }
+
+        // otherwise, we're dealing with a fraction, and need to insert the decimal dot
+
+        // truncate all extra zeros off
+        string.truncate(last_nonzero_byte);
+
+        // push a dummy character so we have space for the decimal dot
+        string.push('$');
+
+        // Safety: at any time, the string only contains ascii characters, so it is always valid utf8
+        let bytes = unsafe { string.as_bytes_mut() };
+
+        // shift the fractional part by one
+        bytes.copy_within(decimal_location..last_nonzero_byte, decimal_location + 1);
+
+        // and put in the decimal dot in the right place
+        bytes[decimal_location] = b'.';
+
+        string.as_str()
+    }
+
+    pub fn to_str(&self) -> RocStr {
+        RocStr::from(self.to_str_helper(&mut ArrayString::new()))
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.as_i128().checked_add(other.as_i128()).map(Self::new)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.as_i128().checked_sub(other.as_i128()).map(Self::new)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        self.as_i128().checked_neg().map(Self::new)
+    }
+
+    /// Returns `None` when the product doesn't fit in a `Dec`. The product is rounded towards zero.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let self_i128 = self.as_i128();
+        let other_i128 = other.as_i128();
+
+        let is_answer_negative = (self_i128 < 0) != (other_i128 < 0);
+
+        let answer = mul_and_decimalize(self_i128.unsigned_abs(), other_i128.unsigned_abs())?;
+
+        if answer.hi != 0 {
+            return None;
+        }
+
+        if is_answer_negative {
+            // The magnitude of MIN is one more than i128::MAX, so this has to go through a wrap
+            let negated = (answer.lo as i128).wrapping_neg();
+
+            (negated <= 0).then(|| Self::new(negated))
+        } else {
+            i128::try_from(answer.lo).ok().map(Self::new)
+        }
+    }
+
+    /// Multiplies the way dec.zig does: a product that doesn't fit in a `Dec` wraps around,
+    /// except that `None` is returned when one of the numbers is `MIN` and the other is neither
+    /// 0 nor 1, which is when dec.zig reports an overflow.
+    fn wrapping_mul_like_roc(self, other: Self) -> Option<Self> {
+        let self_i128 = self.as_i128();
+        let other_i128 = other.as_i128();
+
+        let is_answer_negative = (self_i128 < 0) != (other_i128 < 0);
+
+        // i128::MIN has no absolute value; like dec.zig, the only products with it we can
+        // compute are with 0 and 1.
+        let self_u128 = match self_i128.checked_abs() {
+            Some(abs) => abs as u128,
+            None if other_i128 == 0 => return Some(Self::ZERO),
+            None if other_i128 == Self::ONE_POINT_ZERO => return Some(self),
+            None => return None,
+        };
+
+        let other_u128 = match other_i128.checked_abs() {
+            Some(abs) => abs as u128,
+            None if self_i128 == 0 => return Some(Self::ZERO),
+            None if self_i128 == Self::ONE_POINT_ZERO => return Some(other),
+            None => return None,
+        };
+
+        // Like dec.zig, this keeps only the lowest 128 bits of a product that's too big
+        let unsigned_answer = mul_and_decimalize(self_u128, other_u128)?.lo as i128;
+
+        if is_answer_negative {
+            Some(Self::new(unsigned_answer.wrapping_neg()))
+        } else {
+            Some(Self::new(unsigned_answer))
+        }
+    }
+
+    /// Returns `None` when dividing by zero or when the quotient doesn't fit in a `Dec`.
+    /// The quotient is rounded towards zero.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        let numerator = self.as_i128();
+        let denominator = other.as_i128();
+
+        // Like dec.zig, this is checked before the denominator, so 0 / 0 is 0
+        if numerator == 0 {
+            return Some(Self::ZERO);
+        }
+
+        if denominator == 0 {
+            return None;
+        }
+
+        let is_answer_negative = (numerator < 0) != (denominator < 0);
+
+        let numerator_u128 = match numerator.checked_abs() {
+            Some(abs) => abs as u128,
+            None if denominator == Self::ONE_POINT_ZERO => return Some(self),
+            None => return None,
+        };
+
+        let denominator_u128 = match denominator.checked_abs() {
+            Some(abs) => abs as u128,
+            // This is what dec.zig does, even though 1 / MIN is 0 at this precision
+            None if numerator == Self::ONE_POINT_ZERO => return Some(other),
+            None => return None,
+        };
+
+        let numerator_u256 = mul_u128(numerator_u128, Self::ONE_POINT_ZERO as u128);
+        let answer = div_u256_by_u128(numerator_u256, denominator_u128);
+
+        if answer.hi != 0 {
+            return None;
+        }
+
+        let unsigned_answer = i128::try_from(answer.lo).ok()?;
+
+        if is_answer_negative {
+            Some(Self::new(-unsigned_answer))
+        } else {
+            Some(Self::new(unsigned_answer))
+        }
+    }
+
+    /// Like `checked_add`, but returns `MAX` or `MIN` instead of overflowing.
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self::new(self.as_i128().saturating_add(other.as_i128()))
+    }
+
+    /// Like `checked_sub`, but returns `MAX` or `MIN` instead of overflowing.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self::new(self.as_i128().saturating_sub(other.as_i128()))
+    }
+
+    /// Like `checked_mul`, but returns `MAX` or `MIN` where that returns `None`.
+    pub fn saturating_mul(self, other: Self) -> Self {
+        match self.checked_mul(other) {
+            Some(answer) => answer,
+            None if (self.as_i128() < 0) != (other.as_i128() < 0) => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Whether there's nothing after the decimal point
+    fn is_integer(self) -> bool {
+        self.as_i128() % Self::ONE_POINT_ZERO == 0
+    }
+}
+
+/// A 256-bit unsigned integer, as its high and low 128 bits
+#[derive(Clone, Copy)]
+struct U256 {
+    hi: u128,
+    lo: u128,
+}
+
+/// Multiplies two u128s into a u256, the same way `mul_u128` in num.zig does.
+fn mul_u128(a: u128, b: u128) -> U256 {
+    const BITS_IN_DWORD_2: u32 = 64;
+    const LOWER_MASK: u128 = u128::MAX >> BITS_IN_DWORD_2;
+
+    let mut lo = (a & LOWER_MASK) * (b & LOWER_MASK);
+    let mut t = lo >> BITS_IN_DWORD_2;
+
+    lo &= LOWER_MASK;
+    t += (a >> BITS_IN_DWORD_2) * (b & LOWER_MASK);
+    lo += (t & LOWER_MASK) << BITS_IN_DWORD_2;
+
+    let mut hi = t >> BITS_IN_DWORD_2;
+
+    t = lo >> BITS_IN_DWORD_2;
+    lo &= LOWER_MASK;
+    t += (b >> BITS_IN_DWORD_2) * (a & LOWER_MASK);
+    lo += (t & LOWER_MASK) << BITS_IN_DWORD_2;
+    hi += t >> BITS_IN_DWORD_2;
+    hi += (a >> BITS_IN_DWORD_2) * (b >> BITS_IN_DWORD_2);
+
+    U256 { hi, lo }
+}
+
+/// Computes `a * b / 10^18`, rounded down, the same way `mul_and_decimalize` in dec.zig does:
+/// rather than dividing, it multiplies by `2^315 / 10^18` and keeps the bits above the 315th.
+///
+/// The answer can be up to 256 bits long; dec.zig keeps only its lowest 128 bits.
+fn mul_and_decimalize(a: u128, b: u128) -> Option<U256> {
+    let answer_u256 = mul_u128(a, b);
+
+    let mut lhs_hi = answer_u256.hi;
+    let mut lhs_lo = answer_u256.lo;
+
+    // Divide - or just add 1, multiply by floor(2^315/10^18), then right shift 315 times.
+
+    // Add 1.
+    // This can't overflow because the initial numbers are at most 2^127, so their product is at most 254 bits.
+    let (sum, overflowed) = lhs_lo.overflowing_add(1);
+    lhs_lo = sum;
+    if overflowed {
+        lhs_hi += 1;
+    }
+
+    // This needs to do multiplication in a way that expands,
+    // since we throw away 315 bits we care only about the higher end, not lower.
+    // So like need to do high low mult with 2 U256's and then bitshift.
+    // I bet this has a lot of room for multiplication optimization.
+    let rhs_hi: u128 = 0x9392ee8e921d5d073aff322e62439fcf;
+    let rhs_lo: u128 = 0x32d7f344649470f90cac0c573bf9e1b5;
+
+    let ea = mul_u128(lhs_lo, rhs_lo);
+    let gf = mul_u128(lhs_hi, rhs_lo);
+    let jh = mul_u128(lhs_lo, rhs_hi);
+    let lk = mul_u128(lhs_hi, rhs_hi);
+
+    let e = ea.hi;
+
+    let f = gf.lo;
+    let g = gf.hi;
+
+    let h = jh.lo;
+    let j = jh.hi;
+
+    let k = lk.lo;
+    let l = lk.hi;
+
+    // b = e + f + h
+    let (e_plus_f, b_carry1) = e.overflowing_add(f);
+    let (_, b_carry2) = e_plus_f.overflowing_add(h);
+
+    // c = carry + g + j + k // it doesn't say +k but I think it should be?
+    let (g_plus_j, c_carry1) = g.overflowing_add(j);
+    let (g_plus_j_plus_k, c_carry2) = g_plus_j.overflowing_add(k);
+    let (c_without_bcarry2, c_carry3) = g_plus_j_plus_k.overflowing_add(b_carry1 as u128);
+    let (c, c_carry4) = c_without_bcarry2.overflowing_add(b_carry2 as u128);
+
+    // d = carry + l
+    let d = l
+        .checked_add(c_carry1 as u128)?
+        .checked_add(c_carry2 as u128)?
+        .checked_add(c_carry3 as u128)?
+        .checked_add(c_carry4 as u128)?;
+
+    // Final 512bit value is d, c, b, a
+    // need to left shift 321 times
+    // 315 - 256 is 59. So left shift d, c 59 times.
+    Some(U256 {
+        hi: d >> 59,
+        lo: c >> 59 | (d << (128 - 59)),
+    })
+}
+
+/// Divides a u256 by a u128, rounding down
+fn div_u256_by_u128(numerator: U256, denominator: u128) -> U256 {
+    if numerator.hi == 0 {
+        return U256 {
+            hi: 0,
+            lo: numerator.lo / denominator,
+        };
+    }
+
+    let mut quotient = U256 { hi: 0, lo: 0 };
+    let mut remainder: u128 = 0;
+
+    // Long division, one bit at a time
+    for bit_index in (0..256).rev() {
+        let numerator_bit = if bit_index >= 128 {
+            (numerator.hi >> (bit_index - 128)) & 1
+        } else {
+            (numerator.lo >> bit_index) & 1
+        };
+
+        // The remainder is less than the denominator, so after shifting it in
+        // it's at most 129 bits long; the carry is that 129th bit.
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | numerator_bit;
+
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+
+            if bit_index >= 128 {
+                quotient.hi |= 1 << (bit_index - 128);
+            } else {
+                quotient.lo |= 1 << bit_index;
+            }
+        }
+    }
+
+    quotient
+}
+
+impl fmt::Display for RocDec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str_helper(&mut ArrayString::new()))
+    }
+}
+
+impl fmt::Debug for RocDec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str_helper(&mut ArrayString::new()))
+    }
+}
+
+impl PartialOrd for RocDec {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RocDec {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_i128().cmp(&other.as_i128())
+    }
+}
+
+// These panic where the Roc operators would; the checked_* methods return None instead.
+
+impl Add for RocDec {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .unwrap_or_else(|| panic!("Decimal addition overflowed!"))
+    }
+}
+
+impl Sub for RocDec {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .unwrap_or_else(|| panic!("Decimal subtraction overflowed!"))
+    }
+}
+
+impl Mul for RocDec {
+    type Output = Self;
+
+    /// Like in Roc, a product that's too big for a `Dec` wraps around. Panics when one of the
+    /// numbers is `MIN` and the other is neither 0 nor 1.
+    fn mul(self, other: Self) -> Self {
+        self.wrapping_mul_like_roc(other)
+            .unwrap_or_else(|| panic!("Decimal multiplication overflowed!"))
+    }
+}
+
+impl Div for RocDec {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        if self != Self::ZERO && other == Self::ZERO {
+            panic!("Decimal division by zero!");
+        }
+
+        self.checked_div(other)
+            .unwrap_or_else(|| panic!("Decimal division overflowed!"))
+    }
+}
+
+impl Neg for RocDec {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg()
+            .unwrap_or_else(|| panic!("Decimal negation overflowed!"))
+    }
+}
+
+/// The error from converting a number to or from a `RocDec` when the answer would be out of range,
+/// or (when converting to an integer) would lose the digits after the decimal point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecConversionError;
+
+impl fmt::Display for DecConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("number out of range for the conversion, or not an integer")
+    }
+}
+
+macro_rules! dec_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for RocDec {
+                fn from(num: $int) -> Self {
+                    Self::new(num as i128 * Self::ONE_POINT_ZERO)
+                }
+            }
+        )*
+    };
+}
+
+// Every one of these fits in a Dec, like in dec.zig's fromU64
+dec_from_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl TryFrom<i128> for RocDec {
+    type Error = DecConversionError;
+
+    fn try_from(num: i128) -> Result<Self, Self::Error> {
+        num.checked_mul(Self::ONE_POINT_ZERO)
+            .map(Self::new)
+            .ok_or(DecConversionError)
+    }
+}
+
+impl TryFrom<u128> for RocDec {
+    type Error = DecConversionError;
+
+    fn try_from(num: u128) -> Result<Self, Self::Error> {
+        let num = i128::try_from(num).map_err(|_| DecConversionError)?;
+
+        Self::try_from(num)
+    }
+}
+
+macro_rules! int_try_from_dec {
+    ($($int:ty),*) => {
+        $(
+            impl TryFrom<RocDec> for $int {
+                type Error = DecConversionError;
+
+                fn try_from(dec: RocDec) -> Result<Self, Self::Error> {
+                    if !dec.is_integer() {
+                        return Err(DecConversionError);
+                    }
+
+                    <$int>::try_from(dec.as_i128() / RocDec::ONE_POINT_ZERO)
+                        .map_err(|_| DecConversionError)
+                }
+            }
+        )*
+    };
+}
+
+int_try_from_dec!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+/// Like dec.zig's fromF64, this drops any digits beyond the 18th after the decimal point.
+impl TryFrom<f64> for RocDec {
+    type Error = DecConversionError;
+
+    fn try_from(num: f64) -> Result<Self, Self::Error> {
+        let result = num * Self::ONE_POINT_ZERO as f64;
+
+        // i128::MAX rounds up to 2^127 as an f64, which is already out of range.
+        // NaN fails both of these comparisons.
+        if result < i128::MAX as f64 && result >= i128::MIN as f64 {
+            Ok(Self::new(result as i128))
+        } else {
+            Err(DecConversionError)
+        }
+    }
+}
+
+impl From<RocDec> for f64 {
+    fn from(dec: RocDec) -> Self {
+        dec.as_i128() as f64 / RocDec::ONE_POINT_ZERO as f64
+    }
+}
+
+/// Serialized as a string, so no precision gets lost on the way through formats (like JSON)
+/// whose numbers are floats. Deserializing also accepts numbers.
+#[cfg(feature = "serde")]
+impl Serialize for RocDec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.to_str_helper(&mut ArrayString::new()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RocDec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(RocDecVisitor {})
+    }
+}
+
+#[cfg(feature = "serde")]
+struct RocDecVisitor {}
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for RocDecVisitor {
+    type Value = RocDec;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(formatter, "a decimal number, or a string containing one")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        RocDec::from_str(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(RocDec::from(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(RocDec::from(value))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        RocDec::try_from(value).map_err(|_| E::invalid_value(de::Unexpected::Float(value), &self))
+    }
+}
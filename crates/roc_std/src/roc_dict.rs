@@ -1,8 +1,20 @@
 use crate::roc_list::RocList;
 use core::{
+    borrow::Borrow,
     fmt::{self, Debug},
     hash::Hash,
+    iter::FromIterator,
     mem::{align_of, ManuallyDrop},
+    ptr,
+};
+
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{
+    de::{Deserializer, MapAccess, Visitor},
+    ser::{SerializeMap, Serializer},
+    Deserialize, Serialize,
 };
 
 /// At the moment, Roc's Dict is just an association list. Its lookups are O(n) but
@@ -11,15 +23,24 @@ use core::{
 /// We do some things in this data structure that only make sense because the
 /// memory is managed in Roc:
 ///
-/// 1. We don't implement an [`IntoIterator`] that iterates over owned values,
-///    since Roc owns the memory, not rust.
+/// 1. Mutating a dict (or iterating over its owned entries) requires `K: Clone` and
+///    `V: Clone`, because if Roc still holds a reference to the same list, we have to
+///    copy it first.
 /// 2. We use a union for [`RocDictItem`] instead of just a struct. See the
 ///    comment on that data structure for why.
+///
+/// Like in Roc, inserting a key that's already present replaces its value in place,
+/// removing a key moves the last entry into its spot, and two dicts are only equal
+/// if they have the same entries in the same order.
 #[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct RocDict<K, V>(RocList<RocDictItem<K, V>>);
 
 impl<K, V> RocDict<K, V> {
+    pub fn empty() -> Self {
+        Self(RocList::empty())
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -43,50 +64,134 @@ impl<K, V> RocDict<K, V> {
     pub fn iter_values(&self) -> impl Iterator<Item = &V> {
         self.0.iter().map(|item| item.value())
     }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.index_of(key).map(|index| self.0[index].value())
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.index_of(key).is_some()
+    }
+
+    /// Like Roc's Dict, this compares keys with `==`, so they don't need to be `Hash`
+    fn index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.0.iter().position(|item| item.key().borrow() == key)
+    }
 }
 
-impl<K: Hash, V> RocDict<K, V> {
-    pub fn from_iter<I: Iterator<Item = (K, V)>>(src: I) -> Self {
-        let mut ret = Self::with_capacity(src.size_hint().0);
+impl<K: Clone, V: Clone> RocDict<K, V> {
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let index = self.index_of(key)?;
+
+        Some(self.0.as_mut_slice()[index].value_mut())
+    }
 
-        for (key, val) in src {
-            unsafe {
-                ret.insert_unchecked(key, val);
+    /// If the key was already present, replaces its value (leaving the entry where it was)
+    /// and returns the old value.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: PartialEq,
+    {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+
+                None
             }
         }
+    }
 
-        ret
+    /// Removes the key and returns its value, if it was present.
+    /// Like Roc's `Dict.remove`, the last entry takes the removed entry's place.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let index = self.index_of(key)?;
+
+        Some(self.remove_at(index).1)
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V>
+    where
+        K: PartialEq,
+    {
+        match self.index_of(&key) {
+            Some(index) => Entry::Occupied(OccupiedEntry { dict: self, index }),
+            None => Entry::Vacant(VacantEntry { dict: self, key }),
+        }
     }
 
-    unsafe fn insert_unchecked(&mut self, _key: K, _val: V) {
-        todo!();
+    fn remove_at(&mut self, index: usize) -> (K, V) {
+        let last_index = self.len() - 1;
+
+        self.0.as_mut_slice().swap(index, last_index);
+
+        self.0.pop().unwrap().into_key_value()
     }
 }
 
-impl<'a, K: Hash, V> FromIterator<(K, V)> for RocDict<K, V> {
+impl<K: PartialEq + Clone, V: Clone> RocDict<K, V> {
+    /// If the same key appears more than once, the last value wins.
+    pub fn from_iter<I: Iterator<Item = (K, V)>>(src: I) -> Self {
+        let mut ret = Self::with_capacity(src.size_hint().0);
+
+        ret.extend(src);
+
+        ret
+    }
+}
+
+impl<K: PartialEq + Clone, V: Clone> FromIterator<(K, V)> for RocDict<K, V> {
     fn from_iter<T: IntoIterator<Item = (K, V)>>(into_iter: T) -> Self {
         RocDict::from_iter(into_iter.into_iter())
     }
 }
 
+impl<K: PartialEq + Clone, V: Clone> Extend<(K, V)> for RocDict<K, V> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
 impl<'a, K, V> IntoIterator for &'a RocDict<K, V> {
     type Item = (&'a K, &'a V);
-    type IntoIter = IntoIter<'a, K, V>;
+    type IntoIter = Iter<'a, K, V>;
 
     fn into_iter(self) -> Self::IntoIter {
-        IntoIter {
+        Iter {
             index: 0,
             items: self.0.as_slice(),
         }
     }
 }
 
-pub struct IntoIter<'a, K, V> {
+pub struct Iter<'a, K, V> {
     index: usize,
     items: &'a [RocDictItem<K, V>],
 }
 
-impl<'a, K, V> Iterator for IntoIter<'a, K, V> {
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -101,12 +206,150 @@ impl<'a, K, V> Iterator for IntoIter<'a, K, V> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.items.len() - self.index;
+        let remaining = self.items.len().saturating_sub(self.index);
 
         (remaining, Some(remaining))
     }
 }
 
+impl<K: Clone, V: Clone> IntoIterator for RocDict<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            index: 0,
+            items: self.0,
+        }
+    }
+}
+
+/// Roc may still be referencing the entries, so this clones them out of the list
+/// rather than moving them; the list itself gets released when the iterator is dropped.
+pub struct IntoIter<K, V> {
+    index: usize,
+    items: RocList<RocDictItem<K, V>>,
+}
+
+impl<K: Clone, V: Clone> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self
+            .items
+            .get(self.index)
+            .map(|item| (item.key().clone(), item.value().clone()));
+
+        self.index += 1;
+
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.items.len().saturating_sub(self.index);
+
+        (remaining, Some(remaining))
+    }
+}
+
+/// A view into a single entry of a [`RocDict`], which may either be vacant or occupied.
+/// This is returned by [`RocDict::entry`].
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    dict: &'a mut RocDict<K, V>,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V> {
+    dict: &'a mut RocDict<K, V>,
+    key: K,
+}
+
+impl<'a, K: Clone, V: Clone> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(self, modify: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                modify(entry.get_mut());
+
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K: Clone, V: Clone> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        self.dict.0[self.index].key()
+    }
+
+    pub fn get(&self) -> &V {
+        self.dict.0[self.index].value()
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        self.dict.0.as_mut_slice()[self.index].value_mut()
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        self.dict.0.as_mut_slice()[self.index].value_mut()
+    }
+
+    /// Replaces the value, returning the old one
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    /// Like [`RocDict::remove`], the last entry takes this one's place.
+    pub fn remove(self) -> V {
+        self.dict.remove_at(self.index).1
+    }
+}
+
+impl<'a, K: Clone, V: Clone> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Like Roc's `Dict.insert`, new entries go at the end.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let dict = self.dict;
+
+        dict.0.push(RocDictItem::new(self.key, value));
+
+        dict.0.as_mut_slice().last_mut().unwrap().value_mut()
+    }
+}
+
 impl<K: Debug, V: Debug> Debug for RocDict<K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("RocDict ")?;
@@ -117,6 +360,67 @@ impl<K: Debug, V: Debug> Debug for RocDict<K, V> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<K: Serialize, V: Serialize> Serialize for RocDict<K, V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self.iter() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> Deserialize<'de> for RocDict<K, V>
+where
+    K: Deserialize<'de> + PartialEq + Clone,
+    V: Deserialize<'de> + Clone,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(RocDictVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+struct RocDictVisitor<K, V> {
+    marker: PhantomData<(K, V)>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> Visitor<'de> for RocDictVisitor<K, V>
+where
+    K: Deserialize<'de> + PartialEq + Clone,
+    V: Deserialize<'de> + Clone,
+{
+    type Value = RocDict<K, V>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(formatter, "a map")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut out = RocDict::with_capacity(map.size_hint().unwrap_or(0));
+
+        while let Some((key, value)) = map.next_entry()? {
+            out.insert(key, value);
+        }
+
+        Ok(out)
+    }
+}
+
 /// Roc is constructing these values according to its memory layout rules.
 /// Specifically:
 ///
@@ -152,6 +456,18 @@ struct ValueFirst<K, V> {
 }
 
 impl<K, V> RocDictItem<K, V> {
+    fn new(key: K, value: V) -> Self {
+        if align_of::<K>() >= align_of::<V>() {
+            Self {
+                key_first: ManuallyDrop::new(KeyFirst { key, value }),
+            }
+        } else {
+            Self {
+                value_first: ManuallyDrop::new(ValueFirst { value, key }),
+            }
+        }
+    }
+
     fn key(&self) -> &K {
         if align_of::<K>() >= align_of::<V>() {
             unsafe { &self.key_first.key }
@@ -167,6 +483,37 @@ impl<K, V> RocDictItem<K, V> {
             unsafe { &self.value_first.value }
         }
     }
+
+    fn value_mut(&mut self) -> &mut V {
+        if align_of::<K>() >= align_of::<V>() {
+            unsafe { &mut self.key_first.value }
+        } else {
+            unsafe { &mut self.value_first.value }
+        }
+    }
+
+    fn into_key_value(self) -> (K, V) {
+        // Move the fields out without running our Drop, which would drop them too
+        let item = ManuallyDrop::new(self);
+
+        if align_of::<K>() >= align_of::<V>() {
+            let KeyFirst { key, value } =
+                ManuallyDrop::into_inner(unsafe { ptr::read(&item.key_first) });
+
+            (key, value)
+        } else {
+            let ValueFirst { value, key } =
+                ManuallyDrop::into_inner(unsafe { ptr::read(&item.value_first) });
+
+            (key, value)
+        }
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for RocDictItem<K, V> {
+    fn clone(&self) -> Self {
+        Self::new(self.key().clone(), self.value().clone())
+    }
 }
 
 impl<K, V> Drop for RocDictItem<K, V> {
@@ -239,6 +239,70 @@ where
 
         self.capacity = self.length
     }
+
+    /// Appends an element to the end of the list.
+    ///
+    /// May make a new allocation, if the list was not unique.
+    pub fn push(&mut self, value: T) {
+        self.make_unique();
+
+        if self.length == self.capacity {
+            // Double the capacity, like Vec does
+            self.reserve(self.capacity.max(1));
+        }
+
+        unsafe {
+            self.elements
+                .unwrap()
+                .as_ptr()
+                .add(self.length)
+                .write(ManuallyDrop::new(value));
+        }
+
+        self.length += 1;
+    }
+
+    /// Removes the last element from the list and returns it, or `None` if it is empty.
+    ///
+    /// May make a new allocation, if the list was not unique.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.make_unique();
+
+        self.length -= 1;
+
+        let last = unsafe { self.elements.unwrap().as_ptr().add(self.length).read() };
+
+        Some(ManuallyDrop::into_inner(last))
+    }
+
+    /// May make a new allocation, if the list was not unique.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.make_unique();
+
+        match self.elements {
+            Some(elements) => {
+                let elements =
+                    ptr::slice_from_raw_parts_mut(elements.as_ptr().cast::<T>(), self.length);
+
+                unsafe { &mut *elements }
+            }
+            None => &mut [],
+        }
+    }
+
+    /// Other references to this list (e.g. ones Roc is holding on to) must not see it change,
+    /// so before mutating a shared list, replace it with a copy only this one references.
+    fn make_unique(&mut self) {
+        if let Some(storage) = self.storage() {
+            if !storage.is_unique() {
+                *self = Self::from_slice(self.as_slice());
+            }
+        }
+    }
 }
 
 impl<T> RocList<T> {
@@ -266,6 +330,8 @@ impl<T> RocList<T> {
 
                         if new_alloc == old_alloc {
                             // We successfully reallocated in-place; we're done!
+                            self.capacity = new_len;
+
                             return;
                         } else {
                             // We got back a different allocation; copy the existing elements
@@ -621,9 +687,7 @@ where
         };
 
         while let Some(next) = seq.next_element()? {
-            // TODO: it would be ideal to call `out.push` here, but we haven't
-            // implemented that yet! I think this is also why we need Clone.
-            out.extend_from_slice(&[next])
+            out.push(next)
         }
 
         Ok(out)
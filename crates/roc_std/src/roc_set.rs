@@ -1,13 +1,30 @@
-use crate::roc_dict::RocDict;
+use crate::roc_dict::{self, RocDict};
 use core::{
+    borrow::Borrow,
     fmt::{self, Debug},
-    hash::Hash,
+    iter::FromIterator,
 };
 
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{
+    de::{Deserializer, SeqAccess, Visitor},
+    ser::{SerializeSeq, Serializer},
+    Deserialize, Serialize,
+};
+
+/// Like Roc's Set, this is a [`RocDict`] whose values are all `{}`, so the same
+/// caveats apply: mutating it requires `T: Clone`, and elements stay in insertion
+/// order except that removing one moves the last element into its spot.
 #[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RocSet<T>(RocDict<T, ()>);
 
 impl<T> RocSet<T> {
+    pub fn empty() -> Self {
+        Self(RocDict::empty())
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -25,13 +42,93 @@ impl<T> RocSet<T> {
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.0.iter_keys()
     }
+
+    pub fn contains<Q>(&self, elem: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.0.contains_key(elem)
+    }
 }
 
-impl<T: Hash> RocSet<T> {
+impl<T: PartialEq + Clone> RocSet<T> {
     #[allow(unused)]
     pub fn from_iter<I: Iterator<Item = T>>(src: I) -> Self {
         Self(RocDict::from_iter(src.map(|elem| (elem, ()))))
     }
+
+    /// Returns whether the element was newly inserted
+    pub fn insert(&mut self, elem: T) -> bool {
+        self.0.insert(elem, ()).is_none()
+    }
+
+    /// Returns whether the element was present
+    pub fn remove<Q>(&mut self, elem: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.0.remove(elem).is_some()
+    }
+}
+
+impl<T: PartialEq + Clone> FromIterator<T> for RocSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(into_iter: I) -> Self {
+        RocSet::from_iter(into_iter.into_iter())
+    }
+}
+
+impl<T: PartialEq + Clone> Extend<T> for RocSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter.into_iter().map(|elem| (elem, ())))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RocSet<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter((&self.0).into_iter())
+    }
+}
+
+pub struct Iter<'a, T>(roc_dict::Iter<'a, T, ()>);
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(elem, ())| elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T: Clone> IntoIterator for RocSet<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.0.into_iter())
+    }
+}
+
+pub struct IntoIter<T>(roc_dict::IntoIter<T, ()>);
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(elem, ())| elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
 }
 
 impl<T: Debug> Debug for RocSet<T> {
@@ -41,3 +138,62 @@ impl<T: Debug> Debug for RocSet<T> {
         f.debug_set().entries(self.iter()).finish()
     }
 }
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for RocSet<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for elem in self.iter() {
+            seq.serialize_element(elem)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for RocSet<T>
+where
+    T: Deserialize<'de> + PartialEq + Clone,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(RocSetVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+struct RocSetVisitor<T> {
+    marker: PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Visitor<'de> for RocSetVisitor<T>
+where
+    T: Deserialize<'de> + PartialEq + Clone,
+{
+    type Value = RocSet<T>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(formatter, "a sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut out = RocSet::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(next) = seq.next_element()? {
+            out.insert(next);
+        }
+
+        Ok(out)
+    }
+}
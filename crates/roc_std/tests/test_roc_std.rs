@@ -284,9 +284,12 @@ mod test_roc_std {
     fn roc_dec_fmt() {
         assert_eq!(
             format!("{}", RocDec::MIN),
-            "-1701411834604692317316.87303715884105728"
+            "-170141183460469231731.687303715884105728"
         );
 
+        let negative_half = RocDec::from_str("-0.5").unwrap();
+        assert_eq!(format!("{}", negative_half), "-0.5");
+
         let half = RocDec::from_str("0.5").unwrap();
         assert_eq!(format!("{}", half), "0.5");
 
@@ -296,6 +299,373 @@ mod test_roc_std {
         let example = RocDec::from_str("1234.5678").unwrap();
         assert_eq!(format!("{}", example), "1234.5678");
     }
+
+    fn dec(string: &str) -> RocDec {
+        RocDec::from_str(string).unwrap()
+    }
+
+    #[test]
+    fn roc_dec_arithmetic() {
+        assert_eq!(dec("1.5") + dec("2.25"), dec("3.75"));
+        assert_eq!(dec("1.5") - dec("2.25"), dec("-0.75"));
+        assert_eq!(dec("1.5") * dec("-2.5"), dec("-3.75"));
+        assert_eq!(dec("10") / dec("3"), dec("3.333333333333333333"));
+        assert_eq!(dec("-10") / dec("3"), dec("-3.333333333333333333"));
+        assert_eq!(-dec("1.5"), dec("-1.5"));
+
+        // Digits past the 18th get dropped, rather than rounded
+        assert_eq!(dec("0.000000000000000001") * dec("0.5"), RocDec::ZERO);
+        assert_eq!(dec("2") / dec("3"), dec("0.666666666666666666"));
+    }
+
+    #[test]
+    fn roc_dec_overflow() {
+        assert_eq!(RocDec::MAX.checked_add(RocDec::ONE), None);
+        assert_eq!(RocDec::MIN.checked_sub(RocDec::ONE), None);
+        assert_eq!(RocDec::MIN.checked_neg(), None);
+        assert_eq!(RocDec::MAX.checked_div(dec("0.5")), None);
+        assert_eq!(RocDec::ONE.checked_div(RocDec::ZERO), None);
+
+        assert_eq!(RocDec::MIN.checked_mul(RocDec::ONE), Some(RocDec::MIN));
+        assert_eq!(RocDec::MIN.checked_mul(RocDec::ZERO), Some(RocDec::ZERO));
+        assert_eq!(
+            RocDec::MIN.checked_mul(dec("0.5")),
+            Some(dec("-85070591730234615865.843651857942052864"))
+        );
+        assert_eq!(RocDec::MIN.checked_mul(dec("-1")), None);
+        assert_eq!(RocDec::MAX.checked_mul(dec("2")), None);
+        assert_eq!(RocDec::MAX.checked_mul(RocDec::MAX), None);
+        assert_eq!(RocDec::MIN.checked_div(RocDec::ONE), Some(RocDec::MIN));
+        assert_eq!(RocDec::ONE.checked_div(RocDec::MIN), Some(RocDec::MIN));
+        assert_eq!(RocDec::ZERO.checked_div(RocDec::ZERO), Some(RocDec::ZERO));
+
+        assert_eq!(RocDec::MAX.saturating_add(RocDec::ONE), RocDec::MAX);
+        assert_eq!(RocDec::MIN.saturating_sub(RocDec::ONE), RocDec::MIN);
+        assert_eq!(RocDec::MIN.saturating_mul(dec("2")), RocDec::MIN);
+        assert_eq!(RocDec::MIN.saturating_mul(dec("-2")), RocDec::MAX);
+        assert_eq!(RocDec::MAX.saturating_mul(dec("-2")), RocDec::MIN);
+
+        // Like in Roc, products that are too big for a Dec wrap around with `*`
+        assert_eq!(RocDec::MAX * dec("2"), dec("-0.000000000000000002"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Decimal multiplication overflowed!")]
+    fn roc_dec_mul_overflow_panics() {
+        let _ = RocDec::MIN * dec("2");
+    }
+
+    #[test]
+    #[should_panic(expected = "Decimal division by zero!")]
+    fn roc_dec_div_by_zero_panics() {
+        let _ = RocDec::ONE / RocDec::ZERO;
+    }
+
+    #[test]
+    fn roc_dec_ord() {
+        let mut decs = vec![
+            dec("1.5"),
+            RocDec::MIN,
+            dec("-0.25"),
+            RocDec::MAX,
+            RocDec::ZERO,
+        ];
+
+        decs.sort();
+
+        assert_eq!(
+            decs,
+            [
+                RocDec::MIN,
+                dec("-0.25"),
+                RocDec::ZERO,
+                dec("1.5"),
+                RocDec::MAX
+            ]
+        );
+    }
+
+    #[test]
+    fn roc_dec_conversions() {
+        use core::convert::TryFrom;
+        use roc_std::DecConversionError;
+
+        assert_eq!(RocDec::from(-3i8), dec("-3"));
+        assert_eq!(RocDec::from(u64::MAX), dec("18446744073709551615"));
+        assert_eq!(RocDec::try_from(i128::MAX), Err(DecConversionError));
+
+        assert_eq!(i64::try_from(dec("-42")), Ok(-42));
+        assert_eq!(i64::try_from(dec("-42.5")), Err(DecConversionError));
+        assert_eq!(u8::try_from(dec("256")), Err(DecConversionError));
+        assert_eq!(u64::try_from(dec("-1")), Err(DecConversionError));
+
+        assert_eq!(RocDec::try_from(0.25f64), Ok(dec("0.25")));
+        assert_eq!(RocDec::try_from(-1.5f64), Ok(dec("-1.5")));
+        assert_eq!(RocDec::try_from(f64::NAN), Err(DecConversionError));
+        assert_eq!(RocDec::try_from(1e21f64), Err(DecConversionError));
+        assert_eq!(f64::from(dec("-1.5")), -1.5);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn roc_dec_serde() {
+        let orig = dec("-1234.5678");
+        let serialized = serde_json::to_string(&orig).expect("failed to serialize dec");
+        assert_eq!(serialized, "\"-1234.5678\"");
+
+        let deserialized: RocDec =
+            serde_json::from_str(&serialized).expect("failed to deserialize dec");
+        assert_eq!(deserialized, orig);
+
+        let from_number: RocDec = serde_json::from_str("-2.5").expect("failed to deserialize dec");
+        assert_eq!(from_number, dec("-2.5"));
+
+        let from_integer: RocDec = serde_json::from_str("7").expect("failed to deserialize dec");
+        assert_eq!(from_integer, dec("7"));
+    }
+
+    #[test]
+    fn roc_i128_arithmetic() {
+        use roc_std::{I128, U128};
+
+        assert_eq!(I128::from(-7) + I128::from(3), I128::from(-4));
+        assert_eq!(I128::from(-7) / I128::from(2), I128::from(-3));
+        assert_eq!(I128::from(-7) % I128::from(2), I128::from(-1));
+        assert_eq!(-I128::from(5), I128::from(-5));
+        assert_eq!(I128::from(i128::MAX).checked_add(I128::from(1)), None);
+        assert_eq!(U128::from(0).checked_sub(U128::from(1)), None);
+        assert_eq!(U128::from(6) * U128::from(7), U128::from(42));
+    }
+
+    #[test]
+    fn list_push_pop() {
+        let mut list = RocList::empty();
+
+        for n in 0..100u64 {
+            list.push(n);
+        }
+
+        assert_eq!(list.len(), 100);
+        assert!(list.iter().copied().eq(0..100));
+
+        assert_eq!(list.pop(), Some(99));
+        assert_eq!(list.len(), 99);
+    }
+
+    #[test]
+    fn list_push_to_shared() {
+        let original = RocList::from_slice(&[1u8, 2, 3]);
+        let mut copy = original.clone();
+
+        copy.push(4);
+        copy.as_mut_slice()[0] = 10;
+
+        assert_eq!(original.as_slice(), &[1, 2, 3]);
+        assert_eq!(copy.as_slice(), &[10, 2, 3, 4]);
+    }
+
+    #[test]
+    fn dict_insert_get_remove() {
+        use roc_std::RocDict;
+
+        let mut dict = RocDict::empty();
+
+        assert_eq!(dict.insert(RocStr::from("a"), 1u64), None);
+        assert_eq!(dict.insert(RocStr::from("b"), 2), None);
+        assert_eq!(dict.insert(RocStr::from("c"), 3), None);
+        assert_eq!(dict.insert(RocStr::from("a"), 10), Some(1));
+
+        assert_eq!(dict.len(), 3);
+        assert_eq!(dict.get(&RocStr::from("a")), Some(&10));
+        assert!(dict.contains_key(&RocStr::from("c")));
+        assert!(!dict.contains_key(&RocStr::from("d")));
+
+        *dict.get_mut(&RocStr::from("b")).unwrap() += 5;
+
+        // Like Roc's Dict.remove, the last entry takes the removed one's place
+        assert_eq!(dict.remove(&RocStr::from("a")), Some(10));
+        assert_eq!(dict.remove(&RocStr::from("a")), None);
+
+        let entries: Vec<_> = dict.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        assert_eq!(entries, [("c", 3), ("b", 7)]);
+    }
+
+    #[test]
+    fn dict_entry() {
+        use roc_std::{Entry, RocDict};
+
+        let mut counts: RocDict<u8, u32> = RocDict::empty();
+
+        for byte in b"mississippi" {
+            *counts.entry(*byte).or_insert(0) += 1;
+        }
+
+        assert_eq!(counts.get(&b's'), Some(&4));
+        assert_eq!(counts.get(&b'm'), Some(&1));
+
+        counts
+            .entry(b'm')
+            .and_modify(|count| *count = 100)
+            .or_default();
+        assert_eq!(counts.get(&b'm'), Some(&100));
+
+        match counts.entry(b'p') {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), 2),
+            Entry::Vacant(_) => panic!("p should be in the dict"),
+        }
+
+        assert_eq!(counts.len(), 3);
+    }
+
+    #[test]
+    fn dict_mutate_shared() {
+        use roc_std::RocDict;
+
+        let original: RocDict<u64, RocStr> = [(1, RocStr::from("one")), (2, RocStr::from("two"))]
+            .into_iter()
+            .collect();
+        let mut copy = original.clone();
+
+        copy.insert(3, RocStr::from("three"));
+        copy.remove(&1);
+
+        assert_eq!(original.len(), 2);
+        assert_eq!(original.get(&1), Some(&RocStr::from("one")));
+        assert_eq!(copy.len(), 2);
+        assert_eq!(copy.get(&1), None);
+    }
+
+    #[test]
+    fn dict_from_iter_and_extend() {
+        use roc_std::RocDict;
+
+        // The last value for a key wins, like inserting them one by one
+        let mut dict: RocDict<u8, u8> = [(1, 1), (2, 2), (1, 3)].into_iter().collect();
+        dict.extend([(3, 3), (2, 4)]);
+
+        let entries: Vec<(u8, u8)> = dict.into_iter().collect();
+        assert_eq!(entries, [(1, 3), (2, 4), (3, 3)]);
+    }
+
+    #[test]
+    fn dict_mixed_alignment() {
+        use roc_std::RocDict;
+
+        // The value has a higher alignment than the key, so it comes first in memory
+        let mut dict = RocDict::empty();
+        dict.insert(1u8, u64::MAX);
+        dict.insert(2u8, 42);
+
+        assert_eq!(dict.get(&1), Some(&u64::MAX));
+        assert_eq!(dict.remove(&1), Some(u64::MAX));
+        assert_eq!(dict.get(&2), Some(&42));
+    }
+
+    #[test]
+    fn set_insert_remove() {
+        use roc_std::RocSet;
+
+        let mut set: RocSet<u32> = [3, 1, 3, 2].into_iter().collect();
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&1));
+        assert!(!set.insert(1));
+        assert!(set.insert(4));
+        assert!(set.remove(&3));
+        assert!(!set.remove(&3));
+
+        set.extend([5, 4]);
+
+        let elems: Vec<u32> = set.into_iter().collect();
+        assert_eq!(elems, [4, 1, 2, 5]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn dict_and_set_serde_roundtrip() {
+        use roc_std::{RocDict, RocSet};
+
+        let dict: RocDict<RocStr, u64> = [(RocStr::from("a"), 1), (RocStr::from("b"), 2)]
+            .into_iter()
+            .collect();
+
+        let serialized = serde_json::to_string(&dict).expect("failed to serialize dict");
+        assert_eq!(serialized, r#"{"a":1,"b":2}"#);
+
+        let deserialized: RocDict<RocStr, u64> =
+            serde_json::from_str(&serialized).expect("failed to deserialize dict");
+        assert_eq!(deserialized, dict);
+
+        let set: RocSet<u64> = [3, 1, 2].into_iter().collect();
+
+        let serialized = serde_json::to_string(&set).expect("failed to serialize set");
+        assert_eq!(serialized, "[3,1,2]");
+
+        let deserialized: RocSet<u64> =
+            serde_json::from_str(&serialized).expect("failed to deserialize set");
+        assert_eq!(deserialized, set);
+    }
+}
+
+/// Checks properties of RocDec that hold for any numbers
+#[cfg(test)]
+mod roc_dec_quickcheck {
+    use core::convert::TryFrom;
+    use quickcheck_macros::quickcheck;
+    use roc_std::RocDec;
+
+    #[quickcheck]
+    fn add_sub(a: i128, b: i128) -> bool {
+        let (a, b) = (RocDec::new(a), RocDec::new(b));
+
+        match a.checked_add(b) {
+            Some(sum) => sum.checked_sub(b) == Some(a) && b.checked_add(a) == Some(sum),
+            None => b.checked_add(a).is_none(),
+        }
+    }
+
+    #[quickcheck]
+    fn mul_large_products(a: i128, b: i128, shift: u8) -> bool {
+        // Scale `b` down by a random amount, so products of every size come up
+        let (a, b) = (a, b >> (shift % 128));
+
+        // a * b / 10^18, split up so that every step fits in a u128 unless the answer doesn't
+        const ONE: u128 = 1_000_000_000_000_000_000;
+        let (a_abs, b_abs) = (a.unsigned_abs(), b.unsigned_abs());
+        let magnitude = (a_abs / ONE)
+            .checked_mul(b_abs)
+            .and_then(|sum| sum.checked_add((a_abs % ONE) * (b_abs / ONE)))
+            .and_then(|sum| sum.checked_add((a_abs % ONE) * (b_abs % ONE) / ONE));
+
+        let expected = match magnitude {
+            Some(magnitude) if (a < 0) != (b < 0) => {
+                (magnitude <= 1 << 127).then(|| (magnitude as i128).wrapping_neg())
+            }
+            Some(magnitude) => i128::try_from(magnitude).ok(),
+            None => None,
+        };
+
+        RocDec::new(a).checked_mul(RocDec::new(b)) == expected.map(RocDec::new)
+    }
+
+    // Multiplication and division are compared with what compiled Roc code computes in
+    // `dec_arithmetic_matches_roc_std` in test_gen's gen_num.rs, since they have to agree with
+    // dec.zig to the last bit.
+
+    #[quickcheck]
+    fn ord(a: i128, b: i128) -> bool {
+        RocDec::new(a).cmp(&RocDec::new(b)) == a.cmp(&b)
+    }
+
+    #[quickcheck]
+    fn str_roundtrip(a: i128) -> bool {
+        RocDec::from_str(&RocDec::new(a).to_string()) == Some(RocDec::new(a))
+    }
+
+    #[quickcheck]
+    fn int_roundtrip(a: i64) -> bool {
+        i64::try_from(RocDec::from(a)) == Ok(a)
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 platform "cli"
     requires {} { main : InternalProgram }
-    exposes []
+    exposes [Arg, Dir, Env, File, FileMetadata, Http, Path, Program, Stderr, Stdin, Stdout, Task, Url]
     packages {}
     imports [Effect.{ Effect }, InternalProgram.{ InternalProgram }]
     provides [mainForHost]